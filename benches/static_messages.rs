@@ -0,0 +1,86 @@
+// logging-rs static message benchmark
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Number of messages logged per run.
+const ITERATIONS: u32 = 1_000_000;
+
+
+//////////
+// SINK //
+//////////
+
+/// Sink counting the messages it receives, accepting static messages.
+struct Counter(AtomicUsize);
+
+impl logging_rs::sinks::Sink for Counter {
+    fn write(&self, _formatter: &logging_rs::Formatter, _record: &logging_rs::Record) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn write_static(&self, _formatter: &logging_rs::Formatter, _level: logging_rs::Level, _message: &'static str, _path: &'static str) -> bool {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+}
+
+
+//////////
+// MAIN //
+//////////
+
+/// Measures the average time of the given operation.
+fn measure<F: Fn()>(name: &str, operation: F) -> Duration {
+    let start: Instant = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        operation();
+    }
+
+    let average: Duration = start.elapsed() / ITERATIONS;
+    println!("{:<24} {:>8} ns/message", name, average.as_nanos());
+
+    average
+}
+
+fn main() {
+    let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+    logger.add_sink(Counter(AtomicUsize::new(0)));
+
+    let dynamic: Duration = measure("log()", || logger.log(std::hint::black_box("Cache hit"), logging_rs::Level::INFO, file!(), vec![]));
+    let fast: Duration = measure("log_static()", || logger.log_static(std::hint::black_box("Cache hit"), logging_rs::Level::INFO, file!()));
+    measure("info!()", || logging_rs::info!(logger, "Cache hit"));
+
+    println!("log_static() is {:.1}x faster", dynamic.as_secs_f64() / fast.as_secs_f64().max(f64::EPSILON));
+}
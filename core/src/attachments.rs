@@ -0,0 +1,254 @@
+#![doc = include_str!("../../.github/attachments.md")]
+// logging-rs attachments
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Maximum number of bytes stored per attachment. Larger payloads are cut off.
+pub const MAX_ATTACHMENT_SIZE: usize = 64 * 1024;
+
+/// Number of bytes shown when rendering attachments in text formats.
+pub const PREVIEW_SIZE: usize = 32;
+
+/// Characters used for base64 encoding.
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+
+////////////////
+// ATTACHMENT //
+////////////////
+
+/// Binary attachment object.
+///
+/// Attachments carry binary payloads, like protocol frames, alongside a record. They are rendered as a truncated hex
+/// dump by text formats and as base64 by structured formats.
+///
+/// Use [`Attachment::new()`] to create attachment objects instead of using this struct.
+///
+/// # Parameters
+///
+/// - `name`: The attachment name, used as formatting argument name
+/// - `data`: The stored bytes, at most [`MAX_ATTACHMENT_SIZE`] bytes
+/// - `size`: The size of the original payload in bytes
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::attachments::Attachment {
+///     name: "frame".to_owned(),
+///     data: vec![0xde, 0xad, 0xbe, 0xef],
+///     size: 4
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Attachment {
+    /// The attachment name, used as formatting argument name
+    pub name: String,
+    /// The stored bytes
+    pub data: Vec<u8>,
+    /// The size of the original payload in bytes
+    pub size: usize,
+}
+
+impl Attachment {
+    /// Creates a new attachment object.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The attachment name, used as formatting argument name
+    /// - `data`: The payload. Only the first [`MAX_ATTACHMENT_SIZE`] bytes are stored
+    ///
+    /// # Returns
+    ///
+    /// A new `Attachment` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::attachments::Attachment::new("frame", &[0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Attachment`]
+    pub fn new(name: &str, data: &[u8]) -> Attachment {
+        Attachment {
+            name: name.to_owned(),
+            data: data[..data.len().min(MAX_ATTACHMENT_SIZE)].to_vec(),
+            size: data.len()
+        }
+    }
+
+    /// Renders the attachment as hex, showing at most the given number of bytes.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The attachment object
+    /// - `max_bytes`: The maximum number of bytes to show
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the space separated hex bytes, followed by the number of omitted bytes if truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let attachment: logging_rs::attachments::Attachment = logging_rs::attachments::Attachment::new("frame", &[0xde, 0xad, 0xbe, 0xef]);
+    /// assert_eq!(attachment.to_hex(2), "de ad ... (+2 bytes)");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Attachment`]
+    pub fn to_hex(&self, max_bytes: usize) -> String {
+        let shown: &[u8] = &self.data[..self.data.len().min(max_bytes)];
+        let mut result: String = shown.iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<String>>().join(" ");
+
+        if self.size > shown.len() {
+            result.push_str(&format!(" ... (+{} bytes)", self.size - shown.len()));
+        }
+
+        result
+    }
+
+    /// Renders the stored bytes as base64.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The attachment object
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the padded base64 encoding of the stored bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let attachment: logging_rs::attachments::Attachment = logging_rs::attachments::Attachment::new("frame", b"bytes");
+    /// assert_eq!(attachment.to_base64(), "Ynl0ZXM=");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Attachment`]
+    pub fn to_base64(&self) -> String {
+        encode_base64(&self.data)
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Renders the given bytes as a classic hex dump with offsets, hex bytes and ASCII columns.
+///
+/// Non printable characters as well as `{` and `}` (to avoid creating formatting arguments) are shown as `.`.
+///
+/// # Parameters
+///
+/// - `data`: The bytes to render
+///
+/// # Returns
+///
+/// A `String` containing one line per 16 bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(
+///     logging_rs::attachments::hexdump(b"Hello"),
+///     "00000000  48 65 6c 6c 6f                                    |Hello|"
+/// );
+/// ```
+///
+/// # See also
+///
+/// - [`crate::hexdump!()`]
+pub fn hexdump(data: &[u8]) -> String {
+    let mut lines: Vec<String> = vec![];
+
+    for (index, chunk) in data.chunks(16).enumerate() {
+        let mut hex: String = String::new();
+
+        for (position, byte) in chunk.iter().enumerate() {
+            if position == 8 {
+                hex.push(' ');
+            }
+
+            hex.push_str(&format!("{:02x} ", byte));
+        }
+
+        let ascii: String = chunk.iter().map(|byte| match byte {
+            b'{' | b'}' => '.',
+            0x20..=0x7e => *byte as char,
+            _ => '.'
+        }).collect();
+
+        lines.push(format!("{:08x}  {:<50}|{}|", index * 16, hex, ascii));
+    }
+
+    lines.join("\n")
+}
+
+/// Encodes the given bytes as padded base64.
+///
+/// # Parameters
+///
+/// - `data`: The bytes to encode
+///
+/// # Returns
+///
+/// A `String` containing the base64 encoding.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::attachments::encode_base64(b"hi"), "aGk=");
+/// ```
+pub fn encode_base64(data: &[u8]) -> String {
+    let mut result: String = String::with_capacity((data.len() + 2) / 3 * 4);
+
+    for chunk in data.chunks(3) {
+        let bytes: [u8; 3] = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let indices: [u8; 4] = [bytes[0] >> 2, ((bytes[0] & 0b11) << 4) | (bytes[1] >> 4), ((bytes[1] & 0b1111) << 2) | (bytes[2] >> 6), bytes[2] & 0b111111];
+
+        for (index, value) in indices.iter().enumerate() {
+            if index <= chunk.len() {
+                result.push(BASE64_ALPHABET[*value as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+
+    result
+}
@@ -0,0 +1,226 @@
+#![doc = include_str!("../../.github/benchmark.md")]
+// logging-rs benchmark
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::time::{Duration, Instant};
+
+use crate::{Level, Logger, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The time the sinks are given to flush after the benchmark at most.
+pub const FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+
+//////////////////////
+// BENCHMARK REPORT //
+//////////////////////
+
+/// Report of [`Logger::self_benchmark()`].
+///
+/// Latencies are the times single logging calls took. The elapsed time includes flushing all sinks afterwards, so
+/// buffering sinks do not appear faster than they are.
+///
+/// # Parameters
+///
+/// - `records`: The number of logged records
+/// - `elapsed`: The time logging and flushing took
+/// - `p50`: The median latency of a logging call
+/// - `p99`: The 99th percentile latency of a logging call
+/// - `max`: The highest latency of a logging call
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::benchmark::BenchmarkReport {
+///     records: 1000,
+///     elapsed: std::time::Duration::from_millis(20),
+///     p50: std::time::Duration::from_micros(12),
+///     p99: std::time::Duration::from_micros(80),
+///     max: std::time::Duration::from_micros(450)
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BenchmarkReport {
+    /// The number of logged records
+    pub records: usize,
+    /// The time logging and flushing took
+    pub elapsed: Duration,
+    /// The median latency of a logging call
+    pub p50: Duration,
+    /// The 99th percentile latency of a logging call
+    pub p99: Duration,
+    /// The highest latency of a logging call
+    pub max: Duration,
+}
+
+impl BenchmarkReport {
+    /// Returns the number of records logged per second.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The benchmark report object
+    ///
+    /// # Returns
+    ///
+    /// The number of records per second, including flushing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let report: logging_rs::benchmark::BenchmarkReport = logging_rs::benchmark::BenchmarkReport {
+    ///     records: 500,
+    ///     elapsed: std::time::Duration::from_millis(250),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(report.per_second(), 2000.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`BenchmarkReport`]
+    pub fn per_second(&self) -> f64 {
+        self.records as f64 / self.elapsed.as_secs_f64()
+    }
+
+    /// Returns the report as structured record.
+    ///
+    /// The record has the arguments `records`, `per_second`, and `elapsed_us`, `p50_us`, `p99_us` and `max_us` in
+    /// microseconds, so it can be logged and compared across configurations.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The benchmark report object
+    ///
+    /// # Returns
+    ///
+    /// A [`Level::INFO`] [`Record`] containing the report.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let report: logging_rs::benchmark::BenchmarkReport = logging_rs::benchmark::BenchmarkReport::default();
+    ///
+    /// logger.log_record(&report.to_record());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::self_benchmark()`]
+    pub fn to_record(&self) -> Record {
+        Record::new(
+            Level::INFO,
+            "Logged {{records}} records at {{per_second}} per second with a p99 latency of {{p99_us}}us",
+            file!(),
+            vec![
+                ("records", self.records.to_string()),
+                ("per_second", format!("{:.0}", self.per_second())),
+                ("elapsed_us", self.elapsed.as_micros().to_string()),
+                ("p50_us", self.p50.as_micros().to_string()),
+                ("p99_us", self.p99.as_micros().to_string()),
+                ("max_us", self.max.as_micros().to_string())
+            ]
+        )
+    }
+}
+
+
+///////////////
+// BENCHMARK //
+///////////////
+
+impl Logger {
+    /// Measures the throughput and latency of the logger.
+    ///
+    /// Logs the given number of [`Level::MESSAGE`] records with two arguments through the outputs, sinks and filters
+    /// of the logger, then flushes all sinks. The records are written like any other record, so use a logger
+    /// configured like the one to measure, for example with the file outputs pointing to a temporary directory.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `count`: The number of records to log
+    ///
+    /// # Returns
+    ///
+    /// A [`BenchmarkReport`] containing the measurements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+    /// logger.add_sink(logging_rs::sinks::coalesced(logging_rs::Output::STDERR, 100));
+    ///
+    /// let report: logging_rs::benchmark::BenchmarkReport = logger.self_benchmark(1000);
+    /// println!("{:.0} records per second, p99 {:?}", report.per_second(), report.p99);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`BenchmarkReport::to_record()`]
+    pub fn self_benchmark(&self, count: usize) -> BenchmarkReport {
+        let mut latencies: Vec<Duration> = Vec::with_capacity(count);
+        let start: Instant = Instant::now();
+
+        for sequence in 0..count {
+            let call: Instant = Instant::now();
+            self.log("Benchmark record {{sequence}} of {{count}}", Level::MESSAGE, file!(), vec![("sequence", sequence.to_string()), ("count", count.to_string())]);
+            latencies.push(call.elapsed());
+        }
+
+        self.shutdown(FLUSH_TIMEOUT);
+
+        let elapsed: Duration = start.elapsed();
+        latencies.sort_unstable();
+
+        BenchmarkReport {
+            records: count,
+            elapsed,
+            p50: percentile(&latencies, 50),
+            p99: percentile(&latencies, 99),
+            max: latencies.last().copied().unwrap_or_default()
+        }
+    }
+}
+
+/// Returns the given percentile of the sorted latencies using the nearest rank.
+fn percentile(latencies: &[Duration], percent: usize) -> Duration {
+    if latencies.is_empty() {
+        return Duration::ZERO;
+    }
+
+    latencies[((latencies.len() * percent + 99) / 100).max(1) - 1]
+}
@@ -0,0 +1,321 @@
+#![doc = include_str!("../../.github/bridge.md")]
+// logging-rs bridge
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::RwLock;
+use std::time::Duration;
+
+use crate::{errors, filter, Level, Logger, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The time [`log::Log::flush()`] waits for the sinks of the logger at most.
+pub const FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
+
+
+////////////////
+// LOG BRIDGE //
+////////////////
+
+/// Bridge forwarding records of the `log` crate to a logger.
+///
+/// Records are logged with the level, message and file of the `log` record, and its target as `target` argument.
+/// [`log::Level::Trace`] records are logged as [`Level::DEBUG`].
+///
+/// Use [`LogBridge::new()`] to create log bridge objects.
+///
+/// # Parameters
+///
+/// - `logger`: The [`Logger`] to forward records to
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::bridge::LogBridge {
+///     logger: logging_rs::Logger::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct LogBridge {
+    /// The logger to forward records to
+    pub logger: Logger,
+}
+
+impl LogBridge {
+    /// Creates a new log bridge object.
+    ///
+    /// # Parameters
+    ///
+    /// - `logger`: The [`Logger`] to forward records to
+    ///
+    /// # Returns
+    ///
+    /// A new `LogBridge` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::bridge::LogBridge::new(logging_rs::Logger::default());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`install_as_log_backend()`]
+    pub fn new(logger: Logger) -> LogBridge {
+        LogBridge {
+            logger
+        }
+    }
+}
+
+impl log::Log for LogBridge {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        self.logger.is_enabled(level(metadata.level()))
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let record: Record = Record::new(
+            level(record.level()),
+            &record.args().to_string(),
+            record.file().unwrap_or(record.target()),
+            vec![("target", record.target().to_owned())]
+        );
+
+        self.logger.log_record(&record);
+    }
+
+    fn flush(&self) {
+        self.logger.shutdown(FLUSH_TIMEOUT);
+    }
+}
+
+
+/// The bridge installed using [`install_as_log_backend()`].
+static INSTALLED: RwLock<Option<LogBridge>> = RwLock::new(None);
+
+/// Backend of the `log` crate forwarding records to the [`INSTALLED`] bridge.
+struct Installed;
+
+impl log::Log for Installed {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        INSTALLED.read().unwrap_or_else(|error| error.into_inner()).as_ref().is_some_and(|bridge| bridge.enabled(metadata))
+    }
+
+    fn log(&self, record: &log::Record) {
+        if let Some(bridge) = INSTALLED.read().unwrap_or_else(|error| error.into_inner()).as_ref() {
+            bridge.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(bridge) = INSTALLED.read().unwrap_or_else(|error| error.into_inner()).as_ref() {
+            log::Log::flush(bridge);
+        }
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Returns the level matching the given level of the `log` crate.
+///
+/// # Parameters
+///
+/// - `level`: The [`log::Level`]
+///
+/// # Returns
+///
+/// The matching [`Level`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::bridge::level(log::Level::Warn), logging_rs::Level::WARN);
+/// assert_eq!(logging_rs::bridge::level(log::Level::Trace), logging_rs::Level::DEBUG);
+/// ```
+///
+/// # See also
+///
+/// - [`level_filter()`]
+pub fn level(level: log::Level) -> Level {
+    match level {
+        log::Level::Error => Level::ERROR,
+        log::Level::Warn => Level::WARN,
+        log::Level::Info => Level::INFO,
+        log::Level::Debug | log::Level::Trace => Level::DEBUG
+    }
+}
+
+/// Returns the level filter of the `log` crate letting records of the given minimum level pass.
+///
+/// # Parameters
+///
+/// - `level`: The minimum [`Level`]
+///
+/// # Returns
+///
+/// The matching [`log::LevelFilter`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::bridge::level_filter(logging_rs::Level::INFO), log::LevelFilter::Info);
+/// assert_eq!(logging_rs::bridge::level_filter(logging_rs::filter::OFF), log::LevelFilter::Off);
+/// ```
+///
+/// # See also
+///
+/// - [`level()`]
+pub fn level_filter(level: Level) -> log::LevelFilter {
+    match level.severity() {
+        severity if severity == filter::OFF.severity() => log::LevelFilter::Off,
+        severity if severity > Level::WARN.severity() => log::LevelFilter::Error,
+        severity if severity > Level::INFO.severity() => log::LevelFilter::Warn,
+        severity if severity > Level::DEBUG.severity() => log::LevelFilter::Info,
+        _ => log::LevelFilter::Trace
+    }
+}
+
+/// Returns the maximum level of the `log` crate matching the lowest level the given logger logs.
+///
+/// # Parameters
+///
+/// - `logger`: The [`Logger`]
+///
+/// # Returns
+///
+/// The [`log::LevelFilter`] of the level of the logger, or of its [`filter::EnvFilter`] if that is higher.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let logger: logging_rs::Logger = logging_rs::Logger::default().with_filter(logging_rs::filter::EnvFilter::parse("warn").unwrap());
+/// assert_eq!(logging_rs::bridge::max_level(&logger), log::LevelFilter::Warn);
+/// ```
+///
+/// # See also
+///
+/// - [`level_filter()`]
+pub fn max_level(logger: &Logger) -> log::LevelFilter {
+    match logger.filter.min_level() {
+        Some(minimum) if minimum > logger.level => level_filter(minimum),
+        _ => level_filter(logger.level)
+    }
+}
+
+/// Installs the given logger as the backend of the `log` crate.
+///
+/// The maximum level of the `log` crate is set to the lowest level the logger logs, so records below it are skipped
+/// without being formatted. Use [`reconfigure()`] to change the logger afterwards, keeping the maximum level in sync.
+///
+/// # Parameters
+///
+/// - `logger`: The [`Logger`] to forward records to
+///
+/// # Returns
+///
+/// A `Result` indicating whether the logger was installed.
+///
+/// # Errors
+///
+/// Returns an initialization error if a backend of the `log` crate was already installed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::bridge::install_as_log_backend(logging_rs::Logger::default()).unwrap();
+///
+/// log::info!("Logged using the log crate");
+/// ```
+///
+/// # See also
+///
+/// - [`LogBridge`]
+/// - [`reconfigure()`]
+pub fn install_as_log_backend(logger: Logger) -> Result<(), errors::Error> {
+    log::set_logger(&Installed).map_err(|error: log::SetLoggerError| errors::Error::new("Initialization error", &error.to_string(), 7))?;
+
+    log::set_max_level(max_level(&logger));
+    *INSTALLED.write().unwrap_or_else(|error| error.into_inner()) = Some(LogBridge::new(logger));
+
+    Ok(())
+}
+
+/// Changes the logger installed as backend of the `log` crate and updates the maximum level of the `log` crate.
+///
+/// # Parameters
+///
+/// - `update`: Function changing the installed [`Logger`], for example its level or filter
+///
+/// # Returns
+///
+/// A `Result` indicating whether the logger was changed.
+///
+/// # Errors
+///
+/// Returns an initialization error if no logger was installed using [`install_as_log_backend()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::bridge::install_as_log_backend(logging_rs::Logger::default().with_level(logging_rs::Level::WARN)).unwrap();
+///
+/// logging_rs::bridge::reconfigure(|logger: &mut logging_rs::Logger| logger.level = logging_rs::Level::DEBUG).unwrap();
+/// assert_eq!(log::max_level(), log::LevelFilter::Trace);
+/// ```
+///
+/// # See also
+///
+/// - [`install_as_log_backend()`]
+/// - [`max_level()`]
+pub fn reconfigure<F: FnOnce(&mut Logger)>(update: F) -> Result<(), errors::Error> {
+    let mut installed = INSTALLED.write().unwrap_or_else(|error| error.into_inner());
+    let Some(bridge) = installed.as_mut() else {
+        return Err(errors::Error::new("Initialization error", "No logger was installed as backend of the log crate", 7));
+    };
+
+    update(&mut bridge.logger);
+    log::set_max_level(max_level(&bridge.logger));
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+#![doc = include_str!("../../.github/build.md")]
+// logging-rs build
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::process::Command;
+use std::sync::RwLock;
+
+
+////////////////
+// BUILD INFO //
+////////////////
+
+/// Build information object.
+///
+/// Once set using [`set_build_info()`], the fields are available as `{{build.sha}}`, `{{build.time}}` and
+/// `{{build.profile}}` formatting arguments.
+///
+/// Use the [`crate::build_info!()`] macro to create build information objects from the values embedded by
+/// [`emit()`].
+///
+/// # Parameters
+///
+/// - `sha`: The git commit hash the binary was built from
+/// - `time`: The UTC time the binary was built at
+/// - `profile`: The cargo profile used for building
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::build::BuildInfo {
+///     sha: "8e3bb57".to_owned(),
+///     time: "2023-11-27T20:49:47Z".to_owned(),
+///     profile: "release".to_owned()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BuildInfo {
+    pub sha: String,
+    pub time: String,
+    pub profile: String
+}
+
+impl BuildInfo {
+    /// Creates a new build information object.
+    ///
+    /// # Parameters
+    ///
+    /// - `sha`: The git commit hash the binary was built from
+    /// - `time`: The UTC time the binary was built at
+    /// - `profile`: The cargo profile used for building
+    ///
+    /// # Returns
+    ///
+    /// A new `BuildInfo` object with the specified values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::build::BuildInfo::new("8e3bb57", "2023-11-27T20:49:47Z", "release");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`BuildInfo`]
+    /// - [`crate::build_info!()`]
+    pub fn new(sha: &str, time: &str, profile: &str) -> BuildInfo {
+        BuildInfo {
+            sha: sha.to_owned(),
+            time: time.to_owned(),
+            profile: profile.to_owned()
+        }
+    }
+
+    /// Returns the formatting arguments of the build information.
+    pub(crate) fn arguments(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("build.sha", self.sha.clone()),
+            ("build.time", self.time.clone()),
+            ("build.profile", self.profile.clone())
+        ]
+    }
+}
+
+/// The build information added to formatted messages.
+static BUILD_INFO: RwLock<Option<BuildInfo>> = RwLock::new(None);
+
+/// Sets the build information available as formatting arguments.
+///
+/// # Parameters
+///
+/// - `info`: The [`BuildInfo`] to use
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::build::set_build_info(logging_rs::build_info!());
+/// ```
+///
+/// # See also
+///
+/// - [`build_info()`]
+/// - [`crate::build_info!()`]
+pub fn set_build_info(info: BuildInfo) {
+    *BUILD_INFO.write().unwrap_or_else(|error| error.into_inner()) = Some(info);
+}
+
+/// Returns the build information set using [`set_build_info()`].
+///
+/// # Returns
+///
+/// The [`BuildInfo`] or `None` if not set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// if let Some(info) = logging_rs::build::build_info() {
+///     println!("Built from {}", info.sha);
+/// }
+/// ```
+///
+/// # See also
+///
+/// - [`set_build_info()`]
+pub fn build_info() -> Option<BuildInfo> {
+    BUILD_INFO.read().unwrap_or_else(|error| error.into_inner()).clone()
+}
+
+
+//////////////////
+// BUILD SCRIPT //
+//////////////////
+
+/// Embeds the build information into the crate being built.
+///
+/// Call this function from the `build.rs` of your crate (with `logging-rs` as build dependency), then use
+/// [`crate::build_info!()`] to read the embedded values:
+///
+/// ```rust,ignore
+/// // build.rs
+/// fn main() {
+///     logging_rs::build::emit();
+/// }
+///
+/// // src/main.rs
+/// fn main() {
+///     logging_rs::build::set_build_info(logging_rs::build_info!());
+/// }
+/// ```
+///
+/// The git commit hash is `unknown` if git is not available or the crate is not in a git repository.
+///
+/// # See also
+///
+/// - [`crate::build_info!()`]
+/// - [`set_build_info()`]
+pub fn emit() {
+    let sha: String = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+        .unwrap_or_else(|| "unknown".to_owned());
+
+    println!("cargo:rustc-env=LOGGING_RS_BUILD_SHA={}", sha);
+    println!("cargo:rustc-env=LOGGING_RS_BUILD_TIME={}", chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+    println!("cargo:rustc-env=LOGGING_RS_BUILD_PROFILE={}", std::env::var("PROFILE").unwrap_or_else(|_| "unknown".to_owned()));
+}
@@ -0,0 +1,886 @@
+#![doc = include_str!("../../.github/builder.md")]
+// logging-rs builder
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::{errors, filter, sinks, Formatter, Level, Logger, Output, TimeZone};
+
+
+///////////////////
+// EMPTY OUTPUTS //
+///////////////////
+
+/// Behaviors for loggers built without outputs and sinks, see [`LoggerBuilder::empty_outputs()`].
+///
+/// Loggers without outputs and sinks silently drop all records except mirrored fatal ones, which is rarely intended.
+/// The behavior only applies to [`LoggerBuilder::build()`] and loggers created from configuration. [`Logger::new()`]
+/// keeps the given outputs, so sinks can be added to loggers created without outputs.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "lowercase"))]
+pub enum EmptyOutputs {
+    /// Writes to stdout, like [`Logger::default()`]. The default value
+    #[default]
+    STDOUT,
+    /// Writes to stderr, keeping stdout free for the output of the program
+    STDERR,
+    /// Drops all records, except fatal records mirrored to stderr
+    DROP,
+    /// Fails to build the logger with a configuration error
+    ERROR,
+}
+
+
+////////////////////
+// LOGGER BUILDER //
+////////////////////
+
+/// Builder object for loggers.
+///
+/// Configuration errors, like invalid timestamp formats or outputs added twice, are reported by
+/// [`LoggerBuilder::build()`] instead of surfacing while logging. Loggers built without outputs and sinks write to
+/// stdout, like [`Logger::default()`], unless configured otherwise using [`LoggerBuilder::empty_outputs()`].
+///
+/// Use [`Logger::builder()`] to create logger builder objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let path: String = std::env::temp_dir().join("logging_rs_doc_builder.log").to_str().unwrap().to_owned();
+/// let logger: logging_rs::Logger = logging_rs::Logger::builder()
+///     .level(logging_rs::Level::INFO)
+///     .output(logging_rs::Output::STDOUT)
+///     .output_file(&path)
+///     .formatter(logging_rs::Formatter::default())
+///     .build()
+///     .unwrap();
+/// # std::fs::remove_file(path).ok();
+/// ```
+#[derive(Clone, Debug)]
+pub struct LoggerBuilder {
+    /// The logger being built.
+    logger: Logger,
+    /// The behavior if no outputs and sinks were added.
+    empty_outputs: EmptyOutputs,
+}
+
+impl Default for LoggerBuilder {
+    fn default() -> LoggerBuilder {
+        LoggerBuilder::new()
+    }
+}
+
+impl LoggerBuilder {
+    /// Creates a new logger builder object without outputs.
+    ///
+    /// # Returns
+    ///
+    /// A new `LoggerBuilder` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::builder::LoggerBuilder::new();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::builder()`]
+    pub fn new() -> LoggerBuilder {
+        LoggerBuilder {
+            logger: Logger::new(Formatter::default(), vec![]),
+            empty_outputs: EmptyOutputs::STDOUT
+        }
+    }
+
+    /// Sets the minimum level of logged records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `level`: The minimum [`Level`]
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::builder().level(logging_rs::Level::WARN);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::with_level()`]
+    pub fn level(mut self, level: Level) -> LoggerBuilder {
+        self.logger.level = level;
+        self
+    }
+
+    /// Sets the formatter.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `formatter`: The [`Formatter`] to use
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::builder().formatter(logging_rs::Formatter::new("{{level}} {{message}}", "{{level}} {{message}}", ""));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter`]
+    pub fn formatter(mut self, formatter: Formatter) -> LoggerBuilder {
+        self.logger.formatter = formatter;
+        self
+    }
+
+    /// Adds an output to write to.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `output`: The [`Output`] to add
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::builder().output(logging_rs::Output::STDERR);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`LoggerBuilder::output_file()`]
+    pub fn output(mut self, output: Output) -> LoggerBuilder {
+        self.logger.writable_list.push(output);
+        self
+    }
+
+    /// Adds a file to write to.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `path`: The path of the file
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::builder().output_file("app.log");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`LoggerBuilder::output()`]
+    pub fn output_file(self, path: &str) -> LoggerBuilder {
+        self.output(Output::FILE { path: path.to_owned() })
+    }
+
+    /// Adds a sink to write to.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `sink`: The [`sinks::Sink`] to add
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::builder().sink(logging_rs::sinks::Json::new(logging_rs::Output::STDOUT));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::add_sink()`]
+    /// - [`LoggerBuilder::named_sink()`]
+    pub fn sink<S: sinks::Sink + 'static>(mut self, sink: S) -> LoggerBuilder {
+        self.logger.add_sink(sink);
+        self
+    }
+
+    /// Adds a named sink to write to.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `name`: The name of the sink
+    /// - `sink`: The [`sinks::Sink`] to add
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::builder().named_sink("audit", logging_rs::Output::STDERR);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::add_named_sink()`]
+    pub fn named_sink<S: sinks::Sink + 'static>(mut self, name: &str, sink: S) -> LoggerBuilder {
+        self.logger.add_named_sink(name, sink);
+        self
+    }
+
+    /// Sets the per-target filter.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `filter`: The [`filter::EnvFilter`] to use
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::builder().filter(logging_rs::filter::EnvFilter::parse("info,hyper=off").unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::with_filter()`]
+    pub fn filter(mut self, filter: filter::EnvFilter) -> LoggerBuilder {
+        self.logger.filter = filter;
+        self
+    }
+
+    /// Sets whether fatal records are always written to stderr as well.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `mirror_fatal`: Whether to mirror fatal records
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::builder().mirror_fatal(false);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger`]
+    pub fn mirror_fatal(mut self, mirror_fatal: bool) -> LoggerBuilder {
+        self.logger.mirror_fatal = mirror_fatal;
+        self
+    }
+
+    /// Sets the behavior if neither outputs nor sinks were added.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    /// - `empty_outputs`: The [`EmptyOutputs`] behavior
+    ///
+    /// # Returns
+    ///
+    /// The logger builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::Logger::builder().empty_outputs(logging_rs::builder::EmptyOutputs::ERROR).build().is_err());
+    ///
+    /// let logger: logging_rs::Logger = logging_rs::Logger::builder().empty_outputs(logging_rs::builder::EmptyOutputs::STDERR).build().unwrap();
+    /// assert_eq!(logger.writable_list, vec![logging_rs::Output::STDERR]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`LoggerBuilder::build()`]
+    pub fn empty_outputs(mut self, empty_outputs: EmptyOutputs) -> LoggerBuilder {
+        self.empty_outputs = empty_outputs;
+        self
+    }
+
+    /// Checks the configuration and builds the logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger builder object
+    ///
+    /// # Returns
+    ///
+    /// The configured [`Logger`]. If neither outputs nor sinks were added, it writes to stdout or stderr, or drops all
+    /// records, depending on the [`EmptyOutputs`] behavior.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if an output was added twice, a file output has an empty path or no outputs
+    /// were added with [`EmptyOutputs::ERROR`], and a format error if the timestamp format is invalid, see
+    /// [`Formatter::validate()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::Logger::builder().build().is_ok());
+    /// assert!(logging_rs::Logger::builder().output(logging_rs::Output::STDOUT).output(logging_rs::Output::STDOUT).build().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::builder()`]
+    pub fn build(mut self) -> Result<Logger, errors::Error> {
+        self.logger.formatter.validate()?;
+
+        for (index, output) in self.logger.writable_list.iter().enumerate() {
+            if matches!(output, Output::FILE { path } if path.is_empty()) {
+                return Err(errors::Error::new("Configuration error", "File outputs need a path", 10));
+            }
+
+            if self.logger.writable_list[..index].contains(output) {
+                return Err(errors::Error::new("Configuration error", &format!("The output {:?} was added more than once", output), 10));
+            }
+        }
+
+        if self.logger.writable_list.is_empty() && self.logger.sinks.is_empty() {
+            match self.empty_outputs {
+                EmptyOutputs::STDOUT => self.logger.writable_list.push(Output::STDOUT),
+                EmptyOutputs::STDERR => self.logger.writable_list.push(Output::STDERR),
+                EmptyOutputs::DROP => {},
+                EmptyOutputs::ERROR => {
+                    return Err(errors::Error::new("Configuration error", "The logger has no outputs or sinks", 10));
+                }
+            }
+        }
+
+        Ok(self.logger)
+    }
+}
+
+
+///////////////////////
+// FORMATTER BUILDER //
+///////////////////////
+
+/// Part of a format string built by a [`FormatterBuilder`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Part {
+    /// A placeholder, written with the given color in colored outputs.
+    Placeholder { name: String, color: Option<&'static str> },
+    /// Literal text.
+    Text(String)
+}
+
+/// Builder object for formatters.
+///
+/// Placeholders are separated by the separator, which is a single space by default. No separator is added next to
+/// literal text.
+///
+/// Use [`Formatter::builder()`] to create formatter builder objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let formatter: logging_rs::Formatter = logging_rs::Formatter::builder()
+///     .timestamp()
+///     .level()
+///     .text(": ")
+///     .message()
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(formatter.format_string, "{{timestamp}} {{level}}: {{message}}");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FormatterBuilder {
+    /// The parts of the format strings.
+    parts: Vec<Part>,
+    /// The separator between placeholders.
+    separator: String,
+    /// The timestamp format string in strftime format.
+    timestamp_format: String,
+    /// The time zone timestamps are formatted in.
+    time_zone: TimeZone,
+    /// The builders of the format strings for single levels.
+    level_formats: Vec<(Level, FormatterBuilder)>
+}
+
+impl Default for FormatterBuilder {
+    fn default() -> FormatterBuilder {
+        FormatterBuilder::new()
+    }
+}
+
+impl FormatterBuilder {
+    /// Creates a new formatter builder object without parts.
+    ///
+    /// # Returns
+    ///
+    /// A new `FormatterBuilder` object using the timestamp format of [`Formatter::DEFAULT`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::builder::FormatterBuilder::new();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::builder()`]
+    pub fn new() -> FormatterBuilder {
+        FormatterBuilder {
+            parts: vec![],
+            separator: " ".to_owned(),
+            timestamp_format: Formatter::DEFAULT.timestamp_format.to_string(),
+            time_zone: TimeZone::UTC,
+            level_formats: vec![]
+        }
+    }
+
+    /// Adds the timestamp, written in bright blue in colored outputs.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().timestamp();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FormatterBuilder::timestamp_format()`]
+    pub fn timestamp(self) -> FormatterBuilder {
+        self.placeholder("timestamp", Some("bright_blue"))
+    }
+
+    /// Adds the level.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().level();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Level`]
+    pub fn level(self) -> FormatterBuilder {
+        self.placeholder("level", None)
+    }
+
+    /// Adds the path of the calling file.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().path();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FormatterBuilder::field()`]
+    pub fn path(self) -> FormatterBuilder {
+        self.placeholder("path", None)
+    }
+
+    /// Adds the message.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().message();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FormatterBuilder::field()`]
+    pub fn message(self) -> FormatterBuilder {
+        self.placeholder("message", None)
+    }
+
+    /// Adds an argument of the records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    /// - `name`: The name of the argument, like `uuid` or `span`
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().field("uuid");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`crate::Record`]
+    pub fn field(self, name: &str) -> FormatterBuilder {
+        self.placeholder(name, None)
+    }
+
+    /// Adds literal text.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    /// - `text`: The text to add
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().level().text(": ").message();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FormatterBuilder::separator()`]
+    pub fn text(mut self, text: &str) -> FormatterBuilder {
+        self.parts.push(Part::Text(text.to_owned()));
+        self
+    }
+
+    /// Sets the separator between placeholders.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    /// - `separator`: The separator to use
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().separator(" | ").level().message();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FormatterBuilder::text()`]
+    pub fn separator(mut self, separator: &str) -> FormatterBuilder {
+        self.separator = separator.to_owned();
+        self
+    }
+
+    /// Sets the timestamp format.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    /// - `timestamp_format`: Timestamp format string in strftime format
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().timestamp_format("%H:%M:%S").timestamp();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FormatterBuilder::timestamp()`]
+    pub fn timestamp_format(mut self, timestamp_format: &str) -> FormatterBuilder {
+        self.timestamp_format = timestamp_format.to_owned();
+        self
+    }
+
+    /// Sets the time zone timestamps are formatted in.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    /// - `time_zone`: The [`TimeZone`]
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::builder().time_zone(logging_rs::TimeZone::LOCAL).timestamp();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::with_time_zone()`]
+    pub fn time_zone(mut self, time_zone: TimeZone) -> FormatterBuilder {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// Uses the parts of the given builder for records of the given level.
+    ///
+    /// The separator, timestamp format and time zone of the given builder are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    /// - `level`: The [`Level`] the parts are used for
+    /// - `format`: The formatter builder object with the parts
+    ///
+    /// # Returns
+    ///
+    /// The formatter builder object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let formatter: logging_rs::Formatter = logging_rs::Formatter::builder()
+    ///     .timestamp()
+    ///     .level()
+    ///     .message()
+    ///     .level_format(logging_rs::Level::DEBUG, logging_rs::Formatter::builder().message())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(formatter.level_formats[0].format_string, "{{message}}");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::with_level_format()`]
+    pub fn level_format(mut self, level: Level, format: FormatterBuilder) -> FormatterBuilder {
+        self.level_formats.retain(|(other, _)| *other != level);
+        self.level_formats.push((level, format));
+        self
+    }
+
+    /// Checks the timestamp format and builds the formatter.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter builder object
+    ///
+    /// # Returns
+    ///
+    /// The [`Formatter`] with the built format strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a format error if the timestamp format is invalid, see [`Formatter::validate()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let formatter: logging_rs::Formatter = logging_rs::Formatter::builder().level().message().build().unwrap();
+    ///
+    /// assert_eq!(formatter.format_string, "{{level}} {{message}}");
+    /// assert!(logging_rs::Formatter::builder().timestamp_format("%Q").build().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::try_new()`]
+    pub fn build(self) -> Result<Formatter, errors::Error> {
+        let (color_format_string, format_string): (String, String) = self.render(&self.separator);
+        let mut formatter: Formatter = Formatter::try_new(&color_format_string, &format_string, &self.timestamp_format)?.with_time_zone(self.time_zone);
+
+        for (level, builder) in &self.level_formats {
+            let (color_format_string, format_string): (String, String) = builder.render(&self.separator);
+            formatter = formatter.with_level_format(*level, &color_format_string, &format_string);
+        }
+
+        Ok(formatter)
+    }
+
+    /// Renders the color and plain format strings of the parts using the given separator.
+    fn render(&self, separator: &str) -> (String, String) {
+        let mut color_format_string: String = String::new();
+        let mut format_string: String = String::new();
+        let mut previous: Option<&Part> = None;
+
+        for part in &self.parts {
+            if let (Some(Part::Placeholder { .. }), Part::Placeholder { .. }) = (previous, part) {
+                color_format_string.push_str(separator);
+                format_string.push_str(separator);
+            }
+
+            match part {
+                Part::Placeholder { name, color: Some(color) } => {
+                    color_format_string.push_str(&format!("{{{{color.{}}}}}{{{{{}}}}}{{{{end}}}}", color, name));
+                    format_string.push_str(&format!("{{{{{}}}}}", name));
+                }
+                Part::Placeholder { name, color: None } => {
+                    color_format_string.push_str(&format!("{{{{{}}}}}", name));
+                    format_string.push_str(&format!("{{{{{}}}}}", name));
+                }
+                Part::Text(text) => {
+                    color_format_string.push_str(text);
+                    format_string.push_str(text);
+                }
+            }
+
+            previous = Some(part);
+        }
+
+        (color_format_string, format_string)
+    }
+
+    /// Adds a placeholder with an optional color.
+    fn placeholder(mut self, name: &str, color: Option<&'static str>) -> FormatterBuilder {
+        self.parts.push(Part::Placeholder { name: name.to_owned(), color });
+        self
+    }
+}
+
+impl Formatter {
+    /// Creates a builder for formatters, so format strings don't have to be written by hand.
+    ///
+    /// # Returns
+    ///
+    /// A new [`FormatterBuilder`] object without parts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let formatter: logging_rs::Formatter = logging_rs::Formatter::builder().level().message().build().unwrap();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FormatterBuilder`]
+    /// - [`Formatter::compact()`]
+    pub fn builder() -> FormatterBuilder {
+        FormatterBuilder::new()
+    }
+}
+
+
+////////////
+// LOGGER //
+////////////
+
+impl Logger {
+    /// Creates a builder for configuring a logger declaratively.
+    ///
+    /// # Returns
+    ///
+    /// A new [`LoggerBuilder`] object without outputs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::builder().level(logging_rs::Level::INFO).build().unwrap();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`LoggerBuilder`]
+    pub fn builder() -> LoggerBuilder {
+        LoggerBuilder::new()
+    }
+}
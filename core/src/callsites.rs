@@ -0,0 +1,426 @@
+#![doc = include_str!("../../.github/callsites.md")]
+// logging-rs callsites
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use crate::{levels, Level};
+
+#[doc(hidden)]
+pub use linkme as __linkme;
+
+
+///////////////
+// CALLSITES //
+///////////////
+
+/// The file, line and column of the disabled call sites.
+static DISABLED: RwLock<Vec<(String, u32, u32)>> = RwLock::new(Vec::new());
+
+/// Whether any call site is disabled, checked before locking [`DISABLED`].
+static ANY_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// All call sites of the logging macros, collected at link time.
+#[doc(hidden)]
+#[linkme::distributed_slice]
+pub static CALLSITES: [Callsite];
+
+/// Call site object.
+///
+/// Describes a call of a logging macro. Call sites are registered at link time, so every log statement of the
+/// binary is listed, including statements that were never executed.
+///
+/// # Parameters
+///
+/// - `file`: The path of the file containing the call
+/// - `line`: The line of the call
+/// - `column`: The column of the call
+/// - `module_path`: The path of the module containing the call
+/// - `level_expression`: The source code of the level expression
+/// - `message_expression`: The source code of the message expression
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logging_rs::warn!(logger, "Low disk space");
+///
+/// let callsite: &logging_rs::callsites::Callsite = logging_rs::callsites::callsites()
+///     .iter()
+///     .find(|callsite: &&logging_rs::callsites::Callsite| callsite.template().as_deref() == Some("Low disk space"))
+///     .unwrap();
+///
+/// assert_eq!(callsite.level(), Some(logging_rs::Level::WARN));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Callsite {
+    /// The path of the file containing the call
+    pub file: &'static str,
+    /// The line of the call
+    pub line: u32,
+    /// The column of the call
+    pub column: u32,
+    /// The path of the module containing the call
+    pub module_path: &'static str,
+    /// The source code of the level expression
+    pub level_expression: &'static str,
+    /// The source code of the message expression
+    pub message_expression: &'static str,
+}
+
+impl Callsite {
+    /// Returns the id of the call site.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The call site object
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the id in the form `file:line:column`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let callsite: logging_rs::callsites::Callsite = logging_rs::callsites::Callsite {
+    ///     file: "src/main.rs",
+    ///     line: 12,
+    ///     column: 5,
+    ///     module_path: "main",
+    ///     level_expression: "logging_rs::Level::INFO",
+    ///     message_expression: "\"Started\""
+    /// };
+    ///
+    /// assert_eq!(callsite.id(), "src/main.rs:12:5");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`disable()`]
+    /// - [`enable()`]
+    pub fn id(&self) -> String {
+        format!("{}:{}:{}", self.file, self.line, self.column)
+    }
+
+    /// Returns whether the call site is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The call site object
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the call site was not disabled using [`disable()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// for callsite in logging_rs::callsites::callsites() {
+    ///     println!("{} {}", callsite.id(), callsite.is_enabled());
+    /// }
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`disable()`]
+    /// - [`enable()`]
+    pub fn is_enabled(&self) -> bool {
+        if !ANY_DISABLED.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        !DISABLED.read().unwrap_or_else(|error| error.into_inner()).iter().any(|(file, line, column)| {
+            *line == self.line && *column == self.column && file == self.file
+        })
+    }
+
+    /// Returns the level of the call site.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The call site object
+    ///
+    /// # Returns
+    ///
+    /// The [`Level`] of the call site or `None` if the level is only known at runtime, for example when using
+    /// [`crate::log_at!()`]. Custom levels are found by the name of the constant if they are registered using
+    /// [`crate::levels::register()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let callsite: logging_rs::callsites::Callsite = logging_rs::callsites::Callsite {
+    ///     file: "src/main.rs",
+    ///     line: 1,
+    ///     column: 1,
+    ///     module_path: "main",
+    ///     level_expression: "logging_rs::Level::ERROR",
+    ///     message_expression: "\"Failed\""
+    /// };
+    ///
+    /// assert_eq!(callsite.level(), Some(logging_rs::Level::ERROR));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Callsite`]
+    pub fn level(&self) -> Option<Level> {
+        let (path, name): (&str, &str) = self.level_expression.rsplit_once("::").unwrap_or(("", self.level_expression));
+
+        if !path.replace(' ', "").ends_with("Level") {
+            return match levels::find(name.trim()) {
+                Some(level @ Level::CUSTOM { .. }) => Some(level),
+                _ => None
+            };
+        }
+
+        match name.trim() {
+            "DEBUG" => Some(Level::DEBUG),
+            "INFO" => Some(Level::INFO),
+            "WARN" => Some(Level::WARN),
+            "ERROR" => Some(Level::ERROR),
+            "FATAL" => Some(Level::FATAL),
+            "MESSAGE" => Some(Level::MESSAGE),
+            _ => None
+        }
+    }
+
+    /// Returns the message template of the call site.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The call site object
+    ///
+    /// # Returns
+    ///
+    /// The message template or `None` if the message is not a string literal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let callsite: logging_rs::callsites::Callsite = logging_rs::callsites::Callsite {
+    ///     file: "src/main.rs",
+    ///     line: 1,
+    ///     column: 1,
+    ///     module_path: "main",
+    ///     level_expression: "level",
+    ///     message_expression: r#""Hello \"{{name}}\"""#
+    /// };
+    ///
+    /// assert_eq!(callsite.template().as_deref(), Some("Hello \"{{name}}\""));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Callsite`]
+    pub fn template(&self) -> Option<String> {
+        let expression: &str = self.message_expression.trim();
+
+        if let Some(raw) = expression.strip_prefix('r') {
+            let hashes: usize = raw.len() - raw.trim_start_matches('#').len();
+            let delimiter: String = "#".repeat(hashes);
+
+            return raw.strip_prefix(&(delimiter.clone() + "\""))?.strip_suffix(&("\"".to_owned() + &delimiter)).map(str::to_owned);
+        }
+
+        let literal: &str = expression.strip_prefix('"')?.strip_suffix('"')?;
+        let mut template: String = String::with_capacity(literal.len());
+        let mut characters = literal.chars().peekable();
+
+        while let Some(character) = characters.next() {
+            if character != '\\' {
+                template.push(character);
+                continue;
+            }
+
+            match characters.next()? {
+                'n' => template.push('\n'),
+                'r' => template.push('\r'),
+                't' => template.push('\t'),
+                '0' => template.push('\0'),
+                'x' => {
+                    let code: String = characters.by_ref().take(2).collect();
+                    template.push(char::from(u8::from_str_radix(&code, 16).ok()?));
+                },
+                'u' => {
+                    let code: String = characters.by_ref().skip(1).take_while(|character| *character != '}').collect();
+                    template.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                },
+                '\n' => {
+                    while characters.peek().is_some_and(|character| character.is_whitespace()) {
+                        characters.next();
+                    }
+                },
+                character => template.push(character)
+            }
+        }
+
+        Some(template)
+    }
+}
+
+impl fmt::Display for Callsite {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}:{}:{} [{}] {}", self.file, self.line, self.column, self.level_expression, self.message_expression)
+    }
+}
+
+/// Returns all call sites of the logging macros in the binary.
+///
+/// # Returns
+///
+/// A slice containing the [`Callsite`]s in no particular order.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// for callsite in logging_rs::callsites::callsites() {
+///     println!("{}", callsite);
+/// }
+/// ```
+///
+/// # See also
+///
+/// - [`Callsite`]
+pub fn callsites() -> &'static [Callsite] {
+    &CALLSITES
+}
+
+/// Splits the given call site id into file, line and column.
+fn parse_id(id: &str) -> Option<(String, u32, u32)> {
+    let mut parts = id.rsplitn(3, ':');
+    let column: u32 = parts.next()?.parse().ok()?;
+    let line: u32 = parts.next()?.parse().ok()?;
+
+    Some((parts.next()?.to_owned(), line, column))
+}
+
+/// Disables the call site with the given id.
+///
+/// Disabled call sites log nothing and skip formatting their arguments, until they are enabled again.
+///
+/// # Parameters
+///
+/// - `id`: The id of the call site in the form `file:line:column`
+///
+/// # Returns
+///
+/// A boolean indicating whether the id was valid.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let noisy: &logging_rs::callsites::Callsite = logging_rs::callsites::callsites().iter().max_by_key(|callsite| callsite.line).unwrap();
+///
+/// logging_rs::callsites::disable(&noisy.id());
+/// assert!(!noisy.is_enabled());
+///
+/// logging_rs::callsites::enable(&noisy.id());
+/// assert!(noisy.is_enabled());
+/// # logging_rs::info!(logger, "Example");
+/// ```
+///
+/// # See also
+///
+/// - [`enable()`]
+/// - [`enable_all()`]
+/// - [`Callsite::id()`]
+pub fn disable(id: &str) -> bool {
+    let Some(callsite) = parse_id(id) else {
+        return false;
+    };
+
+    let mut disabled = DISABLED.write().unwrap_or_else(|error| error.into_inner());
+
+    if !disabled.contains(&callsite) {
+        disabled.push(callsite);
+    }
+
+    ANY_DISABLED.store(true, Ordering::Relaxed);
+    true
+}
+
+/// Enables the call site with the given id again.
+///
+/// # Parameters
+///
+/// - `id`: The id of the call site in the form `file:line:column`
+///
+/// # Returns
+///
+/// A boolean indicating whether the id was valid.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::callsites::enable("src/main.rs:12:5");
+/// ```
+///
+/// # See also
+///
+/// - [`disable()`]
+/// - [`enable_all()`]
+pub fn enable(id: &str) -> bool {
+    let Some(callsite) = parse_id(id) else {
+        return false;
+    };
+
+    let mut disabled = DISABLED.write().unwrap_or_else(|error| error.into_inner());
+    disabled.retain(|other| *other != callsite);
+
+    ANY_DISABLED.store(!disabled.is_empty(), Ordering::Relaxed);
+    true
+}
+
+/// Enables all call sites again.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::callsites::enable_all();
+/// ```
+///
+/// # See also
+///
+/// - [`disable()`]
+/// - [`enable()`]
+pub fn enable_all() {
+    DISABLED.write().unwrap_or_else(|error| error.into_inner()).clear();
+    ANY_DISABLED.store(false, Ordering::Relaxed);
+}
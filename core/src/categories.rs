@@ -0,0 +1,158 @@
+#![doc = include_str!("../../.github/categories.md")]
+// logging-rs categories
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::Logger;
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Environment variable containing a comma separated list of enabled debug categories, or `*` to enable all of them.
+pub const ENVIRONMENT_VARIABLE: &str = "LOGGING_RS_DEBUG";
+
+
+////////////////
+// CATEGORIES //
+////////////////
+
+/// Checks whether the given debug category is enabled by the [`ENVIRONMENT_VARIABLE`].
+///
+/// # Parameters
+///
+/// - `category`: The name of the category
+///
+/// # Returns
+///
+/// A boolean indicating whether the category is listed in the environment variable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// std::env::set_var("LOGGING_RS_DEBUG", "wire,cache");
+/// assert!(logging_rs::categories::enabled_by_environment("wire"));
+/// assert!(!logging_rs::categories::enabled_by_environment("sql"));
+///
+/// std::env::set_var("LOGGING_RS_DEBUG", "*");
+/// assert!(logging_rs::categories::enabled_by_environment("sql"));
+/// ```
+///
+/// # See also
+///
+/// - [`Logger::is_category_enabled()`]
+pub fn enabled_by_environment(category: &str) -> bool {
+    match std::env::var(ENVIRONMENT_VARIABLE) {
+        Ok(categories) => categories.split(',').map(str::trim).any(|name| name == "*" || name == category),
+        Err(_) => false
+    }
+}
+
+impl Logger {
+    /// Enables the given debug category.
+    ///
+    /// Records logged with [`crate::debug_cat!()`] are only written if their category is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `category`: The name of the category
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.enable_category("wire");
+    /// logging_rs::debug_cat!(logger, "wire", "Sent {{bytes}} bytes", "bytes" = 42);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::disable_category()`]
+    /// - [`crate::debug_cat!()`]
+    pub fn enable_category(&mut self, category: &str) {
+        if !self.categories.iter().any(|name| name == category) {
+            self.categories.push(category.to_owned());
+        }
+    }
+
+    /// Disables the given debug category.
+    ///
+    /// Categories enabled by the [`ENVIRONMENT_VARIABLE`] stay enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `category`: The name of the category
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.enable_category("wire");
+    /// logger.disable_category("wire");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::enable_category()`]
+    pub fn disable_category(&mut self, category: &str) {
+        self.categories.retain(|name| name != category);
+    }
+
+    /// Checks whether the given debug category is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `category`: The name of the category
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the category was enabled using [`Logger::enable_category()`] or the
+    /// [`ENVIRONMENT_VARIABLE`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.enable_category("wire");
+    /// assert!(logger.is_category_enabled("wire"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::enable_category()`]
+    /// - [`enabled_by_environment()`]
+    pub fn is_category_enabled(&self, category: &str) -> bool {
+        self.categories.iter().any(|name| name == category) || enabled_by_environment(category)
+    }
+}
@@ -0,0 +1,474 @@
+#![doc = include_str!("../../.github/codec.md")]
+// logging-rs codec
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{errors, sandbox, Formatter, Output, Record};
+#[cfg(any(feature = "gzip", feature = "lz4"))]
+use std::io::{Read, Write};
+
+
+///////////
+// CODEC //
+///////////
+
+/// Compression codec for archived log files and batches sent over the network.
+///
+/// Sinks accepting a codec use it for all compressed data, so trading CPU time for size works the same way across
+/// sinks. Codecs are implemented for [`Identity`] and, behind the features of the same names, [`Gzip`], [`Zstd`]
+/// and [`Lz4`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// struct Reversed;
+///
+/// impl logging_rs::codec::Codec for Reversed {
+///     fn name(&self) -> &'static str {
+///         "reversed"
+///     }
+///
+///     fn extension(&self) -> &'static str {
+///         "rev"
+///     }
+///
+///     fn compress(&self, data: &[u8]) -> Result<Vec<u8>, logging_rs::errors::Error> {
+///         Ok(data.iter().rev().copied().collect())
+///     }
+///
+///     fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, logging_rs::errors::Error> {
+///         self.compress(data)
+///     }
+/// }
+/// ```
+pub trait Codec: Send + Sync {
+    /// Returns the name of the codec, like `gzip`.
+    fn name(&self) -> &'static str;
+
+    /// Returns the file extension of data compressed by the codec without a leading dot, like `gz`.
+    ///
+    /// An empty extension means the data is not changed by the codec.
+    fn extension(&self) -> &'static str;
+
+    /// Compresses the given data.
+    ///
+    /// # Errors
+    ///
+    /// Returns a compression error if the data could not be compressed.
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error>;
+
+    /// Decompresses the given data compressed by [`Codec::compress()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a compression error if the data is not valid compressed data.
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error>;
+}
+
+/// Creates the codec with the given name and its default compression level.
+///
+/// # Parameters
+///
+/// - `name`: The name of the codec, one of `none`, `gzip`, `zstd` or `lz4`
+///
+/// # Returns
+///
+/// The codec, or `None` if the name is unknown or the feature of the codec is not enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::codec::from_name("none").unwrap().name(), "none");
+/// assert!(logging_rs::codec::from_name("rar").is_none());
+/// ```
+///
+/// # See also
+///
+/// - [`Codec::name()`]
+pub fn from_name(name: &str) -> Option<Box<dyn Codec>> {
+    match name {
+        "none" => Some(Box::new(Identity)),
+        #[cfg(feature = "gzip")]
+        "gzip" => Some(Box::new(Gzip::default())),
+        #[cfg(feature = "zstd")]
+        "zstd" => Some(Box::new(Zstd::default())),
+        #[cfg(feature = "lz4")]
+        "lz4" => Some(Box::new(Lz4)),
+        _ => None
+    }
+}
+
+/// Creates a compression error for the given codec.
+#[cfg(any(feature = "gzip", feature = "zstd", feature = "lz4"))]
+fn error<E: std::fmt::Display>(codec: &str, error: E) -> errors::Error {
+    errors::Error::new("Compression error", &format!("The {} codec failed: {}", codec, error), 11)
+}
+
+
+//////////////
+// IDENTITY //
+//////////////
+
+/// Codec leaving data unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # use logging_rs::codec::Codec;
+/// assert_eq!(logging_rs::codec::Identity.compress(b"data").unwrap(), b"data");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Identity;
+
+impl Codec for Identity {
+    fn name(&self) -> &'static str {
+        "none"
+    }
+
+    fn extension(&self) -> &'static str {
+        ""
+    }
+
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error> {
+        Ok(data.to_vec())
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error> {
+        Ok(data.to_vec())
+    }
+}
+
+
+//////////
+// GZIP //
+//////////
+
+/// Gzip codec using `flate2`.
+///
+/// # Parameters
+///
+/// - `level`: The compression level from `0` (fastest) to `9` (smallest)
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # use logging_rs::codec::Codec;
+/// let codec: logging_rs::codec::Gzip = logging_rs::codec::Gzip::new(9);
+/// assert_eq!(codec.decompress(&codec.compress(b"data").unwrap()).unwrap(), b"data");
+/// ```
+#[cfg(feature = "gzip")]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Gzip {
+    /// The compression level from `0` (fastest) to `9` (smallest).
+    pub level: u32,
+}
+
+#[cfg(feature = "gzip")]
+impl Default for Gzip {
+    fn default() -> Gzip {
+        Gzip::new(6)
+    }
+}
+
+#[cfg(feature = "gzip")]
+impl Gzip {
+    /// Creates a new gzip codec object.
+    ///
+    /// # Parameters
+    ///
+    /// - `level`: The compression level from `0` (fastest) to `9` (smallest), higher levels are clamped
+    ///
+    /// # Returns
+    ///
+    /// A new `Gzip` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::codec::Gzip::new(1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Gzip`]
+    pub fn new(level: u32) -> Gzip {
+        Gzip { level: level.min(9) }
+    }
+}
+
+#[cfg(feature = "gzip")]
+impl Codec for Gzip {
+    fn name(&self) -> &'static str {
+        "gzip"
+    }
+
+    fn extension(&self) -> &'static str {
+        "gz"
+    }
+
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error> {
+        let mut encoder: flate2::write::GzEncoder<Vec<u8>> = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::new(self.level));
+
+        encoder.write_all(data).and_then(|_| encoder.finish()).map_err(|error| self::error(self.name(), error))
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error> {
+        let mut result: Vec<u8> = Vec::new();
+
+        flate2::read::GzDecoder::new(data).read_to_end(&mut result).map_err(|error| self::error(self.name(), error))?;
+        Ok(result)
+    }
+}
+
+
+//////////
+// ZSTD //
+//////////
+
+/// Zstandard codec using `zstd`.
+///
+/// # Parameters
+///
+/// - `level`: The compression level from `1` (fastest) to `22` (smallest), `0` uses the default level
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # use logging_rs::codec::Codec;
+/// let codec: logging_rs::codec::Zstd = logging_rs::codec::Zstd::new(19);
+/// assert_eq!(codec.decompress(&codec.compress(b"data").unwrap()).unwrap(), b"data");
+/// ```
+#[cfg(feature = "zstd")]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Zstd {
+    /// The compression level from `1` (fastest) to `22` (smallest), `0` uses the default level.
+    pub level: i32,
+}
+
+#[cfg(feature = "zstd")]
+impl Default for Zstd {
+    fn default() -> Zstd {
+        Zstd::new(3)
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl Zstd {
+    /// Creates a new zstandard codec object.
+    ///
+    /// # Parameters
+    ///
+    /// - `level`: The compression level from `1` (fastest) to `22` (smallest), `0` uses the default level
+    ///
+    /// # Returns
+    ///
+    /// A new `Zstd` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::codec::Zstd::new(1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Zstd`]
+    pub fn new(level: i32) -> Zstd {
+        Zstd { level }
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl Codec for Zstd {
+    fn name(&self) -> &'static str {
+        "zstd"
+    }
+
+    fn extension(&self) -> &'static str {
+        "zst"
+    }
+
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error> {
+        zstd::stream::encode_all(data, self.level).map_err(|error| self::error(self.name(), error))
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error> {
+        zstd::stream::decode_all(data).map_err(|error| self::error(self.name(), error))
+    }
+}
+
+
+/////////
+// LZ4 //
+/////////
+
+/// LZ4 codec using the frame format of `lz4_flex`, trading size for very fast compression.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # use logging_rs::codec::Codec;
+/// assert_eq!(logging_rs::codec::Lz4.decompress(&logging_rs::codec::Lz4.compress(b"data").unwrap()).unwrap(), b"data");
+/// ```
+#[cfg(feature = "lz4")]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Lz4;
+
+#[cfg(feature = "lz4")]
+impl Codec for Lz4 {
+    fn name(&self) -> &'static str {
+        "lz4"
+    }
+
+    fn extension(&self) -> &'static str {
+        "lz4"
+    }
+
+    fn compress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error> {
+        let mut encoder: lz4_flex::frame::FrameEncoder<Vec<u8>> = lz4_flex::frame::FrameEncoder::new(Vec::new());
+
+        encoder.write_all(data).map_err(|error| self::error(self.name(), error))?;
+        encoder.finish().map_err(|error| self::error(self.name(), error))
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, errors::Error> {
+        let mut result: Vec<u8> = Vec::new();
+
+        lz4_flex::frame::FrameDecoder::new(data).read_to_end(&mut result).map_err(|error| self::error(self.name(), error))?;
+        Ok(result)
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Compresses a finished log file next to it and removes the original, like log rotation does with old files.
+///
+/// The archive has the path of the file with the extension of the codec appended. Files are left unchanged by the
+/// [`Identity`] codec.
+///
+/// # Parameters
+///
+/// - `path`: The path of the log file
+/// - `codec`: The [`Codec`] to compress the file with
+///
+/// # Returns
+///
+/// The path of the archive.
+///
+/// # Errors
+///
+/// Returns a file error if the file could not be read, the archive could not be written or the logger is in
+/// [`sandbox`] mode, or a compression error if compressing failed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_doc_archive.log");
+/// std::fs::write(&path, "Some message\n").unwrap();
+///
+/// assert_eq!(logging_rs::codec::archive(&path, &logging_rs::codec::Identity).unwrap(), path);
+/// # std::fs::remove_file(path).ok();
+/// ```
+///
+/// # See also
+///
+/// - [`Codec`]
+pub fn archive<P: AsRef<Path>>(path: P, codec: &dyn Codec) -> Result<PathBuf, errors::Error> {
+    let path: &Path = path.as_ref();
+
+    if sandbox::is_enabled() {
+        return Err(errors::Error::new("File error", &format!("The file {} can not be archived in sandbox mode", path.display()), 1));
+    }
+
+    if codec.extension().is_empty() {
+        return Ok(path.to_path_buf());
+    }
+
+    let mut archive: std::ffi::OsString = path.as_os_str().to_owned();
+    archive.push(".");
+    archive.push(codec.extension());
+    let archive: PathBuf = PathBuf::from(archive);
+
+    let data: Vec<u8> = fs::read(path)
+        .map_err(|error| errors::Error::new("File error", &format!("The file {} could not be read: {}", path.display(), error), 1))?;
+
+    fs::write(&archive, codec.compress(&data)?)
+        .and_then(|_| fs::remove_file(path))
+        .map_err(|error| errors::Error::new("File error", &format!("The archive {} could not be written: {}", archive.display(), error), 1))?;
+
+    Ok(archive)
+}
+
+/// Formats the given records as newline terminated lines without colors and compresses them as one batch.
+///
+/// # Parameters
+///
+/// - `codec`: The [`Codec`] to compress the batch with
+/// - `formatter`: The [`Formatter`] to format the records with
+/// - `records`: The records of the batch
+///
+/// # Returns
+///
+/// The compressed batch.
+///
+/// # Errors
+///
+/// Returns a compression error if compressing failed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let records: Vec<logging_rs::Record> = vec![logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/main.rs", vec![])];
+/// let batch: Vec<u8> = logging_rs::codec::encode_batch(&logging_rs::codec::Identity, &logging_rs::Formatter::compact(), &records).unwrap();
+///
+/// assert!(batch.ends_with(b" INFO Some message\n"));
+/// ```
+///
+/// # See also
+///
+/// - [`Codec`]
+pub fn encode_batch(codec: &dyn Codec, formatter: &Formatter, records: &[Record]) -> Result<Vec<u8>, errors::Error> {
+    let output: Output = Output::FILE { path: String::new() };
+    let text: String = records.iter().map(|record| formatter.format_record(&output, record) + "\n").collect();
+
+    codec.compress(text.as_bytes())
+}
@@ -0,0 +1,1225 @@
+#![doc = include_str!("../../.github/config.md")]
+// logging-rs config
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::BTreeMap;
+
+use crate::{codec, errors, filter, sinks, Formatter, Level, Logger};
+
+
+///////////////////
+// CONFIGURATION //
+///////////////////
+
+/// Logger configuration, usually loaded from a configuration file.
+///
+/// Unset values keep the defaults of [`Logger::builder()`]. Unknown keys are rejected, so typos don't go unnoticed.
+///
+/// # Parameters
+///
+/// - `level`: The minimum [`Level`] of logged records
+/// - `filter`: Per-target directives, see [`filter::EnvFilter::parse()`]
+/// - `mirror_fatal`: Whether fatal records are always written to stderr as well
+/// - `empty_outputs`: The behavior without outputs, see [`crate::builder::EmptyOutputs`]
+/// - `format`: The [`FormatConfig`]
+/// - `outputs`: The [`OutputConfig`]s to write to
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let config: logging_rs::config::Config = logging_rs::config::Config {
+///     level: Some(logging_rs::Level::INFO),
+///     outputs: vec![logging_rs::config::OutputConfig::Stderr { level: None }],
+///     ..Default::default()
+/// };
+///
+/// let logger: logging_rs::Logger = config.build().unwrap();
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The minimum level of logged records
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<Level>,
+    /// Per-target directives
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Whether fatal records are always written to stderr as well
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror_fatal: Option<bool>,
+    /// The behavior without outputs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_outputs: Option<crate::builder::EmptyOutputs>,
+    /// The format configuration
+    pub format: FormatConfig,
+    /// The outputs to write to
+    pub outputs: Vec<OutputConfig>,
+}
+
+/// Format configuration.
+///
+/// The format strings and timestamp format override the ones of the preset. The color format string defaults to
+/// the plain format string if only the latter is set.
+///
+/// # Parameters
+///
+/// - `preset`: The name of a [`Formatter`] preset, one of `default`, `compact`, `detailed`, `json`, `jsonl`, `gelf`, `syslog`, `xml` or `csv`
+/// - `format`: Format string *NOT* supporting special ASCII control characters
+/// - `color_format`: Format string supporting special ASCII control characters
+/// - `timestamp_format`: Timestamp format string in strftime format
+/// - `time_zone`: The [`crate::TimeZone`] timestamps are formatted in, `utc`, `local` or an offset like `+02:00`
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::config::FormatConfig {
+///     preset: Some("compact".to_owned()),
+///     timestamp_format: Some("%H:%M:%S%.3f".to_owned()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FormatConfig {
+    /// The name of a formatter preset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+    /// Format string *NOT* supporting special ASCII control characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Format string supporting special ASCII control characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_format: Option<String>,
+    /// Timestamp format string in strftime format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_format: Option<String>,
+    /// The time zone timestamps are formatted in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<crate::TimeZone>,
+}
+
+/// Output configuration, tagged by the `type` key.
+///
+/// Outputs with a level only write records of at least that level, using a [`sinks::Leveled`] sink. File outputs
+/// with a maximum size are written using a [`sinks::Rotating`] sink, compressing rotated files using the codec of the
+/// given name, see [`codec::from_name()`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::config::OutputConfig::File {
+///     path: "app.log".to_owned(),
+///     level: Some(logging_rs::Level::WARN),
+///     max_size: Some(10 * 1024 * 1024),
+///     max_files: Some(5),
+///     compression: Some("gzip".to_owned())
+/// };
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase", deny_unknown_fields)]
+pub enum OutputConfig {
+    /// Stdout
+    Stdout {
+        /// The minimum level of records written to the output
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        level: Option<Level>
+    },
+    /// Stderr
+    Stderr {
+        /// The minimum level of records written to the output
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        level: Option<Level>
+    },
+    /// File
+    File {
+        /// File path
+        path: String,
+        /// The minimum level of records written to the output
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        level: Option<Level>,
+        /// The maximum size of the file in bytes before it is rotated
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_size: Option<u64>,
+        /// The maximum number of rotated files to keep, `5` by default
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_files: Option<usize>,
+        /// The name of the codec compressing rotated files
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        compression: Option<String>
+    },
+}
+
+impl Config {
+    /// Parses the given TOML configuration.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The TOML configuration
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the text is not valid TOML or contains unknown keys or levels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let config: logging_rs::config::Config = logging_rs::config::Config::from_toml(r#"
+    ///     level = "info"
+    ///
+    ///     [[outputs]]
+    ///     type = "stdout"
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(config.level, Some(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::from_file()`]
+    #[cfg(feature = "toml")]
+    pub fn from_toml(text: &str) -> Result<Config, errors::Error> {
+        toml::from_str(text).map_err(|error| errors::Error::new("Configuration error", &format!("Invalid TOML configuration: {}", error.message()), 10))
+    }
+
+    /// Parses the given YAML configuration.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The YAML configuration
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error with the location of the problem if the text is not valid YAML or contains
+    /// unknown keys or levels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let config: logging_rs::config::Config = logging_rs::config::Config::from_yaml("level: info\noutputs:\n  - type: stdout\n").unwrap();
+    ///
+    /// assert_eq!(config.level, Some(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Document::from_yaml()`]
+    #[cfg(feature = "config-yaml")]
+    pub fn from_yaml(text: &str) -> Result<Config, errors::Error> {
+        serde_norway::from_str(text).map_err(yaml_error)
+    }
+
+    /// Parses the given JSON configuration.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The JSON configuration
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error with the location of the problem if the text is not valid JSON or contains
+    /// unknown keys or levels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let config: logging_rs::config::Config = logging_rs::config::Config::from_json(r#"{"level": "info", "outputs": [{"type": "stdout"}]}"#).unwrap();
+    ///
+    /// assert_eq!(config.level, Some(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::from_json_str()`]
+    #[cfg(feature = "config-json")]
+    pub fn from_json(text: &str) -> Result<Config, errors::Error> {
+        serde_json::from_str(text).map_err(|error| errors::Error::new("Configuration error", &format!("Invalid JSON configuration: {}", error), 10))
+    }
+
+    /// Builds the formatter of the configuration.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The configuration object
+    ///
+    /// # Returns
+    ///
+    /// The configured [`Formatter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the preset is unknown, and a format error if the timestamp format is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::config::Config::default().formatter().unwrap(), logging_rs::Formatter::default());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`FormatConfig`]
+    pub fn formatter(&self) -> Result<Formatter, errors::Error> {
+        let mut formatter: Formatter = match self.format.preset.as_deref() {
+            None | Some("default") => Formatter::DEFAULT,
+            Some("compact") => Formatter::compact(),
+            Some("detailed") => Formatter::detailed(),
+            Some("json") => Formatter::json(),
+            Some("jsonl") => Formatter::json_lines(),
+            Some("gelf") => Formatter::gelf(),
+            Some("syslog") => Formatter::syslog(crate::syslog::Facility::USER),
+            Some("xml") => Formatter::xml(),
+            Some("csv") => Formatter::csv(),
+            Some(preset) => {
+                return Err(errors::Error::new("Configuration error", &format!("Unknown format preset `{}`, expected one of default, compact, detailed, json, jsonl, gelf, syslog, xml, csv", preset), 10));
+            }
+        };
+
+        if let Some(format) = &self.format.format {
+            formatter.format_string = format.clone().into();
+            formatter.color_format_string = format.clone().into();
+        }
+
+        if let Some(color_format) = &self.format.color_format {
+            formatter.color_format_string = color_format.clone().into();
+        }
+
+        if let Some(timestamp_format) = &self.format.timestamp_format {
+            formatter.timestamp_format = timestamp_format.clone().into();
+        }
+
+        if let Some(time_zone) = self.format.time_zone {
+            formatter.time_zone = time_zone;
+        }
+
+        formatter.validate()?;
+        Ok(formatter)
+    }
+
+    /// Builds a logger from the configuration.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The configuration object
+    ///
+    /// # Returns
+    ///
+    /// The configured [`Logger`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if a value is invalid or not supported, like unknown codecs, see
+    /// [`crate::builder::LoggerBuilder::build()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::config::Config::default().build().unwrap();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::builder()`]
+    pub fn build(&self) -> Result<Logger, errors::Error> {
+        let mut builder: crate::builder::LoggerBuilder = Logger::builder().formatter(self.formatter()?);
+
+        if let Some(level) = self.level {
+            builder = builder.level(level);
+        }
+
+        if let Some(directives) = &self.filter {
+            builder = builder.filter(filter::EnvFilter::parse(directives)?);
+        }
+
+        if let Some(mirror_fatal) = self.mirror_fatal {
+            builder = builder.mirror_fatal(mirror_fatal);
+        }
+
+        if let Some(empty_outputs) = self.empty_outputs {
+            builder = builder.empty_outputs(empty_outputs);
+        }
+
+        for output in &self.outputs {
+            builder = match output {
+                OutputConfig::Stdout { level: None } => builder.output(crate::Output::STDOUT),
+                OutputConfig::Stdout { level: Some(level) } => builder.sink(sinks::leveled(crate::Output::STDOUT, *level)),
+                OutputConfig::Stderr { level: None } => builder.output(crate::Output::STDERR),
+                OutputConfig::Stderr { level: Some(level) } => builder.sink(sinks::leveled(crate::Output::STDERR, *level)),
+                OutputConfig::File { path, level, max_size: None, max_files: None, compression: None } => match level {
+                    Some(level) => builder.sink(sinks::leveled(crate::Output::FILE { path: path.clone() }, *level)),
+                    None => builder.output_file(path)
+                },
+                OutputConfig::File { path, max_size: None, .. } => {
+                    return Err(errors::Error::new("Configuration error", &format!("The file output {} needs a maximum size for rotation", path), 10));
+                }
+                OutputConfig::File { path, level, max_size: Some(max_size), max_files, compression } => {
+                    let mut rotating: sinks::Rotating = sinks::Rotating::new(path, *max_size, max_files.unwrap_or(5));
+
+                    if let Some(name) = compression {
+                        rotating.codec = codec::from_name(name)
+                            .ok_or_else(|| errors::Error::new("Configuration error", &format!("Unknown or disabled compression codec `{}`", name), 10))?
+                            .into();
+                    }
+
+                    match level {
+                        Some(level) => builder.sink(sinks::leveled(rotating, *level)),
+                        None => builder.sink(rotating)
+                    }
+                }
+            };
+        }
+
+        builder.build()
+    }
+}
+
+
+//////////////
+// DOCUMENT //
+//////////////
+
+/// Configuration of multiple named loggers sharing formatters and outputs, like log4rs configurations.
+///
+/// Loggers refer to formatters and outputs by name. Loggers without a formatter use the default formatter, loggers
+/// without outputs write to stdout.
+///
+/// # Parameters
+///
+/// - `formatters`: The named [`FormatConfig`]s
+/// - `outputs`: The named [`OutputConfig`]s
+/// - `loggers`: The named [`LoggerConfig`]s
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut document: logging_rs::config::Document = logging_rs::config::Document::default();
+/// document.outputs.insert("console".to_owned(), logging_rs::config::OutputConfig::Stderr { level: None });
+/// document.loggers.insert("app".to_owned(), logging_rs::config::LoggerConfig {
+///     outputs: vec!["console".to_owned()],
+///     ..Default::default()
+/// });
+///
+/// let loggers: std::collections::BTreeMap<String, logging_rs::Logger> = document.build().unwrap();
+/// assert_eq!(loggers["app"].writable_list, vec![logging_rs::Output::STDERR]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Document {
+    /// The named format configurations
+    pub formatters: BTreeMap<String, FormatConfig>,
+    /// The named output configurations
+    pub outputs: BTreeMap<String, OutputConfig>,
+    /// The named logger configurations
+    pub loggers: BTreeMap<String, LoggerConfig>,
+}
+
+/// Configuration of a named logger in a [`Document`].
+///
+/// # Parameters
+///
+/// - `level`: The minimum [`Level`] of logged records
+/// - `filter`: Per-target directives, see [`filter::EnvFilter::parse()`]
+/// - `mirror_fatal`: Whether fatal records are always written to stderr as well
+/// - `empty_outputs`: The behavior without outputs, see [`crate::builder::EmptyOutputs`]
+/// - `formatter`: The name of the formatter
+/// - `outputs`: The names of the outputs
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::config::LoggerConfig {
+///     level: Some(logging_rs::Level::WARN),
+///     formatter: Some("plain".to_owned()),
+///     outputs: vec!["console".to_owned()],
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoggerConfig {
+    /// The minimum level of logged records
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<Level>,
+    /// Per-target directives
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Whether fatal records are always written to stderr as well
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror_fatal: Option<bool>,
+    /// The behavior without outputs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_outputs: Option<crate::builder::EmptyOutputs>,
+    /// The name of the formatter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatter: Option<String>,
+    /// The names of the outputs
+    pub outputs: Vec<String>,
+}
+
+impl Document {
+    /// Parses the given YAML document.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The YAML document
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Document`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error with the location of the problem if the text is not valid YAML or contains
+    /// unknown keys or levels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let document: logging_rs::config::Document = logging_rs::config::Document::from_yaml("
+    /// outputs:
+    ///   console:
+    ///     type: stdout
+    /// loggers:
+    ///   app:
+    ///     level: info
+    ///     outputs: [console]
+    /// ").unwrap();
+    ///
+    /// assert_eq!(document.loggers["app"].level, Some(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config::from_yaml()`]
+    #[cfg(feature = "config-yaml")]
+    pub fn from_yaml(text: &str) -> Result<Document, errors::Error> {
+        serde_norway::from_str(text).map_err(yaml_error)
+    }
+
+    /// Resolves the formatter and outputs of the logger with the given name.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The document object
+    /// - `name`: The name of the logger
+    ///
+    /// # Returns
+    ///
+    /// The [`Config`] of the logger.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the logger, its formatter or one of its outputs does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::config::Document::default().config("app").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Document::build()`]
+    pub fn config(&self, name: &str) -> Result<Config, errors::Error> {
+        let logger: &LoggerConfig = self.loggers.get(name).ok_or_else(|| unknown("logger", name, "the document", &self.loggers))?;
+        let context: String = format!("logger `{}`", name);
+
+        let format: FormatConfig = match &logger.formatter {
+            Some(formatter) => self.formatters.get(formatter).ok_or_else(|| unknown("formatter", formatter, &context, &self.formatters))?.clone(),
+            None => FormatConfig::default()
+        };
+
+        let outputs: Vec<OutputConfig> = logger.outputs.iter()
+            .map(|output| self.outputs.get(output).cloned().ok_or_else(|| unknown("output", output, &context, &self.outputs)))
+            .collect::<Result<Vec<OutputConfig>, errors::Error>>()?;
+
+        Ok(Config {
+            level: logger.level,
+            filter: logger.filter.clone(),
+            mirror_fatal: logger.mirror_fatal,
+            empty_outputs: logger.empty_outputs,
+            format,
+            outputs
+        })
+    }
+
+    /// Builds all loggers of the document.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The document object
+    ///
+    /// # Returns
+    ///
+    /// The configured loggers by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error naming the logger if a reference or value is invalid, see [`Config::build()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::config::Document::default().build().unwrap().is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Document::config()`]
+    pub fn build(&self) -> Result<BTreeMap<String, Logger>, errors::Error> {
+        self.loggers.keys()
+            .map(|name| {
+                self.config(name)?.build()
+                    .map(|logger| (name.clone(), logger))
+                    .map_err(|error| errors::Error::new(&error.name, &format!("{} (logger `{}`)", error.description, name), error.exit_code))
+            })
+            .collect()
+    }
+}
+
+
+////////////
+// LOG4RS //
+////////////
+
+/// log4rs YAML configuration.
+#[cfg(feature = "config-yaml")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Log4rs {
+    /// The refresh rate, ignored as configurations are reloaded using [`crate::reload`]
+    #[serde(default, rename = "refresh_rate")]
+    _refresh_rate: Option<serde::de::IgnoredAny>,
+    /// The named appenders
+    #[serde(default)]
+    appenders: BTreeMap<String, Appender>,
+    /// The root logger
+    #[serde(default)]
+    root: Log4rsLogger,
+    /// The named loggers
+    #[serde(default)]
+    loggers: BTreeMap<String, Log4rsLogger>,
+}
+
+/// log4rs appender, tagged by the `kind` key.
+#[cfg(feature = "config-yaml")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Appender {
+    /// The kind of the appender, `console`, `file` or `rolling_file`
+    kind: String,
+    /// The target of console appenders, `stdout` or `stderr`
+    target: Option<String>,
+    /// The path of file appenders
+    path: Option<String>,
+    /// Whether file appenders append to existing files, ignored as files are always appended to
+    #[serde(default, rename = "append")]
+    _append: Option<serde::de::IgnoredAny>,
+    /// The encoder
+    encoder: Option<Encoder>,
+    /// The filters
+    #[serde(default)]
+    filters: Vec<Threshold>,
+    /// The rolling policy of rolling file appenders
+    policy: Option<Policy>,
+}
+
+/// log4rs encoder, `pattern` or `json`.
+#[cfg(feature = "config-yaml")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Encoder {
+    /// The kind of the encoder, `pattern` by default
+    kind: Option<String>,
+    /// The pattern of pattern encoders
+    pattern: Option<String>,
+}
+
+/// log4rs threshold filter.
+#[cfg(feature = "config-yaml")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Threshold {
+    /// The kind of the filter, only `threshold` is supported
+    kind: String,
+    /// The minimum level
+    level: String,
+}
+
+/// log4rs compound rolling policy.
+#[cfg(feature = "config-yaml")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Policy {
+    /// The kind of the policy, only `compound` is supported
+    kind: Option<String>,
+    /// The trigger, only `size` triggers are supported
+    trigger: Trigger,
+    /// The roller, `fixed_window` or `delete`
+    roller: Option<Roller>,
+}
+
+/// log4rs size trigger.
+#[cfg(feature = "config-yaml")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Trigger {
+    /// The kind of the trigger
+    kind: String,
+    /// The size limit, in bytes or with a unit like `10 mb`
+    limit: serde_norway::Value,
+}
+
+/// log4rs roller.
+#[cfg(feature = "config-yaml")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Roller {
+    /// The kind of the roller
+    kind: String,
+    /// The pattern of rotated files, only its extension is used to choose the compression codec
+    pattern: Option<String>,
+    /// The number of rotated files to keep
+    count: Option<usize>,
+    /// The index of the first rotated file, ignored as rotated files are always numbered from one
+    #[serde(default, rename = "base")]
+    _base: Option<serde::de::IgnoredAny>,
+}
+
+/// log4rs logger.
+#[cfg(feature = "config-yaml")]
+#[derive(Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Log4rsLogger {
+    /// The minimum level, inherited from the parent logger if unset
+    level: Option<String>,
+    /// The names of the appenders
+    #[serde(default)]
+    appenders: Vec<String>,
+    /// Whether the appenders of the parent logger are used as well, `true` by default
+    additive: Option<bool>,
+}
+
+impl Document {
+    /// Converts the given log4rs YAML configuration, easing the migration of existing services.
+    ///
+    /// Appenders become outputs and their encoders formatters of the same name. The root logger is named `root`.
+    /// Loggers inherit the level of their closest configured parent logger, using `::` separated names, and the
+    /// appenders of their parents unless they are not additive. Loggers without appenders and loggers with the level
+    /// `off` drop all records, `trace` is mapped to [`Level::DEBUG`].
+    ///
+    /// Console, file and rolling file appenders with size triggers, pattern and JSON encoders and threshold filters
+    /// are supported. Rotated files are named like those of [`sinks::Rotating`], the extension of the roller pattern
+    /// only chooses the compression codec. Patterns support the date, level, message, newline, module, target, file,
+    /// highlight and MDC formatters, format specifiers like `{l:<5}` are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The log4rs YAML configuration
+    ///
+    /// # Returns
+    ///
+    /// The converted `Document`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the text is not valid YAML, contains unknown keys, unsupported appenders,
+    /// encoders, filters or pattern formatters, or if the appenders of a logger use different encoders.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let document: logging_rs::config::Document = logging_rs::config::Document::from_log4rs_yaml("
+    /// appenders:
+    ///   stdout:
+    ///     kind: console
+    ///     encoder:
+    ///       pattern: '{d(%H:%M:%S)} {l} - {m}{n}'
+    /// root:
+    ///   level: warn
+    ///   appenders: [stdout]
+    /// loggers:
+    ///   app::db:
+    ///     level: info
+    /// ").unwrap();
+    ///
+    /// assert_eq!(document.formatters["stdout"].format.as_deref(), Some("{{timestamp}} {{level}} - {{message}}"));
+    /// assert_eq!(document.loggers["app::db"].outputs, vec!["stdout".to_owned()]);
+    ///
+    /// let loggers: std::collections::BTreeMap<String, logging_rs::Logger> = document.build().unwrap();
+    /// assert_eq!(loggers["root"].level, logging_rs::Level::WARN);
+    /// assert_eq!(loggers["app::db"].level, logging_rs::Level::INFO);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Document::from_yaml()`]
+    #[cfg(feature = "config-yaml")]
+    pub fn from_log4rs_yaml(text: &str) -> Result<Document, errors::Error> {
+        let log4rs: Log4rs = serde_norway::from_str(text).map_err(yaml_error)?;
+        let mut document: Document = Document::default();
+
+        for (name, appender) in &log4rs.appenders {
+            document.formatters.insert(name.clone(), encoder(name, appender.encoder.as_ref())?);
+            document.outputs.insert(name.clone(), output(name, appender)?);
+        }
+
+        let mut names: Vec<&str> = vec!["root"];
+        names.extend(log4rs.loggers.keys().map(String::as_str));
+
+        for name in names {
+            let ancestors: Vec<&Log4rsLogger> = ancestors(name, &log4rs);
+            let level: Option<Option<Level>> = match ancestors.iter().find_map(|ancestor| ancestor.level.as_ref()) {
+                Some(level) => Some(log4rs_level(level)?),
+                None => None
+            };
+
+            let additive: usize = ancestors.iter().position(|ancestor| ancestor.additive == Some(false)).map_or(ancestors.len(), |index| index + 1);
+            let mut appenders: Vec<String> = vec![];
+
+            for appender in ancestors[..additive].iter().rev().flat_map(|ancestor| &ancestor.appenders) {
+                if !document.outputs.contains_key(appender) {
+                    return Err(unknown("appender", appender, &format!("logger `{}`", name), &document.outputs));
+                }
+
+                if !appenders.contains(appender) {
+                    appenders.push(appender.clone());
+                }
+            }
+
+            if appenders.iter().any(|appender| document.formatters[appender] != document.formatters[&appenders[0]]) {
+                return Err(errors::Error::new(
+                    "Configuration error",
+                    &format!("The appenders {} of logger `{}` use different encoders, which is not supported", appenders.join(", "), name),
+                    10
+                ));
+            }
+
+            let off: bool = level == Some(None);
+
+            document.loggers.insert(name.to_owned(), LoggerConfig {
+                level: level.flatten(),
+                mirror_fatal: off.then_some(false),
+                empty_outputs: Some(crate::builder::EmptyOutputs::DROP),
+                formatter: appenders.first().cloned(),
+                outputs: if off { vec![] } else { appenders },
+                ..Default::default()
+            });
+        }
+
+        Ok(document)
+    }
+}
+
+/// Returns the configured loggers from the logger with the given name up to the root logger.
+#[cfg(feature = "config-yaml")]
+fn ancestors<'a>(name: &str, log4rs: &'a Log4rs) -> Vec<&'a Log4rsLogger> {
+    let mut ancestors: Vec<&Log4rsLogger> = vec![];
+    let mut name: &str = if name == "root" { "" } else { name };
+
+    while !name.is_empty() {
+        if let Some(logger) = log4rs.loggers.get(name) {
+            ancestors.push(logger);
+        }
+
+        name = name.rfind("::").map_or("", |index| &name[..index]);
+    }
+
+    ancestors.push(&log4rs.root);
+    ancestors
+}
+
+/// Parses the given log4rs level, returning `None` for `off`.
+#[cfg(feature = "config-yaml")]
+fn log4rs_level(name: &str) -> Result<Option<Level>, errors::Error> {
+    match name.trim().to_lowercase().as_str() {
+        "off" => Ok(None),
+        "trace" => Ok(Some(Level::DEBUG)),
+        _ => name.parse().map(Some).map_err(|error: errors::Error| errors::Error::new("Configuration error", &error.description, 10))
+    }
+}
+
+/// Converts the encoder of the appender with the given name.
+#[cfg(feature = "config-yaml")]
+fn encoder(name: &str, encoder: Option<&Encoder>) -> Result<FormatConfig, errors::Error> {
+    let (kind, text): (&str, &str) = match encoder {
+        Some(encoder) => (encoder.kind.as_deref().unwrap_or("pattern"), encoder.pattern.as_deref().unwrap_or("{d} {l} {t} - {m}{n}")),
+        None => ("pattern", "{d} {l} {t} - {m}{n}")
+    };
+
+    match kind {
+        "pattern" => {
+            let mut timestamp_format: Option<String> = None;
+            let mut format: String = pattern(text, &mut timestamp_format).map_err(|description| {
+                errors::Error::new("Configuration error", &format!("{} in the pattern of appender `{}`", description, name), 10)
+            })?;
+
+            if format.ends_with('\n') {
+                format.pop();
+            }
+
+            Ok(FormatConfig {
+                format: Some(format),
+                timestamp_format: Some(timestamp_format.unwrap_or_else(|| "%Y-%m-%dT%H:%M:%S%.6f%:z".to_owned())),
+                ..Default::default()
+            })
+        }
+        "json" => Ok(FormatConfig { preset: Some("jsonl".to_owned()), ..Default::default() }),
+        _ => Err(errors::Error::new("Configuration error", &format!("Unsupported encoder `{}` of appender `{}`, expected pattern or json", kind, name), 10))
+    }
+}
+
+/// Converts the given log4rs pattern into a format string, setting the timestamp format of date formatters.
+#[cfg(feature = "config-yaml")]
+fn pattern(text: &str, timestamp_format: &mut Option<String>) -> Result<String, String> {
+    let mut result: String = String::new();
+    let mut characters: std::iter::Peekable<std::str::Chars> = text.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        if (character == '{' || character == '}') && characters.peek() == Some(&character) {
+            characters.next();
+            result.push(character);
+            continue;
+        }
+
+        if character != '{' {
+            result.push(character);
+            continue;
+        }
+
+        let mut name: String = String::new();
+
+        while let Some(character) = characters.next_if(|character| character.is_alphanumeric() || *character == '_') {
+            name.push(character);
+        }
+
+        let mut argument: Option<String> = None;
+
+        if characters.next_if_eq(&'(').is_some() {
+            let mut depth: usize = 1;
+            let mut text: String = String::new();
+
+            for character in characters.by_ref() {
+                match character {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+
+                if depth == 0 {
+                    break;
+                }
+
+                text.push(character);
+            }
+
+            if depth != 0 {
+                return Err(format!("Unclosed `(` of `{{{}`", name));
+            }
+
+            argument = Some(text);
+        }
+
+        if !characters.by_ref().any(|character| character == '}') {
+            return Err(format!("Unclosed `{{{}`", name));
+        }
+
+        result.push_str(&match (name.as_str(), argument) {
+            ("d" | "date", argument) => {
+                if let Some(format) = argument.filter(|format| !format.is_empty()) {
+                    *timestamp_format = Some(format);
+                }
+
+                "{{timestamp}}".to_owned()
+            }
+            ("l" | "level", _) => "{{level}}".to_owned(),
+            ("m" | "message", _) => "{{message}}".to_owned(),
+            ("n", _) => "\n".to_owned(),
+            ("M" | "module" | "t" | "target" | "f" | "file", _) => "{{path}}".to_owned(),
+            ("X" | "mdc", Some(key)) => format!("{{{{{}}}}}", key.split(':').next().unwrap_or_default().trim()),
+            ("h" | "highlight" | "", Some(inner)) => pattern(&inner, timestamp_format)?,
+            (name, _) => return Err(format!("Unsupported formatter `{{{}}}`", name))
+        });
+    }
+
+    Ok(result)
+}
+
+/// Converts the appender with the given name into an output.
+#[cfg(feature = "config-yaml")]
+fn output(name: &str, appender: &Appender) -> Result<OutputConfig, errors::Error> {
+    let invalid = |description: &str| errors::Error::new("Configuration error", &format!("{} (appender `{}`)", description, name), 10);
+
+    let mut level: Option<Level> = None;
+
+    for filter in &appender.filters {
+        if filter.kind != "threshold" {
+            return Err(invalid(&format!("Unsupported filter `{}`, expected threshold", filter.kind)));
+        }
+
+        match log4rs_level(&filter.level)? {
+            Some(threshold) => level = level.max(Some(threshold)),
+            None => return Err(invalid("Unsupported threshold `off`"))
+        }
+    }
+
+    let path = || appender.path.clone().ok_or_else(|| invalid("Missing path"));
+
+    match appender.kind.as_str() {
+        "console" => match appender.target.as_deref().unwrap_or("stdout") {
+            "stdout" => Ok(OutputConfig::Stdout { level }),
+            "stderr" => Ok(OutputConfig::Stderr { level }),
+            target => Err(invalid(&format!("Unknown target `{}`, expected stdout or stderr", target)))
+        },
+        "file" => Ok(OutputConfig::File { path: path()?, level, max_size: None, max_files: None, compression: None }),
+        "rolling_file" => {
+            let policy: &Policy = appender.policy.as_ref().ok_or_else(|| invalid("Missing rolling policy"))?;
+
+            if policy.kind.as_deref().unwrap_or("compound") != "compound" || policy.trigger.kind != "size" {
+                return Err(invalid("Unsupported rolling policy, expected a compound policy with a size trigger"));
+            }
+
+            let max_size: u64 = match &policy.trigger.limit {
+                serde_norway::Value::Number(number) => number.as_u64(),
+                serde_norway::Value::String(limit) => size(limit),
+                _ => None
+            }.ok_or_else(|| invalid("Invalid size limit"))?;
+
+            let (max_files, compression): (usize, Option<String>) = match &policy.roller {
+                None => (0, None),
+                Some(roller) if roller.kind == "delete" => (0, None),
+                Some(roller) if roller.kind == "fixed_window" => (
+                    roller.count.unwrap_or(1),
+                    roller.pattern.as_deref().filter(|pattern| pattern.ends_with(".gz")).map(|_| "gzip".to_owned())
+                ),
+                Some(roller) => return Err(invalid(&format!("Unsupported roller `{}`, expected fixed_window or delete", roller.kind)))
+            };
+
+            Ok(OutputConfig::File { path: path()?, level, max_size: Some(max_size), max_files: Some(max_files), compression })
+        }
+        kind => Err(invalid(&format!("Unsupported appender `{}`, expected console, file or rolling_file", kind)))
+    }
+}
+
+/// Parses the given size like `10 mb`, using binary units.
+#[cfg(feature = "config-yaml")]
+fn size(text: &str) -> Option<u64> {
+    let text: String = text.trim().to_lowercase();
+    let index: usize = text.find(|character: char| !character.is_ascii_digit()).unwrap_or(text.len());
+    let factor: u64 = match text[index..].trim() {
+        "" | "b" => 1,
+        "kb" => 1 << 10,
+        "mb" => 1 << 20,
+        "gb" => 1 << 30,
+        "tb" => 1 << 40,
+        _ => return None
+    };
+
+    text[..index].parse::<u64>().ok()?.checked_mul(factor)
+}
+
+/// Creates a configuration error for a reference to an unknown name.
+fn unknown<T>(kind: &str, name: &str, context: &str, known: &BTreeMap<String, T>) -> errors::Error {
+    let known: Vec<&str> = known.keys().map(String::as_str).collect();
+    let expected: String = if known.is_empty() {
+        format!("no {}s are defined", kind)
+    } else {
+        format!("expected one of {}", known.join(", "))
+    };
+
+    errors::Error::new("Configuration error", &format!("Unknown {} `{}` in {}, {}", kind, name, context, expected), 10)
+}
+
+/// Creates a configuration error for the given YAML error.
+#[cfg(feature = "config-yaml")]
+fn yaml_error(error: serde_norway::Error) -> errors::Error {
+    errors::Error::new("Configuration error", &format!("Invalid YAML configuration: {}", error), 10)
+}
+
+
+////////////
+// LOGGER //
+////////////
+
+impl Logger {
+    /// Loads a logger from the given configuration file.
+    ///
+    /// The format of the file is detected from its extension, `.toml` for TOML, `.yaml` or `.yml` for YAML and `.json`
+    /// for JSON. See [`Config`] for the supported keys.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the configuration file
+    ///
+    /// # Returns
+    ///
+    /// The configured [`Logger`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a file error if the file could not be read, or a configuration error if the format is not supported or
+    /// the configuration is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # #[cfg(not(any(feature = "toml", feature = "config-yaml")))]
+    /// # let (name, text): (&str, &str) = ("logging_rs_doc_config.json", r#"{"level": "warn", "format": {"preset": "compact"}}"#);
+    /// # #[cfg(all(not(feature = "toml"), feature = "config-yaml"))]
+    /// # let (name, text): (&str, &str) = ("logging_rs_doc_config.yaml", "level: warn\nformat:\n  preset: compact\n");
+    /// # #[cfg(feature = "toml")]
+    /// let (name, text): (&str, &str) = ("logging_rs_doc_config.toml", "level = \"warn\"\n\n[format]\npreset = \"compact\"\n");
+    /// # let path: std::path::PathBuf = std::env::temp_dir().join(name);
+    /// std::fs::write(&path, text).unwrap();
+    ///
+    /// let logger: logging_rs::Logger = logging_rs::Logger::from_file(&path).unwrap();
+    /// assert_eq!(logger.level, logging_rs::Level::WARN);
+    /// # std::fs::remove_file(path).ok();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Config::build()`]
+    #[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Logger, errors::Error> {
+        let path: &std::path::Path = path.as_ref();
+        let text: String = read(path)?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            #[cfg(feature = "toml")]
+            Some("toml") => Config::from_toml(&text)?.build(),
+            #[cfg(feature = "config-yaml")]
+            Some("yaml" | "yml") => Config::from_yaml(&text)?.build(),
+            #[cfg(feature = "config-json")]
+            Some("json") => Config::from_json(&text)?.build(),
+            _ => Err(errors::Error::new("Configuration error", &format!("Unsupported format of configuration file {}", path.display()), 10))
+        }
+    }
+
+    /// Loads a logger from the given JSON configuration, for example generated by templating in container environments.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The JSON configuration, see [`Config`] for the supported keys
+    ///
+    /// # Returns
+    ///
+    /// The configured [`Logger`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the JSON is invalid or the configuration is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::from_json_str(r#"{
+    ///     "level": "debug",
+    ///     "format": {"format": "{{level}} {{message}}"},
+    ///     "outputs": [{"type": "stdout"}, {"type": "stderr", "level": "error"}]
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(logger.writable_list, vec![logging_rs::Output::STDOUT]);
+    /// assert_eq!(logger.sinks.len(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::from_json_file()`]
+    #[cfg(feature = "config-json")]
+    pub fn from_json_str(text: &str) -> Result<Logger, errors::Error> {
+        Config::from_json(text)?.build()
+    }
+
+    /// Loads a logger from the given JSON configuration file, regardless of its extension.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the JSON configuration file
+    ///
+    /// # Returns
+    ///
+    /// The configured [`Logger`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a file error if the file could not be read, or a configuration error if the JSON is invalid or the
+    /// configuration is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_doc_config.conf");
+    /// std::fs::write(&path, r#"{"level": "warn"}"#).unwrap();
+    ///
+    /// assert_eq!(logging_rs::Logger::from_json_file(&path).unwrap().level, logging_rs::Level::WARN);
+    /// # std::fs::remove_file(path).ok();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::from_json_str()`]
+    #[cfg(feature = "config-json")]
+    pub fn from_json_file<P: AsRef<std::path::Path>>(path: P) -> Result<Logger, errors::Error> {
+        Logger::from_json_str(&read(path.as_ref())?)
+    }
+}
+
+/// Reads the given configuration file.
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+fn read(path: &std::path::Path) -> Result<String, errors::Error> {
+    std::fs::read_to_string(path)
+        .map_err(|error| errors::Error::new("File error", &format!("The configuration file {} could not be read: {}", path.display(), error), 1))
+}
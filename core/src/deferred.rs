@@ -0,0 +1,428 @@
+#![doc = include_str!("../../.github/deferred.md")]
+// logging-rs deferred
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::{errors, memory, Level, Logger, Record};
+
+
+////////////////////
+// DEFERRED DEBUG //
+////////////////////
+
+/// Deferred debug object.
+///
+/// Holds back [`Level::DEBUG`] records of an operation and only emits them if the operation logs an
+/// [`Level::ERROR`] or [`Level::FATAL`] record. If the operation finishes without errors, the held back records are
+/// discarded, keeping full detail on failures without the noise of successful operations.
+///
+/// Deferred debug objects can be used with the logging macros like loggers.
+///
+/// Use [`Logger::deferred()`] or [`DeferredDebug::new()`] to create deferred debug objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+///
+/// logging_rs::debug!(operation, "Connecting");
+/// logging_rs::debug!(operation, "Sending request");
+/// logging_rs::error!(operation, "Request failed"); // Emits both debug records followed by the error
+/// ```
+#[derive(Debug)]
+pub struct DeferredDebug<'a> {
+    /// The logger to emit records with.
+    logger: &'a Logger,
+    /// The maximum number of held back records. The oldest records are dropped first.
+    capacity: usize,
+    /// The held back records and whether an error was logged.
+    state: Mutex<(VecDeque<Record>, bool)>,
+    /// The memory budget of the held back records.
+    memory: memory::Budget,
+}
+
+impl<'a> DeferredDebug<'a> {
+    /// Creates a new deferred debug object.
+    ///
+    /// # Parameters
+    ///
+    /// - `logger`: The [`Logger`] to emit records with
+    /// - `capacity`: The maximum number of held back records
+    ///
+    /// # Returns
+    ///
+    /// A new `DeferredDebug` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::deferred::DeferredDebug::new(&logger, 100);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`DeferredDebug`]
+    /// - [`Logger::deferred()`]
+    pub fn new(logger: &'a Logger, capacity: usize) -> DeferredDebug<'a> {
+        DeferredDebug {
+            logger,
+            capacity,
+            state: Mutex::new((VecDeque::new(), false)),
+            memory: memory::Budget::default()
+        }
+    }
+
+    /// Sets the maximum number of bytes the held back records may use.
+    ///
+    /// The oldest records are dropped first to make room for new records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    /// - `limit`: The maximum number of bytes, see [`Record::memory_size()`]
+    ///
+    /// # Returns
+    ///
+    /// The deferred debug object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let operation: logging_rs::deferred::DeferredDebug = logger.deferred().with_memory_limit(64 * 1024);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`DeferredDebug::memory()`]
+    /// - [`memory::Budget`]
+    pub fn with_memory_limit(self, limit: usize) -> DeferredDebug<'a> {
+        self.memory.set_limit(limit);
+        self
+    }
+
+    /// Returns the memory usage statistics of the held back records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    ///
+    /// # Returns
+    ///
+    /// The [`memory::MemoryStats`] of the held back records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// assert_eq!(operation.memory().used, 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`DeferredDebug::with_memory_limit()`]
+    pub fn memory(&self) -> memory::MemoryStats {
+        self.memory.stats()
+    }
+
+    /// Returns whether records with the given level are logged by the logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    /// - `level`: The [`Level`] to check
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the level is at least the minimum level of the logger.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// assert!(operation.is_enabled(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::is_enabled()`]
+    /// - [`DeferredDebug`]
+    pub fn is_enabled(&self, level: Level) -> bool {
+        self.logger.is_enabled(level)
+    }
+
+    /// Logs the given message, holding it back if it is a debug message.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// operation.log("Some message", logging_rs::Level::DEBUG, "src/lib.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log()`]
+    /// - [`DeferredDebug`]
+    pub fn log(&self, message: &str, level: Level, path: &str, arguments: Vec<(&str, String)>) {
+        self.log_record(&Record::new(level, message, path, arguments));
+    }
+
+    /// Logs the given static message without arguments, holding it back if it is a debug message.
+    ///
+    /// Held back records are always created, so this is the same as calling [`DeferredDebug::log()`] without
+    /// arguments. Used by the logging macros for string literals without arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// operation.log_static("Some message", logging_rs::Level::DEBUG, "src/lib.rs");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_static()`]
+    /// - [`DeferredDebug`]
+    pub fn log_static(&self, message: &'static str, level: Level, path: &'static str) {
+        self.log(message, level, path, vec![]);
+    }
+
+    /// Logs the given error with logging level [`Level::ERROR`], emitting all held back records first.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    /// - `message`: The message to log
+    /// - `error`: The error to log
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+    /// operation.log_error("Saving failed", &error, "src/lib.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_error()`]
+    /// - [`DeferredDebug`]
+    pub fn log_error<E: std::error::Error + ?Sized>(&self, message: &str, error: &E, path: &str, mut arguments: Vec<(&str, String)>) {
+        arguments.append(&mut errors::error_arguments(error));
+        self.log(message, Level::ERROR, path, arguments);
+    }
+
+    /// Logs the given record, holding it back if it is a debug record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    /// - `record`: The [`Record`] to log
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// operation.log_record(&logging_rs::Record::new(logging_rs::Level::DEBUG, "Some message", "src/lib.rs", vec![]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_record()`]
+    /// - [`DeferredDebug`]
+    pub fn log_record(&self, record: &Record) {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+        let (held, failed) = &mut *state;
+
+        match record.level {
+            Level::DEBUG if !*failed => {
+                if held.len() >= self.capacity {
+                    if let Some(dropped) = held.pop_front() {
+                        self.memory.release(dropped.memory_size());
+                    }
+                }
+
+                if self.capacity == 0 {
+                    return;
+                }
+
+                let size: usize = record.memory_size();
+                let limit: usize = self.memory.stats().limit;
+
+                while self.memory.stats().used.saturating_add(size) > limit {
+                    match held.pop_front() {
+                        Some(dropped) => self.memory.release(dropped.memory_size()),
+                        None => break
+                    }
+                }
+
+                if self.memory.reserve(size) {
+                    held.push_back(record.clone());
+                }
+            },
+            Level::ERROR | Level::FATAL => {
+                *failed = true;
+
+                for held_record in held.drain(..) {
+                    self.memory.release(held_record.memory_size());
+                    self.logger.log_record(&held_record);
+                }
+
+                self.logger.log_record(record);
+            },
+            _ => self.logger.log_record(record)
+        }
+    }
+
+    /// Returns the number of held back records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    ///
+    /// # Returns
+    ///
+    /// The number of held back records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// logging_rs::debug!(operation, "Held back");
+    /// assert_eq!(operation.pending(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`DeferredDebug`]
+    pub fn pending(&self) -> usize {
+        self.state.lock().unwrap_or_else(|error| error.into_inner()).0.len()
+    }
+
+    /// Emits all held back records, regardless of whether an error was logged.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The deferred debug object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// operation.flush();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`DeferredDebug`]
+    pub fn flush(&self) {
+        let held: Vec<Record> = self.state.lock().unwrap_or_else(|error| error.into_inner()).0.drain(..).collect();
+
+        for record in held {
+            self.memory.release(record.memory_size());
+            self.logger.log_record(&record);
+        }
+    }
+}
+
+impl Drop for DeferredDebug<'_> {
+    fn drop(&mut self) {
+        self.memory.release(self.memory.stats().used);
+    }
+}
+
+impl Logger {
+    /// Creates a deferred debug object for an operation.
+    ///
+    /// Debug records logged through the returned object are only emitted if the operation logs an error. Holds back
+    /// at most 1000 records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    ///
+    /// # Returns
+    ///
+    /// A new [`DeferredDebug`] object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+    /// logging_rs::debug!(operation, "Only shown if the operation fails");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`DeferredDebug`]
+    pub fn deferred(&self) -> DeferredDebug<'_> {
+        DeferredDebug::new(self, 1000)
+    }
+}
@@ -0,0 +1,400 @@
+#![doc = include_str!("../../.github/environment.md")]
+// logging-rs environment
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::io::IsTerminal;
+use std::sync::RwLock;
+
+use crate::builder::LoggerBuilder;
+use crate::filter::{EnvFilter, ENVIRONMENT_VARIABLE, FALLBACK_ENVIRONMENT_VARIABLE};
+use crate::{errors, sandbox, sinks, Formatter, Level, Logger, Output, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Value replacing the values of secret environment variables.
+pub const MASK: &str = "[REDACTED]";
+
+/// Parts of environment variable names marking their values as secret.
+pub const SECRET_MARKERS: [&str; 8] = ["SECRET", "TOKEN", "PASSWORD", "PASSWD", "KEY", "CREDENTIAL", "AUTH", "PRIVATE"];
+
+/// Environment variable containing the comma separated outputs of loggers created using [`Logger::from_env()`].
+///
+/// Supported outputs are `stdout`, `stderr` and `file:<path>`. Defaults to `stdout`.
+pub const OUTPUT_ENVIRONMENT_VARIABLE: &str = "LOGGING_RS_OUTPUT";
+
+/// Environment variable containing the format of loggers created using [`Logger::from_env()`].
+///
+/// Either one of the presets `default`, `compact`, `detailed`, `json`, `jsonl`, `gelf`, `syslog`, `xml` and `csv` or a
+/// format string. Defaults to `default`.
+pub const FORMAT_ENVIRONMENT_VARIABLE: &str = "LOGGING_RS_FORMAT";
+
+/// Environment variable containing the timestamp format of loggers created using [`Logger::from_env()`].
+pub const TIMESTAMP_FORMAT_ENVIRONMENT_VARIABLE: &str = "LOGGING_RS_TIMESTAMP_FORMAT";
+
+/// Environment variable controlling the colors of loggers created using [`Logger::from_env()`].
+///
+/// Either `always`, `never` or `auto`. Defaults to `auto`, which disables colors if the stream is not a terminal, the
+/// `NO_COLOR` environment variable is set or `TERM` is `dumb`.
+pub const COLOR_ENVIRONMENT_VARIABLE: &str = "LOGGING_RS_COLOR";
+
+
+/////////////////
+// ENVIRONMENT //
+/////////////////
+
+/// Checks whether the environment variable with the given name likely contains a secret.
+///
+/// # Parameters
+///
+/// - `name`: The name of the environment variable
+///
+/// # Returns
+///
+/// A boolean indicating whether the name contains one of the [`SECRET_MARKERS`], ignoring case.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert!(logging_rs::environment::is_secret("AWS_SECRET_ACCESS_KEY"));
+/// assert!(!logging_rs::environment::is_secret("HOME"));
+/// ```
+///
+/// # See also
+///
+/// - [`environment_arguments()`]
+pub fn is_secret(name: &str) -> bool {
+    let name: String = name.to_uppercase();
+
+    SECRET_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Checks whether the given environment variable name is matched by the allowlist.
+///
+/// Entries ending with `*` match all names starting with the rest of the entry.
+fn is_allowed(allowlist: &[&str], name: &str) -> bool {
+    allowlist.iter().any(|entry| match entry.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => name == *entry
+    })
+}
+
+/// Creates formatting arguments describing the environment of the process.
+///
+/// - `cwd`: The current working directory
+/// - `args`: The command line arguments, separated by spaces
+/// - `env.<name>`: The value of every allowed environment variable. Values of secret variables are replaced by
+///   [`MASK`]
+///
+/// # Parameters
+///
+/// - `allowlist`: The names of the environment variables to include. Entries ending with `*` match all names
+///   starting with the rest of the entry
+///
+/// # Returns
+///
+/// A vector of key-value pairs of the argument names and values, sorted by name.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let arguments: Vec<(String, String)> = logging_rs::environment::environment_arguments(&["CARGO_PKG_*", "PATH"]);
+/// ```
+///
+/// # See also
+///
+/// - [`Logger::log_environment()`]
+/// - [`is_secret()`]
+pub fn environment_arguments(allowlist: &[&str]) -> Vec<(String, String)> {
+    let mut variables: Vec<(String, String)> = std::env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, value.to_string_lossy().to_string())))
+        .filter(|(name, _)| is_allowed(allowlist, name))
+        .map(|(name, value)| {
+            let value: String = if is_secret(&name) { MASK.to_owned() } else { value };
+            (format!("env.{}", name), value)
+        })
+        .collect();
+    variables.sort();
+
+    let cwd: String = std::env::current_dir().map(|path| path.to_string_lossy().to_string()).unwrap_or_default();
+    let args: String = std::env::args_os().map(|argument| argument.to_string_lossy().to_string()).collect::<Vec<String>>().join(" ");
+
+    let mut arguments: Vec<(String, String)> = vec![("cwd".to_owned(), cwd), ("args".to_owned(), args)];
+    arguments.append(&mut variables);
+    arguments
+}
+
+/// Returns the host name of the machine, as used by network formats like GELF.
+///
+/// The host name is read from the `HOSTNAME` or `COMPUTERNAME` environment variables, falling back to
+/// `/proc/sys/kernel/hostname` and `/etc/hostname` outside of [sandbox mode](crate::sandbox). It is only read once
+/// and cached afterwards.
+///
+/// # Returns
+///
+/// A `String` containing the host name, or `localhost` if it could not be determined.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert!(!logging_rs::environment::hostname().is_empty());
+/// ```
+///
+/// # See also
+///
+/// - [`environment_arguments()`]
+pub fn hostname() -> String {
+    static HOSTNAME: RwLock<Option<String>> = RwLock::new(None);
+
+    if let Some(hostname) = HOSTNAME.read().unwrap_or_else(|error| error.into_inner()).as_ref() {
+        return hostname.clone();
+    }
+
+    let hostname: String = ["HOSTNAME", "COMPUTERNAME"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok())
+        .or_else(|| {
+            if sandbox::is_enabled() {
+                return None;
+            }
+
+            ["/proc/sys/kernel/hostname", "/etc/hostname"].iter().find_map(|path| std::fs::read_to_string(path).ok())
+        })
+        .map(|hostname| hostname.trim().to_owned())
+        .filter(|hostname| !hostname.is_empty())
+        .unwrap_or_else(|| "localhost".to_owned());
+
+    *HOSTNAME.write().unwrap_or_else(|error| error.into_inner()) = Some(hostname.clone());
+    hostname
+}
+
+impl Logger {
+    /// Logs a snapshot of the environment of the process with logging level [`Level::INFO`].
+    ///
+    /// The record contains the arguments created by [`environment_arguments()`], which structured sinks can use for
+    /// startup diagnostics.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `allowlist`: The names of the environment variables to include. Entries ending with `*` match all names
+    ///   starting with the rest of the entry
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.log_environment(&["CARGO_PKG_*", "RUST_LOG"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`environment_arguments()`]
+    /// - [`Logger`]
+    #[track_caller]
+    pub fn log_environment(&self, allowlist: &[&str]) {
+        let mut record: Record = Record::new(Level::INFO, "Environment of {{args}} in {{cwd}}", std::panic::Location::caller().file(), vec![]);
+        record.arguments = environment_arguments(allowlist);
+
+        self.log_record(&record);
+    }
+}
+
+
+///////////////////
+// CONFIGURATION //
+///////////////////
+
+/// Reads the environment variable with the given name, treating empty values as unset.
+fn variable(name: &str) -> Option<String> {
+    std::env::var(name).ok().map(|value| value.trim().to_owned()).filter(|value| !value.is_empty())
+}
+
+/// Creates the configuration error for an invalid value of an environment variable.
+fn invalid(name: &str, value: &str, expected: &str) -> errors::Error {
+    errors::Error::new("Configuration error", &format!("Invalid value `{}` of {}, expected {}", value, name, expected), 10)
+}
+
+/// Parses the outputs of the [`OUTPUT_ENVIRONMENT_VARIABLE`].
+fn outputs(value: &str) -> Result<Vec<Output>, errors::Error> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|output| !output.is_empty())
+        .map(|output| output.parse::<Output>().map_err(|_| invalid(OUTPUT_ENVIRONMENT_VARIABLE, output, "stdout, stderr or file:<path>")))
+        .collect()
+}
+
+/// Creates the formatter described by the [`FORMAT_ENVIRONMENT_VARIABLE`] and [`TIMESTAMP_FORMAT_ENVIRONMENT_VARIABLE`].
+fn formatter() -> Result<Formatter, errors::Error> {
+    let mut formatter: Formatter = match variable(FORMAT_ENVIRONMENT_VARIABLE) {
+        None => Formatter::DEFAULT,
+        Some(format) => match format.to_lowercase().as_str() {
+            "default" => Formatter::DEFAULT,
+            "compact" => Formatter::compact(),
+            "detailed" => Formatter::detailed(),
+            "json" => Formatter::json(),
+            "jsonl" => Formatter::json_lines(),
+            "gelf" => Formatter::gelf(),
+            "syslog" => Formatter::syslog(crate::syslog::Facility::USER),
+            "xml" => Formatter::xml(),
+            "csv" => Formatter::csv(),
+            _ if format.contains("{{") => {
+                let mut formatter: Formatter = Formatter::DEFAULT;
+                formatter.color_format_string = format.clone().into();
+                formatter.format_string = format.into();
+                formatter
+            }
+            _ => return Err(invalid(FORMAT_ENVIRONMENT_VARIABLE, &format, "default, compact, detailed, json, jsonl, gelf, syslog, xml, csv or a format string"))
+        }
+    };
+
+    if let Some(timestamp_format) = variable(TIMESTAMP_FORMAT_ENVIRONMENT_VARIABLE) {
+        formatter.timestamp_format = timestamp_format.into();
+    }
+
+    formatter.validate()?;
+    Ok(formatter)
+}
+
+/// Parses the [`COLOR_ENVIRONMENT_VARIABLE`], returning `None` in auto mode.
+fn color_mode() -> Result<Option<bool>, errors::Error> {
+    match variable(COLOR_ENVIRONMENT_VARIABLE).map(|value| value.to_lowercase()).as_deref() {
+        None | Some("auto") => Ok(None),
+        Some("always") => Ok(Some(true)),
+        Some("never") => Ok(Some(false)),
+        Some(value) => Err(invalid(COLOR_ENVIRONMENT_VARIABLE, value, "always, never or auto"))
+    }
+}
+
+/// Checks whether the given output is colored in auto mode.
+///
+/// Only stdout and stderr connected to a terminal are colored, unless `NO_COLOR` is set or `TERM` is `dumb`.
+fn colored(output: &Output) -> bool {
+    let terminal: bool = match output {
+        Output::STDOUT => std::io::stdout().is_terminal(),
+        Output::STDERR => std::io::stderr().is_terminal(),
+        _ => false
+    };
+
+    terminal && variable("NO_COLOR").is_none() && variable("TERM").as_deref() != Some("dumb")
+}
+
+impl Logger {
+    /// Creates a new logger configured by environment variables.
+    ///
+    /// - [`ENVIRONMENT_VARIABLE`] or [`FALLBACK_ENVIRONMENT_VARIABLE`]: The filter directives
+    /// - [`OUTPUT_ENVIRONMENT_VARIABLE`]: The outputs, like `stderr,file:/var/log/app.log`
+    /// - [`FORMAT_ENVIRONMENT_VARIABLE`]: The format preset or format string
+    /// - [`TIMESTAMP_FORMAT_ENVIRONMENT_VARIABLE`]: The timestamp format
+    /// - [`COLOR_ENVIRONMENT_VARIABLE`]: Whether stdout and stderr are colored
+    ///
+    /// # Returns
+    ///
+    /// A new `Logger` object, writing to stdout unless configured otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if a variable contains an invalid value, a format error if the format is invalid
+    /// and a level error if the filter directives are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// std::env::set_var("LOGGING_RS_OUTPUT", "stderr");
+    /// std::env::set_var("LOGGING_RS_FORMAT", "compact");
+    /// std::env::set_var("LOGGING_RS_COLOR", "never");
+    ///
+    /// let logger: logging_rs::Logger = logging_rs::Logger::try_from_env().unwrap();
+    /// assert!(logger.writable_list.is_empty());
+    /// assert_eq!(logger.formatter, logging_rs::Formatter::compact());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::from_env()`]
+    /// - [`EnvFilter::from_environment()`]
+    pub fn try_from_env() -> Result<Logger, errors::Error> {
+        let color_mode: Option<bool> = color_mode()?;
+        let mut builder: LoggerBuilder = Logger::builder().formatter(formatter()?).filter(EnvFilter::from_environment()?);
+
+        for output in outputs(&variable(OUTPUT_ENVIRONMENT_VARIABLE).unwrap_or_else(|| "stdout".to_owned()))? {
+            builder = match output {
+                Output::STDOUT | Output::STDERR if !color_mode.unwrap_or_else(|| colored(&output)) => builder.sink(sinks::Plain::new(output)),
+                output => builder.output(output)
+            };
+        }
+
+        builder.build()
+    }
+
+    /// Creates a new logger configured by environment variables.
+    ///
+    /// Invalid values are reported using [`errors::Error::report()`], returning the default logger filtered by the
+    /// valid directives instead.
+    ///
+    /// # Returns
+    ///
+    /// A new `Logger` object, see [`Logger::try_from_env()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// std::env::set_var("RUST_LOG", "info,my_crate::db=debug");
+    ///
+    /// let logger: logging_rs::Logger = logging_rs::Logger::from_env();
+    /// logging_rs::debug!(logger, "Skipped unless logged by my_crate::db");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::try_from_env()`]
+    /// - [`Logger::with_filter()`]
+    pub fn from_env() -> Logger {
+        Logger::try_from_env().unwrap_or_else(|error| {
+            error.report(&format!(
+                "Variables: {}, {}, {}, {}, {}, {}",
+                ENVIRONMENT_VARIABLE,
+                FALLBACK_ENVIRONMENT_VARIABLE,
+                OUTPUT_ENVIRONMENT_VARIABLE,
+                FORMAT_ENVIRONMENT_VARIABLE,
+                TIMESTAMP_FORMAT_ENVIRONMENT_VARIABLE,
+                COLOR_ENVIRONMENT_VARIABLE
+            ));
+
+            Logger::default().with_filter(EnvFilter::from_environment().unwrap_or_default())
+        })
+    }
+}
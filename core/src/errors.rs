@@ -0,0 +1,543 @@
+#![doc = include_str!("../../.github/errors.md")]
+// logging-rs errors
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::cell::Cell;
+use std::fmt;
+use std::io;
+use std::sync::{Arc, RwLock};
+
+
+///////////////////
+// EXIT STRATEGY //
+///////////////////
+
+/// Exit strategies used by [`Error::raise()`].
+#[derive(Clone, Copy, Debug, Default)]
+pub enum ExitStrategy {
+    /// Exits the process with the errors exit code. The default value
+    #[default]
+    EXIT,
+    /// Panics with the error message instead of exiting, allowing tests to catch it
+    PANIC,
+    /// Calls the given function instead of exiting
+    CUSTOM(fn(&Error)),
+}
+
+/// The currently used exit strategy.
+static EXIT_STRATEGY: RwLock<ExitStrategy> = RwLock::new(ExitStrategy::EXIT);
+
+/// Sets the exit strategy used when raising errors.
+///
+/// # Parameters
+///
+/// - `strategy`: The new [`ExitStrategy`]
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::errors::set_exit_strategy(logging_rs::errors::ExitStrategy::PANIC);
+/// ```
+///
+/// # See also
+///
+/// - [`ExitStrategy`]
+/// - [`exit_strategy()`]
+/// - [`Error::raise()`]
+pub fn set_exit_strategy(strategy: ExitStrategy) {
+    *EXIT_STRATEGY.write().unwrap_or_else(|error| error.into_inner()) = strategy;
+}
+
+/// Returns the currently used exit strategy.
+///
+/// # Returns
+///
+/// The current [`ExitStrategy`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::errors::exit_strategy();
+/// ```
+///
+/// # See also
+///
+/// - [`ExitStrategy`]
+/// - [`set_exit_strategy()`]
+pub fn exit_strategy() -> ExitStrategy {
+    *EXIT_STRATEGY.read().unwrap_or_else(|error| error.into_inner())
+}
+
+
+/////////////////////
+// ERROR ARGUMENTS //
+/////////////////////
+
+/// Creates the standardized formatting arguments describing the given error.
+///
+/// The argument names follow the OpenTelemetry and ECS conventions, so structured outputs can be grouped by error kind:
+///
+/// - `error.kind`: The type name of the error
+/// - `error.message`: The error message
+/// - `error.stack`: The chain of error sources, one per line
+///
+/// # Parameters
+///
+/// - `error`: The error to describe
+///
+/// # Returns
+///
+/// A vector of key-value pairs of the argument names and values.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+/// let arguments: Vec<(&str, String)> = logging_rs::errors::error_arguments(&error);
+///
+/// assert_eq!(arguments[0], ("error.kind", "std::io::error::Error".to_owned()));
+/// assert_eq!(arguments[1], ("error.message", "disk full".to_owned()));
+/// ```
+///
+/// # See also
+///
+/// - [`crate::Logger::log_error()`]
+pub fn error_arguments<E: std::error::Error + ?Sized>(error: &E) -> Vec<(&'static str, String)> {
+    let mut stack: Vec<String> = vec![];
+    let mut source: Option<&(dyn std::error::Error + 'static)> = error.source();
+
+    while let Some(cause) = source {
+        stack.push(cause.to_string());
+        source = cause.source();
+    }
+
+    vec![
+        ("error.kind", std::any::type_name::<E>().to_owned()),
+        ("error.message", error.to_string()),
+        ("error.stack", stack.join("\n"))
+    ]
+}
+
+
+//////////////
+// HANDLERS //
+//////////////
+
+/// Function handling internal errors reported by [`Error::report()`].
+pub type Handler = dyn Fn(&Error, &str) + Send + Sync;
+
+/// The currently used internal error handler.
+static HANDLER: RwLock<Option<Arc<Handler>>> = RwLock::new(None);
+
+thread_local! {
+    /// Whether the current thread is already handling an internal error.
+    static REPORTING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Marks the current thread as handling an internal error until it is dropped, even if the handler panics.
+struct Reporting;
+
+impl Reporting {
+    /// Marks the current thread as handling an internal error.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the current thread is already handling an internal error.
+    fn start() -> Option<Reporting> {
+        match REPORTING.with(|reporting| reporting.replace(true)) {
+            true => None,
+            false => Some(Reporting)
+        }
+    }
+}
+
+impl Drop for Reporting {
+    fn drop(&mut self) {
+        REPORTING.with(|reporting| reporting.set(false));
+    }
+}
+
+/// Sets the handler used for internal errors, like files that could not be opened.
+///
+/// Errors reported while the handler is running (for example because the handler logs to a broken file) are printed
+/// to stderr instead of being passed to the handler again.
+///
+/// # Parameters
+///
+/// - `handler`: The function receiving the error and its details
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::errors::set_handler(|error: &logging_rs::errors::Error, details: &str| {
+///     println!("Logging failed: {} ({})", error.name, details);
+/// });
+/// # logging_rs::errors::reset_handler();
+/// ```
+///
+/// # See also
+///
+/// - [`set_internal_logger()`]
+/// - [`reset_handler()`]
+/// - [`Error::report()`]
+pub fn set_handler<F: Fn(&Error, &str) + Send + Sync + 'static>(handler: F) {
+    *HANDLER.write().unwrap_or_else(|error| error.into_inner()) = Some(Arc::new(handler));
+}
+
+/// Logs internal errors with logging level [`crate::Level::ERROR`] using the given logger.
+///
+/// # Parameters
+///
+/// - `logger`: The [`crate::Logger`] to log internal errors with
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::errors::set_internal_logger(logging_rs::Logger::new(logging_rs::Formatter::default(), vec![logging_rs::Output::STDERR]));
+/// # logging_rs::errors::reset_handler();
+/// ```
+///
+/// # See also
+///
+/// - [`set_handler()`]
+/// - [`Error::report()`]
+pub fn set_internal_logger(logger: crate::Logger) {
+    set_handler(move |error: &Error, details: &str| {
+        logger.log("{{error.name}}: {{error.description}}\n{{details}}", crate::Level::ERROR, file!(), vec![
+            ("error.name", error.name.clone()),
+            ("error.description", error.description.clone()),
+            ("details", details.to_owned())
+        ]);
+    });
+}
+
+/// Removes the internal error handler, restoring the default behavior of printing the error and exiting.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::errors::reset_handler();
+/// ```
+///
+/// # See also
+///
+/// - [`set_handler()`]
+pub fn reset_handler() {
+    *HANDLER.write().unwrap_or_else(|error| error.into_inner()) = None;
+}
+
+/// Runs the given operation, reporting panics as internal errors instead of unwinding into the caller.
+pub(crate) fn guard<F: FnOnce()>(operation: F) {
+    guard_with(|| Error::new("Sink error", "A sink panicked while writing a record", 4), operation);
+}
+
+/// Runs the given operation, reporting panics as the given internal error instead of unwinding into the caller.
+///
+/// The error is printed to stderr and passed to the handler, if one is set. The [`ExitStrategy`] is never applied and
+/// panicking handlers are caught, so a panicking operation never exits the process or unwinds into the caller.
+pub(crate) fn guard_with<E: FnOnce() -> Error, F: FnOnce()>(error: E, operation: F) {
+    if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(operation)) {
+        let details: String = panic_details(payload.as_ref());
+        let error: Error = error();
+
+        eprintln!("{}", error);
+        eprintln!("{}", details);
+
+        let handler: Option<Arc<Handler>> = HANDLER.read().unwrap_or_else(|error| error.into_inner()).clone();
+
+        if let (Some(handler), Some(_reporting)) = (handler, Reporting::start()) {
+            if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| handler(&error, &details))) {
+                eprintln!("The internal error handler panicked: {}", panic_details(payload.as_ref()));
+            }
+        }
+    }
+}
+
+/// Returns the message of the given panic payload.
+fn panic_details(payload: &(dyn std::any::Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => payload.downcast_ref::<String>().cloned().unwrap_or_else(|| "Unknown panic".to_owned())
+    }
+}
+
+
+///////////
+// ERROR //
+///////////
+
+/// Error object.
+///
+/// Use [`Error::new()`] to create error objects instead of using this struct.
+///
+/// # Parameters
+///
+/// - `name`: The errors name.
+/// - `description`: The error description.
+/// - `exit_code`: The errors exit code.
+///
+/// # Returns
+///
+/// A new `Error` object with the specified name and description.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::errors::Error {
+///     name: "name".to_owned(),
+///     description: "description".to_owned(),
+///     exit_code: 1
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Error {
+    /// The errors name.
+    pub name: String,
+    /// The error description.
+    pub description: String,
+    /// The errors exit code.
+    pub exit_code: i32,
+}
+
+/// Display implementation for the error object.
+impl fmt::Display for Error {
+    /// Format implementation for the error object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The error object.
+    /// - `f`: The [`fmt::Formatter`] to use.
+    ///
+    /// # Returns
+    ///
+    /// A [`fmt::Result`] containing the formatted error message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let error = logging_rs::errors::Error::new("name", "description", 1);
+    /// println!("{}", error);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`fmt::Display`]
+    /// - [`Error`]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\x1b[31;1m{}\x1b[0m: {}", self.name, self.description)
+    }
+}
+
+/// Standard error implementation for the error object, allowing it to be used with `?` and boxed errors.
+impl std::error::Error for Error {}
+
+/// Conversion from IO errors into error objects.
+impl TryFrom<io::Error> for Error {
+    type Error = io::Error;
+
+    /// Converts the given IO error into an error object.
+    ///
+    /// Errors occurring while opening files are converted into file errors (exit code 1), errors occurring while
+    /// writing are converted into writing errors (exit code 2).
+    ///
+    /// # Parameters
+    ///
+    /// - `error`: The IO error to convert.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the error object or the original IO error if it has no matching error object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::NotFound, "not found");
+    /// assert_eq!(logging_rs::errors::Error::try_from(error).unwrap().exit_code, 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Error`]
+    fn try_from(error: io::Error) -> Result<Error, io::Error> {
+        match error.kind() {
+            io::ErrorKind::NotFound | io::ErrorKind::PermissionDenied | io::ErrorKind::AlreadyExists | io::ErrorKind::InvalidInput => {
+                Ok(Error::new("File error", &error.to_string(), 1))
+            },
+            io::ErrorKind::WriteZero | io::ErrorKind::BrokenPipe | io::ErrorKind::Interrupted | io::ErrorKind::UnexpectedEof => {
+                Ok(Error::new("Writing error", &error.to_string(), 2))
+            },
+            _ => Err(error)
+        }
+    }
+}
+
+impl Error {
+    /// Creates a new error object.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The errors name.
+    /// - `description`: The error description.
+    /// - `exit_code`: The errors exit code.
+    ///
+    /// # Returns
+    ///
+    /// A new `Error` object with the specified name and description.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::errors::Error::new("name", "description", 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Error`]
+    pub fn new(name: &str, description: &str, exit_code: i32) -> Error {
+        Error {
+            name: name.to_owned(),
+            description: description.to_owned(),
+            exit_code,
+        }
+    }
+
+    /// Returns the errors exit code as a process exit code.
+    ///
+    /// Exit codes outside of the range `0..=255` are truncated by the platform, so they are clamped to `1`.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The error object.
+    ///
+    /// # Returns
+    ///
+    /// A [`std::process::ExitCode`] that can be returned from `main`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let error: logging_rs::errors::Error = logging_rs::errors::Error::new("name", "description", 2);
+    /// assert_eq!(error.exit_code(), std::process::ExitCode::from(2));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Error`]
+    pub fn exit_code(&self) -> std::process::ExitCode {
+        std::process::ExitCode::from(u8::try_from(self.exit_code).unwrap_or(1))
+    }
+
+    /// Reports the error to the internal error handler.
+    ///
+    /// If no handler was set using [`set_handler()`], the error is printed to stderr and the current [`ExitStrategy`]
+    /// is applied.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The error object.
+    /// - `details`: The error details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::errors::set_handler(|_error: &logging_rs::errors::Error, _details: &str| {});
+    ///
+    /// let error: logging_rs::errors::Error = logging_rs::errors::Error::new("name", "description", 1);
+    /// error.report("Something went very wrong");
+    /// # logging_rs::errors::reset_handler();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Error`]
+    /// - [`set_handler()`]
+    pub fn report(&self, details: &str) {
+        let handler: Option<Arc<Handler>> = HANDLER.read().unwrap_or_else(|error| error.into_inner()).clone();
+
+        match (handler, Reporting::start()) {
+            (Some(handler), Some(_reporting)) => handler(self, details),
+            (Some(_), None) => {
+                eprintln!("{}", self);
+                eprintln!("{}", details);
+            },
+            (None, _) => self.exit(details)
+        }
+    }
+
+    /// Raises the error and exits with the specified exit code.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The error object.
+    /// - `details`: The error details.
+    ///
+    /// # Aborts
+    ///
+    /// Exits with the specified exit code, unless a different [`ExitStrategy`] was set using [`set_exit_strategy()`].
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # #![allow(deprecated)]
+    /// # use logging_rs;
+    /// # let error: logging_rs::errors::Error = logging_rs::errors::Error::new("name", "description", 1);
+    /// error.raise("Something went very wrong");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Error`]
+    /// - [`ExitStrategy`]
+    #[deprecated(since = "1.2.0", note = "use `Error::report()` to route errors through the internal error handler instead")]
+    pub fn raise(&self, details: &str) {
+        self.exit(details);
+    }
+
+    /// Prints the error and applies the current exit strategy.
+    fn exit(&self, details: &str) {
+        eprintln!("{}", self);
+        eprintln!("{}", details);
+
+        match exit_strategy() {
+            ExitStrategy::EXIT => std::process::exit(self.exit_code),
+            ExitStrategy::PANIC => panic!("{}: {}\n{}", self.name, self.description, details),
+            ExitStrategy::CUSTOM(function) => function(self)
+        }
+    }
+}
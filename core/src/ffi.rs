@@ -0,0 +1,206 @@
+#![doc = include_str!("../../.github/ffi.md")]
+// logging-rs ffi
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::ffi::{c_char, c_int, CStr};
+
+use crate::{Formatter, Level, Logger, Output, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Value of [`Level::DEBUG`] for [`logging_rs_log()`].
+pub const LOGGING_RS_DEBUG: c_int = 0;
+/// Value of [`Level::INFO`] for [`logging_rs_log()`].
+pub const LOGGING_RS_INFO: c_int = 1;
+/// Value of [`Level::WARN`] for [`logging_rs_log()`].
+pub const LOGGING_RS_WARN: c_int = 2;
+/// Value of [`Level::ERROR`] for [`logging_rs_log()`].
+pub const LOGGING_RS_ERROR: c_int = 3;
+/// Value of [`Level::FATAL`] for [`logging_rs_log()`].
+pub const LOGGING_RS_FATAL: c_int = 4;
+/// Value of [`Level::MESSAGE`] for [`logging_rs_log()`].
+pub const LOGGING_RS_MESSAGE: c_int = 5;
+
+/// Return value of successful calls.
+pub const LOGGING_RS_OK: c_int = 0;
+/// Return value of calls with invalid arguments, like null pointers, invalid UTF-8 or unknown levels.
+pub const LOGGING_RS_INVALID_ARGUMENT: c_int = -1;
+
+
+/////////
+// FFI //
+/////////
+
+/// Converts the given C string to a string slice.
+///
+/// # Safety
+///
+/// The pointer has to be null or point to a valid null terminated string.
+unsafe fn to_str<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        return None;
+    }
+
+    CStr::from_ptr(string).to_str().ok()
+}
+
+/// Converts the given level value to a [`Level`].
+fn to_level(level: c_int) -> Option<Level> {
+    match level {
+        LOGGING_RS_DEBUG => Some(Level::DEBUG),
+        LOGGING_RS_INFO => Some(Level::INFO),
+        LOGGING_RS_WARN => Some(Level::WARN),
+        LOGGING_RS_ERROR => Some(Level::ERROR),
+        LOGGING_RS_FATAL => Some(Level::FATAL),
+        LOGGING_RS_MESSAGE => Some(Level::MESSAGE),
+        _ => None
+    }
+}
+
+/// Creates a new logger writing to stdout using the default formatter.
+///
+/// # Returns
+///
+/// A pointer to the new logger, which has to be freed using [`logging_rs_free()`].
+///
+/// # Examples
+///
+/// ```c
+/// LoggingRsLogger *logger = logging_rs_new();
+/// ```
+///
+/// # See also
+///
+/// - [`logging_rs_free()`]
+#[no_mangle]
+pub extern "C" fn logging_rs_new() -> *mut Logger {
+    Box::into_raw(Box::new(Logger::new(Formatter::default(), vec![Output::STDOUT])))
+}
+
+/// Adds a file output to the logger.
+///
+/// # Parameters
+///
+/// - `logger`: The logger created using [`logging_rs_new()`]
+/// - `path`: The path of the file to append to
+///
+/// # Returns
+///
+/// [`LOGGING_RS_OK`] or [`LOGGING_RS_INVALID_ARGUMENT`].
+///
+/// # Safety
+///
+/// `logger` has to be null or a pointer returned by [`logging_rs_new()`] that was not freed yet and is not used by
+/// other threads at the same time. `path` has to be null or point to a valid null terminated string.
+///
+/// # Examples
+///
+/// ```c
+/// logging_rs_add_file(logger, "app.log");
+/// ```
+///
+/// # See also
+///
+/// - [`logging_rs_new()`]
+#[no_mangle]
+pub unsafe extern "C" fn logging_rs_add_file(logger: *mut Logger, path: *const c_char) -> c_int {
+    match (logger.as_mut(), to_str(path)) {
+        (Some(logger), Some(path)) => {
+            logger.writable_list.push(Output::FILE { path: path.to_owned() });
+            LOGGING_RS_OK
+        },
+        _ => LOGGING_RS_INVALID_ARGUMENT
+    }
+}
+
+/// Logs the given message.
+///
+/// # Parameters
+///
+/// - `logger`: The logger created using [`logging_rs_new()`]
+/// - `level`: One of the `LOGGING_RS_<LEVEL>` constants
+/// - `message`: The message to log
+/// - `path`: The path of the calling file, usually `__FILE__`
+///
+/// # Returns
+///
+/// [`LOGGING_RS_OK`] or [`LOGGING_RS_INVALID_ARGUMENT`].
+///
+/// # Safety
+///
+/// `logger` has to be null or a pointer returned by [`logging_rs_new()`] that was not freed yet. `message` and
+/// `path` have to be null or point to valid null terminated strings.
+///
+/// # Examples
+///
+/// ```c
+/// logging_rs_log(logger, LOGGING_RS_INFO, "Hello from C", __FILE__);
+/// ```
+///
+/// # See also
+///
+/// - [`crate::Logger::log()`]
+#[no_mangle]
+pub unsafe extern "C" fn logging_rs_log(logger: *const Logger, level: c_int, message: *const c_char, path: *const c_char) -> c_int {
+    match (logger.as_ref(), to_level(level), to_str(message), to_str(path)) {
+        (Some(logger), Some(level), Some(message), Some(path)) => {
+            logger.log_record(&Record::new(level, message, path, vec![]));
+            LOGGING_RS_OK
+        },
+        _ => LOGGING_RS_INVALID_ARGUMENT
+    }
+}
+
+/// Frees the given logger.
+///
+/// # Parameters
+///
+/// - `logger`: The logger created using [`logging_rs_new()`]. Null pointers are ignored
+///
+/// # Safety
+///
+/// `logger` has to be null or a pointer returned by [`logging_rs_new()`] that was not freed yet. The pointer must
+/// not be used afterwards.
+///
+/// # Examples
+///
+/// ```c
+/// logging_rs_free(logger);
+/// ```
+///
+/// # See also
+///
+/// - [`logging_rs_new()`]
+#[no_mangle]
+pub unsafe extern "C" fn logging_rs_free(logger: *mut Logger) {
+    if !logger.is_null() {
+        drop(Box::from_raw(logger));
+    }
+}
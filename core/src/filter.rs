@@ -0,0 +1,1101 @@
+#![doc = include_str!("../../.github/filter.md")]
+// logging-rs filter
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::{errors, Level, Logger, Record};
+
+#[cfg(feature = "regex")]
+pub use regex::Regex;
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Environment variable containing the filter directives.
+pub const ENVIRONMENT_VARIABLE: &str = "LOGGING_RS";
+
+/// Environment variable containing the filter directives if [`ENVIRONMENT_VARIABLE`] is not set.
+pub const FALLBACK_ENVIRONMENT_VARIABLE: &str = "RUST_LOG";
+
+/// Level disabling all records of a target, parsed from `off`.
+pub const OFF: Level = Level::custom("OFF", u8::MAX, "");
+
+
+///////////////
+// DIRECTIVE //
+///////////////
+
+/// Filter directive object.
+///
+/// # Parameters
+///
+/// - `target`: The target the directive applies to, or `None` for all targets
+/// - `level`: The minimum [`Level`] of records of the target
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::filter::Directive {
+///     target: Some("my_crate::db".to_owned()),
+///     level: logging_rs::Level::DEBUG
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Directive {
+    /// The target the directive applies to, or `None` for all targets
+    pub target: Option<String>,
+    /// The minimum level of records of the target
+    pub level: Level,
+}
+
+impl Directive {
+    /// Checks whether the directive applies to the given target.
+    ///
+    /// The target of the directive is a module path whose segments may contain `*` wildcards matching any
+    /// characters, so `hyper::*` matches all submodules of `hyper` and `*_worker` all modules ending with `_worker`.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The directive object
+    /// - `target`: The target, see [`target()`]
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the target is the target of the directive or one of its submodules.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let directive: logging_rs::filter::Directive = logging_rs::filter::Directive { target: Some("db".to_owned()), level: logging_rs::Level::DEBUG };
+    ///
+    /// assert!(directive.matches("db::pool"));
+    /// assert!(!directive.matches("dbus"));
+    ///
+    /// let directive: logging_rs::filter::Directive = logging_rs::filter::Directive { target: Some("hyper::*".to_owned()), level: logging_rs::filter::OFF };
+    ///
+    /// assert!(directive.matches("hyper::client::conn"));
+    /// assert!(!directive.matches("hyper"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Directive`]
+    pub fn matches(&self, target: &str) -> bool {
+        let Some(pattern) = &self.target else {
+            return true;
+        };
+
+        let mut segments = target.split("::").filter(|segment| !segment.is_empty());
+        pattern.split("::").all(|pattern| segments.next().is_some_and(|segment| wildcard(pattern, segment)))
+    }
+}
+
+
+////////////////
+// ENV FILTER //
+////////////////
+
+/// Filter object applying per-target minimum levels.
+///
+/// Filters are parsed from comma separated directives like `RUST_LOG`:
+///
+/// - `level`: Sets the minimum level of all targets
+/// - `target=level`: Sets the minimum level of the target and its submodules
+/// - `target` or `target=`: Enables all records of the target and its submodules
+///
+/// Levels are parsed case-insensitively, `trace` is parsed as [`Level::DEBUG`] and `off` as [`OFF`]. The most
+/// specific directive matching the [`target()`] of a record applies. Like `env_logger`, records without a matching
+/// directive are not logged, unless the filter has no directives at all.
+///
+/// Use [`EnvFilter::parse()`] or [`EnvFilter::from_environment()`] to create filter objects.
+///
+/// # Parameters
+///
+/// - `directives`: The parsed [`Directive`]s
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse("info,my_crate::db=debug").unwrap();
+///
+/// assert!(filter.is_enabled(logging_rs::Level::DEBUG, "/home/user/.cargo/registry/src/index/my_crate-1.0.0/src/db/pool.rs"));
+/// assert!(!filter.is_enabled(logging_rs::Level::DEBUG, "src/main.rs"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct EnvFilter {
+    /// The parsed directives
+    pub directives: Vec<Directive>,
+}
+
+impl EnvFilter {
+    /// Parses the given directives.
+    ///
+    /// # Parameters
+    ///
+    /// - `directives`: The comma separated directives, for example `info,my_crate::db=debug`
+    ///
+    /// # Returns
+    ///
+    /// The parsed `EnvFilter` or an error if one of the levels is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::filter::EnvFilter::parse("warn,hyper=off").is_ok());
+    /// assert!(logging_rs::filter::EnvFilter::parse("hyper=loud").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`EnvFilter`]
+    pub fn parse(directives: &str) -> Result<EnvFilter, errors::Error> {
+        let mut filter: EnvFilter = EnvFilter::default();
+
+        for directive in directives.split(',').map(str::trim).filter(|directive| !directive.is_empty()) {
+            filter = filter.with(parse_directive(directive)?);
+        }
+
+        Ok(filter)
+    }
+
+    /// Sets the minimum level of the given target, replacing the directive of the same target.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The filter object
+    /// - `target`: The target, may contain `*` wildcards, see [`Directive::matches()`]
+    /// - `level`: The minimum [`Level`] of records of the target
+    ///
+    /// # Returns
+    ///
+    /// The filter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::default()
+    ///     .with_directive("hyper::*", logging_rs::filter::OFF)
+    ///     .with_directive("my_app::payments", logging_rs::Level::DEBUG);
+    ///
+    /// assert!(!filter.is_enabled(logging_rs::Level::ERROR, "/registry/hyper-1.2.0/src/client.rs"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`EnvFilter::with_default()`]
+    pub fn with_directive(self, target: &str, level: Level) -> EnvFilter {
+        self.with(Directive { target: Some(target.to_owned()), level })
+    }
+
+    /// Sets the minimum level of targets without a more specific directive.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The filter object
+    /// - `level`: The minimum [`Level`]
+    ///
+    /// # Returns
+    ///
+    /// The filter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::default().with_default(logging_rs::Level::WARN);
+    /// assert_eq!(filter.min_level(), Some(logging_rs::Level::WARN));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`EnvFilter::with_directive()`]
+    pub fn with_default(self, level: Level) -> EnvFilter {
+        self.with(Directive { target: None, level })
+    }
+
+    /// Adds the given directive, replacing the directive of the same target.
+    fn with(mut self, directive: Directive) -> EnvFilter {
+        self.directives.retain(|other| other.target != directive.target);
+        self.directives.push(directive);
+        self
+    }
+
+    /// Parses the directives of the [`ENVIRONMENT_VARIABLE`], or the [`FALLBACK_ENVIRONMENT_VARIABLE`] if it is not
+    /// set.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `EnvFilter`, an empty filter if neither variable is set or an error if one of the levels is
+    /// unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// std::env::set_var("LOGGING_RS", "warn,my_app::payments=debug");
+    ///
+    /// let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::from_environment().unwrap();
+    /// assert_eq!(filter.directives.len(), 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`EnvFilter::parse()`]
+    /// - [`Logger::from_env()`]
+    pub fn from_environment() -> Result<EnvFilter, errors::Error> {
+        match std::env::var(ENVIRONMENT_VARIABLE).or_else(|_| std::env::var(FALLBACK_ENVIRONMENT_VARIABLE)) {
+            Ok(directives) => EnvFilter::parse(&directives),
+            Err(_) => Ok(EnvFilter::default())
+        }
+    }
+
+    /// Returns the minimum level of records with the given path.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The filter object
+    /// - `path`: The path of the calling file, see [`Record::path`]
+    ///
+    /// # Returns
+    ///
+    /// The level of the most specific matching directive, or `None` if no directive matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse("info,db=debug").unwrap();
+    ///
+    /// assert_eq!(filter.level_for("src/db/pool.rs"), Some(logging_rs::Level::DEBUG));
+    /// assert_eq!(filter.level_for("src/main.rs"), Some(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`target()`]
+    pub fn level_for(&self, path: &str) -> Option<Level> {
+        let target: String = target(path);
+
+        self.directives.iter()
+            .filter(|directive| directive.matches(&target))
+            .max_by_key(|directive| directive.target.as_ref().map_or(0, |target| target.len() + 1))
+            .map(|directive| directive.level)
+    }
+
+    /// Checks whether records with the given level and path are logged.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The filter object
+    /// - `level`: The [`Level`] of the record
+    /// - `path`: The path of the calling file, see [`Record::path`]
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the level is at least the level of the most specific matching directive. Always
+    /// `true` for filters without directives and `false` if no directive matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse("warn").unwrap();
+    /// assert!(!filter.is_enabled(logging_rs::Level::INFO, "src/main.rs"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`EnvFilter::level_for()`]
+    pub fn is_enabled(&self, level: Level, path: &str) -> bool {
+        self.directives.is_empty() || self.level_for(path).is_some_and(|minimum| level >= minimum)
+    }
+
+    /// Returns the lowest level enabled for any target.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The filter object
+    ///
+    /// # Returns
+    ///
+    /// The lowest level of all directives, or `None` if the filter has no directives.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse("warn,db=info").unwrap();
+    /// assert_eq!(filter.min_level(), Some(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::is_enabled()`]
+    pub fn min_level(&self) -> Option<Level> {
+        self.directives.iter().map(|directive| directive.level).min()
+    }
+
+    /// Checks whether the given record is logged.
+    pub(crate) fn accepts(&self, record: &Record) -> bool {
+        self.is_enabled(record.level, &record.path)
+    }
+}
+
+
+////////////////
+// DIRECTIVES //
+////////////////
+
+/// Directives object parsed using the grammar of `env_logger`.
+///
+/// Specifications are the comma separated directives of an [`EnvFilter`], optionally followed by `/` and a message
+/// pattern only logging records whose formatted message matches the pattern.
+///
+/// Unlike [`EnvFilter::parse()`], an empty specification logs records of at least [`Level::ERROR`], invalid
+/// directives are skipped and a specification with more than one `/` is ignored completely. Patterns are regular
+/// expressions with the `regex` feature, and substrings otherwise.
+///
+/// Use [`Directives::parse()`] or [`Directives::from_environment()`] to create directives objects.
+///
+/// # Parameters
+///
+/// - `filter`: The per-target [`EnvFilter`]
+/// - `pattern`: The pattern messages have to match
+/// - `invalid`: The skipped invalid directives
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let directives: logging_rs::filter::Directives = logging_rs::filter::Directives::parse("warn,my_crate::db=trace/timeout");
+///
+/// assert!(!directives.filter.is_enabled(logging_rs::Level::DEBUG, "src/main.rs"));
+/// assert_eq!(directives.pattern.as_deref(), Some("timeout"));
+///
+/// let logger: logging_rs::Logger = logging_rs::Logger::default().with_directives(directives);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Directives {
+    /// The per-target filter
+    pub filter: EnvFilter,
+    /// The pattern messages have to match
+    pub pattern: Option<String>,
+    /// The skipped invalid directives
+    pub invalid: Vec<String>,
+}
+
+impl Directives {
+    /// Parses the given `env_logger` specification.
+    ///
+    /// # Parameters
+    ///
+    /// - `specification`: The specification, for example `warn,my_crate::db=trace/timeout`
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Directives`. Invalid directives are collected in [`Directives::invalid`] instead of failing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let directives: logging_rs::filter::Directives = logging_rs::filter::Directives::parse("info,hyper=loud,tokio=");
+    ///
+    /// assert_eq!(directives.filter.level_for("/registry/tokio-1.0.0/src/lib.rs"), Some(logging_rs::Level::DEBUG));
+    /// assert_eq!(directives.invalid, vec!["hyper=loud".to_owned()]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Directives`]
+    /// - [`EnvFilter::parse()`]
+    pub fn parse(specification: &str) -> Directives {
+        let mut result: Directives = Directives::default();
+        let mut parts = specification.split('/');
+        let (targets, pattern) = (parts.next().unwrap_or_default(), parts.next());
+
+        if parts.next().is_some() {
+            result.invalid.push(specification.to_owned());
+            return result.with_defaults();
+        }
+
+        for directive in targets.split(',').map(str::trim).filter(|directive| !directive.is_empty()) {
+            match parse_directive(directive) {
+                Ok(parsed) => result.filter = result.filter.with(parsed),
+                Err(_) => result.invalid.push(directive.to_owned())
+            }
+        }
+
+        match pattern {
+            #[cfg(feature = "regex")]
+            Some(pattern) if Regex::new(pattern).is_err() => result.invalid.push(format!("/{}", pattern)),
+            Some(pattern) => result.pattern = Some(pattern.to_owned()),
+            None => {}
+        }
+
+        result.with_defaults()
+    }
+
+    /// Adds the default directive of `env_logger` if there are no directives.
+    fn with_defaults(mut self) -> Directives {
+        if self.filter.directives.is_empty() {
+            self.filter = self.filter.with_default(Level::ERROR);
+        }
+
+        self
+    }
+
+    /// Parses the specification of the [`ENVIRONMENT_VARIABLE`], or the [`FALLBACK_ENVIRONMENT_VARIABLE`] if it is
+    /// not set.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Directives`, logging records of at least [`Level::ERROR`] if neither variable is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// std::env::set_var("RUST_LOG", "warn,my_app::payments=debug");
+    ///
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default().with_directives(logging_rs::filter::Directives::from_environment());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Directives::parse()`]
+    pub fn from_environment() -> Directives {
+        Directives::parse(&std::env::var(ENVIRONMENT_VARIABLE).or_else(|_| std::env::var(FALLBACK_ENVIRONMENT_VARIABLE)).unwrap_or_default())
+    }
+
+}
+
+
+///////////////////
+// SHARED FILTER //
+///////////////////
+
+/// Shared record filter object.
+///
+/// Wraps a predicate deciding whether a record is logged. Filters are checked after the minimum level and the
+/// [`EnvFilter`] of the logger, before records are formatted or written. Shared filters are compared by name and
+/// identity.
+///
+/// Use [`SharedFilter::new()`] to create shared filter objects.
+///
+/// # Parameters
+///
+/// - `name`: The name of the filter
+/// - `predicate`: The predicate returning whether a record is logged
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::filter::SharedFilter {
+///     name: None,
+///     predicate: std::sync::Arc::new(|record: &logging_rs::Record| !record.message.is_empty())
+/// };
+/// ```
+#[derive(Clone)]
+pub struct SharedFilter {
+    /// The name of the filter
+    pub name: Option<String>,
+    /// The predicate returning whether a record is logged
+    pub predicate: Arc<dyn Fn(&Record) -> bool + Send + Sync>,
+}
+
+impl SharedFilter {
+    /// Creates a new unnamed shared filter object.
+    ///
+    /// # Parameters
+    ///
+    /// - `predicate`: The predicate returning whether a record is logged
+    ///
+    /// # Returns
+    ///
+    /// A new `SharedFilter` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::filter::SharedFilter::new(|record: &logging_rs::Record| record.argument("tenant") != Some("internal"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`SharedFilter`]
+    pub fn new<F: Fn(&Record) -> bool + Send + Sync + 'static>(predicate: F) -> SharedFilter {
+        SharedFilter {
+            name: None,
+            predicate: Arc::new(predicate)
+        }
+    }
+
+    /// Creates a new named shared filter object.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name used to remove the filter
+    /// - `predicate`: The predicate returning whether a record is logged
+    ///
+    /// # Returns
+    ///
+    /// A new `SharedFilter` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::filter::SharedFilter::named("tenants", |record: &logging_rs::Record| record.argument("tenant").is_some());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`SharedFilter`]
+    /// - [`crate::Logger::remove_filter()`]
+    pub fn named<F: Fn(&Record) -> bool + Send + Sync + 'static>(name: &str, predicate: F) -> SharedFilter {
+        SharedFilter {
+            name: Some(name.to_owned()),
+            predicate: Arc::new(predicate)
+        }
+    }
+
+    /// Checks whether the given record is logged. Panicking predicates are reported and let the record pass.
+    pub(crate) fn accepts(&self, record: &Record) -> bool {
+        let mut accepted: bool = true;
+        errors::guard_with(|| errors::Error::new("Filter error", "A filter panicked while checking a record", 4), || accepted = (self.predicate)(record));
+
+        accepted
+    }
+
+    /// Returns the address of the shared filter, used for comparisons.
+    fn address(&self) -> usize {
+        Arc::as_ptr(&self.predicate) as *const () as usize
+    }
+}
+
+impl fmt::Debug for SharedFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SharedFilter")
+            .field("name", &self.name)
+            .field("predicate", &format_args!("{:#x}", self.address()))
+            .finish()
+    }
+}
+
+/// Loggers are used across unwind boundaries, so shared filters are treated as unwind safe like shared sinks.
+impl std::panic::RefUnwindSafe for SharedFilter {}
+
+impl std::panic::UnwindSafe for SharedFilter {}
+
+impl PartialEq for SharedFilter {
+    fn eq(&self, other: &SharedFilter) -> bool {
+        self.name == other.name && self.address() == other.address()
+    }
+}
+
+impl Eq for SharedFilter {}
+
+impl Hash for SharedFilter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.address().hash(state);
+    }
+}
+
+impl PartialOrd for SharedFilter {
+    fn partial_cmp(&self, other: &SharedFilter) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SharedFilter {
+    fn cmp(&self, other: &SharedFilter) -> std::cmp::Ordering {
+        (&self.name, self.address()).cmp(&(&other.name, other.address()))
+    }
+}
+
+
+////////////////////
+// MESSAGE FILTER //
+////////////////////
+
+/// Filter matching messages against regular expressions.
+///
+/// Records are logged if their message matches any of the included patterns, or there are none, and none of the
+/// excluded patterns. Patterns are matched against the raw message by default, or the message with all arguments
+/// replaced if `formatted` is set.
+///
+/// Use [`MessageFilter::new()`] to create message filter objects.
+///
+/// # Parameters
+///
+/// - `include`: The patterns of which one has to match
+/// - `exclude`: The patterns of which none may match
+/// - `formatted`: Whether to match the message with its arguments replaced
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let filter: logging_rs::filter::MessageFilter = logging_rs::filter::MessageFilter::new()
+///     .with_exclude(logging_rs::filter::Regex::new("^Connection reset").unwrap());
+///
+/// let logger: logging_rs::Logger = logging_rs::Logger::default().with_message_filter(filter);
+/// ```
+#[cfg(feature = "regex")]
+#[derive(Clone, Debug, Default)]
+pub struct MessageFilter {
+    /// The patterns of which one has to match
+    pub include: Vec<Regex>,
+    /// The patterns of which none may match
+    pub exclude: Vec<Regex>,
+    /// Whether to match the message with its arguments replaced
+    pub formatted: bool,
+}
+
+#[cfg(feature = "regex")]
+impl MessageFilter {
+    /// Creates a new message filter object letting all records pass.
+    ///
+    /// # Returns
+    ///
+    /// A new `MessageFilter` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::filter::MessageFilter::new();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`MessageFilter`]
+    pub fn new() -> MessageFilter {
+        MessageFilter::default()
+    }
+
+    /// Adds a pattern of which one has to match.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message filter object
+    /// - `pattern`: The compiled [`Regex`]
+    ///
+    /// # Returns
+    ///
+    /// The message filter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::filter::MessageFilter::new().with_include(logging_rs::filter::Regex::new("payment").unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`MessageFilter::with_exclude()`]
+    pub fn with_include(mut self, pattern: Regex) -> MessageFilter {
+        self.include.push(pattern);
+        self
+    }
+
+    /// Adds a pattern of which none may match.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message filter object
+    /// - `pattern`: The compiled [`Regex`]
+    ///
+    /// # Returns
+    ///
+    /// The message filter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::filter::MessageFilter::new().with_exclude(logging_rs::filter::Regex::new("(?i)deprecated").unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`MessageFilter::with_include()`]
+    pub fn with_exclude(mut self, pattern: Regex) -> MessageFilter {
+        self.exclude.push(pattern);
+        self
+    }
+
+    /// Sets whether to match the message with its arguments replaced.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message filter object
+    /// - `formatted`: Whether to match the formatted message
+    ///
+    /// # Returns
+    ///
+    /// The message filter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::filter::MessageFilter::new()
+    ///     .with_exclude(logging_rs::filter::Regex::new("user=guest").unwrap())
+    ///     .with_formatted(true);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`MessageFilter`]
+    pub fn with_formatted(mut self, formatted: bool) -> MessageFilter {
+        self.formatted = formatted;
+        self
+    }
+
+    /// Checks whether the given record is logged.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The message filter object
+    /// - `record`: The [`Record`] to check
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the message matches an included pattern and no excluded pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let filter: logging_rs::filter::MessageFilter = logging_rs::filter::MessageFilter::new().with_exclude(logging_rs::filter::Regex::new("^Ping").unwrap());
+    ///
+    /// assert!(!filter.accepts(&logging_rs::Record::new(logging_rs::Level::INFO, "Ping received", "src/main.rs", vec![])));
+    /// assert!(filter.accepts(&logging_rs::Record::new(logging_rs::Level::INFO, "Order placed", "src/main.rs", vec![])));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`MessageFilter`]
+    pub fn accepts(&self, record: &Record) -> bool {
+        let formatted: String;
+        let message: &str = if self.formatted {
+            formatted = record.formatted_message();
+            &formatted
+        } else {
+            &record.message
+        };
+
+        (self.include.is_empty() || self.include.iter().any(|pattern| pattern.is_match(message))) && !self.exclude.iter().any(|pattern| pattern.is_match(message))
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Returns the target of the given path of a calling file.
+///
+/// The target is the module path of the file, prefixed by the name of the crate if the path contains it. Paths of
+/// dependencies (`.../my_crate-1.0.0/src/db/mod.rs`) result in `my_crate::db`, relative paths of the current
+/// crate (`src/db/mod.rs`) in `db`. Paths of files outside of a `src` directory are returned with `::` separators.
+///
+/// # Parameters
+///
+/// - `path`: The path of the calling file
+///
+/// # Returns
+///
+/// The target as module path.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::filter::target("src/db/pool.rs"), "db::pool");
+/// assert_eq!(logging_rs::filter::target("/registry/hyper-0.14.28/src/client/mod.rs"), "hyper::client");
+/// assert_eq!(logging_rs::filter::target("crates/my-app/src/lib.rs"), "my_app");
+/// ```
+///
+/// # See also
+///
+/// - [`EnvFilter::level_for()`]
+pub fn target(path: &str) -> String {
+    let path: &str = path.strip_suffix(".rs").unwrap_or(path);
+    let segments: Vec<&str> = path.split(['/', '\\']).filter(|segment| !segment.is_empty() && *segment != ".").collect();
+
+    let (krate, modules): (Option<&str>, &[&str]) = match segments.iter().rposition(|segment| *segment == "src") {
+        Some(0) => (None, &segments[1..]),
+        Some(index) => (Some(segments[index - 1]), &segments[index + 1..]),
+        None => (None, &segments[..])
+    };
+
+    let mut target: Vec<String> = vec![];
+
+    if let Some(krate) = krate {
+        let name: &str = match krate.rsplit_once('-') {
+            Some((name, version)) if version.starts_with(|character: char| character.is_ascii_digit()) => name,
+            _ => krate
+        };
+
+        target.push(name.replace('-', "_"));
+    }
+
+    for (index, module) in modules.iter().enumerate() {
+        let last: bool = index + 1 == modules.len();
+
+        if !(last && (*module == "mod" || (index == 0 && (*module == "lib" || *module == "main")))) {
+            target.push(module.to_string());
+        }
+    }
+
+    target.join("::")
+}
+
+/// Checks whether the given text matches the pattern, where `*` matches any characters.
+fn wildcard(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, rest)) => {
+            let Some(text) = text.strip_prefix(prefix) else {
+                return false;
+            };
+
+            (0..=text.len()).filter(|index| text.is_char_boundary(*index)).any(|index| wildcard(rest, &text[index..]))
+        },
+        None => pattern == text
+    }
+}
+
+/// Parses a single directive of an [`EnvFilter`], shared by [`EnvFilter::parse()`] and [`Directives::parse()`].
+fn parse_directive(directive: &str) -> Result<Directive, errors::Error> {
+    match directive.split_once('=') {
+        Some((target, level)) if level.trim().is_empty() => Ok(Directive { target: Some(target.trim().to_owned()), level: Level::DEBUG }),
+        Some((target, level)) => Ok(Directive { target: Some(target.trim().to_owned()), level: parse_level(level)? }),
+        None => match parse_level(directive) {
+            Ok(level) => Ok(Directive { target: None, level }),
+            Err(_) => Ok(Directive { target: Some(directive.to_owned()), level: Level::DEBUG })
+        }
+    }
+}
+
+/// Parses the given level name, including `trace` and `off`.
+fn parse_level(name: &str) -> Result<Level, errors::Error> {
+    match name.trim().to_lowercase().as_str() {
+        "trace" => Ok(Level::DEBUG),
+        "off" => Ok(OFF),
+        _ => name.parse()
+    }
+}
+
+
+////////////
+// LOGGER //
+////////////
+
+impl Logger {
+    /// Sets the per-target filter of the logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `filter`: The [`EnvFilter`] to use
+    ///
+    /// # Returns
+    ///
+    /// The logger object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default().with_filter(logging_rs::filter::EnvFilter::parse("warn").unwrap());
+    /// assert!(!logger.is_enabled(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`EnvFilter`]
+    pub fn with_filter(mut self, filter: EnvFilter) -> Logger {
+        self.filter = filter;
+        self
+    }
+
+    /// Sets the per-target filter and message pattern of the logger from `env_logger` directives.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `directives`: The [`Directives`] to use
+    ///
+    /// # Returns
+    ///
+    /// The logger object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default().with_directives(logging_rs::filter::Directives::parse("warn"));
+    /// assert!(!logger.is_enabled(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Directives`]
+    /// - [`Logger::with_filter()`]
+    pub fn with_directives(mut self, directives: Directives) -> Logger {
+        self.filter = directives.filter;
+
+        if let Some(pattern) = directives.pattern {
+            #[cfg(feature = "regex")]
+            if let Ok(pattern) = Regex::new(&pattern) {
+                self.filters.push(SharedFilter::new(move |record: &Record| pattern.is_match(&record.formatted_message())));
+                return self;
+            }
+
+            self.filters.push(SharedFilter::new(move |record: &Record| record.formatted_message().contains(pattern.as_str())));
+        }
+
+        self
+    }
+
+    /// Adds a filter matching the messages of records against regular expressions.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `filter`: The [`MessageFilter`] to add
+    ///
+    /// # Returns
+    ///
+    /// The logger object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default().with_message_filter(
+    ///     logging_rs::filter::MessageFilter::new().with_exclude(logging_rs::filter::Regex::new("^hyper: ").unwrap())
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`MessageFilter`]
+    /// - [`SharedFilter`]
+    #[cfg(feature = "regex")]
+    pub fn with_message_filter(mut self, filter: MessageFilter) -> Logger {
+        self.filters.push(SharedFilter::new(move |record: &Record| filter.accepts(record)));
+        self
+    }
+
+    /// Sets the minimum level of records of the given module and its submodules.
+    ///
+    /// If the level is below the minimum level of the logger, the minimum level is lowered and the previous level
+    /// is kept for all other modules. Module rules are checked before the records are formatted or written.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `target`: The module path, may contain `*` wildcards, see [`Directive::matches()`]
+    /// - `level`: The minimum [`Level`] of records of the module
+    ///
+    /// # Returns
+    ///
+    /// The logger object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default()
+    ///     .with_level(logging_rs::Level::INFO)
+    ///     .with_module_level("my_app::payments", logging_rs::Level::DEBUG);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`EnvFilter::with_directive()`]
+    /// - [`Logger::with_module_silenced()`]
+    pub fn with_module_level(mut self, target: &str, level: Level) -> Logger {
+        self.filter = self.filter.with_directive(target, level);
+
+        if !self.filter.directives.iter().any(|directive| directive.target.is_none()) {
+            self.filter = self.filter.with_default(self.level);
+        }
+
+        if level < self.level {
+            self.level = level;
+        }
+
+        self
+    }
+
+    /// Silences all records of the given module and its submodules.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `target`: The module path, may contain `*` wildcards, see [`Directive::matches()`]
+    ///
+    /// # Returns
+    ///
+    /// The logger object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default().with_module_silenced("hyper::*");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::with_module_level()`]
+    pub fn with_module_silenced(self, target: &str) -> Logger {
+        self.with_module_level(target, OFF)
+    }
+}
@@ -0,0 +1,384 @@
+#![doc = include_str!("../../.github/gelf.md")]
+
+// logging-rs gelf
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fmt;
+use std::net::UdpSocket;
+use std::sync::{Arc, Mutex};
+
+use crate::sinks::Sink;
+use crate::{attachments, codec, environment, errors, ids, json, random, sinks, Formatter, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The GELF version written to the `version` field.
+pub const VERSION: &str = "1.1";
+
+/// The magic bytes starting every chunk of a chunked message.
+pub const CHUNK_MAGIC: [u8; 2] = [0x1e, 0x0f];
+
+/// The size of the header of every chunk, containing the magic bytes, the message id, the sequence number and the
+/// sequence count.
+pub const CHUNK_HEADER_SIZE: usize = 12;
+
+/// The maximum number of chunks of a message. Graylog drops messages with more chunks.
+pub const MAX_CHUNKS: usize = 128;
+
+/// The default maximum size of UDP datagrams, fitting into the MTU of most networks.
+pub const DEFAULT_CHUNK_SIZE: usize = 1420;
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Converts the given record into a GELF message.
+///
+/// The message contains the fields
+///
+/// - `version`: [`VERSION`]
+/// - `host`: The given host
+/// - `short_message`: The first line of the message, with the arguments inserted
+/// - `full_message`: The whole message, only if it has multiple lines
+/// - `timestamp`: The seconds since the Unix epoch with millisecond precision
+/// - `level`: The [syslog severity](crate::Level::syslog_severity()) of the level
+/// - `_level_name`, `_path` and `_uuid`: The level name, path and id of the record
+///
+/// followed by the arguments and attachments of the record as additional fields, prefixed with `_`. Characters not
+/// allowed in field names are replaced with `_` and the reserved `_id` field is written as `__id`.
+///
+/// # Parameters
+///
+/// - `record`: The record to convert
+/// - `host`: The name of the host sending the message
+///
+/// # Returns
+///
+/// The GELF message as [`json::Value`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::WARN, "Disk {{disk}} almost full", "src/main.rs", vec![("disk", "sda".to_owned())]);
+/// let message: logging_rs::json::Value = logging_rs::gelf::message(&record, "example.org");
+///
+/// assert_eq!(message.get("short_message").and_then(|value: &logging_rs::json::Value| value.as_str()), Some("Disk sda almost full"));
+/// assert_eq!(message.get("level"), Some(&logging_rs::json::Value::NUMBER("4".to_owned())));
+/// assert_eq!(message.get("_disk").and_then(|value: &logging_rs::json::Value| value.as_str()), Some("sda"));
+/// ```
+///
+/// # See also
+///
+/// - [`crate::Formatter::gelf()`]
+/// - [`Udp`]
+pub fn message(record: &Record, host: &str) -> json::Value {
+    let text: String = Formatter::new("", "{{message}}", "").format_record_with(false, record);
+
+    let mut entries: Vec<(String, json::Value)> = vec![
+        ("version".to_owned(), json::Value::STRING(VERSION.to_owned())),
+        ("host".to_owned(), json::Value::STRING(host.to_owned())),
+        ("short_message".to_owned(), json::Value::STRING(text.lines().next().unwrap_or_default().to_owned()))
+    ];
+
+    if text.contains('\n') {
+        entries.push(("full_message".to_owned(), json::Value::STRING(text.clone())));
+    }
+
+    entries.push(("timestamp".to_owned(), json::Value::NUMBER(format!("{}.{:03}", record.timestamp.timestamp(), record.timestamp.timestamp_subsec_millis()))));
+    entries.push(("level".to_owned(), json::Value::NUMBER(record.level.syslog_severity().to_string())));
+    entries.push(("_level_name".to_owned(), json::Value::STRING(record.level.name().to_owned())));
+    entries.push(("_path".to_owned(), json::Value::STRING(record.path.clone())));
+    entries.push(("_uuid".to_owned(), json::Value::STRING(ids::to_uuid(record.id))));
+
+    let arguments = record.arguments.iter().map(|(key, value)| (key.as_str(), value.clone()));
+    let attachments = record.attachments.iter().map(|attachment| (attachment.name.as_str(), attachment.to_hex(attachments::PREVIEW_SIZE)));
+
+    for (key, value) in arguments.chain(attachments) {
+        let mut key: String = "_".to_owned() + &key.replace(|character: char| !(character.is_ascii_alphanumeric() || "_.-".contains(character)), "_");
+
+        if key == "_id" {
+            key.insert(0, '_');
+        }
+
+        if !entries.iter().any(|(existing, _)| *existing == key) {
+            entries.push((key, json::Value::STRING(value)));
+        }
+    }
+
+    json::Value::OBJECT(entries)
+}
+
+/// Splits the given payload into GELF chunks, each at most the given size including the chunk header.
+///
+/// Payloads fitting into a single datagram are not chunked.
+///
+/// # Parameters
+///
+/// - `payload`: The encoded GELF message
+/// - `chunk_size`: The maximum size of every chunk, at least [`CHUNK_HEADER_SIZE`] + 1
+/// - `message_id`: The id of the message, identical for all chunks
+///
+/// # Returns
+///
+/// The chunks, or `None` if the payload needs more than [`MAX_CHUNKS`] chunks.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let chunks: Vec<Vec<u8>> = logging_rs::gelf::chunks(&[b'x'; 3000], 1420, 42).unwrap();
+///
+/// assert_eq!(chunks.len(), 3);
+/// assert_eq!(chunks[0][..2], logging_rs::gelf::CHUNK_MAGIC);
+/// assert_eq!(chunks[2][10..12], [2, 3]);
+/// ```
+///
+/// # See also
+///
+/// - [`Udp`]
+pub fn chunks(payload: &[u8], chunk_size: usize, message_id: u64) -> Option<Vec<Vec<u8>>> {
+    if payload.len() <= chunk_size {
+        return Some(vec![payload.to_vec()]);
+    }
+
+    let data_size: usize = chunk_size.max(CHUNK_HEADER_SIZE + 1) - CHUNK_HEADER_SIZE;
+    let count: usize = (payload.len() + data_size - 1) / data_size;
+
+    if count > MAX_CHUNKS {
+        return None;
+    }
+
+    Some(payload.chunks(data_size).enumerate().map(|(index, data)| {
+        let mut chunk: Vec<u8> = Vec::with_capacity(CHUNK_HEADER_SIZE + data.len());
+        chunk.extend_from_slice(&CHUNK_MAGIC);
+        chunk.extend_from_slice(&message_id.to_be_bytes());
+        chunk.push(index as u8);
+        chunk.push(count as u8);
+        chunk.extend_from_slice(data);
+        chunk
+    }).collect())
+}
+
+
+/////////
+// UDP //
+/////////
+
+/// Sink sending records as GELF messages to Graylog using UDP.
+///
+/// Messages are compressed using the codec of the sink, Graylog accepts uncompressed and gzip compressed payloads.
+/// Messages larger than the chunk size are split into chunks, messages needing more than [`MAX_CHUNKS`] chunks are
+/// dropped after reporting an error. The socket is opened when writing the first record. The formatter of the logger
+/// is not used, see [`message()`] for the fields of the messages.
+///
+/// # Parameters
+///
+/// - `address`: The address of the Graylog GELF UDP input, like `graylog.example.org:12201`
+/// - `host`: The host name sent in the `host` field
+/// - `chunk_size`: The maximum size of every datagram
+/// - `codec`: The codec compressing the messages
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![logging_rs::Output::STDOUT]);
+/// logger.add_sink(logging_rs::gelf::Udp::new("127.0.0.1:12201"));
+/// ```
+#[derive(Clone)]
+pub struct Udp {
+    /// The address of the Graylog GELF UDP input
+    pub address: String,
+    /// The host name sent in the `host` field
+    pub host: String,
+    /// The maximum size of every datagram
+    pub chunk_size: usize,
+    /// The codec compressing the messages
+    pub codec: Arc<dyn codec::Codec>,
+    /// The socket, opened when writing the first record
+    socket: Arc<Mutex<Option<UdpSocket>>>,
+}
+
+impl fmt::Debug for Udp {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_struct("Udp")
+            .field("address", &self.address)
+            .field("host", &self.host)
+            .field("chunk_size", &self.chunk_size)
+            .field("codec", &self.codec.name())
+            .finish()
+    }
+}
+
+impl Udp {
+    /// Creates a new GELF UDP sink object, sending the [host name](environment::hostname()) of the machine.
+    ///
+    /// # Parameters
+    ///
+    /// - `address`: The address of the Graylog GELF UDP input, like `graylog.example.org:12201`
+    ///
+    /// # Returns
+    ///
+    /// A new `Udp` object using [`DEFAULT_CHUNK_SIZE`] and sending uncompressed messages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::gelf::Udp::new("graylog.example.org:12201");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Udp`]
+    pub fn new(address: &str) -> Udp {
+        Udp {
+            address: address.to_owned(),
+            host: environment::hostname(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            codec: Arc::new(codec::Identity),
+            socket: Arc::new(Mutex::new(None))
+        }
+    }
+
+    /// Sets the host name sent in the `host` field.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The GELF UDP sink object
+    /// - `host`: The host name
+    ///
+    /// # Returns
+    ///
+    /// The GELF UDP sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::gelf::Udp::new("127.0.0.1:12201").with_host("web-1");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Udp`]
+    pub fn with_host(mut self, host: &str) -> Udp {
+        self.host = host.to_owned();
+        self
+    }
+
+    /// Sets the maximum size of every datagram.
+    ///
+    /// Use `8192` inside local networks, the default of [`DEFAULT_CHUNK_SIZE`] is safe for networks with a lower MTU.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The GELF UDP sink object
+    /// - `chunk_size`: The maximum size of every datagram
+    ///
+    /// # Returns
+    ///
+    /// The GELF UDP sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::gelf::Udp::new("127.0.0.1:12201").with_chunk_size(8192);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Udp`]
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Udp {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Sets the codec compressing the messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The GELF UDP sink object
+    /// - `codec`: The [`codec::Codec`] to use, Graylog only accepts [`codec::Identity`] and the gzip codec
+    ///
+    /// # Returns
+    ///
+    /// The GELF UDP sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::gelf::Udp::new("127.0.0.1:12201").with_codec(logging_rs::codec::Identity);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Udp`]
+    pub fn with_codec<C: codec::Codec + 'static>(mut self, codec: C) -> Udp {
+        self.codec = Arc::new(codec);
+        self
+    }
+
+    /// Sends the given encoded message, compressing and chunking it if needed.
+    fn send(&self, payload: &str) {
+        let payload: Vec<u8> = match self.codec.compress(payload.as_bytes()) {
+            Ok(payload) => payload,
+            Err(error) => {
+                error.report(format!("Address: {}", self.address).as_str());
+                return;
+            }
+        };
+
+        let Some(chunks) = chunks(&payload, self.chunk_size, random::next_u64()) else {
+            errors::Error::new("Writing error", "The GELF message exceeds the maximum number of chunks", 2).report(format!("Address: {}\nSize: {}", self.address, payload.len()).as_str());
+            return;
+        };
+
+        sinks::send_datagrams(&self.socket, &self.address, &chunks);
+    }
+}
+
+impl Sink for Udp {
+    fn encode(&self, _formatter: &Formatter, record: &Record) -> String {
+        message(record, &self.host).to_string()
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.send(&self.encode(formatter, record));
+    }
+
+    fn after_fork(&self) {
+        *self.socket.lock().unwrap_or_else(|error| error.into_inner()) = None;
+    }
+}
@@ -0,0 +1,407 @@
+#![doc = include_str!("../../.github/global.md")]
+// logging-rs global
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+
+use crate::{errors, Level, Logger, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Maximum number of records buffered by the [`early()`] logger before the global logger is set.
+///
+/// Further records are dropped.
+pub const EARLY_CAPACITY: usize = 4096;
+
+
+/////////////
+// GLOBALS //
+/////////////
+
+/// The global logger used by the logging macros without a logger argument.
+static GLOBAL: RwLock<Option<&'static Logger>> = RwLock::new(None);
+
+/// Whether the panic hook was installed.
+static PANIC_HOOK: AtomicBool = AtomicBool::new(false);
+
+/// The records logged using the [`early()`] logger before the global logger was set.
+static EARLY_RECORDS: Mutex<Vec<Record>> = Mutex::new(Vec::new());
+
+/// The [`early()`] logger.
+static EARLY: Early = Early { _private: () };
+
+
+///////////
+// EARLY //
+///////////
+
+/// Logger buffering records until the global logger is set.
+///
+/// Records logged before [`init()`] are kept with their original timestamps and logged using the global logger as
+/// soon as it is set, so messages of early startup are not lost. Afterwards, records are logged using the global
+/// logger directly. At most [`EARLY_CAPACITY`] records are buffered.
+///
+/// The early logger can be used with the logging macros like loggers. Use [`early()`] to get the early logger.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::info!(logging_rs::early(), "Loading configuration");
+///
+/// logging_rs::init(logging_rs::Logger::default()).unwrap(); // Logs "Loading configuration"
+/// ```
+#[derive(Debug)]
+pub struct Early {
+    /// Prevents creating early loggers outside of this module.
+    _private: (),
+}
+
+impl Early {
+    /// Checks whether records of the given level are logged.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The early logger object
+    /// - `level`: The log [`Level`] to check
+    ///
+    /// # Returns
+    ///
+    /// `true` before the global logger is set, whether the global logger logs the level afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::early().is_enabled(logging_rs::Level::DEBUG));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::is_enabled()`]
+    pub fn is_enabled(&self, level: Level) -> bool {
+        logger().map_or(true, |logger| logger.is_enabled(level))
+    }
+
+    /// Logs the given message.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The early logger object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::early().log("Some message", logging_rs::Level::INFO, "src/main.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log()`]
+    pub fn log(&self, message: &str, level: Level, path: &str, arguments: Vec<(&str, String)>) {
+        match logger() {
+            Some(logger) => logger.log(message, level, path, arguments),
+            None => self.log_record(&Record::new(level, message, path, arguments))
+        }
+    }
+
+    /// Logs the given static message without arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The early logger object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::early().log_static("Some message", logging_rs::Level::DEBUG, "src/main.rs");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_static()`]
+    pub fn log_static(&self, message: &'static str, level: Level, path: &'static str) {
+        match logger() {
+            Some(logger) => logger.log_static(message, level, path),
+            None => self.log(message, level, path, vec![])
+        }
+    }
+
+    /// Logs the given record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The early logger object
+    /// - `record`: The [`Record`] to log
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::early().log_record(&logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/main.rs", vec![]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_record()`]
+    pub fn log_record(&self, record: &Record) {
+        if let Some(logger) = logger() {
+            return logger.log_record(record);
+        }
+
+        let mut records = EARLY_RECORDS.lock().unwrap_or_else(|error| error.into_inner());
+
+        // The global logger may have been set while waiting for the lock
+        match logger() {
+            Some(logger) => {
+                drop(records);
+                logger.log_record(record);
+            },
+            None if records.len() < EARLY_CAPACITY => records.push(record.clone()),
+            None => {}
+        }
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Sets the global logger used by the logging macros without a logger argument.
+///
+/// The global logger can only be set once and lives until the process exits. Records buffered by the [`early()`]
+/// logger are logged using it.
+///
+/// # Parameters
+///
+/// - `logger`: The [`Logger`] to use globally
+///
+/// # Returns
+///
+/// A `Result` indicating whether the global logger was set.
+///
+/// # Errors
+///
+/// Returns an initialization error if the global logger was already set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::init(logging_rs::Logger::default()).unwrap();
+///
+/// logging_rs::info!("Logged using the global logger");
+/// assert!(logging_rs::init(logging_rs::Logger::default()).is_err());
+/// ```
+///
+/// # See also
+///
+/// - [`logger()`]
+pub fn init(logger: Logger) -> Result<(), errors::Error> {
+    let mut early = EARLY_RECORDS.lock().unwrap_or_else(|error| error.into_inner());
+    let mut global: std::sync::RwLockWriteGuard<Option<&'static Logger>> = GLOBAL.write().unwrap_or_else(|error| error.into_inner());
+
+    if global.is_some() {
+        return Err(errors::Error::new("Initialization error", "The global logger was already set", 7));
+    }
+
+    let logger: &'static Logger = Box::leak(Box::new(logger));
+    *global = Some(logger);
+    drop(global);
+
+    let records: Vec<Record> = std::mem::take(&mut *early);
+    drop(early);
+
+    if !records.is_empty() {
+        logger.log_records(&records);
+    }
+
+    Ok(())
+}
+
+/// Returns the global logger.
+///
+/// # Returns
+///
+/// The global [`Logger`], or `None` if it was not set yet.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert!(logging_rs::global::logger().is_none());
+///
+/// logging_rs::init(logging_rs::Logger::default()).unwrap();
+/// assert!(logging_rs::global::logger().is_some());
+/// ```
+///
+/// # See also
+///
+/// - [`init()`]
+pub fn logger() -> Option<&'static Logger> {
+    *GLOBAL.read().unwrap_or_else(|error| error.into_inner())
+}
+
+/// Returns the logger buffering records until the global logger is set.
+///
+/// # Returns
+///
+/// The [`Early`] logger.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::warn!(logging_rs::early(), "Configuration file not found, using defaults");
+/// ```
+///
+/// # See also
+///
+/// - [`Early`]
+/// - [`init()`]
+pub fn early() -> &'static Early {
+    &EARLY
+}
+
+/// Sets the global logger and installs the panic hook.
+///
+/// # Parameters
+///
+/// - `logger`: The [`Logger`] to use globally
+///
+/// # Returns
+///
+/// A `Result` indicating whether the global logger was set.
+///
+/// # Errors
+///
+/// Returns an initialization error if the global logger was already set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::try_init(logging_rs::Logger::default().with_level(logging_rs::Level::INFO)).unwrap();
+/// ```
+///
+/// # See also
+///
+/// - [`init()`]
+/// - [`init_default()`]
+/// - [`install_panic_hook()`]
+pub fn try_init(logger: Logger) -> Result<(), errors::Error> {
+    init(logger)?;
+    install_panic_hook();
+
+    Ok(())
+}
+
+/// Sets a default logger writing colored records to stdout as global logger and installs the panic hook.
+///
+/// # Returns
+///
+/// A `Result` indicating whether the global logger was set.
+///
+/// # Errors
+///
+/// Returns an initialization error if the global logger was already set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::init_default().unwrap();
+///
+/// logging_rs::info!("Started");
+/// ```
+///
+/// # See also
+///
+/// - [`try_init()`]
+pub fn init_default() -> Result<(), errors::Error> {
+    try_init(Logger::default())
+}
+
+/// Installs a panic hook logging panics as errors using the global logger.
+///
+/// The previous panic hook is called afterwards, so panic messages and backtraces are still printed. Installing the
+/// hook more than once has no effect.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::global::install_panic_hook();
+/// ```
+///
+/// # See also
+///
+/// - [`try_init()`]
+pub fn install_panic_hook() {
+    if PANIC_HOOK.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let previous = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        if let Some(logger) = logger() {
+            let payload: &str = match (info.payload().downcast_ref::<&str>(), info.payload().downcast_ref::<String>()) {
+                (Some(payload), _) => payload,
+                (None, Some(payload)) => payload,
+                (None, None) => "Box<dyn Any>"
+            };
+
+            logger.log(
+                "Thread {{thread}} panicked at {{location}}: {{payload}}",
+                Level::ERROR,
+                info.location().map_or("<unknown>", |location| location.file()),
+                vec![
+                    ("thread", std::thread::current().name().unwrap_or("<unnamed>").to_owned()),
+                    ("location", info.location().map_or_else(|| "<unknown>".to_owned(), |location| location.to_string())),
+                    ("payload", payload.to_owned())
+                ]
+            );
+        }
+
+        previous(info);
+    }));
+}
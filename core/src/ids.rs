@@ -0,0 +1,268 @@
+#![doc = include_str!("../../.github/ids.md")]
+// logging-rs ids
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::{Arc, RwLock};
+
+use crate::random;
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Alphabet used by ULIDs (Crockford's base32).
+const ULID_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+
+////////////////
+// GENERATORS //
+////////////////
+
+/// Trait for generating unique record ids.
+///
+/// Every [`crate::Record`] gets an id when it is created. The id is available using the `{{uuid}}` and `{{ulid}}`
+/// formatting arguments, which render the same 128 bits as UUID or ULID respectively.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// struct Sequential(std::sync::atomic::AtomicU64);
+///
+/// impl logging_rs::ids::IdGenerator for Sequential {
+///     fn generate(&self, _timestamp: chrono::DateTime<chrono::Utc>) -> u128 {
+///         self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed) as u128
+///     }
+/// }
+///
+/// logging_rs::ids::set_generator(Sequential(std::sync::atomic::AtomicU64::new(0)));
+/// # logging_rs::ids::reset_generator();
+/// ```
+///
+/// # See also
+///
+/// - [`set_generator()`]
+/// - [`RandomGenerator`]
+/// - [`UlidGenerator`]
+pub trait IdGenerator: Send + Sync {
+    /// Generates a new id.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The generator object
+    /// - `timestamp`: The timestamp of the record the id is generated for
+    ///
+    /// # Returns
+    ///
+    /// The new id.
+    fn generate(&self, timestamp: chrono::DateTime<chrono::Utc>) -> u128;
+}
+
+/// Generator creating random (version 4) UUIDs.
+///
+/// The random bits are taken from the current source of the [`random`] module.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::ids::set_generator(logging_rs::ids::RandomGenerator);
+/// # logging_rs::ids::reset_generator();
+/// ```
+///
+/// # See also
+///
+/// - [`IdGenerator`]
+/// - [`UlidGenerator`]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RandomGenerator;
+
+impl IdGenerator for RandomGenerator {
+    fn generate(&self, _timestamp: chrono::DateTime<chrono::Utc>) -> u128 {
+        let id: u128 = (random::next_u64() as u128) << 64 | random::next_u64() as u128;
+
+        // Version 4, variant 1
+        id & !(0xf << 76) & !(0x3 << 62) | 0x4 << 76 | 0x2 << 62
+    }
+}
+
+/// Generator creating ULIDs, which sort by the timestamp of their record.
+///
+/// The random bits are taken from the current source of the [`random`] module.
+///
+/// This is the default generator.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::ids::set_generator(logging_rs::ids::UlidGenerator);
+/// # logging_rs::ids::reset_generator();
+/// ```
+///
+/// # See also
+///
+/// - [`IdGenerator`]
+/// - [`RandomGenerator`]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UlidGenerator;
+
+impl IdGenerator for UlidGenerator {
+    fn generate(&self, timestamp: chrono::DateTime<chrono::Utc>) -> u128 {
+        let milliseconds: u128 = timestamp.timestamp_millis().max(0) as u128 & 0xffff_ffff_ffff;
+        let randomness: u128 = ((random::next_u64() as u128) << 64 | random::next_u64() as u128) & ((1 << 80) - 1);
+
+        milliseconds << 80 | randomness
+    }
+}
+
+/// The currently used generator, [`UlidGenerator`] if unset.
+static GENERATOR: RwLock<Option<Arc<dyn IdGenerator>>> = RwLock::new(None);
+
+/// Sets the generator used for the ids of new records.
+///
+/// # Parameters
+///
+/// - `generator`: The [`IdGenerator`] to use
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::ids::set_generator(logging_rs::ids::RandomGenerator);
+/// # logging_rs::ids::reset_generator();
+/// ```
+///
+/// # See also
+///
+/// - [`reset_generator()`]
+/// - [`generate()`]
+pub fn set_generator<G: IdGenerator + 'static>(generator: G) {
+    *GENERATOR.write().unwrap_or_else(|error| error.into_inner()) = Some(Arc::new(generator));
+}
+
+/// Resets the generator to the default [`UlidGenerator`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::ids::reset_generator();
+/// ```
+///
+/// # See also
+///
+/// - [`set_generator()`]
+pub fn reset_generator() {
+    *GENERATOR.write().unwrap_or_else(|error| error.into_inner()) = None;
+}
+
+/// Generates a new id using the current generator.
+///
+/// # Parameters
+///
+/// - `timestamp`: The timestamp of the record the id is generated for
+///
+/// # Returns
+///
+/// The new id.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let id: u128 = logging_rs::ids::generate(chrono::Utc::now());
+/// ```
+///
+/// # See also
+///
+/// - [`set_generator()`]
+/// - [`IdGenerator`]
+pub fn generate(timestamp: chrono::DateTime<chrono::Utc>) -> u128 {
+    let generator: Option<Arc<dyn IdGenerator>> = GENERATOR.read().unwrap_or_else(|error| error.into_inner()).clone();
+
+    match generator {
+        Some(generator) => generator.generate(timestamp),
+        None => UlidGenerator.generate(timestamp)
+    }
+}
+
+
+////////////////
+// FORMATTING //
+////////////////
+
+/// Formats the given id as UUID.
+///
+/// # Parameters
+///
+/// - `id`: The id to format
+///
+/// # Returns
+///
+/// A `String` of 32 lowercase hex digits in groups of 8, 4, 4, 4 and 12.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::ids::to_uuid(0x0123456789abcdef0123456789abcdef), "01234567-89ab-cdef-0123-456789abcdef");
+/// ```
+///
+/// # See also
+///
+/// - [`to_ulid()`]
+pub fn to_uuid(id: u128) -> String {
+    let hex: String = format!("{:032x}", id);
+
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+/// Formats the given id as ULID.
+///
+/// # Parameters
+///
+/// - `id`: The id to format
+///
+/// # Returns
+///
+/// A `String` of 26 characters using Crockford's base32 alphabet.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::ids::to_ulid(u128::MAX), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+/// ```
+///
+/// # See also
+///
+/// - [`to_uuid()`]
+pub fn to_ulid(id: u128) -> String {
+    (0..26).rev().map(|index| ULID_ALPHABET[(id >> (index * 5)) as usize & 0x1f] as char).collect()
+}
@@ -0,0 +1,416 @@
+#![doc = include_str!("../../.github/ingest.md")]
+// logging-rs ingest
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::io::{self, BufRead};
+
+use crate::{json, Level, Logger, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Keys of JSON lines used as message.
+const MESSAGE_KEYS: [&str; 2] = ["message", "msg"];
+
+/// Keys of JSON lines used as level.
+const LEVEL_KEYS: [&str; 3] = ["level", "severity", "lvl"];
+
+/// Keys of JSON lines used as timestamp.
+const TIMESTAMP_KEYS: [&str; 4] = ["timestamp", "time", "ts", "@timestamp"];
+
+/// Keys of JSON lines used as path.
+const PATH_KEYS: [&str; 2] = ["path", "file"];
+
+
+////////////
+// INGEST //
+////////////
+
+/// Ingestion adapter object.
+///
+/// Reads lines from stdin, files or any other reader and re-emits them through a logger, which formats, filters and
+/// routes them like any other record.
+///
+/// Lines containing JSON objects are parsed: the `message`, `level`, `timestamp` and `path` keys (or their common
+/// aliases like `msg`, `severity` or `time`) become the respective record fields and all other keys become
+/// formatting arguments. Other lines are used as message with the default level.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let ingest: logging_rs::ingest::Ingest = logging_rs::ingest::Ingest::new(&logger, "app.log")
+///     .with_level(logging_rs::Level::INFO)
+///     .with_filter(|record: &logging_rs::Record| record.level >= logging_rs::Level::WARN);
+///
+/// let input: &[u8] = b"plain line\n{\"level\": \"error\", \"msg\": \"Disk full\", \"disk\": \"/dev/sda\"}\n";
+/// assert_eq!(ingest.ingest(input).unwrap(), 1);
+/// ```
+pub struct Ingest<'a> {
+    /// The logger to re-emit the records with.
+    logger: &'a Logger,
+    /// The name of the source, used as path of records without path.
+    source: String,
+    /// The level of records without level.
+    level: Level,
+    /// The names of the sinks to route the records to, all outputs and sinks if empty.
+    targets: Vec<String>,
+    /// The predicate deciding which records are emitted.
+    filter: Box<dyn Fn(&Record) -> bool + 'a>,
+}
+
+impl<'a> Ingest<'a> {
+    /// Creates a new ingestion adapter object.
+    ///
+    /// # Parameters
+    ///
+    /// - `logger`: The [`Logger`] to re-emit the records with
+    /// - `source`: The name of the source, used as path of records without path
+    ///
+    /// # Returns
+    ///
+    /// A new `Ingest` object emitting all records with [`Level::INFO`] as default level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::ingest::Ingest::new(&logger, "stdin");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Ingest`]
+    pub fn new(logger: &'a Logger, source: &str) -> Ingest<'a> {
+        Ingest {
+            logger,
+            source: source.to_owned(),
+            level: Level::INFO,
+            targets: vec![],
+            filter: Box::new(|_: &Record| true)
+        }
+    }
+
+    /// Sets the level of records without level.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The ingestion adapter object
+    /// - `level`: The default [`Level`]
+    ///
+    /// # Returns
+    ///
+    /// The ingestion adapter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::ingest::Ingest::new(&logger, "stdin").with_level(logging_rs::Level::DEBUG);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Ingest`]
+    pub fn with_level(mut self, level: Level) -> Ingest<'a> {
+        self.level = level;
+        self
+    }
+
+    /// Sets the predicate deciding which records are emitted.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The ingestion adapter object
+    /// - `filter`: The predicate, returning `true` for records to emit
+    ///
+    /// # Returns
+    ///
+    /// The ingestion adapter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::ingest::Ingest::new(&logger, "stdin").with_filter(|record: &logging_rs::Record| !record.message.is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Ingest`]
+    pub fn with_filter<F: Fn(&Record) -> bool + 'a>(mut self, filter: F) -> Ingest<'a> {
+        self.filter = Box::new(filter);
+        self
+    }
+
+    /// Routes the records to the named sinks with the given names only.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The ingestion adapter object
+    /// - `targets`: The names of the sinks
+    ///
+    /// # Returns
+    ///
+    /// The ingestion adapter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::ingest::Ingest::new(&logger, "stdin").with_targets(&["archive"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_record_to()`]
+    /// - [`Ingest`]
+    pub fn with_targets(mut self, targets: &[&str]) -> Ingest<'a> {
+        self.targets = targets.iter().map(|target| target.to_string()).collect();
+        self
+    }
+
+    /// Parses the given line into a record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The ingestion adapter object
+    /// - `line`: The line to parse
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`Record`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let ingest: logging_rs::ingest::Ingest = logging_rs::ingest::Ingest::new(&logger, "stdin");
+    /// let record: logging_rs::Record = ingest.parse_line(r#"{"severity": "WARNING", "message": "Low memory", "free": 12}"#);
+    ///
+    /// assert_eq!(record.level, logging_rs::Level::WARN);
+    /// assert_eq!(record.argument("free"), Some("12"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Ingest::ingest_line()`]
+    pub fn parse_line(&self, line: &str) -> Record {
+        let line: &str = line.trim_end_matches(['\r', '\n']);
+
+        let entries: Vec<(String, json::Value)> = match json::Value::parse(line) {
+            Ok(json::Value::OBJECT(entries)) => entries,
+            _ => return Record::new(self.level, line, &self.source, vec![])
+        };
+
+        let mut record: Record = Record::new(self.level, "", &self.source, vec![]);
+
+        for (key, value) in entries {
+            let text: String = value.to_text();
+
+            if MESSAGE_KEYS.contains(&key.as_str()) {
+                record.message = text;
+            } else if LEVEL_KEYS.contains(&key.as_str()) {
+                record.level = parse_level(&text).unwrap_or(self.level);
+            } else if TIMESTAMP_KEYS.contains(&key.as_str()) {
+                if let Ok(timestamp) = chrono::DateTime::parse_from_rfc3339(&text) {
+                    record.timestamp = timestamp.with_timezone(&chrono::Utc);
+                }
+            } else if PATH_KEYS.contains(&key.as_str()) {
+                record.path = text;
+            } else {
+                record.arguments.push((key, text));
+            }
+        }
+
+        record
+    }
+
+    /// Parses and emits the given line.
+    ///
+    /// Empty lines and records rejected by the filter are skipped.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The ingestion adapter object
+    /// - `line`: The line to emit
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the record was emitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let ingest: logging_rs::ingest::Ingest = logging_rs::ingest::Ingest::new(&logger, "stdin");
+    /// assert!(ingest.ingest_line("Service started"));
+    /// assert!(!ingest.ingest_line(""));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Ingest::parse_line()`]
+    /// - [`Ingest::ingest()`]
+    pub fn ingest_line(&self, line: &str) -> bool {
+        if line.trim().is_empty() {
+            return false;
+        }
+
+        self.emit(&self.parse_line(line))
+    }
+
+    /// Emits the given parsed record unless it is rejected by the filter.
+    pub(crate) fn emit(&self, record: &Record) -> bool {
+        if !(self.filter)(record) {
+            return false;
+        }
+
+        let targets: Vec<&str> = self.targets.iter().map(String::as_str).collect();
+        self.logger.log_record_to(&targets, record);
+
+        true
+    }
+
+    /// Emits all lines of the given reader.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The ingestion adapter object
+    /// - `reader`: The reader to read the lines from
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of emitted records or the error that occurred while reading.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let ingest: logging_rs::ingest::Ingest = logging_rs::ingest::Ingest::new(&logger, "memory");
+    /// assert_eq!(ingest.ingest(&b"first\nsecond\n"[..]).unwrap(), 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Ingest::ingest_stdin()`]
+    /// - [`Ingest::ingest_file()`]
+    pub fn ingest<R: BufRead>(&self, reader: R) -> io::Result<usize> {
+        let mut count: usize = 0;
+
+        for line in reader.lines() {
+            if self.ingest_line(&line?) {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Emits all lines of stdin until it is closed.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The ingestion adapter object
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of emitted records or the error that occurred while reading.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::ingest::Ingest::new(&logger, "stdin").ingest_stdin().unwrap();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Ingest::ingest()`]
+    pub fn ingest_stdin(&self) -> io::Result<usize> {
+        self.ingest(io::stdin().lock())
+    }
+
+    /// Emits all lines of the given file.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The ingestion adapter object
+    /// - `path`: The path of the file
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of emitted records or the error that occurred while opening or reading the
+    /// file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::ingest::Ingest::new(&logger, "app.log").ingest_file("app.log").unwrap();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Ingest::ingest()`]
+    pub fn ingest_file<P: AsRef<std::path::Path>>(&self, path: P) -> io::Result<usize> {
+        self.ingest(io::BufReader::new(std::fs::File::open(path)?))
+    }
+}
+
+impl std::fmt::Debug for Ingest<'_> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.debug_struct("Ingest")
+            .field("logger", &self.logger)
+            .field("source", &self.source)
+            .field("level", &self.level)
+            .field("targets", &self.targets)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Parses the given level name, ignoring case.
+fn parse_level(name: &str) -> Option<Level> {
+    match name.to_uppercase().as_str() {
+        "TRACE" => Some(Level::DEBUG),
+        "ERR" => Some(Level::ERROR),
+        "CRITICAL" => Some(Level::FATAL),
+        _ => name.parse().ok()
+    }
+}
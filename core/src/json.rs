@@ -0,0 +1,726 @@
+#![doc = include_str!("../../.github/json.md")]
+// logging-rs json
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fmt;
+
+use crate::{attachments, errors, ids, Formatter, Record};
+
+
+///////////
+// VALUE //
+///////////
+
+/// JSON value.
+///
+/// Numbers are stored as their textual representation, so no precision is lost when reading and writing them.
+/// Objects keep the order of their keys.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let value: logging_rs::json::Value = logging_rs::json::Value::OBJECT(vec![
+///     ("message".to_owned(), logging_rs::json::Value::STRING("Hello".to_owned())),
+///     ("count".to_owned(), logging_rs::json::Value::NUMBER("3".to_owned()))
+/// ]);
+///
+/// assert_eq!(value.to_string(), r#"{"message":"Hello","count":3}"#);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Value {
+    /// `null`. The default value
+    #[default]
+    NULL,
+    /// `true` or `false`
+    BOOL(bool),
+    /// A number in its textual representation
+    NUMBER(String),
+    /// A string
+    STRING(String),
+    /// An array of values
+    ARRAY(Vec<Value>),
+    /// An object of key-value pairs in their original order
+    OBJECT(Vec<(String, Value)>)
+}
+
+impl Value {
+    /// Parses the given JSON text.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The JSON text to parse
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the parsed `Value` or an [`errors::Error`] describing the position of the syntax error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let value: logging_rs::json::Value = logging_rs::json::Value::parse(r#"{"level": "INFO", "tags": [1, 2]}"#).unwrap();
+    ///
+    /// assert_eq!(value.get("level").and_then(logging_rs::json::Value::as_str), Some("INFO"));
+    /// assert!(logging_rs::json::Value::parse("{").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Value`]
+    pub fn parse(text: &str) -> Result<Value, errors::Error> {
+        let mut parser: Parser = Parser { text: text.as_bytes(), position: 0 };
+
+        let value: Value = parser.value()?;
+        parser.whitespace();
+
+        if parser.position < parser.text.len() {
+            return Err(parser.error("end of input"));
+        }
+
+        Ok(value)
+    }
+
+    /// Returns the value of the given key if the value is an object.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The value object
+    /// - `key`: The key to look up
+    ///
+    /// # Returns
+    ///
+    /// The value of the first entry with the given key, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let value: logging_rs::json::Value = logging_rs::json::Value::parse(r#"{"count": 3}"#).unwrap();
+    /// assert_eq!(value.get("count"), Some(&logging_rs::json::Value::NUMBER("3".to_owned())));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Value`]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::OBJECT(entries) => entries.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None
+        }
+    }
+
+    /// Returns the string if the value is a string.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The value object
+    ///
+    /// # Returns
+    ///
+    /// The string or `None` for other values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::json::Value::STRING("text".to_owned()).as_str(), Some("text"));
+    /// assert_eq!(logging_rs::json::Value::NULL.as_str(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Value::to_text()`]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::STRING(string) => Some(string),
+            _ => None
+        }
+    }
+
+    /// Returns the value as plain text.
+    ///
+    /// Strings are returned without quotes, all other values as compact JSON.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The value object
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::json::Value::STRING("text".to_owned()).to_text(), "text");
+    /// assert_eq!(logging_rs::json::Value::BOOL(true).to_text(), "true");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Value::as_str()`]
+    pub fn to_text(&self) -> String {
+        match self {
+            Value::STRING(string) => string.clone(),
+            value => value.to_string()
+        }
+    }
+
+    /// Returns the value as indented multi-line JSON.
+    ///
+    /// Meant for development, use the compact [`Display`](fmt::Display) implementation for production.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The value object
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the JSON, indented by two spaces per level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let value: logging_rs::json::Value = logging_rs::json::Value::parse(r#"{"a": [1], "b": {}}"#).unwrap();
+    /// assert_eq!(value.to_pretty(), "{\n  \"a\": [\n    1\n  ],\n  \"b\": {}\n}");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Value::sort_keys()`]
+    pub fn to_pretty(&self) -> String {
+        let mut result: String = String::new();
+        self.write_pretty(&mut result, 0);
+        result
+    }
+
+    /// Sorts the keys of all objects alphabetically, including nested ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The value object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut value: logging_rs::json::Value = logging_rs::json::Value::parse(r#"{"b": 1, "a": {"d": 2, "c": 3}}"#).unwrap();
+    /// value.sort_keys();
+    ///
+    /// assert_eq!(value.to_string(), r#"{"a":{"c":3,"d":2},"b":1}"#);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Value::to_pretty()`]
+    pub fn sort_keys(&mut self) {
+        match self {
+            Value::ARRAY(values) => values.iter_mut().for_each(Value::sort_keys),
+            Value::OBJECT(entries) => {
+                entries.sort_by(|(first, _), (second, _)| first.cmp(second));
+                entries.iter_mut().for_each(|(_, value)| value.sort_keys());
+            },
+            _ => {}
+        }
+    }
+
+    /// Appends the indented value to the given string.
+    fn write_pretty(&self, result: &mut String, depth: usize) {
+        let indentation: String = "  ".repeat(depth + 1);
+
+        match self {
+            Value::ARRAY(values) if !values.is_empty() => {
+                result.push_str("[\n");
+
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        result.push_str(",\n");
+                    }
+
+                    result.push_str(&indentation);
+                    value.write_pretty(result, depth + 1);
+                }
+
+                result.push('\n');
+                result.push_str(&"  ".repeat(depth));
+                result.push(']');
+            },
+            Value::OBJECT(entries) if !entries.is_empty() => {
+                result.push_str("{\n");
+
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        result.push_str(",\n");
+                    }
+
+                    result.push_str(&indentation);
+                    result.push_str(&escape(key));
+                    result.push_str(": ");
+                    value.write_pretty(result, depth + 1);
+                }
+
+                result.push('\n');
+                result.push_str(&"  ".repeat(depth));
+                result.push('}');
+            },
+            value => result.push_str(&value.to_string())
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::NULL => write!(formatter, "null"),
+            Value::BOOL(value) => write!(formatter, "{}", value),
+            Value::NUMBER(number) => write!(formatter, "{}", number),
+            Value::STRING(string) => write!(formatter, "{}", escape(string)),
+            Value::ARRAY(values) => {
+                write!(formatter, "[")?;
+
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, ",")?;
+                    }
+
+                    write!(formatter, "{}", value)?;
+                }
+
+                write!(formatter, "]")
+            },
+            Value::OBJECT(entries) => {
+                write!(formatter, "{{")?;
+
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, ",")?;
+                    }
+
+                    write!(formatter, "{}:{}", escape(key), value)?;
+                }
+
+                write!(formatter, "}}")
+            }
+        }
+    }
+}
+
+impl From<&Record> for Value {
+    /// Converts the record into a JSON object.
+    ///
+    /// The object contains the `timestamp` (RFC 3339), `id` (UUID), `level`, `path` and `message` (with the
+    /// arguments inserted) followed by the arguments and attachments of the record.
+    ///
+    /// Arguments with dotted keys, like `headers.host`, are nested into objects. Objects with the keys `0` to `n`
+    /// become arrays. Keys colliding with other values are kept as they are.
+    fn from(record: &Record) -> Value {
+        let mut entries: Vec<(String, Value)> = vec![
+            ("timestamp".to_owned(), Value::STRING(record.timestamp.to_rfc3339())),
+            ("id".to_owned(), Value::STRING(ids::to_uuid(record.id))),
+            ("level".to_owned(), Value::STRING(record.level.name().to_owned())),
+            ("path".to_owned(), Value::STRING(record.path.clone())),
+            ("message".to_owned(), Value::STRING(Formatter::new("", "{{message}}", "").format_record_with(false, record)))
+        ];
+
+        let mut nested: Vec<String> = vec![];
+
+        for (key, value) in &record.arguments {
+            if let Some(head) = nest(&mut entries, key, Value::STRING(value.clone())) {
+                if !nested.contains(&head) {
+                    nested.push(head);
+                }
+            }
+        }
+
+        for (key, value) in entries.iter_mut() {
+            if nested.contains(key) {
+                to_arrays(value);
+            }
+        }
+
+        entries.extend(record.attachments.iter().map(|attachment| (attachment.name.clone(), Value::STRING(attachment.to_hex(attachments::PREVIEW_SIZE)))));
+
+        Value::OBJECT(entries)
+    }
+}
+
+/// Inserts the value into the object entries, nesting it into objects if the key is dotted.
+///
+/// Returns the first part of the key if the value was nested.
+fn nest(entries: &mut Vec<(String, Value)>, key: &str, value: Value) -> Option<String> {
+    let Some((head, rest)) = key.split_once('.').filter(|(head, rest)| !head.is_empty() && !rest.is_empty()) else {
+        entries.push((key.to_owned(), value));
+        return None;
+    };
+
+    match entries.iter_mut().find(|(name, _)| name == head) {
+        Some((_, Value::OBJECT(children))) => {
+            nest(children, rest, value);
+        }
+        Some(_) => {
+            entries.push((key.to_owned(), value));
+            return None;
+        }
+        None => {
+            let mut children: Vec<(String, Value)> = vec![];
+            nest(&mut children, rest, value);
+            entries.push((head.to_owned(), Value::OBJECT(children)));
+        }
+    }
+
+    Some(head.to_owned())
+}
+
+/// Converts objects with the keys `0` to `n` in order into arrays, including nested ones.
+fn to_arrays(value: &mut Value) {
+    let Value::OBJECT(entries) = value else {
+        return;
+    };
+
+    entries.iter_mut().for_each(|(_, value)| to_arrays(value));
+
+    if !entries.is_empty() && entries.iter().enumerate().all(|(index, (key, _))| *key == index.to_string()) {
+        *value = Value::ARRAY(std::mem::take(entries).into_iter().map(|(_, value)| value).collect());
+    }
+}
+
+/// Escapes the given string as JSON string, including the surrounding quotes.
+///
+/// # Parameters
+///
+/// - `string`: The string to escape
+///
+/// # Returns
+///
+/// A `String` containing the JSON string.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::json::escape("Say \"hi\"\n"), r#""Say \"hi\"\n""#);
+/// ```
+///
+/// # See also
+///
+/// - [`Value`]
+pub fn escape(string: &str) -> String {
+    let mut result: String = String::with_capacity(string.len() + 2);
+    result.push('"');
+
+    for character in string.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            character if (character as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", character as u32)),
+            character => result.push(character)
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+
+///////////////
+// KEY ORDER //
+///////////////
+
+/// Orders of object keys in structured output.
+///
+/// Ordering keys deterministically keeps diffs of log files small and hashes of records stable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut value: logging_rs::json::Value = logging_rs::json::Value::parse(r#"{"user": 1, "message": "Hi", "level": "INFO"}"#).unwrap();
+/// logging_rs::json::KeyOrder::PRIORITY { keys: vec!["level".to_owned(), "message".to_owned()] }.apply(&mut value);
+///
+/// assert_eq!(value.to_string(), r#"{"level":"INFO","message":"Hi","user":1}"#);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyOrder {
+    /// Keeps the order the keys were added in. The default value
+    #[default]
+    INSERTION,
+    /// Orders all keys alphabetically, including the keys of nested objects
+    ALPHABETICAL,
+    /// Orders the given keys first in the given order, followed by all other keys alphabetically
+    PRIORITY {
+        /// The keys to order first
+        keys: Vec<String>
+    },
+}
+
+impl KeyOrder {
+    /// Orders the keys of the given value.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The key order object
+    /// - `value`: The [`Value`] to order the keys of
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut value: logging_rs::json::Value = logging_rs::json::Value::parse(r#"{"b": 1, "a": 2}"#).unwrap();
+    /// logging_rs::json::KeyOrder::ALPHABETICAL.apply(&mut value);
+    ///
+    /// assert_eq!(value.to_string(), r#"{"a":2,"b":1}"#);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Value::sort_keys()`]
+    pub fn apply(&self, value: &mut Value) {
+        match self {
+            KeyOrder::INSERTION => {},
+            KeyOrder::ALPHABETICAL => value.sort_keys(),
+            KeyOrder::PRIORITY { keys } => {
+                value.sort_keys();
+
+                if let Value::OBJECT(entries) = value {
+                    entries.sort_by_key(|(key, _)| keys.iter().position(|priority| priority == key).unwrap_or(keys.len()));
+                }
+            }
+        }
+    }
+}
+
+
+////////////
+// PARSER //
+////////////
+
+/// Recursive descent JSON parser.
+struct Parser<'a> {
+    /// The text to parse.
+    text: &'a [u8],
+    /// The current position in the text.
+    position: usize,
+}
+
+impl Parser<'_> {
+    /// Creates an error describing what was expected at the current position.
+    fn error(&self, expected: &str) -> errors::Error {
+        errors::Error::new("JSON error", &format!("Expected {} at position {}", expected, self.position), 5)
+    }
+
+    /// Skips whitespace.
+    fn whitespace(&mut self) {
+        while self.position < self.text.len() && matches!(self.text[self.position], b' ' | b'\t' | b'\n' | b'\r') {
+            self.position += 1;
+        }
+    }
+
+    /// Consumes the given literal.
+    fn literal(&mut self, literal: &str, value: Value) -> Result<Value, errors::Error> {
+        if self.text[self.position..].starts_with(literal.as_bytes()) {
+            self.position += literal.len();
+            Ok(value)
+        } else {
+            Err(self.error(literal))
+        }
+    }
+
+    /// Parses any value.
+    fn value(&mut self) -> Result<Value, errors::Error> {
+        self.whitespace();
+
+        match self.text.get(self.position) {
+            Some(b'n') => self.literal("null", Value::NULL),
+            Some(b't') => self.literal("true", Value::BOOL(true)),
+            Some(b'f') => self.literal("false", Value::BOOL(false)),
+            Some(b'"') => Ok(Value::STRING(self.string()?)),
+            Some(b'[') => self.array(),
+            Some(b'{') => self.object(),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.error("value"))
+        }
+    }
+
+    /// Parses a number.
+    fn number(&mut self) -> Result<Value, errors::Error> {
+        let start: usize = self.position;
+
+        while self.position < self.text.len() && matches!(self.text[self.position], b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') {
+            self.position += 1;
+        }
+
+        let number: &str = std::str::from_utf8(&self.text[start..self.position]).unwrap_or_default();
+
+        if number.parse::<f64>().is_err() {
+            self.position = start;
+            return Err(self.error("number"));
+        }
+
+        Ok(Value::NUMBER(number.to_owned()))
+    }
+
+    /// Parses four hex digits of an unicode escape sequence.
+    fn hex(&mut self) -> Result<u32, errors::Error> {
+        let digits: Option<u32> = self.text.get(self.position..self.position + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok());
+
+        match digits {
+            Some(value) => {
+                self.position += 4;
+                Ok(value)
+            },
+            None => Err(self.error("four hex digits"))
+        }
+    }
+
+    /// Parses a string.
+    fn string(&mut self) -> Result<String, errors::Error> {
+        let mut bytes: Vec<u8> = vec![];
+        self.position += 1;
+
+        loop {
+            match self.text.get(self.position) {
+                None => return Err(self.error("closing quote")),
+                Some(b'"') => {
+                    self.position += 1;
+                    break;
+                },
+                Some(b'\\') => {
+                    self.position += 1;
+
+                    let character: char = match self.text.get(self.position) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.position += 1;
+                            let mut code: u32 = self.hex()?;
+
+                            if (0xd800..0xdc00).contains(&code) && self.text[self.position..].starts_with(b"\\u") {
+                                self.position += 2;
+                                code = 0x10000 + ((code - 0xd800) << 10) + (self.hex()? - 0xdc00);
+                            }
+
+                            let character: char = char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER);
+                            bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes());
+                            continue;
+                        },
+                        _ => return Err(self.error("escape sequence"))
+                    };
+
+                    self.position += 1;
+                    bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes());
+                },
+                Some(byte) => {
+                    bytes.push(*byte);
+                    self.position += 1;
+                }
+            }
+        }
+
+        String::from_utf8(bytes).map_err(|_| self.error("valid UTF-8"))
+    }
+
+    /// Parses an array.
+    fn array(&mut self) -> Result<Value, errors::Error> {
+        let mut values: Vec<Value> = vec![];
+        self.position += 1;
+        self.whitespace();
+
+        if self.text.get(self.position) == Some(&b']') {
+            self.position += 1;
+            return Ok(Value::ARRAY(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+            self.whitespace();
+
+            match self.text.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Value::ARRAY(values));
+                },
+                _ => return Err(self.error("',' or ']'"))
+            }
+        }
+    }
+
+    /// Parses an object.
+    fn object(&mut self) -> Result<Value, errors::Error> {
+        let mut entries: Vec<(String, Value)> = vec![];
+        self.position += 1;
+        self.whitespace();
+
+        if self.text.get(self.position) == Some(&b'}') {
+            self.position += 1;
+            return Ok(Value::OBJECT(entries));
+        }
+
+        loop {
+            self.whitespace();
+
+            if self.text.get(self.position) != Some(&b'"') {
+                return Err(self.error("key"));
+            }
+
+            let key: String = self.string()?;
+            self.whitespace();
+
+            if self.text.get(self.position) != Some(&b':') {
+                return Err(self.error("':'"));
+            }
+
+            self.position += 1;
+            entries.push((key, self.value()?));
+            self.whitespace();
+
+            match self.text.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Value::OBJECT(entries));
+                },
+                _ => return Err(self.error("',' or '}'"))
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+#![doc = include_str!("../../.github/lazy.md")]
+// logging-rs lazy
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::RwLock;
+
+use crate::Logger;
+
+
+/////////////////
+// LAZY LOGGER //
+/////////////////
+
+/// Logger initialized on first use, for storing loggers in statics.
+///
+/// Logging only needs a shared reference to a logger, so a lazy logger can be used with all logging macros. The
+/// logger is created by the initialization function the first time it is used and lives until the process exits.
+///
+/// Use [`LazyLogger::new()`] to create lazy logger objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// static LOGGER: logging_rs::lazy::LazyLogger = logging_rs::lazy::LazyLogger::new(|| {
+///     logging_rs::Logger::default().with_level(logging_rs::Level::INFO)
+/// });
+///
+/// logging_rs::info!(LOGGER, "Logged from a static");
+/// ```
+#[derive(Debug)]
+pub struct LazyLogger {
+    /// The function creating the logger
+    init: fn() -> Logger,
+    /// The logger, once created
+    logger: RwLock<Option<&'static Logger>>,
+}
+
+impl LazyLogger {
+    /// Creates a new lazy logger object.
+    ///
+    /// # Parameters
+    ///
+    /// - `init`: The function creating the logger on first use
+    ///
+    /// # Returns
+    ///
+    /// A new `LazyLogger` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// static LOGGER: logging_rs::lazy::LazyLogger = logging_rs::lazy::LazyLogger::new(logging_rs::Logger::default);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`LazyLogger`]
+    pub const fn new(init: fn() -> Logger) -> LazyLogger {
+        LazyLogger {
+            init,
+            logger: RwLock::new(None)
+        }
+    }
+
+    /// Returns the logger, creating it if it was not used before.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The lazy logger object
+    ///
+    /// # Returns
+    ///
+    /// A reference to the [`Logger`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// static LOGGER: logging_rs::lazy::LazyLogger = logging_rs::lazy::LazyLogger::new(logging_rs::Logger::default);
+    ///
+    /// let logger: &logging_rs::Logger = LOGGER.get();
+    /// logging_rs::info!(logger, "Logged using a reference");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`LazyLogger::is_initialized()`]
+    pub fn get(&self) -> &'static Logger {
+        if let Some(logger) = *self.logger.read().unwrap_or_else(|error| error.into_inner()) {
+            return logger;
+        }
+
+        let mut logger: std::sync::RwLockWriteGuard<Option<&'static Logger>> = self.logger.write().unwrap_or_else(|error| error.into_inner());
+        let created: &'static Logger = logger.unwrap_or_else(|| Box::leak(Box::new((self.init)())));
+        *logger = Some(created);
+
+        created
+    }
+
+    /// Returns whether the logger was already created.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The lazy logger object
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the logger was used before.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// static LOGGER: logging_rs::lazy::LazyLogger = logging_rs::lazy::LazyLogger::new(logging_rs::Logger::default);
+    ///
+    /// assert!(!LOGGER.is_initialized());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`LazyLogger::get()`]
+    pub fn is_initialized(&self) -> bool {
+        self.logger.read().unwrap_or_else(|error| error.into_inner()).is_some()
+    }
+}
+
+impl std::ops::Deref for LazyLogger {
+    type Target = Logger;
+
+    fn deref(&self) -> &Logger {
+        self.get()
+    }
+}
@@ -0,0 +1,190 @@
+#![doc = include_str!("../../.github/levels.md")]
+// logging-rs levels
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::RwLock;
+
+use crate::Level;
+
+
+/////////////
+// GLOBALS //
+/////////////
+
+/// The registered custom levels.
+static LEVELS: RwLock<Vec<Level>> = RwLock::new(Vec::new());
+
+
+//////////////
+// REGISTRY //
+//////////////
+
+/// Registers the given custom level, so it can be found by its name.
+///
+/// Registering a level with the name of an already registered level replaces it. Built-in levels can't be
+/// registered and custom levels can't use the name of a built-in level.
+///
+/// # Parameters
+///
+/// - `level`: The [`Level::CUSTOM`] to register
+///
+/// # Returns
+///
+/// Whether the level was registered.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// const AUDIT: logging_rs::Level = logging_rs::Level::custom("AUDIT", 45, "{{color.magenta}}");
+///
+/// assert!(logging_rs::levels::register(AUDIT));
+/// assert!(!logging_rs::levels::register(logging_rs::Level::custom("INFO", 20, "")));
+/// assert_eq!(logging_rs::levels::find("audit"), Some(AUDIT));
+/// # logging_rs::levels::unregister("AUDIT");
+/// ```
+///
+/// # See also
+///
+/// - [`find()`]
+/// - [`unregister()`]
+/// - [`Level::custom()`]
+pub fn register(level: Level) -> bool {
+    let Level::CUSTOM { name, .. } = level else {
+        return false;
+    };
+
+    if builtin(name).is_some() {
+        return false;
+    }
+
+    let mut levels = LEVELS.write().unwrap_or_else(|error| error.into_inner());
+    levels.retain(|other| !other.name().eq_ignore_ascii_case(name));
+    levels.push(level);
+
+    true
+}
+
+/// Removes the registered custom level with the given name.
+///
+/// # Parameters
+///
+/// - `name`: The name of the level, compared case-insensitively
+///
+/// # Returns
+///
+/// Whether a level was removed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::levels::register(logging_rs::Level::custom("NOTICE", 25, ""));
+///
+/// assert!(logging_rs::levels::unregister("NOTICE"));
+/// assert_eq!(logging_rs::levels::find("NOTICE"), None);
+/// ```
+///
+/// # See also
+///
+/// - [`register()`]
+pub fn unregister(name: &str) -> bool {
+    let mut levels = LEVELS.write().unwrap_or_else(|error| error.into_inner());
+    let length: usize = levels.len();
+    levels.retain(|other| !other.name().eq_ignore_ascii_case(name));
+
+    levels.len() != length
+}
+
+/// Finds the level with the given name.
+///
+/// Built-in levels are found by their variant name (`WARN`) as well as their formatted name (`WARNING`).
+///
+/// # Parameters
+///
+/// - `name`: The name of the level, compared case-insensitively
+///
+/// # Returns
+///
+/// The built-in or registered level with the given name, if any.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::levels::find("warning"), Some(logging_rs::Level::WARN));
+/// assert_eq!(logging_rs::levels::find("VERBOSE"), None);
+/// ```
+///
+/// # See also
+///
+/// - [`register()`]
+/// - [`registered()`]
+pub fn find(name: &str) -> Option<Level> {
+    builtin(name).or_else(|| {
+        LEVELS.read().unwrap_or_else(|error| error.into_inner()).iter().find(|level| level.name().eq_ignore_ascii_case(name)).copied()
+    })
+}
+
+/// Returns all registered custom levels, ordered by their severity.
+///
+/// # Returns
+///
+/// A list of the registered levels.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::levels::register(logging_rs::Level::custom("TRACE_ALL", 5, ""));
+///
+/// assert!(logging_rs::levels::registered().contains(&logging_rs::Level::custom("TRACE_ALL", 5, "")));
+/// # logging_rs::levels::unregister("TRACE_ALL");
+/// ```
+///
+/// # See also
+///
+/// - [`register()`]
+pub fn registered() -> Vec<Level> {
+    let mut levels: Vec<Level> = LEVELS.read().unwrap_or_else(|error| error.into_inner()).clone();
+    levels.sort();
+
+    levels
+}
+
+/// Returns the built-in level with the given name.
+fn builtin(name: &str) -> Option<Level> {
+    match name.to_uppercase().as_str() {
+        "DEBUG" => Some(Level::DEBUG),
+        "INFO" => Some(Level::INFO),
+        "WARN" | "WARNING" => Some(Level::WARN),
+        "ERROR" => Some(Level::ERROR),
+        "FATAL" => Some(Level::FATAL),
+        "MESSAGE" => Some(Level::MESSAGE),
+        _ => None
+    }
+}
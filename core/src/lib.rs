@@ -0,0 +1,3783 @@
+#![doc = include_str!("../../.github/core.md")]
+// Logging-rs core
+// Version: 1.1.0
+
+// Copyright (c) 2023-present I Language Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+/////////////
+// EXPORTS //
+/////////////
+
+pub mod attachments;
+pub mod benchmark;
+#[cfg(feature = "log")]
+pub mod bridge;
+pub mod build;
+pub mod builder;
+#[cfg(feature = "callsites")]
+pub mod callsites;
+pub mod categories;
+pub mod codec;
+#[cfg(feature = "serde")]
+pub mod config;
+pub mod deferred;
+pub mod environment;
+pub mod errors;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod filter;
+pub mod gelf;
+pub mod global;
+pub mod ids;
+pub mod ingest;
+pub mod json;
+pub mod lazy;
+pub mod levels;
+pub mod maintenance;
+pub mod memory;
+pub mod metrics;
+pub mod prelude;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod random;
+pub mod reload;
+pub mod replay;
+pub mod rules;
+pub mod sandbox;
+pub mod serverless;
+pub mod shutdown;
+pub mod sinks;
+pub mod span;
+pub mod state;
+pub mod syslog;
+#[cfg(feature = "testutil")]
+pub mod testutil;
+#[cfg(feature = "tracing")]
+pub mod tracing_adapter;
+pub mod transaction;
+pub mod value;
+pub mod xml;
+
+pub use global::{early, init, init_default, try_init};
+
+
+////////////////
+// LOG LEVELS //
+////////////////
+
+/// Log levels
+///
+/// Levels are ordered by their [severity](Level::severity()), so custom levels can be placed between the built-in
+/// ones. See the [`levels`] module for registering custom levels by name.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Level {
+    /// Debug log level. The default value
+    #[default]
+    DEBUG,
+    /// Info log level
+    INFO,
+    /// Warn log level
+    WARN,
+    /// Error log level
+    ERROR,
+    /// Fatal log level
+    FATAL,
+    /// Message log level
+    MESSAGE,
+    /// User-defined log level
+    CUSTOM {
+        /// The name of the level as used by the `{{level}}` placeholder
+        name: &'static str,
+        /// The severity of the level, see [`Level::severity()`]
+        severity: u8,
+        /// The formatting codes used before the name when colored, for example `{{color.magenta}}`
+        color: &'static str
+    }
+}
+
+impl Level {
+    /// Returns the log level matching the given HTTP status code.
+    ///
+    /// Server errors (`5xx`) are [`Level::ERROR`], client errors (`4xx`) are [`Level::WARN`] and everything else is
+    /// [`Level::INFO`].
+    ///
+    /// # Parameters
+    ///
+    /// - `status`: The HTTP status code
+    ///
+    /// # Returns
+    ///
+    /// The matching `Level`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::Level::from_http_status(503), logging_rs::Level::ERROR);
+    /// assert_eq!(logging_rs::Level::from_http_status(404), logging_rs::Level::WARN);
+    /// assert_eq!(logging_rs::Level::from_http_status(200), logging_rs::Level::INFO);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`log_at!()`]
+    pub fn from_http_status(status: u16) -> Level {
+        match status {
+            500..=599 => Level::ERROR,
+            400..=499 => Level::WARN,
+            _ => Level::INFO
+        }
+    }
+
+    /// Creates a new custom log level.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the level as used by the `{{level}}` placeholder
+    /// - `severity`: The severity of the level, see [`Level::severity()`]
+    /// - `color`: The formatting codes used before the name when colored, for example `{{color.magenta}}`
+    ///
+    /// # Returns
+    ///
+    /// A new [`Level::CUSTOM`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// const NOTICE: logging_rs::Level = logging_rs::Level::custom("NOTICE", 25, "{{color.cyan}}");
+    ///
+    /// assert!(NOTICE > logging_rs::Level::INFO);
+    /// assert!(NOTICE < logging_rs::Level::WARN);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`levels::register()`]
+    /// - [`Level::severity()`]
+    pub const fn custom(name: &'static str, severity: u8, color: &'static str) -> Level {
+        Level::CUSTOM { name, severity, color }
+    }
+
+    /// Returns the severity of the level. Levels are ordered by their severity.
+    ///
+    /// The built-in levels use `10` ([`Level::DEBUG`]), `20` ([`Level::INFO`]), `30` ([`Level::WARN`]),
+    /// `40` ([`Level::ERROR`]), `50` ([`Level::FATAL`]) and `60` ([`Level::MESSAGE`]).
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The level
+    ///
+    /// # Returns
+    ///
+    /// The severity of the level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::Level::WARN.severity(), 30);
+    /// assert_eq!(logging_rs::Level::custom("AUDIT", 45, "").severity(), 45);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Level::custom()`]
+    pub const fn severity(&self) -> u8 {
+        match self {
+            Level::DEBUG => 10,
+            Level::INFO => 20,
+            Level::WARN => 30,
+            Level::ERROR => 40,
+            Level::FATAL => 50,
+            Level::MESSAGE => 60,
+            Level::CUSTOM { severity, .. } => *severity
+        }
+    }
+
+    /// Returns the syslog severity of the level, as used by syslog and GELF.
+    ///
+    /// The built-in levels map to `7` (Debug, [`Level::DEBUG`]), `6` (Informational, [`Level::INFO`]), `4` (Warning,
+    /// [`Level::WARN`]), `3` (Error, [`Level::ERROR`]), `2` (Critical, [`Level::FATAL`]) and `5` (Notice,
+    /// [`Level::MESSAGE`]). Custom levels map to the severity of the closest built-in level at or below their
+    /// [severity](Level::severity()).
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The level
+    ///
+    /// # Returns
+    ///
+    /// The syslog severity of the level, from `0` (Emergency) to `7` (Debug).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::Level::WARN.syslog_severity(), 4);
+    /// assert_eq!(logging_rs::Level::custom("AUDIT", 45, "").syslog_severity(), 3);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Level::severity()`]
+    pub const fn syslog_severity(&self) -> u8 {
+        match self {
+            Level::MESSAGE => 5,
+            level => match level.severity() {
+                0..=19 => 7,
+                20..=29 => 6,
+                30..=39 => 4,
+                40..=49 => 3,
+                _ => 2
+            }
+        }
+    }
+
+    /// Returns the name of the level as used by the `{{level}}` placeholder.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Level::DEBUG => "DEBUG",
+            Level::INFO => "INFO",
+            Level::WARN => "WARNING",
+            Level::ERROR => "ERROR",
+            Level::FATAL => "FATAL",
+            Level::MESSAGE => "MESSAGE",
+            Level::CUSTOM { name, .. } => name
+        }
+    }
+
+    /// Returns the name of the level including its formatting codes as used by the colored `{{level}}` placeholder.
+    fn colored_name(&self) -> String {
+        match self {
+            Level::DEBUG => "DEBUG".to_owned(),
+            Level::INFO => "{{color.blue}}INFO{{end}}".to_owned(),
+            Level::WARN => "{{color.yellow}}WARNING{{end}}".to_owned(),
+            Level::ERROR => "{{color.red}}ERROR{{end}}".to_owned(),
+            Level::FATAL => "{{color.red}}FATAL{{end}}".to_owned(),
+            Level::MESSAGE => "{{color.blue}}MESSAGE{{end}}".to_owned(),
+            Level::CUSTOM { name, color: "", .. } => name.to_string(),
+            Level::CUSTOM { name, color, .. } => format!("{}{}{{{{end}}}}", color, name)
+        }
+    }
+}
+
+impl PartialOrd for Level {
+    fn partial_cmp(&self, other: &Level) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Level {
+    fn cmp(&self, other: &Level) -> std::cmp::Ordering {
+        let key = |level: &Level| match *level {
+            Level::CUSTOM { name, severity, color } => (severity, true, name, color),
+            _ => (level.severity(), false, "", "")
+        };
+
+        key(self).cmp(&key(other))
+    }
+}
+
+/// Display implementation for log levels, using the name of the `{{level}}` placeholder.
+impl std::fmt::Display for Level {
+    /// Formats the level as its name, for example `WARNING`.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The level
+    /// - `f`: The [`std::fmt::Formatter`] to use
+    ///
+    /// # Returns
+    ///
+    /// A [`std::fmt::Result`] containing the name of the level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::Level::WARN.to_string(), "WARNING");
+    /// assert_eq!(logging_rs::Level::custom("NOTICE", 25, "").to_string(), "NOTICE");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Level`]
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parsing implementation for log levels.
+impl std::str::FromStr for Level {
+    type Err = errors::Error;
+
+    /// Parses the given level name case-insensitively.
+    ///
+    /// Built-in levels are parsed from their variant name (`WARN`) as well as their formatted name (`WARNING`),
+    /// custom levels have to be registered using [`levels::register()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the level
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`Level`] or an error listing the known levels.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!("warn".parse::<logging_rs::Level>().unwrap(), logging_rs::Level::WARN);
+    /// assert_eq!("Warning".parse::<logging_rs::Level>().unwrap(), logging_rs::Level::WARN);
+    /// assert!("verbose".parse::<logging_rs::Level>().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`levels::find()`]
+    fn from_str(name: &str) -> Result<Level, errors::Error> {
+        levels::find(name.trim()).ok_or_else(|| {
+            let mut known: Vec<String> = ["DEBUG", "INFO", "WARN", "ERROR", "FATAL", "MESSAGE"].iter().map(|name| name.to_string()).collect();
+            known.extend(levels::registered().iter().map(Level::to_string));
+
+            errors::Error::new("Level error", &format!("Unknown level `{}`, expected one of {}", name, known.join(", ")), 6)
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Level {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            Level::WARN => "WARN",
+            _ => self.name()
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Level {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Level, D::Error> {
+        let name: String = serde::Deserialize::deserialize(deserializer)?;
+        name.parse().map_err(|error: errors::Error| serde::de::Error::custom(error.description))
+    }
+}
+
+/// The minimum level of records logged by the logging macros, set at compile time.
+///
+/// Macro calls with a lower level compile to no-ops, so they cost nothing in hot paths. The level is set using the
+/// `max_level_*` features, or the `release_max_level_*` features in builds without debug assertions. In such builds,
+/// an enabled `release_max_level_*` feature overrides all `max_level_*` features. If multiple features of the same
+/// group are enabled, the lowest level wins, so enabling a feature never removes records another crate relies on.
+/// Calling [`Logger::log()`] directly is not affected.
+///
+/// | Feature                                      | Compiled in                |
+/// | :------------------------------------------- | :------------------------- |
+/// | `max_level_debug`, `release_max_level_debug` | All records, the default   |
+/// | `max_level_info`, `release_max_level_info`   | [`Level::INFO`] and above  |
+/// | `max_level_warn`, `release_max_level_warn`   | [`Level::WARN`] and above  |
+/// | `max_level_error`, `release_max_level_error` | [`Level::ERROR`] and above |
+/// | `max_level_fatal`, `release_max_level_fatal` | [`Level::FATAL`] and above |
+/// | `max_level_off`, `release_max_level_off`     | No records                 |
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// if logging_rs::STATIC_LEVEL <= logging_rs::Level::DEBUG {
+///     println!("Debug records are compiled in");
+/// }
+/// ```
+///
+/// # See also
+///
+/// - [`Logger::is_enabled()`]
+pub const STATIC_LEVEL: Level = static_level();
+
+/// Returns the minimum level of records logged by the logging macros, depending on the enabled features.
+const fn static_level() -> Level {
+    if cfg!(all(not(debug_assertions), feature = "release_max_level_debug")) {
+        Level::DEBUG
+    } else if cfg!(all(not(debug_assertions), feature = "release_max_level_info")) {
+        Level::INFO
+    } else if cfg!(all(not(debug_assertions), feature = "release_max_level_warn")) {
+        Level::WARN
+    } else if cfg!(all(not(debug_assertions), feature = "release_max_level_error")) {
+        Level::ERROR
+    } else if cfg!(all(not(debug_assertions), feature = "release_max_level_fatal")) {
+        Level::FATAL
+    } else if cfg!(all(not(debug_assertions), feature = "release_max_level_off")) {
+        filter::OFF
+    } else if cfg!(feature = "max_level_debug") {
+        Level::DEBUG
+    } else if cfg!(feature = "max_level_info") {
+        Level::INFO
+    } else if cfg!(feature = "max_level_warn") {
+        Level::WARN
+    } else if cfg!(feature = "max_level_error") {
+        Level::ERROR
+    } else if cfg!(feature = "max_level_fatal") {
+        Level::FATAL
+    } else if cfg!(feature = "max_level_off") {
+        filter::OFF
+    } else {
+        Level::DEBUG
+    }
+}
+
+
+
+/////////////////
+// OUTPUT TYPE //
+/////////////////
+
+/// Output types
+///
+/// With the `serde` feature enabled, outputs are serialized as `"STDOUT"`, `"STDERR"` and
+/// `{"FILE": {"path": "app.log"}}`. They can also be deserialized from the output names parsed using
+/// [`std::str::FromStr`], like `"stderr"` or `"file:app.log"`, which read better in configuration files.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Output {
+    /// Stdout. The default value
+    #[default]
+    STDOUT,
+    /// Stderr
+    STDERR,
+    /// File
+    FILE {
+        /// File path
+        path: String
+    }
+}
+
+impl std::str::FromStr for Output {
+    type Err = errors::Error;
+
+    /// Parses the given output name.
+    ///
+    /// `stdout` and `stderr` are parsed case-insensitively, file outputs are written as `file:<path>`.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the output
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`Output`] or a configuration error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!("stderr".parse::<logging_rs::Output>().unwrap(), logging_rs::Output::STDERR);
+    /// assert_eq!("file:/var/log/app.log".parse::<logging_rs::Output>().unwrap(), logging_rs::Output::FILE { path: "/var/log/app.log".to_owned() });
+    /// assert!("syslog".parse::<logging_rs::Output>().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Output`]
+    fn from_str(name: &str) -> Result<Output, errors::Error> {
+        let name: &str = name.trim();
+
+        if name.eq_ignore_ascii_case("stdout") {
+            return Ok(Output::STDOUT);
+        }
+
+        if name.eq_ignore_ascii_case("stderr") {
+            return Ok(Output::STDERR);
+        }
+
+        match name.split_once(':') {
+            Some((kind, path)) if kind.eq_ignore_ascii_case("file") && !path.trim().is_empty() => Ok(Output::FILE { path: path.trim().to_owned() }),
+            _ => Err(errors::Error::new("Configuration error", &format!("Unknown output `{}`, expected stdout, stderr or file:<path>", name), 10))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Output {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Output, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "UPPERCASE")]
+        enum Tagged {
+            Stdout,
+            Stderr,
+            File { path: String }
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Representation {
+            Name(String),
+            Tagged(Tagged)
+        }
+
+        match Representation::deserialize(deserializer)? {
+            Representation::Name(name) => name.parse().map_err(|error: errors::Error| serde::de::Error::custom(error.description)),
+            Representation::Tagged(Tagged::Stdout) => Ok(Output::STDOUT),
+            Representation::Tagged(Tagged::Stderr) => Ok(Output::STDERR),
+            Representation::Tagged(Tagged::File { path }) => Ok(Output::FILE { path })
+        }
+    }
+}
+
+
+//////////////
+// LOGGABLE //
+//////////////
+
+/// Values that can safely be passed as log arguments.
+///
+/// With the `derive` feature enabled, `#[derive(Loggable)]` implements this trait together with a
+/// [`std::fmt::Display`] implementation that replaces fields marked with `#[loggable(redact)]` with `[REDACTED]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// struct Token;
+///
+/// impl std::fmt::Display for Token {
+///     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+///         write!(f, "Token([REDACTED])")
+///     }
+/// }
+///
+/// impl logging_rs::Loggable for Token {}
+///
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logger.log("Using {{token}}", logging_rs::Level::INFO, "src/lib.rs", vec![("token", logging_rs::Loggable::log_value(&Token))]);
+/// ```
+pub trait Loggable: std::fmt::Display {
+    /// Returns the value used when passing the object as a log argument.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The loggable object
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the safe representation of the object.
+    fn log_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+
+impl Output {
+    /// Checks whether the output supports special ASCII control characters.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The output object
+    ///
+    /// # Returns
+    ///
+    /// `true` for stdout and stderr, `false` for files.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::Output::STDOUT.is_colored());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Output`]
+    /// - [`Formatter`]
+    pub fn is_colored(&self) -> bool {
+        matches!(self, Output::STDOUT | Output::STDERR)
+    }
+}
+
+
+///////////////
+// FORMATTER //
+///////////////
+
+/// Formatting codes and colors replaced in both format strings.
+const COLORS: [(&str, &str); 37] = [
+    // Formatting codes
+    ("end", "\x1b[0m"),
+    ("bold", "\x1b[1m"),
+    ("italic", "\x1b[3m"),
+    ("underline", "\x1b[4m"),
+    ("overline", "\x1b[53m"),
+
+    // Foreground colors
+    ("color.black", "\x1b[30m"),
+    ("color.red", "\x1b[31m"),
+    ("color.green", "\x1b[32m"),
+    ("color.yellow", "\x1b[33m"),
+    ("color.blue", "\x1b[34m"),
+    ("color.magenta", "\x1b[35m"),
+    ("color.cyan", "\x1b[36m"),
+    ("color.white", "\x1b[37m"),
+
+    // Bright foreground colors
+    ("color.bright_black", "\x1b[90m"),
+    ("color.bright_red", "\x1b[91m"),
+    ("color.bright_green", "\x1b[92m"),
+    ("color.bright_yellow", "\x1b[93m"),
+    ("color.bright_blue", "\x1b[94m"),
+    ("color.bright_magenta", "\x1b[95m"),
+    ("color.bright_cyan", "\x1b[96m"),
+    ("color.bright_white", "\x1b[97m"),
+
+    // Background colors
+    ("back.black", "\x1b[40m"),
+    ("back.red", "\x1b[41m"),
+    ("back.green", "\x1b[42m"),
+    ("back.yellow", "\x1b[43m"),
+    ("back.blue", "\x1b[44m"),
+    ("back.magenta", "\x1b[45m"),
+    ("back.cyan", "\x1b[46m"),
+    ("back.white", "\x1b[47m"),
+
+    // Bright background colors
+    ("back.bright_black", "\x1b[100m"),
+    ("back.bright_red", "\x1b[101m"),
+    ("back.bright_green", "\x1b[102m"),
+    ("back.bright_yellow", "\x1b[103m"),
+    ("back.bright_blue", "\x1b[104m"),
+    ("back.bright_magenta", "\x1b[105m"),
+    ("back.bright_cyan", "\x1b[106m"),
+    ("back.bright_white", "\x1b[107m")
+];
+
+/// Logging formatter object.
+///
+/// The format strings are borrowed if the formatter is created from static strings using [`Formatter::DEFAULT`] or
+/// [`Formatter::from_static()`], so creating and cloning such formatters never allocates.
+///
+/// Placeholders with a `.json` suffix, like `{{message.json}}`, are replaced by the value as JSON string. The
+/// `{{backtrace}}` placeholder is replaced by a backtrace of the thread formatting the record, which is only captured if
+/// the used format string contains it. Use [`Formatter::with_level_format()`] to use different format strings for
+/// single levels, for example to include backtraces for errors only.
+///
+/// Use [`Formatter::new()`], [`Formatter::builder()`] or a preset like [`Formatter::compact()`] to create formatter
+/// objects instead of using this struct.
+///
+/// # Parameters
+///
+/// - `color_format_string`: Format string supporting special ASCII control characters
+/// - `format_string`: Format string *NOT* supporting special ASCII control characters
+/// - `timestamp_format`: Timestamp format string in strftime format
+/// - `level_formats`: The [`LevelFormat`]s overriding the format strings for single levels
+/// - `time_zone`: The [`TimeZone`] timestamps are formatted in
+///
+/// # Returns
+///
+/// A new `Formatter` object with the specified format strings.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::Formatter {
+///     color_format_string: "format string with color support".into(),
+///     format_string: "format string".into(),
+///     timestamp_format: "timestamp format".into(),
+///     level_formats: vec![],
+///     time_zone: logging_rs::TimeZone::UTC
+/// };
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Formatter {
+    /// Format string supporting special ASCII control characters
+    pub color_format_string: std::borrow::Cow<'static, str>,
+    /// Format string *NOT* supporting special ASCII control characters
+    pub format_string: std::borrow::Cow<'static, str>,
+    /// Timestamp format string in strftime format
+    pub timestamp_format: std::borrow::Cow<'static, str>,
+    /// Format strings overriding the format strings for single levels
+    pub level_formats: Vec<LevelFormat>,
+    /// The time zone timestamps are formatted in
+    pub time_zone: TimeZone,
+}
+
+/// Format strings overriding the format strings of a [`Formatter`] for a single level.
+///
+/// Use [`Formatter::with_level_format()`] to add level formats to formatters.
+///
+/// # Parameters
+///
+/// - `level`: The [`Level`] the format strings are used for
+/// - `color_format_string`: Format string supporting special ASCII control characters
+/// - `format_string`: Format string *NOT* supporting special ASCII control characters
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::LevelFormat {
+///     level: logging_rs::Level::DEBUG,
+///     color_format_string: "{{message}}".into(),
+///     format_string: "{{message}}".into()
+/// };
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LevelFormat {
+    /// The level the format strings are used for
+    pub level: Level,
+    /// Format string supporting special ASCII control characters
+    pub color_format_string: std::borrow::Cow<'static, str>,
+    /// Format string *NOT* supporting special ASCII control characters
+    pub format_string: std::borrow::Cow<'static, str>,
+}
+
+/// Time zones timestamps are formatted in.
+///
+/// Time zones are parsed from and displayed as `utc`, `local` or offsets like `+02:00`, which read better in
+/// configuration files.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!("local".parse::<logging_rs::TimeZone>().unwrap(), logging_rs::TimeZone::LOCAL);
+/// assert_eq!("-05:30".parse::<logging_rs::TimeZone>().unwrap(), logging_rs::TimeZone::OFFSET { seconds: -19800 });
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TimeZone {
+    /// Coordinated Universal Time. The default value
+    #[default]
+    UTC,
+    /// The local time zone of the system
+    LOCAL,
+    /// A fixed offset east of UTC
+    OFFSET {
+        /// The offset in seconds
+        seconds: i32
+    },
+}
+
+impl std::fmt::Display for TimeZone {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TimeZone::UTC => write!(formatter, "utc"),
+            TimeZone::LOCAL => write!(formatter, "local"),
+            TimeZone::OFFSET { seconds } => {
+                let sign: char = if *seconds < 0 { '-' } else { '+' };
+                let seconds: u32 = seconds.unsigned_abs();
+
+                write!(formatter, "{}{:02}:{:02}", sign, seconds / 3600, seconds % 3600 / 60)
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for TimeZone {
+    type Err = errors::Error;
+
+    /// Parses the given time zone case-insensitively.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: `utc`, `local` or an offset like `+02:00`
+    ///
+    /// # Returns
+    ///
+    /// The parsed [`TimeZone`] or a configuration error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!("UTC".parse::<logging_rs::TimeZone>().unwrap(), logging_rs::TimeZone::UTC);
+    /// assert_eq!("+02:00".parse::<logging_rs::TimeZone>().unwrap(), logging_rs::TimeZone::OFFSET { seconds: 7200 });
+    /// assert!("Europe/Berlin".parse::<logging_rs::TimeZone>().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::with_time_zone()`]
+    fn from_str(name: &str) -> Result<TimeZone, errors::Error> {
+        let name: &str = name.trim();
+        let invalid = || errors::Error::new("Configuration error", &format!("Unknown time zone `{}`, expected utc, local or an offset like +02:00", name), 10);
+
+        match name.to_lowercase().as_str() {
+            "utc" | "z" => return Ok(TimeZone::UTC),
+            "local" => return Ok(TimeZone::LOCAL),
+            _ => {}
+        }
+
+        let sign: i32 = match name.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => return Err(invalid())
+        };
+
+        let (hours, minutes): (&str, &str) = name[1..].split_once(':').ok_or_else(invalid)?;
+        let hours: i32 = hours.parse().ok().filter(|hours| (0..24).contains(hours)).ok_or_else(invalid)?;
+        let minutes: i32 = minutes.parse().ok().filter(|minutes| (0..60).contains(minutes)).ok_or_else(invalid)?;
+
+        Ok(TimeZone::OFFSET { seconds: sign * (hours * 3600 + minutes * 60) })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TimeZone {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TimeZone {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<TimeZone, D::Error> {
+        let name: String = serde::Deserialize::deserialize(deserializer)?;
+        name.parse().map_err(|error: errors::Error| serde::de::Error::custom(error.description))
+    }
+}
+
+impl Default for Formatter {
+    fn default() -> Formatter {
+        Formatter::DEFAULT
+    }
+}
+
+impl Formatter {
+    /// The default formatter, usable in constants and statics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// static FORMATTER: logging_rs::Formatter = logging_rs::Formatter::DEFAULT;
+    ///
+    /// assert_eq!(FORMATTER, logging_rs::Formatter::default());
+    /// ```
+    pub const DEFAULT: Formatter = Formatter::from_static(
+        "[{{color.bright_blue}}{{timestamp}}{{end}}] [{{level}}] {{path}}: {{message}}",
+        "[{{timestamp}}] [{{level}}] {{path}}: {{message}}",
+        "%Y-%m-%d %H:%M:%S"
+    );
+
+    /// Creates a formatter writing only the time, level and message.
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object formatting records like `12:30:00 INFO Some message`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::compact(), vec![logging_rs::Output::STDOUT]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::detailed()`]
+    /// - [`Formatter::json()`]
+    /// - [`Formatter::json_lines()`]
+    pub const fn compact() -> Formatter {
+        Formatter::from_static(
+            "{{color.bright_blue}}{{timestamp}}{{end}} {{level}} {{message}}",
+            "{{timestamp}} {{level}} {{message}}",
+            "%H:%M:%S"
+        )
+    }
+
+    /// Creates a formatter writing the timestamp in milliseconds, the level, record id, path and message.
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object formatting records like
+    /// `[2023-01-01 12:30:00.000] [INFO] [<uuid>] src/main.rs: Some message`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::detailed(), vec![logging_rs::Output::STDOUT]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::compact()`]
+    /// - [`Formatter::json()`]
+    /// - [`Formatter::json_lines()`]
+    pub const fn detailed() -> Formatter {
+        Formatter::from_static(
+            "[{{color.bright_blue}}{{timestamp}}{{end}}] [{{level}}] [{{color.bright_black}}{{uuid}}{{end}}] {{path}}: {{message}}",
+            "[{{timestamp}}] [{{level}}] [{{uuid}}] {{path}}: {{message}}",
+            "%Y-%m-%d %H:%M:%S%.3f"
+        )
+    }
+
+    /// Creates a formatter writing every record as JSON object on a single line.
+    ///
+    /// Both format strings are the same, so the output never contains colors. Use [`sinks::Json`] to include all
+    /// arguments of the records.
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object formatting records like
+    /// `{"timestamp":"2023-01-01T12:30:00.000Z","level":"INFO","path":"src/main.rs","message":"Some message"}`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::json(), vec![logging_rs::Output::STDOUT]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::compact()`]
+    /// - [`Formatter::detailed()`]
+    /// - [`Formatter::json_lines()`]
+    pub const fn json() -> Formatter {
+        Formatter::from_static(
+            r#"{"timestamp":{{timestamp.json}},"level":{{level.json}},"path":{{path.json}},"message":{{message.json}}}"#,
+            r#"{"timestamp":{{timestamp.json}},"level":{{level.json}},"path":{{path.json}},"message":{{message.json}}}"#,
+            "%Y-%m-%dT%H:%M:%S%.3fZ"
+        )
+    }
+
+    /// Creates a formatter writing every record as JSON object on a single line, including all arguments.
+    ///
+    /// Unlike [`Formatter::json()`], the arguments and attachments of the records become fields of the object, so
+    /// log shippers can ingest them without parsing the message. The format strings only contain the `record.json`
+    /// argument, see [`json::Value::from()`] for the fields of the object. The timestamp is formatted using the
+    /// timestamp format.
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object formatting records like
+    /// `{"timestamp":"2023-01-01T12:30:00.000Z","id":"<uuid>","level":"INFO","path":"src/main.rs","message":"Some message","user":"42"}`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Hello {{user}}", "src/main.rs", vec![("user", "42".to_owned())]);
+    /// let line: String = logging_rs::Formatter::json_lines().format_record(&logging_rs::Output::STDOUT, &record);
+    ///
+    /// let value: logging_rs::json::Value = logging_rs::json::Value::parse(&line).unwrap();
+    /// assert_eq!(value.get("message").and_then(|message: &logging_rs::json::Value| message.as_str()), Some("Hello 42"));
+    /// assert_eq!(value.get("user").and_then(|user: &logging_rs::json::Value| user.as_str()), Some("42"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::json()`]
+    /// - [`sinks::Json`]
+    pub const fn json_lines() -> Formatter {
+        Formatter::from_static("{{record.json}}", "{{record.json}}", "%Y-%m-%dT%H:%M:%S%.3fZ")
+    }
+
+    /// Creates a formatter writing every record as GELF message on a single line, including all arguments.
+    ///
+    /// The format strings only contain the `record.gelf` argument, see [`gelf::message()`] for the fields of the
+    /// message. The host is the [host name](environment::hostname()) of the machine. The timestamp format is not used,
+    /// as GELF requires seconds since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object formatting records like
+    /// `{"version":"1.1","host":"example.org","short_message":"Some message","timestamp":1672576200.000,"level":6,...}`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Hello {{user}}", "src/main.rs", vec![("user", "42".to_owned())]);
+    /// let line: String = logging_rs::Formatter::gelf().format_record(&logging_rs::Output::STDOUT, &record);
+    ///
+    /// let value: logging_rs::json::Value = logging_rs::json::Value::parse(&line).unwrap();
+    /// assert_eq!(value.get("short_message").and_then(|message: &logging_rs::json::Value| message.as_str()), Some("Hello 42"));
+    /// assert_eq!(value.get("_user").and_then(|user: &logging_rs::json::Value| user.as_str()), Some("42"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::json_lines()`]
+    /// - [`gelf`]
+    pub const fn gelf() -> Formatter {
+        Formatter::from_static("{{record.gelf}}", "{{record.gelf}}", "%Y-%m-%dT%H:%M:%S%.3fZ")
+    }
+
+    /// Creates a formatter writing every record as RFC 5424 syslog message, including all arguments as structured data.
+    ///
+    /// The format strings only contain the `record.syslog.<facility>` argument, see [`syslog::message()`] for the
+    /// structure of the message. The host is the [host name](environment::hostname()) of the machine and the
+    /// application name the name of the executable. The timestamp format is not used, as RFC 5424 requires RFC 3339
+    /// timestamps in UTC.
+    ///
+    /// # Parameters
+    ///
+    /// - `facility`: The [`syslog::Facility`] of the messages
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object formatting records like
+    /// `<14>1 2023-01-01T12:30:00.000000Z example.org server 1234 - [args@32473 user="42"] Some message`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Hello {{user}}", "src/main.rs", vec![("user", "42".to_owned())]);
+    /// let line: String = logging_rs::Formatter::syslog(logging_rs::syslog::Facility::LOCAL0).format_record(&logging_rs::Output::STDOUT, &record);
+    ///
+    /// assert!(line.starts_with("<134>1 "));
+    /// assert!(line.ends_with(" - [args@32473 user=\"42\"] \u{feff}Hello 42"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`sinks::Udp`]
+    /// - [`syslog`]
+    pub const fn syslog(facility: syslog::Facility) -> Formatter {
+        Formatter::from_static(facility.placeholder(), facility.placeholder(), "%Y-%m-%dT%H:%M:%S%.6fZ")
+    }
+
+    /// Creates a formatter writing every record as XML element on a single line, including all arguments.
+    ///
+    /// The format strings only contain the `record.xml` argument, see [`xml::element()`] for the structure of the
+    /// element. The timestamp is formatted using the timestamp format.
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object formatting records like
+    /// `<record id="<uuid>"><timestamp>2023-01-01T12:30:00.000Z</timestamp><level>INFO</level>...</record>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Hello {{user}}", "src/main.rs", vec![("user", "42".to_owned())]);
+    /// let line: String = logging_rs::Formatter::xml().format_record(&logging_rs::Output::STDOUT, &record);
+    ///
+    /// assert!(line.contains("<message>Hello 42</message>"));
+    /// assert!(line.contains("<argument name=\"user\">42</argument>"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::json_lines()`]
+    /// - [`xml`]
+    pub const fn xml() -> Formatter {
+        Formatter::from_static("{{record.xml}}", "{{record.xml}}", "%Y-%m-%dT%H:%M:%S%.3fZ")
+    }
+
+    /// Creates a formatter writing every record as comma-separated values on a single line.
+    ///
+    /// The format strings only contain the `record.csv` argument, a row with the `timestamp`, `level`, `path` and
+    /// `message` columns of [`sinks::Csv::new()`]. The timestamp format is not used, as the timestamps are written in
+    /// RFC 3339 format. Use [`sinks::Csv`] for a header, other columns or delimiters.
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object formatting records like `2023-01-01T12:30:00+00:00,INFO,src/main.rs,Some message`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Hello, {{user}}", "src/main.rs", vec![("user", "42".to_owned())]);
+    /// let line: String = logging_rs::Formatter::csv().format_record(&logging_rs::Output::STDOUT, &record);
+    ///
+    /// assert!(line.ends_with(",INFO,src/main.rs,\"Hello, 42\""));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::json_lines()`]
+    /// - [`sinks::Csv`]
+    pub const fn csv() -> Formatter {
+        Formatter::from_static("{{record.csv}}", "{{record.csv}}", "%Y-%m-%dT%H:%M:%S%.3fZ")
+    }
+
+    /// Creates a new formatter object.
+    ///
+    /// The timestamp format is not checked, use [`Formatter::try_new()`] to reject invalid specifiers.
+    ///
+    /// # Parameters
+    ///
+    /// - `color_format_string`: Format string supporting special ASCII control characters
+    /// - `format_string`: Format string *NOT* supporting special ASCII control characters
+    /// - `timestamp_format`: Timestamp format string in strftime format
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object with the specified format strings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Formatter::new(
+    ///     "[{{color.bright_blue}}{{timestamp}}{{end}}] [{{level}}] {{path}}: {{message}}",
+    ///     "[{{timestamp}}] [{{level}}] {{path}}: {{message}}",
+    ///     "%Y-%m-%d %H:%M:%S"
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter`]
+    /// - [`Formatter::try_new()`]
+    pub fn new(color_format_string: &str, format_string: &str, timestamp_format: &str) -> Formatter {
+        Formatter {
+            color_format_string: std::borrow::Cow::Owned(color_format_string.to_owned()),
+            format_string: std::borrow::Cow::Owned(format_string.to_owned()),
+            timestamp_format: std::borrow::Cow::Owned(timestamp_format.to_owned()),
+            level_formats: Vec::new(),
+            time_zone: TimeZone::UTC
+        }
+    }
+
+    /// Creates a new formatter object, checking the timestamp format.
+    ///
+    /// # Parameters
+    ///
+    /// - `color_format_string`: Format string supporting special ASCII control characters
+    /// - `format_string`: Format string *NOT* supporting special ASCII control characters
+    /// - `timestamp_format`: Timestamp format string in strftime format
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object with the specified format strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a format error if the timestamp format contains invalid specifiers, see [`Formatter::validate()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::Formatter::try_new("{{timestamp}} {{message}}", "{{timestamp}} {{message}}", "%H:%M:%S").is_ok());
+    /// assert!(logging_rs::Formatter::try_new("{{timestamp}} {{message}}", "{{timestamp}} {{message}}", "%H:%Q").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::new()`]
+    pub fn try_new(color_format_string: &str, format_string: &str, timestamp_format: &str) -> Result<Formatter, errors::Error> {
+        let formatter: Formatter = Formatter::new(color_format_string, format_string, timestamp_format);
+        formatter.validate()?;
+
+        Ok(formatter)
+    }
+
+    /// Checks whether the timestamp format only contains valid strftime specifiers.
+    ///
+    /// Formatters with invalid timestamp formats fall back to ISO 8601 timestamps when formatting records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter object
+    ///
+    /// # Errors
+    ///
+    /// Returns a format error naming the first invalid specifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::Formatter::default().validate().is_ok());
+    ///
+    /// let error: logging_rs::errors::Error = logging_rs::Formatter::new("", "", "%Y-%m-%d %Q").validate().unwrap_err();
+    /// assert!(error.description.contains("`%Q`"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::try_new()`]
+    pub fn validate(&self) -> Result<(), errors::Error> {
+        let mut rest: &str = &self.timestamp_format;
+
+        while let Some(index) = rest.find('%') {
+            rest = &rest[index..];
+
+            let length: usize = rest.char_indices()
+                .skip(1)
+                .find(|(_, character)| !matches!(character, '-' | '_' | '0' | '.' | ':' | '#' | '3' | '6' | '9'))
+                .map_or(rest.len(), |(index, character)| index + character.len_utf8());
+            let specifier: &str = &rest[..length];
+
+            if chrono::format::StrftimeItems::new(specifier).any(|item| item == chrono::format::Item::Error) {
+                return Err(errors::Error::new(
+                    "Format error",
+                    &format!("Invalid specifier `{}` in timestamp format `{}`", specifier, self.timestamp_format),
+                    9
+                ));
+            }
+
+            rest = &rest[length..];
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new formatter object borrowing the given static format strings.
+    ///
+    /// # Parameters
+    ///
+    /// - `color_format_string`: Format string supporting special ASCII control characters
+    /// - `format_string`: Format string *NOT* supporting special ASCII control characters
+    /// - `timestamp_format`: Timestamp format string in strftime format
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatter` object with the specified format strings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// const FORMATTER: logging_rs::Formatter = logging_rs::Formatter::from_static("{{level}} {{message}}", "{{level}} {{message}}", "%H:%M:%S");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter::new()`]
+    /// - [`Formatter::DEFAULT`]
+    pub const fn from_static(color_format_string: &'static str, format_string: &'static str, timestamp_format: &'static str) -> Formatter {
+        Formatter {
+            color_format_string: std::borrow::Cow::Borrowed(color_format_string),
+            format_string: std::borrow::Cow::Borrowed(format_string),
+            timestamp_format: std::borrow::Cow::Borrowed(timestamp_format),
+            level_formats: Vec::new(),
+            time_zone: TimeZone::UTC
+        }
+    }
+
+    /// Overrides the format strings for records of the given level.
+    ///
+    /// Replaces previous format strings for the same level.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter object
+    /// - `level`: The [`Level`] the format strings are used for
+    /// - `color_format_string`: Format string supporting special ASCII control characters
+    /// - `format_string`: Format string *NOT* supporting special ASCII control characters
+    ///
+    /// # Returns
+    ///
+    /// The formatter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let formatter: logging_rs::Formatter = logging_rs::Formatter::default()
+    ///     .with_level_format(logging_rs::Level::DEBUG, "{{message}}", "{{message}}")
+    ///     .with_level_format(logging_rs::Level::ERROR, "[{{level}}] {{message}}\n{{backtrace}}", "[{{level}}] {{message}}\n{{backtrace}}");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`LevelFormat`]
+    pub fn with_level_format(mut self, level: Level, color_format_string: &str, format_string: &str) -> Formatter {
+        self.level_formats.retain(|level_format| level_format.level != level);
+        self.level_formats.push(LevelFormat {
+            level,
+            color_format_string: std::borrow::Cow::Owned(color_format_string.to_owned()),
+            format_string: std::borrow::Cow::Owned(format_string.to_owned())
+        });
+        self
+    }
+
+    /// Sets the time zone timestamps are formatted in.
+    ///
+    /// Use [`sinks::formatted()`] to format timestamps differently per output, like local time on the console and
+    /// UTC in files.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter object
+    /// - `time_zone`: The [`TimeZone`]
+    ///
+    /// # Returns
+    ///
+    /// The formatter object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let formatter: logging_rs::Formatter = logging_rs::Formatter::compact().with_time_zone(logging_rs::TimeZone::LOCAL);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`TimeZone`]
+    pub fn with_time_zone(mut self, time_zone: TimeZone) -> Formatter {
+        self.time_zone = time_zone;
+        self
+    }
+
+    /// Formats the given message.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter object
+    /// - `output`: The [`Output`] to write to
+    /// - `level`: The log [`Level`] to use for formatting
+    /// - `message`: The message to log
+    /// - `arguments`: A vector of additional formatting arguments
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let formatter: logging_rs::Formatter = logging_rs::Formatter::default();
+    /// formatter.format(
+    ///     logging_rs::Output::default(),
+    ///     logging_rs::Level::default(),
+    ///     "Some message with an {{argument}}",
+    ///     vec![("argument", "replaced value".to_string())]
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter`]
+    /// - [`Output`]
+    /// - [`Level`]
+    #[doc = include_str!("../../.github/formatting_codes.md")]
+    pub fn format(&self, output: Output, level: Level, message: &str, extra_arguments: Vec<(&str, String)>) -> String {
+        let timestamp: chrono::DateTime<chrono::Utc> = utc_now();
+        let id: u128 = ids::generate(timestamp);
+        let mut extra_arguments: Vec<(&str, String)> = extra_arguments;
+        extra_arguments.push(("uuid", ids::to_uuid(id)));
+        extra_arguments.push(("ulid", ids::to_ulid(id)));
+
+        self.format_with_timestamp(output.is_colored(), level, message, extra_arguments, timestamp)
+    }
+
+    /// Formats the given record.
+    ///
+    /// Unlike [`Formatter::format()`], the timestamp of the record is used instead of the current time and the
+    /// `path` argument is added automatically.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The formatter object
+    /// - `output`: The [`Output`] to write to
+    /// - `record`: The [`Record`] to format
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted record.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let formatter: logging_rs::Formatter = logging_rs::Formatter::default();
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/lib.rs", vec![]);
+    /// formatter.format_record(&logging_rs::Output::default(), &record);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatter`]
+    /// - [`Output`]
+    /// - [`Record`]
+    pub fn format_record(&self, output: &Output, record: &Record) -> String {
+        self.format_record_with(output.is_colored(), record)
+    }
+
+    /// Formats the given record using either the color format string or the plain format string.
+    pub(crate) fn format_record_with(&self, colored: bool, record: &Record) -> String {
+        let mut arguments: Vec<(&str, String)> = record.arguments.iter().map(|(key, value)| (key.as_str(), value.clone())).collect();
+        arguments.extend(record.attachments.iter().map(|attachment| (attachment.name.as_str(), attachment.to_hex(attachments::PREVIEW_SIZE))));
+        arguments.push(("path", record.path.clone()));
+        arguments.push(("uuid", ids::to_uuid(record.id)));
+        arguments.push(("ulid", ids::to_ulid(record.id)));
+        arguments.push(("span", String::new()));
+
+        if self.uses("{{record.json}}") {
+            let mut value: json::Value = json::Value::from(record);
+
+            if let json::Value::OBJECT(entries) = &mut value {
+                entries[0].1 = json::Value::STRING(self.format_timestamp(record.timestamp));
+            }
+
+            arguments.push(("record.json", value.to_string()));
+        }
+
+        if self.uses("{{record.gelf}}") {
+            arguments.push(("record.gelf", gelf::message(record, &environment::hostname()).to_string()));
+        }
+
+        if self.uses("{{record.syslog.") {
+            for facility in syslog::Facility::ALL {
+                let placeholder: &'static str = facility.placeholder();
+
+                if self.uses(placeholder) {
+                    arguments.push((&placeholder[2..placeholder.len() - 2], syslog::message(record, facility, &environment::hostname(), &syslog::app_name())));
+                }
+            }
+        }
+
+        if self.uses("{{record.xml}}") {
+            arguments.push(("record.xml", xml::element_with(record, &self.format_timestamp(record.timestamp))));
+        }
+
+        if self.uses("{{record.csv}}") {
+            arguments.push(("record.csv", sinks::Sink::encode(&sinks::Csv::default(), self, record)));
+        }
+
+        self.format_with_timestamp(colored, record.level, &record.message, arguments, record.timestamp)
+    }
+
+    /// Checks whether any format string of the formatter contains the given text.
+    fn uses(&self, text: &str) -> bool {
+        self.format_string.contains(text)
+            || self.color_format_string.contains(text)
+            || self.level_formats.iter().any(|level_format| level_format.format_string.contains(text) || level_format.color_format_string.contains(text))
+    }
+
+    /// Formats the given timestamp using the timestamp format.
+    ///
+    /// Invalid timestamp formats fall back to ISO 8601 timestamps, after writing a warning to stderr once.
+    fn format_timestamp(&self, timestamp: chrono::DateTime<chrono::Utc>) -> String {
+        static WARNED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+        let mut result: String = String::new();
+
+        let written: std::fmt::Result = match self.time_zone {
+            TimeZone::UTC => std::fmt::Write::write_fmt(&mut result, format_args!("{}", timestamp.format(&self.timestamp_format))),
+            TimeZone::LOCAL => std::fmt::Write::write_fmt(&mut result, format_args!("{}", timestamp.with_timezone(&chrono::Local).format(&self.timestamp_format))),
+            TimeZone::OFFSET { seconds } => match chrono::FixedOffset::east_opt(seconds) {
+                Some(offset) => std::fmt::Write::write_fmt(&mut result, format_args!("{}", timestamp.with_timezone(&offset).format(&self.timestamp_format))),
+                None => Err(std::fmt::Error)
+            }
+        };
+
+        if written.is_ok() {
+            return result;
+        }
+
+        if !WARNED.swap(true, std::sync::atomic::Ordering::Relaxed) {
+            eprintln!("{}", Formatter::DEFAULT.format_record_with(false, &Record::new(
+                Level::WARN,
+                "Invalid timestamp format `{{format}}`, using ISO 8601 timestamps instead",
+                file!(),
+                vec![("format", self.timestamp_format.to_string())]
+            )));
+        }
+
+        iso8601(timestamp.into())
+    }
+
+    /// Formats the given message using the given timestamp.
+    ///
+    /// Placeholders are replaced in a single pass over the format string, so placeholders contained in the values of
+    /// arguments are never replaced. Placeholders of the message are replaced before it is inserted.
+    fn format_with_timestamp(&self, colored: bool, level: Level, message: &str, mut extra_arguments: Vec<(&str, String)>, timestamp: chrono::DateTime<chrono::Utc>) -> String {
+        let mut arguments: Vec<(&str, String)> = vec![];
+
+        let level_string: (&str, String) = ("level", level.name().to_string());
+        let colored_level_string: (&str, String) = ("level", substitute(&level.colored_name(), |key: &str| color(key).map(str::to_owned)));
+
+        arguments.push(("timestamp", self.format_timestamp(timestamp)));
+        arguments.append(&mut build::build_info().map(|info| info.arguments()).unwrap_or_default());
+        arguments.append(&mut extra_arguments);
+
+        let level_format: Option<&LevelFormat> = self.level_formats.iter().find(|level_format| level_format.level == level);
+
+        let format_string: &str = if colored {
+            arguments.push(colored_level_string);
+            level_format.map_or(&self.color_format_string, |level_format| &level_format.color_format_string)
+        } else {
+            arguments.push(level_string);
+            level_format.map_or(&self.format_string, |level_format| &level_format.format_string)
+        };
+
+        if format_string.contains("{{backtrace") {
+            arguments.push(("backtrace", std::backtrace::Backtrace::force_capture().to_string()));
+        }
+
+        let argument = |key: &str| arguments.iter().find(|(name, _)| *name == key).map(|(_, value)| value.as_str());
+
+        substitute(format_string, |key: &str| match key {
+            "message" => Some(substitute(message, |key: &str| argument(key).or_else(|| color(key)).map(str::to_owned))),
+            "message.json" => Some(json::escape(&substitute(message, |key: &str| argument(key).map(str::to_owned)))),
+            "level.json" => Some(json::escape(level.name())),
+            _ => argument(key).or_else(|| color(key)).map(str::to_owned).or_else(|| key.strip_suffix(".json").and_then(argument).map(json::escape))
+        })
+    }
+}
+
+/// Returns the escape code of the given formatting code or color, see [`COLORS`].
+fn color(key: &str) -> Option<&'static str> {
+    COLORS.iter().find(|(name, _)| *name == key).map(|(_, value)| *value)
+}
+
+/// Removes ANSI escape sequences, like the escape codes of [`COLORS`], from the given text.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut result: String = String::with_capacity(text.len());
+    let mut characters: std::str::Chars = text.chars();
+
+    while let Some(character) = characters.next() {
+        if character != '\x1b' {
+            result.push(character);
+            continue;
+        }
+
+        if characters.clone().next() == Some('[') {
+            characters.next();
+            characters.by_ref().find(|character: &char| ('\x40'..='\x7e').contains(character));
+        }
+    }
+
+    result
+}
+
+/// Replaces the placeholders of the given template in a single pass, without rescanning the inserted values.
+///
+/// Placeholders without a value are kept unchanged.
+fn substitute<F: Fn(&str) -> Option<String>>(template: &str, value: F) -> String {
+    let mut result: String = String::with_capacity(template.len());
+    let mut rest: &str = template;
+
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let replaced: Option<(String, usize)> = rest[2..].find("}}").and_then(|end| value(&rest[2..end + 2]).map(|value| (value, end + 4)));
+
+        match replaced {
+            Some((value, length)) => {
+                result.push_str(&value);
+                rest = &rest[length..];
+            },
+            None => {
+                result.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Formats the given time as ISO 8601 timestamp in UTC with millisecond precision, without using chrono.
+///
+/// Used as fallback when the timestamp format is invalid. Times before the Unix epoch are formatted as the epoch.
+fn iso8601(time: std::time::SystemTime) -> String {
+    let since_epoch: std::time::Duration = time.duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+    let seconds: u64 = since_epoch.as_secs();
+    let days: i64 = (seconds / 86_400) as i64;
+
+    // Converts days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let shifted: i64 = days + 719_468;
+    let era: i64 = shifted / 146_097;
+    let day_of_era: i64 = shifted - era * 146_097;
+    let year_of_era: i64 = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month: i64 = (5 * day_of_year + 2) / 153;
+    let day: i64 = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month: i64 = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year: i64 = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        seconds % 86_400 / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+
+///////////
+// CLOCK //
+///////////
+
+/// Returns the current instant, used to measure windows and durations.
+///
+/// Reads the virtual clock installed on the current thread, see [`testutil::clock::VirtualClock::install()`].
+pub(crate) fn now() -> std::time::Instant {
+    #[cfg(feature = "testutil")]
+    if let Some(clock) = testutil::clock::current() {
+        return clock.now();
+    }
+
+    std::time::Instant::now()
+}
+
+/// Returns the current time in UTC, used to timestamp records.
+///
+/// Reads the virtual clock installed on the current thread, see [`testutil::clock::VirtualClock::install()`].
+pub(crate) fn utc_now() -> chrono::DateTime<chrono::Utc> {
+    #[cfg(feature = "testutil")]
+    if let Some(clock) = testutil::clock::current() {
+        return clock.timestamp();
+    }
+
+    chrono::Utc::now()
+}
+
+/// The minimum time the wall clock has to go back to annotate records with `clock_skew`.
+const CLOCK_SKEW_TOLERANCE: std::time::Duration = std::time::Duration::from_millis(100);
+
+thread_local! {
+    /// The instant and timestamp of the last record created on the current thread before the wall clock went back,
+    /// and whether it is currently behind.
+    static CLOCK_REFERENCE: std::cell::Cell<Option<(std::time::Instant, chrono::DateTime<chrono::Utc>, bool)>> = const { std::cell::Cell::new(None) };
+}
+
+/// Checks whether the wall clock went back since earlier records were created on the current thread.
+///
+/// The timestamp is compared to the timestamp of the last record advanced by the time measured using the monotonic
+/// clock, so regressions like NTP corrections are detected while the wall clock stays behind. A warning is written to
+/// stderr once per regression.
+pub(crate) fn clock_skew(timestamp: chrono::DateTime<chrono::Utc>) -> bool {
+    let instant: std::time::Instant = now();
+    let reference: Option<(std::time::Instant, chrono::DateTime<chrono::Utc>, bool)> = CLOCK_REFERENCE.with(|reference| reference.get());
+
+    let behind: Option<chrono::Duration> = reference.and_then(|(start, time, _)| {
+        let expected: chrono::DateTime<chrono::Utc> = time + chrono::Duration::from_std(instant.saturating_duration_since(start)).ok()?;
+        Some(expected - timestamp).filter(|behind| behind.to_std().is_ok_and(|behind| behind > CLOCK_SKEW_TOLERANCE))
+    });
+
+    match (reference, behind) {
+        (Some((start, time, skewed)), Some(behind)) => {
+            CLOCK_REFERENCE.with(|reference| reference.set(Some((start, time, true))));
+
+            if !skewed {
+                eprintln!("{}", Formatter::DEFAULT.format_record_with(false, &Record::new(
+                    Level::WARN,
+                    "The system clock went back by {{behind}} ms, annotating records with `clock_skew`",
+                    file!(),
+                    vec![("behind", behind.num_milliseconds().to_string())]
+                )));
+            }
+
+            true
+        }
+        _ => {
+            CLOCK_REFERENCE.with(|reference| reference.set(Some((instant, timestamp, false))));
+            false
+        }
+    }
+}
+
+
+////////////
+// RECORD //
+////////////
+
+/// Log record object.
+///
+/// Records are created by the logger for every logged message and passed to the [`sinks::Sink`]s.
+///
+/// Use [`Record::new()`] to create record objects instead of using this struct.
+///
+/// # Parameters
+///
+/// - `level`: The log [`Level`] of the record
+/// - `message`: The unformatted message
+/// - `path`: The path of the calling file
+/// - `arguments`: A vector of key-value pairs of the formatting arguments
+/// - `attachments`: A vector of binary [`attachments::Attachment`]s
+/// - `timestamp`: The UTC timestamp the record was created at
+/// - `id`: The unique id of the record
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::Record {
+///     level: logging_rs::Level::INFO,
+///     message: "Some message".to_owned(),
+///     path: "src/lib.rs".to_owned(),
+///     arguments: vec![],
+///     attachments: vec![],
+///     timestamp: chrono::Utc::now(),
+///     id: 0
+/// };
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Record {
+    /// The log level of the record
+    pub level: Level,
+    /// The unformatted message
+    pub message: String,
+    /// The path of the calling file
+    pub path: String,
+    /// Key-value pairs of the formatting arguments
+    pub arguments: Vec<(String, String)>,
+    /// Binary attachments, available as formatting arguments using their names
+    pub attachments: Vec<attachments::Attachment>,
+    /// The UTC timestamp the record was created at
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// The unique id of the record, available as `uuid` and `ulid` formatting arguments
+    pub id: u128,
+}
+
+impl Record {
+    /// Creates a new record object with the current time as timestamp.
+    ///
+    /// If the wall clock went back since earlier records were created on the same thread, for example after a NTP
+    /// correction, the `clock_skew` argument is set to `true` until it caught up, explaining out-of-order timestamps.
+    ///
+    /// # Parameters
+    ///
+    /// - `level`: The log [`Level`] of the record
+    /// - `message`: The unformatted message
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A vector of key-value pairs of the formatting arguments
+    ///
+    /// # Returns
+    ///
+    /// A new `Record` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Record::new(logging_rs::Level::INFO, "Some {{thing}}", "src/lib.rs", vec![("thing", "message".to_owned())]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Record`]
+    pub fn new(level: Level, message: &str, path: &str, arguments: Vec<(&str, String)>) -> Record {
+        let timestamp: chrono::DateTime<chrono::Utc> = utc_now();
+        let mut arguments: Vec<(String, String)> = arguments.into_iter().map(|(key, value)| (key.to_owned(), value)).collect();
+
+        if let Some(span) = span::current() {
+            if !arguments.iter().any(|(key, _)| key == "span") {
+                arguments.push(("span".to_owned(), span));
+            }
+        }
+
+        if clock_skew(timestamp) {
+            arguments.push(("clock_skew".to_owned(), "true".to_owned()));
+        }
+
+        Record {
+            level,
+            message: message.to_owned(),
+            path: path.to_owned(),
+            arguments,
+            attachments: vec![],
+            timestamp,
+            id: ids::generate(timestamp)
+        }
+    }
+
+    /// Attaches the given binary payload to the record.
+    ///
+    /// The payload is available as formatting argument with the given name and rendered as truncated hex dump.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The record object
+    /// - `name`: The attachment name
+    /// - `data`: The binary payload
+    ///
+    /// # Returns
+    ///
+    /// The record with the attachment added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::DEBUG, "Received {{frame}}", "src/lib.rs", vec![])
+    ///     .with_attachment("frame", &[0xde, 0xad, 0xbe, 0xef]);
+    /// logger.log_record(&record);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Record`]
+    /// - [`attachments::Attachment`]
+    pub fn with_attachment(mut self, name: &str, data: &[u8]) -> Record {
+        self.attachments.push(attachments::Attachment::new(name, data));
+        self
+    }
+
+    /// Returns the value of the given argument.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The record object
+    /// - `name`: The argument name
+    ///
+    /// # Returns
+    ///
+    /// The value of the first argument with the given name, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Message", "src/lib.rs", vec![("key", "value".to_owned())]);
+    /// assert_eq!(record.argument("key"), Some("value"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Record`]
+    pub fn argument(&self, name: &str) -> Option<&str> {
+        self.arguments.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the message of the record with all placeholders replaced.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The record object
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::ERROR, "Request to {{path}} failed", "src/main.rs", vec![("path", "/a".to_owned())]);
+    ///
+    /// assert_eq!(record.formatted_message(), "Request to /a failed");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Record`]
+    pub fn formatted_message(&self) -> String {
+        const MESSAGE: Formatter = Formatter::from_static("{{message}}", "{{message}}", "");
+
+        MESSAGE.format_record_with(false, self)
+    }
+
+    /// Computes the fingerprint of the record.
+    ///
+    /// The fingerprint is a hash of the unformatted message, the path and the values of the given arguments. Records
+    /// logged by the same call with the same selected values share their fingerprint, even across processes and
+    /// machines, so downstream tools can group them.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The record object
+    /// - `fields`: The names of the arguments to include
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the fingerprint as 16 hexadecimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let first: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::ERROR, "Request to {{path}} failed", "src/main.rs", vec![("path", "/a".to_owned())]);
+    /// let second: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::ERROR, "Request to {{path}} failed", "src/main.rs", vec![("path", "/b".to_owned())]);
+    ///
+    /// assert_eq!(first.fingerprint(&[]), second.fingerprint(&[]));
+    /// assert_ne!(first.fingerprint(&["path"]), second.fingerprint(&["path"]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`rules::Rule::FINGERPRINT`]
+    pub fn fingerprint(&self, fields: &[&str]) -> String {
+        // 64 bit FNV-1a, stable across platforms and compiler versions unlike the hashers of the standard library
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes.iter().chain(&[0]) {
+                hash ^= *byte as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        };
+
+        write(self.message.as_bytes());
+        write(self.path.as_bytes());
+
+        for field in fields {
+            write(field.as_bytes());
+            write(self.argument(field).unwrap_or_default().as_bytes());
+        }
+
+        format!("{:016x}", hash)
+    }
+
+    /// Returns the approximate number of bytes the record uses in memory.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The record object
+    ///
+    /// # Returns
+    ///
+    /// The size of the record including its message, path, arguments and attachments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Message", "src/lib.rs", vec![]);
+    /// assert!(record.memory_size() > "Message".len());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`memory::Budget`]
+    pub fn memory_size(&self) -> usize {
+        let arguments: usize = self.arguments.iter().map(|(key, value)| std::mem::size_of::<(String, String)>() + key.capacity() + value.capacity()).sum();
+        let attachments: usize = self.attachments.iter().map(|attachment| std::mem::size_of::<attachments::Attachment>() + attachment.name.capacity() + attachment.data.capacity()).sum();
+
+        std::mem::size_of::<Record>() + self.message.capacity() + self.path.capacity() + arguments + attachments
+    }
+}
+
+
+/// Pending record object.
+///
+/// Returned by the logging macros when called with `pending:` before the logger. Additional arguments can be added
+/// to the record until it is emitted using [`PendingRecord::emit()`] or when the object is dropped, which happens at
+/// the end of the statement unless it is bound to a variable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let attempt: u32 = 3;
+/// logging_rs::warn!(pending: logger, "Retrying request").with("attempt", attempt);
+///
+/// let record: logging_rs::Record = logging_rs::info!(pending: logger, "Done").emit();
+/// assert_eq!(record.message, "Done");
+/// ```
+pub struct PendingRecord<F: FnOnce(&Record)> {
+    /// The record to emit.
+    record: Record,
+    /// The function emitting the record, `None` once emitted.
+    emit: Option<F>,
+}
+
+impl<F: FnOnce(&Record)> PendingRecord<F> {
+    /// Creates a new pending record object.
+    ///
+    /// # Parameters
+    ///
+    /// - `record`: The [`Record`] to emit
+    /// - `emit`: The function emitting the record
+    ///
+    /// # Returns
+    ///
+    /// A new `PendingRecord` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/lib.rs", vec![]);
+    /// logging_rs::PendingRecord::new(record, |record: &logging_rs::Record| logger.log_record(record));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`PendingRecord`]
+    pub fn new(record: Record, emit: F) -> PendingRecord<F> {
+        PendingRecord {
+            record,
+            emit: Some(emit)
+        }
+    }
+
+    /// Adds the given argument to the record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The pending record object
+    /// - `key`: The argument name
+    /// - `value`: The argument value
+    ///
+    /// # Returns
+    ///
+    /// The pending record object with the added argument.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::error!(pending: logger, "Request failed after {{attempt}} attempts").with("attempt", 3);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`PendingRecord`]
+    pub fn with<V: std::fmt::Display>(mut self, key: &str, value: V) -> PendingRecord<F> {
+        self.record.arguments.push((key.to_owned(), value.to_string()));
+        self
+    }
+
+    /// Returns the record that will be emitted.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The pending record object
+    ///
+    /// # Returns
+    ///
+    /// A reference to the [`Record`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let pending = logging_rs::info!(pending: logger, "Some message", user_id = 42);
+    /// assert_eq!(pending.record().argument("user_id"), Some("42"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`PendingRecord`]
+    pub fn record(&self) -> &Record {
+        &self.record
+    }
+
+    /// Emits the record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The pending record object
+    ///
+    /// # Returns
+    ///
+    /// The emitted [`Record`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let record: logging_rs::Record = logging_rs::info!(pending: logger, "Some message").emit();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`PendingRecord`]
+    pub fn emit(self) -> Record {
+        let record: Record = self.record.clone();
+        drop(self);
+
+        record
+    }
+}
+
+impl<F: FnOnce(&Record)> std::fmt::Debug for PendingRecord<F> {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.debug_struct("PendingRecord").field("record", &self.record).field("emitted", &self.emit.is_none()).finish()
+    }
+}
+
+impl<F: FnOnce(&Record)> Drop for PendingRecord<F> {
+    fn drop(&mut self) {
+        if let Some(emit) = self.emit.take() {
+            emit(&self.record);
+        }
+    }
+}
+
+///////////////////
+// LOGGER STRUCT //
+///////////////////
+
+/// Logger object.
+///
+/// Logging never panics: panics of outputs, sinks and filters are caught, printed to stderr and passed to the handler
+/// set using [`errors::set_handler()`], without applying the [`errors::ExitStrategy`]. So loggers can be used in
+/// `extern "C"` callbacks and other places that must not unwind or exit. Loggers are [`std::panic::UnwindSafe`] and
+/// [`std::panic::RefUnwindSafe`].
+///
+/// Cloning a logger is cheap and clones share their state: sinks and filters are reference counted, so clones write
+/// to the same sinks, including open files, buffers and counters, instead of duplicating them. File outputs open
+/// their file for every write and hold no handles. The configuration itself is copied, so adding sinks or changing
+/// the level of a clone does not affect the original logger.
+///
+/// Logging only needs a shared reference, so loggers can be shared between threads and stored in statics using
+/// [`lazy::LazyLogger`].
+///
+/// Use [`Logger::new()`] to create logger objects instead of using this struct.
+///
+/// # Parameters
+///
+/// - `formatter`: The [`Formatter`] to use for formatting messages
+/// - `writable_list`: A vector of [`Output`]s to write to
+/// - `level`: The minimum [`Level`] of logged records, lower records are skipped before formatting
+/// - `sinks`: A vector of additional [`sinks::Sink`]s to write to
+/// - `mirror_fatal`: Whether [`Level::FATAL`] records are always written to stderr as well
+/// - `categories`: The names of the enabled debug categories, see [`categories`]
+/// - `filter`: The per-target [`filter::EnvFilter`], applied after the minimum level
+/// - `filters`: Additional [`filter::SharedFilter`]s, applied after the per-target filter
+///
+/// # Returns
+///
+/// A new `Logger` object with the specified formatter and writables.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::Logger {
+///     formatter: logging_rs::Formatter::default(),
+///     writable_list: vec![logging_rs::Output::default()],
+///     level: logging_rs::Level::DEBUG,
+///     sinks: vec![],
+///     mirror_fatal: true,
+///     categories: vec![],
+///     filter: logging_rs::filter::EnvFilter::default(),
+///     filters: vec![]
+/// };
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Logger {
+    pub formatter: Formatter,
+    pub writable_list: Vec<Output>,
+    pub level: Level,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub sinks: Vec<sinks::SharedSink>,
+    pub mirror_fatal: bool,
+    pub categories: Vec<String>,
+    pub filter: filter::EnvFilter,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub filters: Vec<filter::SharedFilter>
+}
+
+impl Default for Logger {
+    fn default() -> Logger {
+        Logger::new(Formatter::default(), vec![Output::STDOUT])
+    }
+}
+
+impl Logger {
+    /// Creates a new logger object.
+    ///
+    /// The outputs are used as given. Unlike [`builder::LoggerBuilder::build()`], no [`builder::EmptyOutputs`] policy
+    /// applies, so a logger created without outputs writes nowhere until outputs or sinks are added.
+    ///
+    /// # Parameters
+    ///
+    /// - `formatter`: The [`Formatter`] to use for formatting messages
+    /// - `writable_list`: A vector of [`Output`]s to write to
+    ///
+    /// # Returns
+    ///
+    /// A new `Logger` object with the specified formatter and writables.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::Logger::new(logging_rs::Formatter::default(), vec![logging_rs::Output::default()]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger`]
+    pub fn new(formatter: Formatter, writable_list: Vec<Output>) -> Logger {
+        Logger {
+            formatter,
+            writable_list,
+            level: Level::DEBUG,
+            sinks: vec![],
+            mirror_fatal: true,
+            categories: vec![],
+            filter: filter::EnvFilter::default(),
+            filters: vec![]
+        }
+    }
+
+    /// Sets the minimum level of logged records.
+    ///
+    /// Records below the level are skipped. The logging macros check the level before formatting any arguments, so
+    /// disabled calls are cheap.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `level`: The minimum [`Level`]
+    ///
+    /// # Returns
+    ///
+    /// The logger object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default().with_level(logging_rs::Level::INFO);
+    /// logging_rs::debug!(logger, "Skipped");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::is_enabled()`]
+    /// - [`Logger`]
+    pub fn with_level(mut self, level: Level) -> Logger {
+        self.level = level;
+        self
+    }
+
+    /// Returns whether records with the given level are logged.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `level`: The [`Level`] to check
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the level is at least the minimum level of the logger and enabled for any target
+    /// of its [`filter::EnvFilter`]. Always `false` for loggers writing nowhere, see [`Logger::noop()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default().with_level(logging_rs::Level::WARN);
+    ///
+    /// assert!(logger.is_enabled(logging_rs::Level::ERROR));
+    /// assert!(!logger.is_enabled(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::with_level()`]
+    /// - [`Logger`]
+    pub fn is_enabled(&self, level: Level) -> bool {
+        !self.is_noop() && level >= self.level && self.filter.min_level().map_or(true, |minimum| level >= minimum)
+    }
+
+    /// Creates a new logger writing nowhere.
+    ///
+    /// The logger has no outputs and sinks and does not mirror fatal records, so [`Logger::is_enabled()`] is always
+    /// `false`. The logging macros check it before formatting any arguments, so libraries can accept a logger and
+    /// cost next to nothing when the host passes this logger. Adding an output or sink enables the logger again.
+    ///
+    /// # Returns
+    ///
+    /// A new `Logger` object logging nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// fn connect(logger: &logging_rs::Logger, host: &str) {
+    ///     logging_rs::debug!(logger, "Connecting to {{host}}", "host" = host);
+    /// }
+    ///
+    /// let logger: logging_rs::Logger = logging_rs::Logger::noop();
+    /// connect(&logger, "example.com");
+    ///
+    /// assert!(!logger.is_enabled(logging_rs::Level::FATAL));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::is_noop()`]
+    pub fn noop() -> Logger {
+        Logger {
+            mirror_fatal: false,
+            ..Logger::new(Formatter::default(), vec![])
+        }
+    }
+
+    /// Returns whether the logger writes nowhere.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the logger has no outputs and sinks and does not mirror fatal records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::Logger::noop().is_noop());
+    /// assert!(!logging_rs::Logger::default().is_noop());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::noop()`]
+    pub fn is_noop(&self) -> bool {
+        self.sinks.is_empty() && self.writable_list.is_empty() && !self.mirror_fatal
+    }
+
+    /// Creates a logger for development, writing colored, detailed records of at least [`Level::DEBUG`] to stdout.
+    ///
+    /// # Returns
+    ///
+    /// A new `Logger` object using [`Formatter::detailed()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::development();
+    ///
+    /// assert_eq!(logger.level, logging_rs::Level::DEBUG);
+    /// assert_eq!(logger.writable_list, vec![logging_rs::Output::STDOUT]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::production()`]
+    pub fn development() -> Logger {
+        Logger::new(Formatter::detailed(), vec![Output::STDOUT]).with_level(Level::DEBUG)
+    }
+
+    /// Creates a logger for production, writing records of at least [`Level::INFO`] as JSON lines to stderr and a
+    /// rotating file.
+    ///
+    /// The file is named after the executable, like `server.log`, and created in the working directory. It is rotated
+    /// at 10 MiB, keeping 5 rotated files, see [`sinks::Rotating`]. Use [`Logger::builder()`] to diverge from these
+    /// defaults.
+    ///
+    /// # Returns
+    ///
+    /// A new `Logger` object using [`Formatter::json_lines()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::production();
+    ///
+    /// assert_eq!(logger.level, logging_rs::Level::INFO);
+    /// assert_eq!(logger.writable_list, vec![logging_rs::Output::STDERR]);
+    /// assert_eq!(logger.sinks.len(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::development()`]
+    pub fn production() -> Logger {
+        let name: String = std::env::current_exe()
+            .ok()
+            .and_then(|path| path.file_stem().map(|name| name.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| "app".to_owned());
+
+        let mut logger: Logger = Logger::new(Formatter::json_lines(), vec![Output::STDERR]).with_level(Level::INFO);
+        logger.add_sink(sinks::Rotating::new(&format!("{}.log", name), 10 * 1024 * 1024, 5));
+        logger
+    }
+
+    /// Checks whether the given record passes the minimum level and the filter of the logger.
+    fn accepts(&self, record: &Record) -> bool {
+        self.is_enabled(record.level) && self.filter.accepts(record) && self.filters.iter().all(|filter| filter.accepts(record))
+    }
+
+    /// Adds a sink the logger writes to in addition to its outputs.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `sink`: The [`sinks::Sink`] to add
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+    /// logger.add_sink(logging_rs::sinks::leveled(logging_rs::Output::STDERR, logging_rs::Level::ERROR));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger`]
+    /// - [`sinks`]
+    pub fn add_sink<S: sinks::Sink + 'static>(&mut self, sink: S) {
+        self.sinks.push(sinks::SharedSink::new(sink));
+    }
+
+    /// Adds a named sink the logger writes to in addition to its outputs.
+    ///
+    /// Records can be sent to named sinks only using [`Logger::log_to()`] or the `to:` syntax of the macros.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `name`: The name of the sink
+    /// - `sink`: The [`sinks::Sink`] to add
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.add_named_sink("audit", logging_rs::Output::FILE { path: "audit.log".to_owned() });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger`]
+    /// - [`Logger::log_to()`]
+    pub fn add_named_sink<S: sinks::Sink + 'static>(&mut self, name: &str, sink: S) {
+        self.sinks.push(sinks::SharedSink::named(name, sink));
+    }
+
+    /// Adds a filter deciding whether records are logged.
+    ///
+    /// Filters are checked after the minimum level and the per-target filter, before records are formatted or
+    /// written. A record is only logged if all filters return `true`. Filters panicking are reported and let the
+    /// record pass.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `filter`: The predicate returning whether a record is logged
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.add_filter(|record: &logging_rs::Record| record.argument("tenant") != Some("load-test"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::add_named_filter()`]
+    /// - [`filter::SharedFilter`]
+    pub fn add_filter<F: Fn(&Record) -> bool + Send + Sync + 'static>(&mut self, filter: F) {
+        self.filters.push(filter::SharedFilter::new(filter));
+    }
+
+    /// Adds a named filter deciding whether records are logged.
+    ///
+    /// Named filters can be removed again using [`Logger::remove_filter()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `name`: The name of the filter
+    /// - `filter`: The predicate returning whether a record is logged
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let verbose: std::sync::Arc<std::sync::atomic::AtomicBool> = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    ///
+    /// let flag: std::sync::Arc<std::sync::atomic::AtomicBool> = verbose.clone();
+    /// logger.add_named_filter("verbose", move |record: &logging_rs::Record| {
+    ///     record.level > logging_rs::Level::DEBUG || flag.load(std::sync::atomic::Ordering::Relaxed)
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::add_filter()`]
+    /// - [`Logger::remove_filter()`]
+    pub fn add_named_filter<F: Fn(&Record) -> bool + Send + Sync + 'static>(&mut self, name: &str, filter: F) {
+        self.filters.push(filter::SharedFilter::named(name, filter));
+    }
+
+    /// Removes all filters with the given name.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `name`: The name of the filters to remove
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether a filter was removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.add_named_filter("maintenance", |_record: &logging_rs::Record| false);
+    ///
+    /// assert!(logger.remove_filter("maintenance"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::add_named_filter()`]
+    pub fn remove_filter(&mut self, name: &str) -> bool {
+        let count: usize = self.filters.len();
+        self.filters.retain(|filter| filter.name.as_deref() != Some(name));
+
+        self.filters.len() != count
+    }
+
+    /// Resets the state of the outputs and sinks in the child process after forking.
+    ///
+    /// Call this in the child right after forking, before logging. Sinks discard buffered records and counters of
+    /// the parent using [`sinks::Sink::after_fork()`]. Record ids include the process id, so parent and child never
+    /// generate the same ids. Files are opened for every write, so no file descriptors are shared. Background
+    /// workers of [`maintenance::Maintenance`] have to be restarted using
+    /// [`maintenance::Maintenance::after_fork()`].
+    ///
+    /// Locks held by other threads of the parent while forking can not be released in the child, so avoid forking
+    /// while other threads are logging.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// // In the child process, right after forking
+    /// logger.after_fork();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`sinks::Sink::after_fork()`]
+    /// - [`Logger`]
+    pub fn after_fork(&self) {
+        for sink in &self.sinks {
+            errors::guard(|| sinks::Sink::after_fork(sink));
+        }
+    }
+
+    /// Logs the given message.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the formatted message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.log(
+    ///     "Some message",
+    ///     logging_rs::Level::default(),
+    ///     "src/lib.rs",
+    ///     vec![]
+    /// );
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`debug!()`]
+    /// - [`info!()`]
+    /// - [`warn!()`]
+    /// - [`error!()`]
+    /// - [`fatal!()`]
+    /// - [`message!()`]
+    /// - [`Logger`]
+    /// - [`Level`]
+    pub fn log(&self, message: &str, level: Level, path: &str, arguments: Vec<(&str, String)>) {
+        self.log_record(&Record::new(level, message, path, arguments));
+    }
+
+    /// Logs the given static message without arguments.
+    ///
+    /// Messages without placeholders are passed to [`sinks::Sink::write_static()`] without creating a record, so
+    /// sinks accepting string slices receive them without any allocation. A record is only created for outputs and
+    /// sinks not accepting the message, messages containing placeholders and [`Level::FATAL`] records mirrored to
+    /// stderr. The logging macros use this method for string literals without arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.log_static("Cache hit", logging_rs::Level::DEBUG, "src/lib.rs");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log()`]
+    /// - [`sinks::Sink::write_static()`]
+    pub fn log_static(&self, message: &'static str, level: Level, path: &'static str) {
+        if !self.is_enabled(level) || !self.filter.is_enabled(level, path) {
+            return;
+        }
+
+        if message.contains("{{") || (level == Level::FATAL && self.mirror_fatal) || !self.writable_list.is_empty() || !self.filters.is_empty() || span::current().is_some() {
+            return self.log(message, level, path, vec![]);
+        }
+
+        let mut record: Option<Record> = None;
+
+        for sink in &self.sinks {
+            errors::guard(|| {
+                if !sinks::Sink::write_static(sink, &self.formatter, level, message, path) {
+                    let record: &Record = record.get_or_insert_with(|| Record::new(level, message, path, vec![]));
+                    sinks::Sink::write(sink, &self.formatter, record);
+                }
+            });
+        }
+    }
+
+    /// Logs the given record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `record`: The [`Record`] to log
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.log_record(&logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/lib.rs", vec![]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log()`]
+    /// - [`Logger`]
+    /// - [`Record`]
+    pub fn log_record(&self, record: &Record) {
+        if !self.accepts(record) {
+            return;
+        }
+
+        self.mirror(std::slice::from_ref(record));
+
+        for writable in &self.writable_list {
+            errors::guard(|| sinks::Sink::write(writable, &self.formatter, record));
+        }
+
+        for sink in &self.sinks {
+            errors::guard(|| sinks::Sink::write(sink, &self.formatter, record));
+        }
+    }
+
+    /// Logs the given records as one unit.
+    ///
+    /// Every output and sink receives all records at once, so they are not interleaved with records logged
+    /// concurrently.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `records`: The [`Record`]s to log
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.log_records(&[
+    ///     logging_rs::Record::new(logging_rs::Level::INFO, "First", "src/lib.rs", vec![]),
+    ///     logging_rs::Record::new(logging_rs::Level::INFO, "Second", "src/lib.rs", vec![])
+    /// ]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_record()`]
+    /// - [`transaction::Transaction`]
+    /// - [`Logger`]
+    pub fn log_records(&self, records: &[Record]) {
+        if records.iter().any(|record| !self.accepts(record)) {
+            let records: Vec<Record> = records.iter().filter(|record| self.accepts(record)).cloned().collect();
+            return self.log_records(&records);
+        }
+
+        self.mirror(records);
+
+        for writable in &self.writable_list {
+            errors::guard(|| sinks::Sink::write_batch(writable, &self.formatter, records));
+        }
+
+        for sink in &self.sinks {
+            errors::guard(|| sinks::Sink::write_batch(sink, &self.formatter, records));
+        }
+    }
+
+    /// Logs the given message to the named sinks with the given names only.
+    ///
+    /// The outputs of the `writable_list` and unnamed sinks are skipped. If no targets are given, the message is
+    /// logged like using [`Logger::log()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `targets`: The names of the sinks to write to
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.add_named_sink("audit", logging_rs::Output::STDERR);
+    /// logger.log_to(&["audit"], "User logged in", logging_rs::Level::INFO, "src/lib.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::add_named_sink()`]
+    /// - [`Logger::log_record_to()`]
+    /// - [`Logger`]
+    pub fn log_to(&self, targets: &[&str], message: &str, level: Level, path: &str, arguments: Vec<(&str, String)>) {
+        self.log_record_to(targets, &Record::new(level, message, path, arguments));
+    }
+
+    /// Logs the given record to the named sinks with the given names only.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `targets`: The names of the sinks to write to
+    /// - `record`: The [`Record`] to log
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.log_record_to(&["audit"], &logging_rs::Record::new(logging_rs::Level::INFO, "User logged in", "src/lib.rs", vec![]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_to()`]
+    /// - [`Logger`]
+    pub fn log_record_to(&self, targets: &[&str], record: &Record) {
+        if targets.is_empty() {
+            return self.log_record(record);
+        }
+
+        if !self.accepts(record) {
+            return;
+        }
+
+        self.mirror(std::slice::from_ref(record));
+
+        for sink in &self.sinks {
+            if sink.name.as_deref().is_some_and(|name| targets.contains(&name)) {
+                errors::guard(|| sinks::Sink::write(sink, &self.formatter, record));
+            }
+        }
+    }
+
+    /// Writes the [`Level::FATAL`] records to stderr if enabled and neither an output nor a sink writes there already.
+    fn mirror(&self, records: &[Record]) {
+        if !self.mirror_fatal || self.writable_list.contains(&Output::STDERR) || self.sinks.iter().any(|sink| sinks::Sink::writes_to(sink, &Output::STDERR)) {
+            return;
+        }
+
+        let fatal: Vec<Record> = records.iter().filter(|record| record.level == Level::FATAL).cloned().collect();
+        errors::guard(|| sinks::Sink::write_batch(&Output::STDERR, &self.formatter, &fatal));
+    }
+
+    /// Logs the given error with logging level [`Level::ERROR`].
+    ///
+    /// The error is attached using the standardized `error.kind`, `error.message` and `error.stack` arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `message`: The message to log
+    /// - `error`: The error to log
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+    /// logger.log_error("Saving failed: {{error.message}}", &error, "src/lib.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`error!()`]
+    /// - [`errors::error_arguments()`]
+    /// - [`Logger`]
+    pub fn log_error<E: std::error::Error + ?Sized>(&self, message: &str, error: &E, path: &str, mut arguments: Vec<(&str, String)>) {
+        arguments.append(&mut errors::error_arguments(error));
+        self.log(message, Level::ERROR, path, arguments);
+    }
+}
+
+
+////////////
+// MACROS //
+////////////
+
+/// Logs the given message with the given logging level. Used by the level macros.
+///
+/// Supports sending the record to selected named sinks only using `to: "name"` or `to: ["name", ...]` and returning
+/// a [`PendingRecord`] using `pending:` before the logger.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __log {
+    ($level:expr, $message:literal $(, $($fields:tt)*)?) => {
+        if let ::std::option::Option::Some(logger) = $crate::global::logger() {
+            $crate::__log!($level, logger, $message $(, $($fields)*)?)
+        }
+    };
+
+    ($level:expr, pending: $logger:expr, to: [$($target:literal),+ $(,)?], $message:expr $(, $($fields:tt)*)?) => {
+        {
+            #[allow(unused_mut)]
+            let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![];
+
+            $crate::__callsite!($level, $message);
+            $crate::__arguments!(arguments; $($($fields)*)?);
+
+            $crate::PendingRecord::new(
+                $crate::Record::new($level, $message, ::std::panic::Location::caller().file(), arguments),
+                |record: &$crate::Record| if $crate::__static_enabled!(record.level) && $crate::__callsite_enabled!() {
+                    $logger.log_record_to(&[$($target),+], record);
+                }
+            )
+        }
+    };
+
+    ($level:expr, pending: $logger:expr, to: $target:literal, $message:expr $(, $($fields:tt)*)?) => {
+        $crate::__log!($level, pending: $logger, to: [$target], $message $(, $($fields)*)?)
+    };
+
+    ($level:expr, pending: $logger:expr, $message:expr $(, $($fields:tt)*)?) => {
+        {
+            #[allow(unused_mut)]
+            let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![];
+
+            $crate::__callsite!($level, $message);
+            $crate::__arguments!(arguments; $($($fields)*)?);
+
+            $crate::PendingRecord::new(
+                $crate::Record::new($level, $message, ::std::panic::Location::caller().file(), arguments),
+                |record: &$crate::Record| if $crate::__static_enabled!(record.level) && $crate::__callsite_enabled!() {
+                    $logger.log_record(record);
+                }
+            )
+        }
+    };
+
+    ($level:expr, $logger:expr, to: [$($target:literal),+ $(,)?], $message:expr $(, $($fields:tt)*)?) => {
+        {
+            let level: $crate::Level = $level;
+            let logger = &$logger;
+
+            $crate::__callsite!($level, $message);
+
+            if $crate::__static_enabled!(level) && $crate::__callsite_enabled!() && logger.is_enabled(level) {
+                #[allow(unused_mut)]
+                let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![];
+
+                $crate::__arguments!(arguments; $($($fields)*)?);
+
+                logger.log_to(&[$($target),+], $message, level, ::std::panic::Location::caller().file(), arguments);
+            }
+        }
+    };
+
+    ($level:expr, $logger:expr, to: $target:literal, $message:expr $(, $($fields:tt)*)?) => {
+        $crate::__log!($level, $logger, to: [$target], $message $(, $($fields)*)?)
+    };
+
+    ($level:expr, $logger:expr, $message:literal $(,)?) => {
+        {
+            let level: $crate::Level = $level;
+            let logger = &$logger;
+
+            $crate::__callsite!($level, $message);
+
+            if $crate::__static_enabled!(level) && $crate::__callsite_enabled!() && logger.is_enabled(level) {
+                logger.log_static($message, level, ::std::panic::Location::caller().file());
+            }
+        }
+    };
+
+    ($level:expr, $logger:expr, $message:expr $(, $($fields:tt)*)?) => {
+        {
+            let level: $crate::Level = $level;
+            let logger = &$logger;
+
+            $crate::__callsite!($level, $message);
+
+            if $crate::__static_enabled!(level) && $crate::__callsite_enabled!() && logger.is_enabled(level) {
+                #[allow(unused_mut)]
+                let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![];
+
+                $crate::__arguments!(arguments; $($($fields)*)?);
+
+                logger.log($message, level, ::std::panic::Location::caller().file(), arguments);
+            }
+        }
+    };
+}
+
+/// Returns whether the given level is at least [`STATIC_LEVEL`]. Used by the logging macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __static_enabled {
+    ($level:expr) => {
+        $crate::Level::severity(&$level) >= $crate::STATIC_LEVEL.severity()
+    };
+}
+
+/// Registers the call site of a logging macro if the `callsites` feature is enabled. Used by the logging macros.
+#[cfg(feature = "callsites")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __callsite {
+    ($level:expr, $message:expr) => {
+        #[$crate::callsites::__linkme::distributed_slice($crate::callsites::CALLSITES)]
+        #[linkme(crate = $crate::callsites::__linkme)]
+        static CALLSITE: $crate::callsites::Callsite = $crate::callsites::Callsite {
+            file: ::std::file!(),
+            line: ::std::line!(),
+            column: ::std::column!(),
+            module_path: ::std::module_path!(),
+            level_expression: ::std::stringify!($level),
+            message_expression: ::std::stringify!($message)
+        };
+    };
+}
+
+/// Registers the call site of a logging macro if the `callsites` feature is enabled. Used by the logging macros.
+#[cfg(not(feature = "callsites"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __callsite {
+    ($level:expr, $message:expr) => {};
+}
+
+/// Returns whether the call site registered in the enclosing block is enabled. Used by the logging macros.
+#[cfg(feature = "callsites")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __callsite_enabled {
+    () => {
+        CALLSITE.is_enabled()
+    };
+}
+
+/// Returns whether the call site registered in the enclosing block is enabled. Used by the logging macros.
+#[cfg(not(feature = "callsites"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __callsite_enabled {
+    () => {
+        true
+    };
+}
+
+/// Collects the key-value pairs of the formatting arguments into the given vector. Used by the logging macros.
+///
+/// Keys are string literals or identifiers. Values are formatted using [`value::ToValue`] if implemented, otherwise
+/// using [`std::fmt::Display`], or [`std::fmt::Debug`] if prefixed with `?`. A `%` prefix explicitly selects `Display`.
+/// Values prefixed with `#` are flattened into arguments with dotted keys using [`value::ToFields`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __arguments {
+    (@value $arguments:ident, $key:expr; ? $value:expr $(, $($rest:tt)*)?) => {
+        $arguments.push(($key, ::std::format!("{:?}", $value)));
+        $crate::__arguments!($arguments; $($($rest)*)?);
+    };
+
+    (@value $arguments:ident, $key:expr; # $value:expr $(, $($rest:tt)*)?) => {
+        let mut fields: ::std::vec::Vec<(::std::string::String, ::std::string::String)> = ::std::vec![];
+        $crate::value::ToFields::to_fields(&$value, $key, &mut fields);
+        $arguments.extend(fields.iter().map(|(key, value)| (key.as_str(), ::std::clone::Clone::clone(value))));
+        $crate::__arguments!($arguments; $($($rest)*)?);
+    };
+
+    (@value $arguments:ident, $key:expr; % $value:expr $(, $($rest:tt)*)?) => {
+        $arguments.push(($key, ::std::string::ToString::to_string(&$value)));
+        $crate::__arguments!($arguments; $($($rest)*)?);
+    };
+
+    (@value $arguments:ident, $key:expr; $value:expr $(, $($rest:tt)*)?) => {
+        $arguments.push(($key, {
+            #[allow(unused_imports)]
+            use $crate::value::{ViaDisplay as _, ViaToValue as _};
+
+            (&$crate::value::Wrap(&$value)).__value()
+        }));
+        $crate::__arguments!($arguments; $($($rest)*)?);
+    };
+
+    ($arguments:ident;) => {};
+
+    ($arguments:ident; $key:literal = $($rest:tt)+) => {
+        $crate::__arguments!(@value $arguments, $key; $($rest)+)
+    };
+
+    ($arguments:ident; $key:ident = $($rest:tt)+) => {
+        $crate::__arguments!(@value $arguments, ::std::stringify!($key); $($rest)+)
+    };
+}
+
+/// Logs the given message with logging level [`Level::DEBUG`].
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with, the [`global`] logger if omitted
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::debug!(logger, "A message");
+/// logging_rs::debug!(logger, "A message with more {{details}}", "details" = "stuff");
+/// logging_rs::debug!(logger, to: "audit", "A message only written to the sink named audit");
+/// ```
+///
+/// # See also
+///
+/// - [`info!()`]
+/// - [`warn!()`]
+/// - [`error!()`]
+/// - [`fatal!()`]
+/// - [`message!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! debug {
+    ($($arguments:tt)+) => {
+        $crate::__log!($crate::Level::DEBUG, $($arguments)+)
+    };
+}
+
+/// Logs the given message with logging level [`Level::DEBUG`] if the given debug category is enabled.
+///
+/// The category is added as `category` argument.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `category`: The name of the debug category
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logger.enable_category("wire");
+/// logging_rs::debug_cat!(logger, "wire", "Received {{bytes}} bytes", "bytes" = 42);
+/// ```
+///
+/// # See also
+///
+/// - [`debug!()`]
+/// - [`Logger::enable_category()`]
+/// - [`categories`]
+#[macro_export]
+macro_rules! debug_cat {
+    ($logger:expr, $category:expr, $message:expr $(, $($fields:tt)*)?) => {
+        {
+            let category: &str = $category;
+
+            if $crate::__static_enabled!($crate::Level::DEBUG) && $logger.is_category_enabled(category) {
+                #[allow(unused_mut)]
+                let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![("category", ::std::string::ToString::to_string(&category))];
+
+                $crate::__arguments!(arguments; $($($fields)*)?);
+
+                $logger.log($message, $crate::Level::DEBUG, ::std::panic::Location::caller().file(), arguments);
+            }
+        }
+    };
+}
+
+/// Logs the given message with logging level [`Level::INFO`].
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with, the [`global`] logger if omitted
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::info!(logger, "A message");
+/// logging_rs::info!(logger, "A message with more {{details}}", "details" = "stuff");
+/// logging_rs::info!(logger, "Request of {{user_id}} to {{path}}", user_id = 42, path = ?std::path::Path::new("/"));
+/// logging_rs::info!(pending: logger, "A message emitted at the end of the statement").with("details", "stuff");
+/// logging_rs::info!(logger, to: "audit", "A message only written to the sink named audit");
+/// logging_rs::info!("A message logged using the global logger set using {{function}}", function = "init()");
+/// ```
+///
+/// # See also
+///
+/// - [`debug!()`]
+/// - [`warn!()`]
+/// - [`error!()`]
+/// - [`fatal!()`]
+/// - [`message!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! info {
+    ($($arguments:tt)+) => {
+        $crate::__log!($crate::Level::INFO, $($arguments)+)
+    };
+}
+
+/// Logs the given message with logging level [`Level::WARN`].
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with, the [`global`] logger if omitted
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::warn!(logger, "A message");
+/// logging_rs::warn!(logger, "A message with more {{details}}", "details" = "stuff");
+/// logging_rs::warn!(logger, to: "audit", "A message only written to the sink named audit");
+/// ```
+///
+/// # See also
+///
+/// - [`debug!()`]
+/// - [`info!()`]
+/// - [`error!()`]
+/// - [`fatal!()`]
+/// - [`message!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! warn {
+    ($($arguments:tt)+) => {
+        $crate::__log!($crate::Level::WARN, $($arguments)+)
+    };
+}
+
+/// Logs the given message with logging level [`Level::ERROR`].
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with, the [`global`] logger if omitted
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::error!(logger, "A message");
+/// logging_rs::error!(logger, "A message with more {{details}}", "details" = "stuff");
+/// logging_rs::error!(logger, to: "audit", "A message only written to the sink named audit");
+///
+/// let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+/// logging_rs::error!(logger, "Saving failed: {{error.message}}", error = error);
+/// logging_rs::error!(logger, "Saving {{file}} failed", error = error, "file" = "data.txt");
+/// ```
+///
+/// # See also
+///
+/// - [`debug!()`]
+/// - [`info!()`]
+/// - [`warn!()`]
+/// - [`fatal!()`]
+/// - [`message!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! error {
+    ($logger:expr, $message:expr, error = $error:expr $(, $($fields:tt)*)?) => {
+        {
+            let logger = &$logger;
+
+            $crate::__callsite!($crate::Level::ERROR, $message);
+
+            if $crate::__static_enabled!($crate::Level::ERROR) && $crate::__callsite_enabled!() && logger.is_enabled($crate::Level::ERROR) {
+                #[allow(unused_mut)]
+                let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![];
+
+                $crate::__arguments!(arguments; $($($fields)*)?);
+
+                logger.log_error($message, &$error, ::std::panic::Location::caller().file(), arguments);
+            }
+        }
+    };
+
+    ($($arguments:tt)+) => {
+        $crate::__log!($crate::Level::ERROR, $($arguments)+)
+    };
+}
+
+/// Logs the given message with logging level [`Level::FATAL`].
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with, the [`global`] logger if omitted
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::fatal!(logger, "A message");
+/// logging_rs::fatal!(logger, "A message with more {{details}}", "details" = "stuff");
+/// logging_rs::fatal!(logger, to: "audit", "A message only written to the sink named audit");
+/// ```
+///
+/// # See also
+///
+/// - [`debug!()`]
+/// - [`info!()`]
+/// - [`warn!()`]
+/// - [`error!()`]
+/// - [`message!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! fatal {
+    ($($arguments:tt)+) => {
+        $crate::__log!($crate::Level::FATAL, $($arguments)+)
+    };
+}
+
+/// Logs the given message with logging level [`Level::MESSAGE`].
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with, the [`global`] logger if omitted
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::message!(logger, "A message");
+/// logging_rs::message!(logger, "A message with more {{details}}", "details" = "stuff");
+/// logging_rs::message!(logger, to: "audit", "A message only written to the sink named audit");
+/// ```
+///
+/// # See also
+///
+/// - [`debug!()`]
+/// - [`info!()`]
+/// - [`warn!()`]
+/// - [`error!()`]
+/// - [`fatal!()`]
+/// - [`event!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! message {
+    ($($arguments:tt)+) => {
+        $crate::__log!($crate::Level::MESSAGE, $($arguments)+)
+    };
+}
+
+/// Logs the given message with logging level [`Level::MESSAGE`].
+///
+/// Deprecated because it conflicts with the macro of the `log` crate, use [`message!()`] instead.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with, the [`global`] logger if omitted
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # #![allow(deprecated)]
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::log!(logger, "A message");
+/// ```
+///
+/// # See also
+///
+/// - [`message!()`]
+#[deprecated(since = "1.2.0", note = "use `message!()` instead, `log!()` conflicts with the `log` crate")]
+#[macro_export]
+macro_rules! log {
+    ($($arguments:tt)+) => {
+        $crate::__log!($crate::Level::MESSAGE, $($arguments)+)
+    };
+}
+
+/// Logs the given message with the given logging level.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `level`: The log [`Level`] to use for logging
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::event!(logger, logging_rs::Level::INFO, "A message");
+/// logging_rs::event!(logger, logging_rs::Level::WARN, "A message with more {{details}}", "details" = "stuff");
+/// logging_rs::event!(logger, logging_rs::Level::ERROR, to: "audit", "A message only written to the sink named audit");
+/// ```
+///
+/// # See also
+///
+/// - [`debug!()`]
+/// - [`info!()`]
+/// - [`warn!()`]
+/// - [`error!()`]
+/// - [`fatal!()`]
+/// - [`message!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! event {
+    ($logger:expr, $level:expr, $($arguments:tt)+) => {
+        $crate::__log!($level, $logger, $($arguments)+)
+    };
+}
+
+/// Logs the given message with a logging level computed at runtime.
+///
+/// The level can be any expression converting into a [`Level`] and is evaluated once, before the message.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `level`: The expression computing the log [`Level`]
+/// - `message`: The message to log
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let status: u16 = 503;
+/// logging_rs::log_at!(logger, logging_rs::Level::from_http_status(status), "Request failed with {{status}}", "status" = 503);
+/// ```
+///
+/// # See also
+///
+/// - [`event!()`]
+/// - [`Level::from_http_status()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! log_at {
+    ($logger:expr, $level:expr, $($arguments:tt)+) => {
+        {
+            let level: $crate::Level = ::std::convert::Into::into($level);
+
+            $crate::__log!(level, $logger, $($arguments)+)
+        }
+    };
+}
+
+/// Logs a deprecation warning with logging level [`Level::WARN`] once per call site.
+///
+/// The record carries the stable `deprecation` and `replacement` arguments, so library authors can
+/// signal misuse through the host application's logging without flooding it.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `api`: The deprecated API
+/// - `replacement`: What to use instead
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// for _ in 0..3 {
+///     logging_rs::deprecation!(logger, "old_function()", "use new_function() instead");
+/// }
+/// ```
+///
+/// # See also
+///
+/// - [`warn!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! deprecation {
+    ($logger:expr, $api:expr, $replacement:expr $(,)?) => {
+        {
+            static ONCE: ::std::sync::Once = ::std::sync::Once::new();
+
+            if $crate::__static_enabled!($crate::Level::WARN) {
+                ONCE.call_once(|| {
+                    $logger.log("{{deprecation}} is deprecated, {{replacement}}", $crate::Level::WARN, ::std::panic::Location::caller().file(), ::std::vec![("deprecation", ::std::string::ToString::to_string(&$api)), ("replacement", ::std::string::ToString::to_string(&$replacement))]);
+                });
+            }
+        }
+    };
+}
+
+/// Checks the given condition and panics after logging the failed expression with logging level [`Level::ERROR`].
+///
+/// The panic message is the logged message ``Assertion `<condition>` failed: <message>``, with all placeholders replaced.
+/// The message expression is evaluated once.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `condition`: The condition to check
+/// - `message`: The message to log and panic with
+///
+/// # Panics
+///
+/// Panics if the condition is `false`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let value: i32 = 5;
+/// logging_rs::log_assert!(logger, value > 0, "Value must be positive");
+/// logging_rs::log_assert!(logger, value < 10, "Value must be smaller than {{max}}", "max" = 10);
+/// ```
+///
+/// # See also
+///
+/// - [`log_ensure!()`]
+/// - [`error!()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! log_assert {
+    ($logger:expr, $condition:expr, $message:expr $(, $($fields:tt)*)?) => {
+        {
+            if !$condition {
+                let message = $message;
+                let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![("expression", ::std::string::ToString::to_string(&::std::stringify!($condition)))];
+
+                $crate::__arguments!(arguments; $($($fields)*)?);
+
+                let record: $crate::Record = $crate::Record::new($crate::Level::ERROR, &::std::format!("Assertion `{{{{expression}}}}` failed: {}", message), ::std::panic::Location::caller().file(), arguments);
+                let message: ::std::string::String = record.formatted_message();
+
+                if $crate::__static_enabled!($crate::Level::ERROR) {
+                    $logger.log_record(&record);
+                }
+                ::std::panic!("{}", message);
+            }
+        }
+    };
+}
+
+/// Checks the given condition and returns an error after logging the failed expression with logging level [`Level::ERROR`].
+///
+/// The returned error is an [`errors::Error`] with the logged message ``Assertion `<condition>` failed: <message>`` as
+/// description, all placeholders replaced, converted using [`From`], so it can be used in any function returning a
+/// `Result` whose error type implements `From<errors::Error>`. The message expression is evaluated once.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `condition`: The condition to check
+/// - `message`: The message to log and use as the error description
+///
+/// # Returns
+///
+/// Returns early with `Err` if the condition is `false`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// fn check(logger: &logging_rs::Logger, value: i32) -> Result<i32, logging_rs::errors::Error> {
+///     logging_rs::log_ensure!(logger, value > 0, "Value must be positive");
+///     Ok(value)
+/// }
+///
+/// assert!(check(&logger, 5).is_ok());
+/// ```
+///
+/// # See also
+///
+/// - [`log_assert!()`]
+/// - [`error!()`]
+/// - [`Logger`]
+/// - [`errors::Error`]
+#[macro_export]
+macro_rules! log_ensure {
+    ($logger:expr, $condition:expr, $message:expr $(, $($fields:tt)*)?) => {
+        {
+            if !$condition {
+                let message = $message;
+                let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![("expression", ::std::string::ToString::to_string(&::std::stringify!($condition)))];
+
+                $crate::__arguments!(arguments; $($($fields)*)?);
+
+                let record: $crate::Record = $crate::Record::new($crate::Level::ERROR, &::std::format!("Assertion `{{{{expression}}}}` failed: {}", message), ::std::panic::Location::caller().file(), arguments);
+                let message: ::std::string::String = record.formatted_message();
+
+                if $crate::__static_enabled!($crate::Level::ERROR) {
+                    $logger.log_record(&record);
+                }
+                return ::std::result::Result::Err(::std::convert::From::from($crate::errors::Error::new("Assertion error", &message, 3)));
+            }
+        }
+    };
+}
+
+/// Logs the given message with logging level [`Level::ERROR`] and returns it as error.
+///
+/// The returned error is an [`errors::Error`] with the message as description, all placeholders replaced, converted
+/// using [`From`]. The message is only written once, for both the log and the error.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `message`: The message to log and use as the error description
+///
+/// # Returns
+///
+/// Returns early with `Err`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// fn withdraw(logger: &logging_rs::Logger, balance: u64, amount: u64) -> Result<u64, logging_rs::errors::Error> {
+///     if amount > balance {
+///         logging_rs::bail_logged!(logger, "Can not withdraw {{amount}} from {{balance}}", amount = amount, balance = balance);
+///     }
+///
+///     Ok(balance - amount)
+/// }
+///
+/// assert_eq!(withdraw(&logger, 10, 20).unwrap_err().description, "Can not withdraw 20 from 10");
+/// ```
+///
+/// # See also
+///
+/// - [`return_logged!()`]
+/// - [`log_ensure!()`]
+/// - [`errors::Error`]
+#[macro_export]
+macro_rules! bail_logged {
+    ($logger:expr, $message:expr $(, $($fields:tt)*)?) => {
+        {
+            #[allow(unused_mut)]
+            let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![];
+
+            $crate::__arguments!(arguments; $($($fields)*)?);
+
+            let record: $crate::Record = $crate::Record::new($crate::Level::ERROR, $message, ::std::panic::Location::caller().file(), arguments);
+
+            if $crate::__static_enabled!($crate::Level::ERROR) {
+                $logger.log_record(&record);
+            }
+            return ::std::result::Result::Err(::std::convert::From::from($crate::errors::Error::new("Logged error", &record.formatted_message(), 8)));
+        }
+    };
+}
+
+/// Logs the given message with logging level [`Level::ERROR`] and returns the given error.
+///
+/// The error is added to the record as `error` argument using [`std::fmt::Display`] and returned converted using
+/// [`From`], like the `?` operator does.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `error`: The error to log and return
+/// - `message`: The message to log
+///
+/// # Returns
+///
+/// Returns early with `Err`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// fn port(logger: &logging_rs::Logger, value: &str) -> Result<u16, std::num::ParseIntError> {
+///     match value.parse() {
+///         Ok(port) => Ok(port),
+///         Err(error) => logging_rs::return_logged!(logger, error, "Invalid port {{value}}: {{error}}", value = value)
+///     }
+/// }
+///
+/// assert!(port(&logger, "http").is_err());
+/// ```
+///
+/// # See also
+///
+/// - [`bail_logged!()`]
+/// - [`Logger::log_error()`]
+#[macro_export]
+macro_rules! return_logged {
+    ($logger:expr, $error:expr, $message:expr $(, $($fields:tt)*)?) => {
+        {
+            let error = $error;
+            #[allow(unused_mut)]
+            let mut arguments: ::std::vec::Vec<(&str, ::std::string::String)> = ::std::vec![("error", ::std::string::ToString::to_string(&error))];
+
+            $crate::__arguments!(arguments; $($($fields)*)?);
+
+            if $crate::__static_enabled!($crate::Level::ERROR) {
+                $logger.log($message, $crate::Level::ERROR, ::std::panic::Location::caller().file(), arguments);
+            }
+            return ::std::result::Result::Err(::std::convert::From::from(error));
+        }
+    };
+}
+
+/// Logs a classic hex dump (offset, hex bytes and ASCII) of the given bytes as multiline message.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with
+/// - `level`: The log [`Level`] to use for logging
+/// - `data`: The bytes to dump
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let bytes: Vec<u8> = vec![0xde, 0xad, 0xbe, 0xef];
+/// logging_rs::hexdump!(logger, logging_rs::Level::DEBUG, &bytes[..]);
+/// ```
+///
+/// # See also
+///
+/// - [`attachments::hexdump()`]
+/// - [`Logger`]
+#[macro_export]
+macro_rules! hexdump {
+    ($logger:expr, $level:expr, $data:expr $(,)?) => {
+        {
+            let data: &[u8] = $data;
+            let level: $crate::Level = $level;
+
+            if $crate::__static_enabled!(level) {
+                $logger.log("Hex dump of {{size}} bytes:\n{{hexdump}}", level, ::std::panic::Location::caller().file(), ::std::vec![("size", ::std::string::ToString::to_string(&data.len())), ("hexdump", $crate::attachments::hexdump(data))]);
+            }
+        }
+    };
+}
+
+/// Enters a [`span::Span`] logging scope enter and exit, exited when the returned guard is dropped.
+///
+/// # Parameters
+///
+/// - `logger`: The logger object to log with, the [`global`] logger if omitted
+/// - `name`: The name of the span
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let _span: logging_rs::span::Span = logging_rs::span!(logger, "request");
+/// logging_rs::info!(logger, "Inside of {{span}}");
+/// ```
+///
+/// # See also
+///
+/// - [`span::Span`]
+/// - [`span::current()`]
+#[macro_export]
+macro_rules! span {
+    ($name:expr $(,)?) => {
+        $crate::span::Span::global($name)
+    };
+    ($logger:expr, $name:expr $(,)?) => {
+        $crate::span::Span::new(&$logger, $name)
+    };
+}
+
+/// Creates a [`build::BuildInfo`] object from the values embedded by [`build::emit()`].
+///
+/// Values not embedded by a build script are `unknown`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let info: logging_rs::build::BuildInfo = logging_rs::build_info!();
+/// ```
+///
+/// # See also
+///
+/// - [`build::emit()`]
+/// - [`build::set_build_info()`]
+#[macro_export]
+macro_rules! build_info {
+    () => {
+        $crate::build::BuildInfo::new(
+            ::std::option_env!("LOGGING_RS_BUILD_SHA").unwrap_or("unknown"),
+            ::std::option_env!("LOGGING_RS_BUILD_TIME").unwrap_or("unknown"),
+            ::std::option_env!("LOGGING_RS_BUILD_PROFILE").unwrap_or("unknown")
+        )
+    };
+}
@@ -0,0 +1,361 @@
+#![doc = include_str!("../../.github/maintenance.md")]
+// logging-rs maintenance
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::{errors, sandbox};
+
+
+//////////
+// TASK //
+//////////
+
+/// Maintenance task object.
+///
+/// Tasks are created by [`Maintenance::add_task()`] and executed on every tick.
+struct Task {
+    /// The tasks name.
+    name: String,
+    /// The function to run.
+    function: Box<dyn FnMut() + Send>,
+}
+
+
+/////////////////
+// MAINTENANCE //
+/////////////////
+
+/// Maintenance worker object.
+///
+/// Runs deferred work like retention cleanup, spool compaction or stats aggregation on a
+/// background thread, so the work never happens inside a log call.
+///
+/// Use [`Maintenance::new()`] to create maintenance objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::new(std::time::Duration::from_secs(60));
+/// maintenance.add_task("cleanup", || {});
+/// maintenance.start();
+/// maintenance.stop();
+/// ```
+pub struct Maintenance {
+    /// The time between two ticks.
+    interval: Duration,
+    /// The registered tasks.
+    tasks: Arc<Mutex<Vec<Task>>>,
+    /// Sender used to stop the background worker.
+    stop: Option<mpsc::Sender<()>>,
+    /// The background worker.
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for Maintenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Maintenance")
+            .field("interval", &self.interval)
+            .field("tasks", &self.task_names())
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+impl Default for Maintenance {
+    fn default() -> Maintenance {
+        Maintenance::new(Duration::from_secs(60))
+    }
+}
+
+impl Maintenance {
+    /// Creates a new maintenance object.
+    ///
+    /// # Parameters
+    ///
+    /// - `interval`: The time between two ticks
+    ///
+    /// # Returns
+    ///
+    /// A new `Maintenance` object without any tasks. The background worker is not started yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::maintenance::Maintenance::new(std::time::Duration::from_secs(60));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Maintenance`]
+    pub fn new(interval: Duration) -> Maintenance {
+        Maintenance {
+            interval,
+            tasks: Arc::new(Mutex::new(vec![])),
+            stop: None,
+            worker: None,
+        }
+    }
+
+    /// Registers a new task which is executed on every tick.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The maintenance object
+    /// - `name`: The tasks name
+    /// - `task`: The function to run
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+    /// maintenance.add_task("stats", || println!("Aggregating stats"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Maintenance`]
+    /// - [`Maintenance::tick()`]
+    pub fn add_task<F: FnMut() + Send + 'static>(&self, name: &str, task: F) {
+        lock(&self.tasks).push(Task {
+            name: name.to_owned(),
+            function: Box::new(task),
+        });
+    }
+
+    /// Returns the names of all registered tasks in the order they are executed.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The maintenance object
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the task names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+    /// maintenance.add_task("cleanup", || {});
+    /// assert_eq!(maintenance.task_names(), vec!["cleanup".to_owned()]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Maintenance`]
+    pub fn task_names(&self) -> Vec<String> {
+        lock(&self.tasks).iter().map(|task| task.name.clone()).collect()
+    }
+
+    /// Runs every registered task once on the calling thread.
+    ///
+    /// Panicking tasks are reported like panicking sinks, see [`crate::Logger`], and do not stop the other tasks.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The maintenance object
+    ///
+    /// # Returns
+    ///
+    /// The number of tasks that were run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+    /// maintenance.add_task("cleanup", || {});
+    /// assert_eq!(maintenance.tick(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Maintenance`]
+    /// - [`Maintenance::start()`]
+    pub fn tick(&self) -> usize {
+        run_tasks(&self.tasks)
+    }
+
+    /// Starts the background worker, ticking once every interval.
+    ///
+    /// Does nothing if the worker is already running or [`crate::sandbox`] mode is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The maintenance object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+    /// maintenance.start();
+    /// # maintenance.stop();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Maintenance`]
+    /// - [`Maintenance::stop()`]
+    pub fn start(&mut self) {
+        if self.is_running() || sandbox::is_enabled() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel::<()>();
+        let interval: Duration = self.interval;
+        let tasks: Arc<Mutex<Vec<Task>>> = Arc::clone(&self.tasks);
+
+        self.worker = Some(thread::spawn(move || {
+            while let Err(mpsc::RecvTimeoutError::Timeout) = receiver.recv_timeout(interval) {
+                run_tasks(&tasks);
+            }
+        }));
+        self.stop = Some(sender);
+    }
+
+    /// Stops the background worker and waits for it to finish its current tick.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The maintenance object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+    /// # maintenance.start();
+    /// maintenance.stop();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Maintenance`]
+    /// - [`Maintenance::start()`]
+    pub fn stop(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+
+    /// Restarts the background worker in the child process after forking.
+    ///
+    /// Only the forking thread exists in the child, so the worker of the parent is forgotten without joining it and
+    /// a new worker is started if it was running. Tasks must not be running while forking, as their lock can not be
+    /// released in the child.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The maintenance object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+    /// // In the child process, right after forking
+    /// maintenance.after_fork();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Maintenance`]
+    /// - [`crate::Logger::after_fork()`]
+    pub fn after_fork(&mut self) {
+        self.stop = None;
+
+        if let Some(worker) = self.worker.take() {
+            std::mem::forget(worker);
+            self.start();
+        }
+    }
+
+    /// Checks whether the background worker is running.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The maintenance object
+    ///
+    /// # Returns
+    ///
+    /// `true` if the worker was started and not stopped yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+    /// assert!(!maintenance.is_running());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Maintenance`]
+    pub fn is_running(&self) -> bool {
+        self.worker.is_some()
+    }
+}
+
+impl Drop for Maintenance {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Locks the given mutex, ignoring poisoning caused by a panicking task.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// Runs all given tasks once and returns the number of tasks run. Panicking tasks are reported as internal errors.
+fn run_tasks(tasks: &Mutex<Vec<Task>>) -> usize {
+    let mut tasks = lock(tasks);
+
+    for task in tasks.iter_mut() {
+        let name: &str = &task.name;
+        errors::guard_with(|| errors::Error::new("Maintenance error", &format!("The maintenance task `{}` panicked", name), 13), &mut task.function);
+    }
+
+    tasks.len()
+}
@@ -0,0 +1,343 @@
+#![doc = include_str!("../../.github/memory.md")]
+// logging-rs memory
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+
+/////////////
+// GLOBALS //
+/////////////
+
+/// The budget shared by all in-memory buffers.
+static GLOBAL: Budget = Budget::new(usize::MAX);
+
+
+//////////////////
+// MEMORY STATS //
+//////////////////
+
+/// Memory usage statistics of a budget.
+///
+/// # Parameters
+///
+/// - `used`: The number of currently reserved bytes
+/// - `peak`: The highest number of reserved bytes
+/// - `limit`: The maximum number of reserved bytes
+/// - `rejected`: The number of rejected reservations
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::memory::MemoryStats {
+///     used: 512,
+///     peak: 2048,
+///     limit: 4096,
+///     rejected: 0
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryStats {
+    /// The number of currently reserved bytes
+    pub used: usize,
+    /// The highest number of reserved bytes
+    pub peak: usize,
+    /// The maximum number of reserved bytes
+    pub limit: usize,
+    /// The number of rejected reservations
+    pub rejected: usize,
+}
+
+
+////////////
+// BUDGET //
+////////////
+
+/// Memory budget object.
+///
+/// Buffers reserve the approximate size of every held record (see [`crate::Record::memory_size()`]) before storing
+/// it and release it once the record is written or discarded. Every reservation is also made in the [`global()`]
+/// budget, so both the budget of the buffer and the process-wide limit are enforced.
+///
+/// Use [`Budget::new()`] to create budget objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let budget: logging_rs::memory::Budget = logging_rs::memory::Budget::new(1024);
+///
+/// assert!(budget.reserve(1000));
+/// assert!(!budget.reserve(100));
+/// budget.release(1000);
+/// ```
+///
+/// # See also
+///
+/// - [`global()`]
+/// - [`crate::deferred::DeferredDebug::with_memory_limit()`]
+/// - [`crate::transaction::Transaction::with_memory_limit()`]
+/// - [`crate::sinks::Coalesced::with_memory_limit()`]
+#[derive(Debug)]
+pub struct Budget {
+    /// The maximum number of reserved bytes.
+    limit: AtomicUsize,
+    /// The number of currently reserved bytes.
+    used: AtomicUsize,
+    /// The highest number of reserved bytes.
+    peak: AtomicUsize,
+    /// The number of rejected reservations.
+    rejected: AtomicUsize,
+}
+
+impl Budget {
+    /// Creates a new budget object.
+    ///
+    /// # Parameters
+    ///
+    /// - `limit`: The maximum number of reserved bytes. Use `usize::MAX` for no limit
+    ///
+    /// # Returns
+    ///
+    /// A new `Budget` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::memory::Budget::new(16 * 1024 * 1024);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Budget`]
+    pub const fn new(limit: usize) -> Budget {
+        Budget {
+            limit: AtomicUsize::new(limit),
+            used: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            rejected: AtomicUsize::new(0)
+        }
+    }
+
+    /// Sets the maximum number of reserved bytes. Already reserved bytes are kept.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The budget object
+    /// - `limit`: The maximum number of reserved bytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let budget: logging_rs::memory::Budget = logging_rs::memory::Budget::default();
+    /// budget.set_limit(4096);
+    /// assert_eq!(budget.stats().limit, 4096);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Budget`]
+    pub fn set_limit(&self, limit: usize) {
+        self.limit.store(limit, Ordering::Relaxed);
+    }
+
+    /// Reserves the given number of bytes in the budget and the [`global()`] budget.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The budget object
+    /// - `bytes`: The number of bytes to reserve
+    ///
+    /// # Returns
+    ///
+    /// Whether the bytes were reserved. Rejected reservations are counted in both budgets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let budget: logging_rs::memory::Budget = logging_rs::memory::Budget::new(100);
+    ///
+    /// assert!(budget.reserve(60));
+    /// assert!(!budget.reserve(60));
+    /// assert_eq!(budget.stats().rejected, 1);
+    /// # budget.release(60);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Budget::release()`]
+    pub fn reserve(&self, bytes: usize) -> bool {
+        if !self.reserve_local(bytes) {
+            return false;
+        }
+
+        if !std::ptr::eq(self, &GLOBAL) && !GLOBAL.reserve_local(bytes) {
+            self.used.fetch_sub(bytes, Ordering::Relaxed);
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
+        true
+    }
+
+    /// Releases the given number of previously reserved bytes.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The budget object
+    /// - `bytes`: The number of bytes to release
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let budget: logging_rs::memory::Budget = logging_rs::memory::Budget::new(100);
+    ///
+    /// budget.reserve(60);
+    /// budget.release(60);
+    /// assert_eq!(budget.stats().used, 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Budget::reserve()`]
+    pub fn release(&self, bytes: usize) {
+        let _ = self.used.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| Some(used.saturating_sub(bytes)));
+
+        if !std::ptr::eq(self, &GLOBAL) {
+            GLOBAL.release(bytes);
+        }
+    }
+
+    /// Returns the memory usage statistics of the budget.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The budget object
+    ///
+    /// # Returns
+    ///
+    /// The current [`MemoryStats`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let budget: logging_rs::memory::Budget = logging_rs::memory::Budget::new(100);
+    /// assert_eq!(budget.stats().used, 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`MemoryStats`]
+    pub fn stats(&self) -> MemoryStats {
+        MemoryStats {
+            used: self.used.load(Ordering::Relaxed),
+            peak: self.peak.load(Ordering::Relaxed),
+            limit: self.limit.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed)
+        }
+    }
+
+    /// Reserves the given number of bytes in this budget only.
+    fn reserve_local(&self, bytes: usize) -> bool {
+        let limit: usize = self.limit.load(Ordering::Relaxed);
+        let reserved = self.used.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+            used.checked_add(bytes).filter(|total| *total <= limit)
+        });
+
+        match reserved {
+            Ok(used) => {
+                self.peak.fetch_max(used + bytes, Ordering::Relaxed);
+                true
+            },
+            Err(_) => {
+                self.rejected.fetch_add(1, Ordering::Relaxed);
+                false
+            }
+        }
+    }
+}
+
+impl Default for Budget {
+    fn default() -> Budget {
+        Budget::new(usize::MAX)
+    }
+}
+
+
+///////////////////
+// GLOBAL BUDGET //
+///////////////////
+
+/// Returns the budget shared by all in-memory buffers of the process.
+///
+/// The global budget is unlimited by default.
+///
+/// # Returns
+///
+/// The global [`Budget`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let stats: logging_rs::memory::MemoryStats = logging_rs::memory::global().stats();
+/// println!("Buffered log records use {} bytes", stats.used);
+/// ```
+///
+/// # See also
+///
+/// - [`set_global_limit()`]
+pub fn global() -> &'static Budget {
+    &GLOBAL
+}
+
+/// Sets the maximum number of bytes all in-memory buffers of the process may reserve together.
+///
+/// # Parameters
+///
+/// - `limit`: The maximum number of reserved bytes. Use `usize::MAX` for no limit
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::memory::set_global_limit(64 * 1024 * 1024);
+/// ```
+///
+/// # See also
+///
+/// - [`global()`]
+pub fn set_global_limit(limit: usize) {
+    GLOBAL.set_limit(limit);
+}
@@ -0,0 +1,223 @@
+#![doc = include_str!("../../.github/metrics.md")]
+
+// logging-rs metrics
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::{Level, Logger, Record};
+
+
+//////////
+// KIND //
+//////////
+
+/// Kinds of metrics, available as `kind` argument of metric records.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::metrics::Kind::COUNTER.name(), "counter");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Kind {
+    /// Counter, the value is added to the total
+    COUNTER,
+    /// Gauge, the value replaces the previous value
+    GAUGE,
+}
+
+impl Kind {
+    /// Returns the name of the kind.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The kind object
+    ///
+    /// # Returns
+    ///
+    /// `counter` or `gauge`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::metrics::Kind::GAUGE.name(), "gauge");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Kind`]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Kind::COUNTER => "counter",
+            Kind::GAUGE => "gauge"
+        }
+    }
+}
+
+
+////////////
+// METRIC //
+////////////
+
+/// Metric read from a metric record.
+///
+/// # Parameters
+///
+/// - `kind`: The [`Kind`] of the metric
+/// - `name`: The name of the metric
+/// - `value`: The value of the metric
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::metrics::Metric {
+///     kind: logging_rs::metrics::Kind::COUNTER,
+///     name: "cache_miss".to_owned(),
+///     value: 1.0
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct Metric {
+    /// The kind of the metric
+    pub kind: Kind,
+    /// The name of the metric
+    pub name: String,
+    /// The value of the metric
+    pub value: f64,
+}
+
+impl Metric {
+    /// Reads the metric from the given record, for example to aggregate metrics in a sink.
+    ///
+    /// # Parameters
+    ///
+    /// - `record`: The record
+    ///
+    /// # Returns
+    ///
+    /// The [`Metric`] if the record was created using [`Logger::count()`] or [`Logger::gauge()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/main.rs", vec![]);
+    /// assert_eq!(logging_rs::metrics::Metric::from_record(&record), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Metric`]
+    pub fn from_record(record: &Record) -> Option<Metric> {
+        let kind: Kind = match record.argument("kind")? {
+            "counter" => Kind::COUNTER,
+            "gauge" => Kind::GAUGE,
+            _ => return None
+        };
+
+        Some(Metric {
+            kind,
+            name: record.argument("metric")?.to_owned(),
+            value: record.argument("value")?.parse().ok()?
+        })
+    }
+}
+
+
+////////////
+// LOGGER //
+////////////
+
+impl Logger {
+    /// Logs a counter metric record, letting applications ship basic metrics alongside their logs.
+    ///
+    /// The record has the level [`Level::INFO`], the message `<name> += <value>` and the arguments `kind` (`counter`),
+    /// `metric` and `value`, so log pipelines can tell metrics apart from other records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `name`: The name of the counter
+    /// - `value`: The value added to the counter
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.count("cache_miss", 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::gauge()`]
+    /// - [`Metric::from_record()`]
+    #[track_caller]
+    pub fn count(&self, name: &str, value: u64) {
+        self.metric(Kind::COUNTER, "{{metric}} += {{value}}", name, value.to_string());
+    }
+
+    /// Logs a gauge metric record, letting applications ship basic metrics alongside their logs.
+    ///
+    /// The record has the level [`Level::INFO`], the message `<name> = <value>` and the arguments `kind` (`gauge`),
+    /// `metric` and `value`, so log pipelines can tell metrics apart from other records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `name`: The name of the gauge
+    /// - `value`: The current value of the gauge
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.gauge("queue_length", 42.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::count()`]
+    /// - [`Metric::from_record()`]
+    #[track_caller]
+    pub fn gauge(&self, name: &str, value: f64) {
+        self.metric(Kind::GAUGE, "{{metric}} = {{value}}", name, value.to_string());
+    }
+
+    /// Logs a metric record of the given kind.
+    #[track_caller]
+    fn metric(&self, kind: Kind, message: &str, name: &str, value: String) {
+        if !self.is_enabled(Level::INFO) {
+            return;
+        }
+
+        self.log(message, Level::INFO, std::panic::Location::caller().file(), vec![("kind", kind.name().to_owned()), ("metric", name.to_owned()), ("value", value)]);
+    }
+}
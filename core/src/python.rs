@@ -0,0 +1,271 @@
+#![doc = include_str!("../../.github/python.md")]
+// logging-rs python
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::{Formatter, Level, Logger, Output, Record};
+
+
+////////////
+// LEVELS //
+////////////
+
+/// Python version of [`Level`].
+#[pyclass(name = "Level", eq, eq_int, module = "logging_rs")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum PyLevel {
+    DEBUG,
+    INFO,
+    WARN,
+    ERROR,
+    FATAL,
+    MESSAGE
+}
+
+impl From<PyLevel> for Level {
+    fn from(level: PyLevel) -> Level {
+        match level {
+            PyLevel::DEBUG => Level::DEBUG,
+            PyLevel::INFO => Level::INFO,
+            PyLevel::WARN => Level::WARN,
+            PyLevel::ERROR => Level::ERROR,
+            PyLevel::FATAL => Level::FATAL,
+            PyLevel::MESSAGE => Level::MESSAGE
+        }
+    }
+}
+
+/// Custom levels are converted to the highest built-in level their severity reaches.
+impl From<Level> for PyLevel {
+    fn from(level: Level) -> PyLevel {
+        match level.severity() {
+            0..=19 => PyLevel::DEBUG,
+            20..=29 => PyLevel::INFO,
+            30..=39 => PyLevel::WARN,
+            40..=49 => PyLevel::ERROR,
+            50..=59 => PyLevel::FATAL,
+            60.. => PyLevel::MESSAGE
+        }
+    }
+}
+
+
+///////////////
+// FORMATTER //
+///////////////
+
+/// Python version of [`Formatter`].
+///
+/// All arguments are optional and default to the values of [`Formatter::default()`].
+#[pyclass(name = "Formatter", module = "logging_rs")]
+#[derive(Clone, Debug)]
+pub struct PyFormatter {
+    /// The wrapped formatter.
+    pub formatter: Formatter,
+}
+
+#[pymethods]
+impl PyFormatter {
+    #[new]
+    #[pyo3(signature = (color_format_string=None, format_string=None, timestamp_format=None))]
+    fn new(color_format_string: Option<&str>, format_string: Option<&str>, timestamp_format: Option<&str>) -> PyFormatter {
+        let default: Formatter = Formatter::default();
+
+        PyFormatter {
+            formatter: Formatter::new(
+                color_format_string.unwrap_or(&default.color_format_string),
+                format_string.unwrap_or(&default.format_string),
+                timestamp_format.unwrap_or(&default.timestamp_format)
+            )
+        }
+    }
+
+    #[getter]
+    fn color_format_string(&self) -> String {
+        self.formatter.color_format_string.to_string()
+    }
+
+    #[getter]
+    fn format_string(&self) -> String {
+        self.formatter.format_string.to_string()
+    }
+
+    #[getter]
+    fn timestamp_format(&self) -> String {
+        self.formatter.timestamp_format.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Formatter({:?}, {:?}, {:?})", self.formatter.color_format_string, self.formatter.format_string, self.formatter.timestamp_format)
+    }
+}
+
+
+////////////
+// LOGGER //
+////////////
+
+/// Python version of [`Logger`].
+///
+/// Logs to stdout at the [`Level::DEBUG`] level by default. The keyword arguments of the logging methods are converted
+/// using `str()` and used as formatting arguments. The path defaults to the file of the calling Python code.
+#[pyclass(name = "Logger", module = "logging_rs")]
+#[derive(Clone, Debug)]
+pub struct PyLogger {
+    /// The wrapped logger.
+    pub logger: Logger,
+}
+
+impl PyLogger {
+    /// Logs the given message with the given level.
+    fn log_with(&self, py: Python<'_>, level: Level, message: &str, path: Option<&str>, arguments: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        let path: String = match path {
+            Some(path) => path.to_owned(),
+            None => py.import("sys")?.call_method1("_getframe", (0,))?.getattr("f_code")?.getattr("co_filename")?.extract()?
+        };
+
+        let mut record: Record = Record::new(level, message, &path, vec![]);
+
+        if let Some(arguments) = arguments {
+            for (key, value) in arguments {
+                record.arguments.push((key.str()?.to_string(), value.str()?.to_string()));
+            }
+        }
+
+        py.allow_threads(|| self.logger.log_record(&record));
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PyLogger {
+    #[new]
+    #[pyo3(signature = (formatter=None, stdout=true, stderr=false, files=vec![], level=PyLevel::DEBUG))]
+    fn new(formatter: Option<PyFormatter>, stdout: bool, stderr: bool, files: Vec<String>, level: PyLevel) -> PyLogger {
+        let mut writable_list: Vec<Output> = vec![];
+
+        if stdout {
+            writable_list.push(Output::STDOUT);
+        }
+
+        if stderr {
+            writable_list.push(Output::STDERR);
+        }
+
+        writable_list.extend(files.into_iter().map(|path| Output::FILE { path }));
+
+        PyLogger {
+            logger: Logger::new(formatter.map(|formatter| formatter.formatter).unwrap_or_default(), writable_list).with_level(level.into())
+        }
+    }
+
+    /// Adds a file output.
+    fn add_file(&mut self, path: String) {
+        self.logger.writable_list.push(Output::FILE { path });
+    }
+
+    #[pyo3(signature = (message, level, path=None, **arguments))]
+    fn log(&self, py: Python<'_>, message: &str, level: PyLevel, path: Option<&str>, arguments: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        self.log_with(py, level.into(), message, path, arguments)
+    }
+
+    #[pyo3(signature = (message, path=None, **arguments))]
+    fn debug(&self, py: Python<'_>, message: &str, path: Option<&str>, arguments: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        self.log_with(py, Level::DEBUG, message, path, arguments)
+    }
+
+    #[pyo3(signature = (message, path=None, **arguments))]
+    fn info(&self, py: Python<'_>, message: &str, path: Option<&str>, arguments: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        self.log_with(py, Level::INFO, message, path, arguments)
+    }
+
+    #[pyo3(signature = (message, path=None, **arguments))]
+    fn warn(&self, py: Python<'_>, message: &str, path: Option<&str>, arguments: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        self.log_with(py, Level::WARN, message, path, arguments)
+    }
+
+    #[pyo3(signature = (message, path=None, **arguments))]
+    fn error(&self, py: Python<'_>, message: &str, path: Option<&str>, arguments: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        self.log_with(py, Level::ERROR, message, path, arguments)
+    }
+
+    #[pyo3(signature = (message, path=None, **arguments))]
+    fn fatal(&self, py: Python<'_>, message: &str, path: Option<&str>, arguments: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        self.log_with(py, Level::FATAL, message, path, arguments)
+    }
+
+    #[pyo3(signature = (message, path=None, **arguments))]
+    fn message(&self, py: Python<'_>, message: &str, path: Option<&str>, arguments: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        self.log_with(py, Level::MESSAGE, message, path, arguments)
+    }
+
+    #[getter]
+    fn formatter(&self) -> PyFormatter {
+        PyFormatter { formatter: self.logger.formatter.clone() }
+    }
+
+    #[setter]
+    fn set_formatter(&mut self, formatter: PyFormatter) {
+        self.logger.formatter = formatter.formatter;
+    }
+
+    #[getter]
+    fn level(&self) -> PyLevel {
+        self.logger.level.into()
+    }
+
+    #[setter]
+    fn set_level(&mut self, level: PyLevel) {
+        self.logger.level = level.into();
+    }
+}
+
+
+////////////
+// MODULE //
+////////////
+
+/// The `logging_rs` Python module.
+///
+/// # Parameters
+///
+/// - `module`: The module to add the classes to
+///
+/// # Returns
+///
+/// An error if adding the classes failed.
+#[pymodule]
+pub fn logging_rs(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyLevel>()?;
+    module.add_class::<PyFormatter>()?;
+    module.add_class::<PyLogger>()?;
+
+    Ok(())
+}
@@ -0,0 +1,267 @@
+#![doc = include_str!("../../.github/random.md")]
+// logging-rs random
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Increment of the SplitMix64 state per generated number.
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+
+/////////////
+// SOURCES //
+/////////////
+
+/// Trait for sources of random numbers.
+///
+/// Random numbers are used for generating record ids, see [`crate::ids`]. Implement this trait to use a hardware
+/// random number generator, or use [`SeededRandom`] for deterministic tests. Closures returning `u64` implement this
+/// trait too.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// struct Hardware;
+///
+/// impl logging_rs::random::RandomSource for Hardware {
+///     fn next_u64(&self) -> u64 {
+///         4 // Read from the hardware random number generator
+///     }
+/// }
+///
+/// logging_rs::random::set_source(Hardware);
+/// # logging_rs::random::reset_source();
+/// ```
+///
+/// # See also
+///
+/// - [`set_source()`]
+/// - [`SeededRandom`]
+/// - [`SystemRandom`]
+pub trait RandomSource: Send + Sync {
+    /// Returns 64 random bits.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The source object
+    ///
+    /// # Returns
+    ///
+    /// The random bits.
+    fn next_u64(&self) -> u64;
+}
+
+impl<F: Fn() -> u64 + Send + Sync> RandomSource for F {
+    fn next_u64(&self) -> u64 {
+        self()
+    }
+}
+
+/// Source using the randomly seeded hasher of the standard library.
+///
+/// This is the default source. The hasher is mixed with a counter so consecutive calls differ and the process id so
+/// forked processes sharing the seed and counter differ.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// use logging_rs::random::RandomSource;
+///
+/// assert_ne!(logging_rs::random::SystemRandom.next_u64(), logging_rs::random::SystemRandom.next_u64());
+/// ```
+///
+/// # See also
+///
+/// - [`RandomSource`]
+/// - [`SeededRandom`]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SystemRandom;
+
+impl RandomSource for SystemRandom {
+    fn next_u64(&self) -> u64 {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+        hasher.write_u32(std::process::id());
+        hasher.finish()
+    }
+}
+
+/// Source generating a deterministic sequence of pseudo random numbers from a seed using SplitMix64.
+///
+/// # Parameters
+///
+/// - `state`: The current state of the generator
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// use logging_rs::random::RandomSource;
+///
+/// assert_eq!(logging_rs::random::SeededRandom::new(42).next_u64(), logging_rs::random::SeededRandom::new(42).next_u64());
+/// ```
+///
+/// # See also
+///
+/// - [`RandomSource`]
+/// - [`SystemRandom`]
+#[derive(Debug, Default)]
+pub struct SeededRandom {
+    /// The current state of the generator
+    pub state: AtomicU64,
+}
+
+impl SeededRandom {
+    /// Creates a new seeded source.
+    ///
+    /// # Parameters
+    ///
+    /// - `seed`: The seed, the same seed always generates the same numbers
+    ///
+    /// # Returns
+    ///
+    /// A new `SeededRandom` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::random::SeededRandom::new(42);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`SeededRandom`]
+    pub fn new(seed: u64) -> SeededRandom {
+        SeededRandom { state: AtomicU64::new(seed) }
+    }
+}
+
+impl RandomSource for SeededRandom {
+    fn next_u64(&self) -> u64 {
+        split_mix(self.state.fetch_add(GOLDEN_GAMMA, Ordering::Relaxed).wrapping_add(GOLDEN_GAMMA))
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// The currently used source, [`SystemRandom`] if unset.
+static SOURCE: RwLock<Option<Arc<dyn RandomSource>>> = RwLock::new(None);
+
+/// Sets the source of random numbers.
+///
+/// # Parameters
+///
+/// - `source`: The [`RandomSource`] to use
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::random::set_source(logging_rs::random::SeededRandom::new(42));
+/// # logging_rs::random::reset_source();
+/// ```
+///
+/// # See also
+///
+/// - [`reset_source()`]
+/// - [`next_u64()`]
+pub fn set_source<S: RandomSource + 'static>(source: S) {
+    *SOURCE.write().unwrap_or_else(|error| error.into_inner()) = Some(Arc::new(source));
+}
+
+/// Resets the source to the default [`SystemRandom`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::random::reset_source();
+/// ```
+///
+/// # See also
+///
+/// - [`set_source()`]
+pub fn reset_source() {
+    *SOURCE.write().unwrap_or_else(|error| error.into_inner()) = None;
+}
+
+/// Returns 64 random bits from the current source.
+///
+/// # Returns
+///
+/// The random bits.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let value: u64 = logging_rs::random::next_u64();
+/// ```
+///
+/// # See also
+///
+/// - [`set_source()`]
+/// - [`RandomSource`]
+pub fn next_u64() -> u64 {
+    let source: Option<Arc<dyn RandomSource>> = SOURCE.read().unwrap_or_else(|error| error.into_inner()).clone();
+
+    match source {
+        Some(source) => source.next_u64(),
+        None => SystemRandom.next_u64()
+    }
+}
+
+/// Advances the given SplitMix64 state and returns the next pseudo random number.
+#[cfg(feature = "testutil")]
+pub(crate) fn next_split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(GOLDEN_GAMMA);
+    split_mix(*state)
+}
+
+/// Mixes the given SplitMix64 state into a pseudo random number.
+fn split_mix(state: u64) -> u64 {
+    let mut value: u64 = state;
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
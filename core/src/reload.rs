@@ -0,0 +1,620 @@
+#![doc = include_str!("../../.github/reload.md")]
+// logging-rs reload
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+use std::sync::{mpsc, Mutex};
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+use std::thread;
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+use std::time::{Duration, SystemTime};
+
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+use crate::{errors, sandbox};
+use crate::{Level, Logger, Record};
+
+
+////////////////
+// RELOADABLE //
+////////////////
+
+/// Logger whose configuration can be replaced while it is in use.
+///
+/// Replacing the logger swaps its levels, filters, outputs, sinks and formatter at once: every record is logged
+/// either entirely by the previous or entirely by the new logger. Clones share the logger, so a clone can be kept to
+/// replace it later. Reloadable loggers can be used with the logging macros like loggers.
+///
+/// Use [`Reloadable::new()`] to create reloadable logger objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::new(logging_rs::Logger::default().with_level(logging_rs::Level::INFO));
+/// logging_rs::debug!(logger, "Skipped");
+///
+/// logger.replace(logging_rs::Logger::default().with_level(logging_rs::Level::DEBUG));
+/// logging_rs::debug!(logger, "Logged");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Reloadable {
+    /// The current logger.
+    logger: Arc<RwLock<Arc<Logger>>>,
+    /// The number of times the logger was replaced.
+    generation: Arc<AtomicU64>,
+}
+
+impl Default for Reloadable {
+    fn default() -> Reloadable {
+        Reloadable::new(Logger::default())
+    }
+}
+
+impl Reloadable {
+    /// Creates a new reloadable logger object.
+    ///
+    /// # Parameters
+    ///
+    /// - `logger`: The initial [`Logger`]
+    ///
+    /// # Returns
+    ///
+    /// A new `Reloadable` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::reload::Reloadable::new(logging_rs::Logger::default());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Reloadable`]
+    pub fn new(logger: Logger) -> Reloadable {
+        Reloadable {
+            logger: Arc::new(RwLock::new(Arc::new(logger))),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Returns the current logger.
+    ///
+    /// The returned logger is not affected by later replacements.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    ///
+    /// # Returns
+    ///
+    /// The current [`Logger`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// let current: std::sync::Arc<logging_rs::Logger> = logger.current();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Reloadable::replace()`]
+    pub fn current(&self) -> Arc<Logger> {
+        Arc::clone(&self.logger.read().unwrap_or_else(|error| error.into_inner()))
+    }
+
+    /// Replaces the logger.
+    ///
+    /// Records logged concurrently are logged by either the previous or the new logger. Buffering sinks of the
+    /// previous logger are not flushed, use [`Logger::shutdown()`] on the returned logger if needed.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    /// - `logger`: The new [`Logger`]
+    ///
+    /// # Returns
+    ///
+    /// The previous [`Logger`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// logger.replace(logging_rs::Logger::default().with_level(logging_rs::Level::WARN));
+    /// assert!(!logger.is_enabled(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Reloadable::generation()`]
+    pub fn replace(&self, logger: Logger) -> Arc<Logger> {
+        let previous: Arc<Logger> = std::mem::replace(&mut *self.logger.write().unwrap_or_else(|error| error.into_inner()), Arc::new(logger));
+        self.generation.fetch_add(1, Ordering::Relaxed);
+        previous
+    }
+
+    /// Returns the number of times the logger was replaced.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    ///
+    /// # Returns
+    ///
+    /// The number of replacements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// logger.replace(logging_rs::Logger::default());
+    ///
+    /// assert_eq!(logger.generation(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Reloadable::replace()`]
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Relaxed)
+    }
+
+    /// Checks whether records of the given level are logged by the current logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    /// - `level`: The log [`Level`] to check
+    ///
+    /// # Returns
+    ///
+    /// Whether the current logger logs the level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// assert!(logger.is_enabled(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::is_enabled()`]
+    pub fn is_enabled(&self, level: Level) -> bool {
+        self.current().is_enabled(level)
+    }
+
+    /// Logs the given message using the current logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// logger.log("Some message", logging_rs::Level::INFO, "src/main.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log()`]
+    pub fn log(&self, message: &str, level: Level, path: &str, arguments: Vec<(&str, String)>) {
+        self.current().log(message, level, path, arguments);
+    }
+
+    /// Logs the given static message without arguments using the current logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// logger.log_static("Some message", logging_rs::Level::DEBUG, "src/main.rs");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_static()`]
+    pub fn log_static(&self, message: &'static str, level: Level, path: &'static str) {
+        self.current().log_static(message, level, path);
+    }
+
+    /// Logs the given record using the current logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    /// - `record`: The [`Record`] to log
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// logger.log_record(&logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/main.rs", vec![]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_record()`]
+    pub fn log_record(&self, record: &Record) {
+        self.current().log_record(record);
+    }
+
+    /// Logs the given message to the named sinks of the current logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    /// - `targets`: The names of the sinks to write to
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// logger.log_to(&["audit"], "Some message", logging_rs::Level::INFO, "src/main.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_to()`]
+    pub fn log_to(&self, targets: &[&str], message: &str, level: Level, path: &str, arguments: Vec<(&str, String)>) {
+        self.current().log_to(targets, message, level, path, arguments);
+    }
+
+    /// Logs the given record to the named sinks of the current logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    /// - `targets`: The names of the sinks to write to
+    /// - `record`: The [`Record`] to log
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// logger.log_record_to(&["audit"], &logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/main.rs", vec![]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_record_to()`]
+    pub fn log_record_to(&self, targets: &[&str], record: &Record) {
+        self.current().log_record_to(targets, record);
+    }
+
+    /// Loads the logger from the given configuration file and watches the file for changes.
+    ///
+    /// The file is checked once every interval on a background thread and loaded again using [`Logger::from_file()`]
+    /// whenever its modification time or size changes. Invalid configurations are logged as errors using the current
+    /// logger, which is kept until the file is fixed.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The reloadable logger object
+    /// - `path`: The path of the configuration file
+    /// - `interval`: The time between two checks
+    ///
+    /// # Returns
+    ///
+    /// A running [`Watcher`], which stops watching the file when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can not be loaded, see [`Logger::from_file()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use logging_rs;
+    /// let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+    /// let _watcher: logging_rs::reload::Watcher = logger.watch("logging.toml", std::time::Duration::from_secs(5)).unwrap();
+    ///
+    /// logging_rs::info!(logger, "Logged using the configuration of logging.toml");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Watcher`]
+    #[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+    pub fn watch<P: AsRef<Path>>(&self, path: P, interval: Duration) -> Result<Watcher, errors::Error> {
+        let mut watcher: Watcher = Watcher::new(self.clone(), path);
+        watcher.check()?;
+        watcher.start(interval);
+
+        Ok(watcher)
+    }
+}
+
+
+/////////////
+// WATCHER //
+/////////////
+
+/// Configuration file watcher object.
+///
+/// Loads a configuration file into a [`Reloadable`] logger whenever the file changes. Changes are detected using the
+/// modification time and size of the file, so no platform specific notification API is needed.
+///
+/// Use [`Watcher::new()`] or [`Reloadable::watch()`] to create watcher objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+/// let watcher: logging_rs::reload::Watcher = logging_rs::reload::Watcher::new(logger.clone(), "logging.toml");
+///
+/// // Without a background thread, for example on every request
+/// let _ = watcher.check();
+/// ```
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+pub struct Watcher {
+    /// The path of the configuration file.
+    path: PathBuf,
+    /// The logger to load the configuration into.
+    logger: Reloadable,
+    /// The modification time and size of the file when it was loaded last.
+    loaded: Arc<Mutex<Option<(SystemTime, u64)>>>,
+    /// Sender used to stop the background worker.
+    stop: Option<mpsc::Sender<()>>,
+    /// The background worker.
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+impl std::fmt::Debug for Watcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Watcher")
+            .field("path", &self.path)
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+impl Watcher {
+    /// Creates a new watcher object.
+    ///
+    /// The file is not loaded yet and the background worker is not started yet.
+    ///
+    /// # Parameters
+    ///
+    /// - `logger`: The [`Reloadable`] logger to load the configuration into
+    /// - `path`: The path of the configuration file
+    ///
+    /// # Returns
+    ///
+    /// A new `Watcher` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::reload::Watcher::new(logging_rs::reload::Reloadable::default(), "logging.yaml");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Reloadable::watch()`]
+    pub fn new<P: AsRef<Path>>(logger: Reloadable, path: P) -> Watcher {
+        Watcher {
+            path: path.as_ref().to_path_buf(),
+            logger,
+            loaded: Arc::new(Mutex::new(None)),
+            stop: None,
+            worker: None,
+        }
+    }
+
+    /// Loads the configuration file if it changed since it was loaded last.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The watcher object
+    ///
+    /// # Returns
+    ///
+    /// Whether the logger was replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns a file error if the file can not be read and the errors of [`Logger::from_file()`] if the
+    /// configuration is invalid. The logger is not replaced in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let watcher: logging_rs::reload::Watcher = logging_rs::reload::Watcher::new(logging_rs::reload::Reloadable::default(), "missing.toml");
+    /// assert!(watcher.check().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Watcher::start()`]
+    pub fn check(&self) -> Result<bool, errors::Error> {
+        check(&self.path, &self.logger, &self.loaded)
+    }
+
+    /// Starts the background worker, checking the file once every interval.
+    ///
+    /// Does nothing if the worker is already running or [`crate::sandbox`] mode is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The watcher object
+    /// - `interval`: The time between two checks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut watcher: logging_rs::reload::Watcher = logging_rs::reload::Watcher::new(logging_rs::reload::Reloadable::default(), "logging.toml");
+    /// watcher.start(std::time::Duration::from_secs(5));
+    /// # watcher.stop();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Watcher::stop()`]
+    pub fn start(&mut self, interval: Duration) {
+        if self.is_running() || sandbox::is_enabled() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel::<()>();
+        let path: PathBuf = self.path.clone();
+        let logger: Reloadable = self.logger.clone();
+        let loaded: Arc<Mutex<Option<(SystemTime, u64)>>> = Arc::clone(&self.loaded);
+
+        self.worker = Some(thread::spawn(move || {
+            while let Err(mpsc::RecvTimeoutError::Timeout) = receiver.recv_timeout(interval) {
+                if let Err(error) = check(&path, &logger, &loaded) {
+                    crate::error!(logger, "Failed to reload {{path}}: {{error}}", "path" = path.display(), "error" = error.description);
+                }
+            }
+        }));
+        self.stop = Some(sender);
+    }
+
+    /// Stops the background worker.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The watcher object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut watcher: logging_rs::reload::Watcher = logging_rs::reload::Watcher::new(logging_rs::reload::Reloadable::default(), "logging.toml");
+    /// watcher.stop();
+    /// assert!(!watcher.is_running());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Watcher::start()`]
+    pub fn stop(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+
+    /// Checks whether the background worker is running.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The watcher object
+    ///
+    /// # Returns
+    ///
+    /// `true` if the worker was started and not stopped yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let watcher: logging_rs::reload::Watcher = logging_rs::reload::Watcher::new(logging_rs::reload::Reloadable::default(), "logging.toml");
+    /// assert!(!watcher.is_running());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Watcher`]
+    pub fn is_running(&self) -> bool {
+        self.worker.is_some()
+    }
+}
+
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+
+/////////////
+// HELPERS //
+/////////////
+
+/// Loads the configuration file into the logger if its modification time or size changed.
+#[cfg(any(feature = "toml", feature = "config-yaml", feature = "config-json"))]
+fn check(path: &Path, logger: &Reloadable, loaded: &Mutex<Option<(SystemTime, u64)>>) -> Result<bool, errors::Error> {
+    let metadata: std::fs::Metadata = std::fs::metadata(path)
+        .map_err(|error| errors::Error::new("File error", &format!("Could not read {}: {}", path.display(), error), 1))?;
+    let version: (SystemTime, u64) = (metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH), metadata.len());
+
+    let mut loaded = loaded.lock().unwrap_or_else(|error| error.into_inner());
+
+    if *loaded == Some(version) {
+        return Ok(false);
+    }
+
+    let result: Result<Logger, errors::Error> = Logger::from_file(path);
+    *loaded = Some(version);
+    logger.replace(result?);
+
+    Ok(true)
+}
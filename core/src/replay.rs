@@ -0,0 +1,202 @@
+#![doc = include_str!("../../.github/replay.md")]
+// logging-rs replay
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::io::{self, BufRead};
+use std::time::{Duration, Instant};
+
+use crate::ingest::Ingest;
+use crate::Record;
+
+
+////////////
+// REPLAY //
+////////////
+
+/// Replay object.
+///
+/// Re-emits the records of a recorded log session through the logger of an [`Ingest`] object, for example to test
+/// dashboards, sinks or formatters against realistic data. Lines are parsed, filtered and routed like ingested
+/// lines and keep their original timestamps. Records are emitted as fast as possible, or at their original relative
+/// timing if a speed is set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let replay: logging_rs::replay::Replay = logging_rs::replay::Replay::new(logging_rs::ingest::Ingest::new(&logger, "session.log"))
+///     .with_speed(100.0);
+///
+/// let session: &[u8] = b"{\"time\": \"2024-01-01T12:00:00Z\", \"msg\": \"Started\"}\n{\"time\": \"2024-01-01T12:00:01Z\", \"msg\": \"Ready\"}\n";
+/// assert_eq!(replay.replay(session).unwrap(), 2);
+/// ```
+#[derive(Debug)]
+pub struct Replay<'a> {
+    /// The ingestion adapter parsing and emitting the records.
+    ingest: Ingest<'a>,
+    /// The factor the original timing is sped up by, as fast as possible if `None`.
+    speed: Option<f64>,
+}
+
+impl<'a> Replay<'a> {
+    /// Creates a new replay object emitting the records as fast as possible.
+    ///
+    /// # Parameters
+    ///
+    /// - `ingest`: The [`Ingest`] object parsing and emitting the records
+    ///
+    /// # Returns
+    ///
+    /// A new `Replay` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::replay::Replay::new(logging_rs::ingest::Ingest::new(&logger, "session.log"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Replay::with_speed()`]
+    pub fn new(ingest: Ingest<'a>) -> Replay<'a> {
+        Replay {
+            ingest,
+            speed: None
+        }
+    }
+
+    /// Emits the records at their original relative timing, sped up by the given factor.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The replay object
+    /// - `speed`: The factor to speed up by, `1.0` for the original timing
+    ///
+    /// # Returns
+    ///
+    /// The replay object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// // Replays an hour of logs in a minute
+    /// logging_rs::replay::Replay::new(logging_rs::ingest::Ingest::new(&logger, "session.log")).with_speed(60.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Replay`]
+    pub fn with_speed(mut self, speed: f64) -> Replay<'a> {
+        self.speed = Some(speed).filter(|speed| speed.is_finite() && *speed > 0.0);
+        self
+    }
+
+    /// Emits all lines of the given reader.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The replay object
+    /// - `reader`: The reader to read the lines from
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of emitted records or the error that occurred while reading.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let replay: logging_rs::replay::Replay = logging_rs::replay::Replay::new(logging_rs::ingest::Ingest::new(&logger, "memory"));
+    /// assert_eq!(replay.replay(&b"first\nsecond\n"[..]).unwrap(), 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Replay::replay_file()`]
+    pub fn replay<R: BufRead>(&self, reader: R) -> io::Result<usize> {
+        let mut count: usize = 0;
+        let mut start: Option<(Instant, chrono::DateTime<chrono::Utc>)> = None;
+
+        for line in reader.lines() {
+            let line: String = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record: Record = self.ingest.parse_line(&line);
+
+            if let Some(speed) = self.speed {
+                let (instant, timestamp) = *start.get_or_insert((Instant::now(), record.timestamp));
+                let offset: Duration = (record.timestamp - timestamp).to_std().unwrap_or_default().div_f64(speed);
+
+                if let Some(remaining) = (instant + offset).checked_duration_since(Instant::now()) {
+                    std::thread::sleep(remaining);
+                }
+            }
+
+            if self.ingest.emit(&record) {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Emits all lines of the given file.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The replay object
+    /// - `path`: The path of the file
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the number of emitted records or the error that occurred while opening or reading the
+    /// file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::replay::Replay::new(logging_rs::ingest::Ingest::new(&logger, "session.log")).with_speed(1.0).replay_file("session.log").unwrap();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Replay::replay()`]
+    pub fn replay_file<P: AsRef<std::path::Path>>(&self, path: P) -> io::Result<usize> {
+        self.replay(io::BufReader::new(std::fs::File::open(path)?))
+    }
+}
@@ -0,0 +1,265 @@
+#![doc = include_str!("../../.github/rules.md")]
+// logging-rs rules
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::{json, sinks, Record};
+
+
+//////////
+// RULE //
+//////////
+
+/// Declarative record transformations.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::rules::Rule::RENAME { from: "user_id".to_owned(), to: "usr.id".to_owned() };
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rule {
+    /// Renames the argument `from` to `to`
+    RENAME {
+        /// The name of the argument to rename
+        from: String,
+        /// The new name of the argument
+        to: String
+    },
+    /// Moves the message into the argument `key`, leaving the message empty
+    MESSAGE {
+        /// The name of the argument receiving the message
+        key: String
+    },
+    /// Replaces arguments containing JSON objects with one argument per entry, named `{key}{separator}{entry}`
+    FLATTEN {
+        /// The separator between the names of the nested keys
+        separator: String
+    },
+    /// Removes the argument `key`
+    REMOVE {
+        /// The name of the argument to remove
+        key: String
+    },
+    /// Adds the `fingerprint` argument, a hash of the message, path and the values of the given arguments
+    FINGERPRINT {
+        /// The names of the arguments to include in the hash
+        fields: Vec<String>
+    },
+}
+
+impl Rule {
+    /// Applies the rule to the given record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The rule object
+    /// - `record`: The [`Record`] to transform
+    ///
+    /// # Returns
+    ///
+    /// The transformed [`Record`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Started", "src/main.rs", vec![]);
+    /// let record: logging_rs::Record = logging_rs::rules::Rule::MESSAGE { key: "msg".to_owned() }.apply(record);
+    ///
+    /// assert_eq!(record.message, "");
+    /// assert_eq!(record.argument("msg"), Some("Started"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Rules::apply()`]
+    pub fn apply(&self, mut record: Record) -> Record {
+        match self {
+            Rule::RENAME { from, to } => {
+                for (key, _) in record.arguments.iter_mut().filter(|(key, _)| key == from) {
+                    key.clone_from(to);
+                }
+            },
+            Rule::MESSAGE { key } => {
+                let message: String = std::mem::take(&mut record.message);
+                record.arguments.push((key.clone(), message));
+            },
+            Rule::FLATTEN { separator } => {
+                let mut arguments: Vec<(String, String)> = vec![];
+
+                for (key, value) in record.arguments {
+                    match json::Value::parse(&value) {
+                        Ok(value @ json::Value::OBJECT(_)) => flatten(&key, &value, separator, &mut arguments),
+                        _ => arguments.push((key, value))
+                    }
+                }
+
+                record.arguments = arguments;
+            },
+            Rule::REMOVE { key } => {
+                record.arguments.retain(|(name, _)| name != key);
+            },
+            Rule::FINGERPRINT { fields } => {
+                let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+                let fingerprint: String = record.fingerprint(&fields);
+                record.arguments.push(("fingerprint".to_owned(), fingerprint));
+            }
+        }
+
+        record
+    }
+}
+
+/// Adds the entries of the given JSON value to the arguments, recursing into nested objects.
+fn flatten(key: &str, value: &json::Value, separator: &str, arguments: &mut Vec<(String, String)>) {
+    match value {
+        json::Value::OBJECT(entries) => {
+            for (name, value) in entries {
+                flatten(&format!("{}{}{}", key, separator, name), value, separator, arguments);
+            }
+        },
+        _ => arguments.push((key.to_owned(), value.to_text()))
+    }
+}
+
+
+///////////
+// RULES //
+///////////
+
+/// Ordered list of rules.
+///
+/// Rules are applied per sink by wrapping the sink with [`Rules::wrap()`], so every backend can receive the field
+/// names it requires.
+///
+/// # Parameters
+///
+/// - `rules`: The rules, applied in order
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let rules: logging_rs::rules::Rules = logging_rs::rules::Rules::new(vec![
+///     logging_rs::rules::Rule::FLATTEN { separator: ".".to_owned() },
+///     logging_rs::rules::Rule::RENAME { from: "user.id".to_owned(), to: "usr.id".to_owned() }
+/// ]);
+///
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logger.add_sink(rules.wrap(logging_rs::Output::STDOUT));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Rules {
+    /// The rules, applied in order
+    pub rules: Vec<Rule>,
+}
+
+impl Rules {
+    /// Creates a new rules object.
+    ///
+    /// # Parameters
+    ///
+    /// - `rules`: The rules, applied in order
+    ///
+    /// # Returns
+    ///
+    /// A new `Rules` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::rules::Rules::new(vec![logging_rs::rules::Rule::REMOVE { key: "password".to_owned() }]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Rules`]
+    pub fn new(rules: Vec<Rule>) -> Rules {
+        Rules { rules }
+    }
+
+    /// Applies all rules in order to the given record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The rules object
+    /// - `record`: The [`Record`] to transform
+    ///
+    /// # Returns
+    ///
+    /// The transformed [`Record`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let rules: logging_rs::rules::Rules = logging_rs::rules::Rules::new(vec![
+    ///     logging_rs::rules::Rule::FLATTEN { separator: "_".to_owned() }
+    /// ]);
+    /// let record: logging_rs::Record = rules.apply(logging_rs::Record::new(
+    ///     logging_rs::Level::INFO, "Request", "src/main.rs", vec![("http", r#"{"status": 200}"#.to_owned())]
+    /// ));
+    ///
+    /// assert_eq!(record.argument("http_status"), Some("200"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Rule::apply()`]
+    pub fn apply(&self, record: Record) -> Record {
+        self.rules.iter().fold(record, |record, rule| rule.apply(record))
+    }
+
+    /// Wraps the given sink, applying the rules to every record before writing it.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The rules object
+    /// - `sink`: The [`sinks::Sink`] to write to
+    ///
+    /// # Returns
+    ///
+    /// A [`sinks::Mapped`] sink applying the rules.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::rules::Rules::new(vec![]).wrap(logging_rs::Output::STDERR);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`sinks::mapped()`]
+    pub fn wrap<S: sinks::Sink>(self, sink: S) -> sinks::Mapped<S> {
+        sinks::Mapped::new(sink, move |record: Record| self.apply(record))
+    }
+}
@@ -0,0 +1,274 @@
+#![doc = include_str!("../../.github/sandbox.md")]
+// logging-rs sandbox
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fs;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::{errors, sinks, Formatter, Logger, Output, Record};
+
+
+/////////////
+// GLOBALS //
+/////////////
+
+/// Whether sandbox mode is enabled.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+
+//////////////////
+// SANDBOX MODE //
+//////////////////
+
+/// Enables sandbox mode for the whole process.
+///
+/// In sandbox mode, no threads are spawned and no files are opened:
+///
+/// - [`Logger::shutdown()`] flushes the sinks on the calling thread
+/// - [`crate::maintenance::Maintenance::start()`] does not start a worker, run the tasks using
+///   [`crate::maintenance::Maintenance::tick()`] instead
+/// - [`Output::FILE`] reports an error instead of opening its file, use [`OpenFile`] instead
+///
+/// The logger never resolves host names, so no network access is needed either.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sandbox::enable();
+/// assert!(logging_rs::sandbox::is_enabled());
+/// # logging_rs::sandbox::disable();
+/// ```
+///
+/// # See also
+///
+/// - [`Logger::sandboxed()`]
+/// - [`disable()`]
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Disables sandbox mode for the whole process.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sandbox::disable();
+/// assert!(!logging_rs::sandbox::is_enabled());
+/// ```
+///
+/// # See also
+///
+/// - [`enable()`]
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Returns whether sandbox mode is enabled.
+///
+/// # Returns
+///
+/// A boolean indicating whether sandbox mode is enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// if logging_rs::sandbox::is_enabled() {
+///     println!("Running sandboxed");
+/// }
+/// ```
+///
+/// # See also
+///
+/// - [`enable()`]
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+
+///////////////
+// OPEN FILE //
+///////////////
+
+/// Sink writing to a file opened once when creating the sink.
+///
+/// Unlike [`Output::FILE`], which opens its file for every write, the file handle is kept open, so writing keeps
+/// working after the process lost the permission to open files.
+///
+/// Use [`OpenFile::open()`] to create open file objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_doc_open_file.log");
+/// # let path: &str = path.to_str().unwrap();
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::sandbox::OpenFile::open(path).unwrap());
+/// # std::fs::remove_file(path).ok();
+/// ```
+///
+/// # See also
+///
+/// - [`Logger::sandboxed()`]
+/// - [`sinks::Sink`]
+#[derive(Debug)]
+pub struct OpenFile {
+    /// The path of the file.
+    pub path: String,
+    /// The open file.
+    file: Mutex<fs::File>
+}
+
+impl OpenFile {
+    /// Opens the given file for appending, creating it if needed.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the file
+    ///
+    /// # Returns
+    ///
+    /// A new `OpenFile` object or a file error if the file could not be opened.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_doc_open_file_open.log");
+    /// # let path: &str = path.to_str().unwrap();
+    /// let file: logging_rs::sandbox::OpenFile = logging_rs::sandbox::OpenFile::open(path).unwrap();
+    /// assert_eq!(file.path, path);
+    /// # std::fs::remove_file(path).ok();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`OpenFile`]
+    pub fn open(path: &str) -> Result<OpenFile, errors::Error> {
+        match fs::OpenOptions::new().create(true).append(true).open(path) {
+            Ok(file) => Ok(OpenFile { path: path.to_owned(), file: Mutex::new(file) }),
+            Err(error) => Err(errors::Error::new("File error", &format!("The file {} could not be opened: {}", path, error), 1))
+        }
+    }
+
+    /// Writes the given text using a single write.
+    fn write_text(&self, text: &str) {
+        let mut file = self.file.lock().unwrap_or_else(|error| error.into_inner());
+
+        if let Err(error) = file.write_all(text.as_bytes()) {
+            errors::Error::new("Writing error", "The file could not be edited", 2).report(format!("File: {}\nText: {}\nError: {}", self.path, text, error).as_str());
+        }
+    }
+}
+
+impl sinks::Sink for OpenFile {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        formatter.format_record_with(false, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.write_text(&(self.encode(formatter, record) + "\n"));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if records.is_empty() {
+            return;
+        }
+
+        let text: String = records.iter().map(|record| self.encode(formatter, record) + "\n").collect();
+        self.write_text(&text);
+    }
+
+    fn flush(&self, _timeout: Duration) -> sinks::Flushed {
+        let _ = self.file.lock().unwrap_or_else(|error| error.into_inner()).flush();
+
+        sinks::Flushed::default()
+    }
+}
+
+
+////////////
+// LOGGER //
+////////////
+
+impl Logger {
+    /// Prepares the logger for running inside a sandbox and enables sandbox mode.
+    ///
+    /// Every [`Output::FILE`] of the `writable_list` is opened once and replaced by an [`OpenFile`] sink. Call this
+    /// after initialization, before restricting the process.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    ///
+    /// # Returns
+    ///
+    /// The prepared logger or a file error if one of the files could not be opened.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_doc_sandboxed.log");
+    /// # let path: String = path.to_str().unwrap().to_owned();
+    /// let logger: logging_rs::Logger = logging_rs::Logger::new(
+    ///     logging_rs::Formatter::default(),
+    ///     vec![logging_rs::Output::STDOUT, logging_rs::Output::FILE { path: path.clone() }]
+    /// ).sandboxed().unwrap();
+    ///
+    /// assert_eq!(logger.writable_list, vec![logging_rs::Output::STDOUT]);
+    /// assert_eq!(logger.sinks.len(), 1);
+    /// # logging_rs::sandbox::disable();
+    /// # std::fs::remove_file(path).ok();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`enable()`]
+    /// - [`OpenFile`]
+    pub fn sandboxed(mut self) -> Result<Logger, errors::Error> {
+        let mut writable_list: Vec<Output> = vec![];
+
+        for writable in std::mem::take(&mut self.writable_list) {
+            match writable {
+                Output::FILE { path } => self.add_sink(OpenFile::open(&path)?),
+                _ => writable_list.push(writable)
+            }
+        }
+
+        self.writable_list = writable_list;
+        enable();
+
+        Ok(self)
+    }
+}
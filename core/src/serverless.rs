@@ -0,0 +1,227 @@
+#![doc = include_str!("../../.github/serverless.md")]
+
+// logging-rs serverless
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::io::Write;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use crate::sinks::{Flushed, Sink};
+use crate::{Formatter, Level, Logger, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The environment variable containing the X-Ray trace id of the current invocation, set by the Lambda runtime.
+pub const TRACE_ID_VARIABLE: &str = "_X_AMZN_TRACE_ID";
+
+/// The environment variable containing the log level configured for the function.
+pub const LEVEL_VARIABLE: &str = "AWS_LAMBDA_LOG_LEVEL";
+
+
+/////////////
+// STATICS //
+/////////////
+
+/// The request id of the current invocation.
+static REQUEST_ID: RwLock<Option<String>> = RwLock::new(None);
+
+
+////////////////
+// REQUEST ID //
+////////////////
+
+/// Sets the request id of the current invocation, added to all records as `aws_request_id` argument.
+///
+/// Must be called at the start of every invocation using the request id of the invocation context, otherwise records
+/// get the request id of the previous invocation. Passing `None` removes the request id. The request id is shared by
+/// the whole process, so functions handling multiple invocations concurrently should add the `aws_request_id`
+/// argument to their records instead, which takes precedence.
+///
+/// # Parameters
+///
+/// - `request_id`: The request id of the current invocation
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::serverless::set_request_id(Some("8f5f1a6e-4c1b-4d8e-9a41-6f1e2a1a9b7c"));
+/// ```
+///
+/// # See also
+///
+/// - [`request_id()`]
+pub fn set_request_id(request_id: Option<&str>) {
+    *REQUEST_ID.write().unwrap_or_else(|error| error.into_inner()) = request_id.map(|request_id| request_id.to_owned());
+}
+
+/// Returns the request id of the current invocation.
+///
+/// # Returns
+///
+/// The request id set using [`set_request_id()`], or `None` if none is set.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::serverless::set_request_id(Some("8f5f1a6e-4c1b-4d8e-9a41-6f1e2a1a9b7c"));
+/// assert_eq!(logging_rs::serverless::request_id().as_deref(), Some("8f5f1a6e-4c1b-4d8e-9a41-6f1e2a1a9b7c"));
+/// ```
+///
+/// # See also
+///
+/// - [`set_request_id()`]
+pub fn request_id() -> Option<String> {
+    REQUEST_ID.read().unwrap_or_else(|error| error.into_inner()).clone()
+}
+
+
+////////////
+// LAMBDA //
+////////////
+
+/// Sink writing records to stdout for serverless functions, like AWS Lambda.
+///
+/// Every record is written using a single unbuffered write followed by a flush, so no records are lost when the
+/// execution environment is frozen between invocations. The sink starts no threads and holds no state, so it keeps
+/// working after the environment is thawed. Write errors are ignored instead of panicking.
+///
+/// Records get the `aws_request_id` argument (see [`request_id()`]) and the `xray_trace_id` argument (see
+/// [`TRACE_ID_VARIABLE`]) if available.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::json_lines(), vec![]);
+/// logger.add_sink(logging_rs::serverless::Lambda);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Lambda;
+
+impl Lambda {
+    /// Adds the invocation arguments to a copy of the given record.
+    fn annotate(record: &Record) -> Record {
+        let mut record: Record = record.clone();
+
+        if let Some(request_id) = request_id() {
+            if record.argument("aws_request_id").is_none() {
+                record.arguments.push(("aws_request_id".to_owned(), request_id));
+            }
+        }
+
+        if let Ok(trace_id) = std::env::var(TRACE_ID_VARIABLE) {
+            if !trace_id.is_empty() && record.argument("xray_trace_id").is_none() {
+                record.arguments.push(("xray_trace_id".to_owned(), trace_id));
+            }
+        }
+
+        record
+    }
+
+    /// Writes the given text to stdout and flushes it, ignoring errors.
+    fn write_text(text: &str) {
+        let mut stdout: std::io::StdoutLock = std::io::stdout().lock();
+        drop(stdout.write_all(text.as_bytes()));
+        drop(stdout.flush());
+    }
+}
+
+impl Sink for Lambda {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        formatter.format_record_with(false, &Lambda::annotate(record)).replace('\n', "\\n")
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        Lambda::write_text(&(self.encode(formatter, record) + "\n"));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if records.is_empty() {
+            return;
+        }
+
+        let text: String = records.iter().map(|record| self.encode(formatter, record) + "\n").collect();
+        Lambda::write_text(&text);
+    }
+
+    fn flush(&self, _timeout: Duration) -> Flushed {
+        drop(std::io::stdout().flush());
+        Flushed::default()
+    }
+}
+
+
+////////////
+// LOGGER //
+////////////
+
+impl Logger {
+    /// Creates a logger for serverless functions, like AWS Lambda, writing records as JSON lines to stdout.
+    ///
+    /// Records are written using the [`Lambda`] sink, one line per record, with the `aws_request_id` argument, so
+    /// CloudWatch Logs can parse and query them. The level is read from the [`LEVEL_VARIABLE`] environment variable
+    /// (`TRACE` is treated as [`Level::DEBUG`]) and defaults to [`Level::INFO`]. Use [`Logger::builder()`] to diverge
+    /// from these defaults.
+    ///
+    /// # Returns
+    ///
+    /// A new `Logger` object using [`Formatter::json_lines()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::serverless();
+    ///
+    /// assert!(logger.writable_list.is_empty());
+    /// assert_eq!(logger.sinks.len(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::production()`]
+    /// - [`set_request_id()`]
+    pub fn serverless() -> Logger {
+        let level: Level = std::env::var(LEVEL_VARIABLE)
+            .ok()
+            .and_then(|level| match level.trim().to_uppercase().as_str() {
+                "TRACE" => Some(Level::DEBUG),
+                level => level.parse().ok()
+            })
+            .unwrap_or(Level::INFO);
+
+        let mut logger: Logger = Logger::new(Formatter::json_lines(), vec![]).with_level(level);
+        logger.add_sink(Lambda);
+        logger
+    }
+}
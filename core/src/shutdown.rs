@@ -0,0 +1,384 @@
+#![doc = include_str!("../../.github/shutdown.md")]
+// logging-rs shutdown
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{errors, sandbox, sinks, Logger};
+
+
+/////////////
+// STATICS //
+/////////////
+
+/// A shutdown hook and the logger it was registered on.
+type Hook = (Logger, Box<dyn FnOnce(&Logger) + Send>);
+
+/// The registered shutdown hooks, in the order they were registered.
+static HOOKS: Mutex<Vec<Hook>> = Mutex::new(Vec::new());
+
+
+/////////////////
+// SINK REPORT //
+/////////////////
+
+/// Shutdown report of a single sink.
+///
+/// # Parameters
+///
+/// - `name`: The name of the sink, if it is named
+/// - `flushed`: The number of records written while flushing
+/// - `dropped`: The number of records the sink dropped since its last flush
+/// - `timed_out`: Whether the sink did not finish flushing before the timeout
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::shutdown::SinkReport {
+///     name: Some("audit".to_owned()),
+///     flushed: 12,
+///     dropped: 0,
+///     timed_out: false
+/// };
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SinkReport {
+    /// The name of the sink, if it is named
+    pub name: Option<String>,
+    /// The number of records written while flushing
+    pub flushed: usize,
+    /// The number of records the sink dropped since its last flush
+    pub dropped: usize,
+    /// Whether the sink did not finish flushing before the timeout
+    pub timed_out: bool,
+}
+
+
+/////////////////////
+// SHUTDOWN REPORT //
+/////////////////////
+
+/// Report of [`Logger::shutdown()`].
+///
+/// # Parameters
+///
+/// - `sinks`: The reports of the sinks, in the order they were added to the logger
+/// - `elapsed`: The time the shutdown took
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let report: logging_rs::shutdown::ShutdownReport = logger.shutdown(std::time::Duration::from_secs(5));
+///
+/// if report.dropped() > 0 || report.timed_out() {
+///     eprintln!("Lost log records during shutdown: {:?}", report);
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ShutdownReport {
+    /// The reports of the sinks, in the order they were added to the logger
+    pub sinks: Vec<SinkReport>,
+    /// The time the shutdown took
+    pub elapsed: Duration,
+}
+
+impl ShutdownReport {
+    /// Returns the total number of records written while flushing.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The shutdown report object
+    ///
+    /// # Returns
+    ///
+    /// The sum of the flushed records of all sinks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::shutdown::ShutdownReport::default().flushed(), 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ShutdownReport`]
+    pub fn flushed(&self) -> usize {
+        self.sinks.iter().map(|sink| sink.flushed).sum()
+    }
+
+    /// Returns the total number of dropped records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The shutdown report object
+    ///
+    /// # Returns
+    ///
+    /// The sum of the dropped records of all sinks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::shutdown::ShutdownReport::default().dropped(), 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ShutdownReport`]
+    pub fn dropped(&self) -> usize {
+        self.sinks.iter().map(|sink| sink.dropped).sum()
+    }
+
+    /// Returns whether any sink did not finish flushing before the timeout.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The shutdown report object
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether any sink timed out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(!logging_rs::shutdown::ShutdownReport::default().timed_out());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ShutdownReport`]
+    pub fn timed_out(&self) -> bool {
+        self.sinks.iter().any(|sink| sink.timed_out)
+    }
+}
+
+
+//////////////
+// SHUTDOWN //
+//////////////
+
+impl Logger {
+    /// Flushes all outputs and sinks, waiting at most the given time.
+    ///
+    /// Every sink is flushed on a separate thread using [`sinks::Sink::flush()`]. Sinks not finished before the
+    /// timeout are reported as timed out and left flushing in the background. In [`sandbox`] mode, the sinks are
+    /// flushed on the calling thread instead and reported as timed out if they finished after the timeout. The
+    /// logger can still be used afterwards.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `timeout`: The maximum time to wait for all sinks
+    ///
+    /// # Returns
+    ///
+    /// A [`ShutdownReport`] containing the number of flushed and dropped records per sink.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logger.add_named_sink("console", logging_rs::sinks::coalesced(logging_rs::Output::STDERR, 100));
+    ///
+    /// let report: logging_rs::shutdown::ShutdownReport = logger.shutdown(std::time::Duration::from_secs(2));
+    /// assert_eq!(report.sinks[0].name.as_deref(), Some("console"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ShutdownReport`]
+    /// - [`Logger`]
+    pub fn shutdown(&self, timeout: Duration) -> ShutdownReport {
+        let start: Instant = Instant::now();
+        let deadline: Instant = start + timeout;
+
+        for writable in &self.writable_list {
+            errors::guard(|| {
+                sinks::Sink::flush(writable, timeout);
+            });
+        }
+
+        let sinks: Vec<SinkReport> = self.sinks.iter().map(|sink| {
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+
+            if sandbox::is_enabled() {
+                let mut flushed: sinks::Flushed = sinks::Flushed::default();
+                errors::guard(|| flushed = sinks::Sink::flush(sink, remaining));
+
+                return SinkReport {
+                    name: sink.name.clone(),
+                    flushed: flushed.flushed,
+                    dropped: flushed.dropped,
+                    timed_out: Instant::now() > deadline
+                };
+            }
+
+            let (sender, receiver) = mpsc::channel::<sinks::Flushed>();
+            let shared: sinks::SharedSink = sink.clone();
+
+            thread::spawn(move || errors::guard(|| {
+                let _ = sender.send(sinks::Sink::flush(&shared, remaining));
+            }));
+
+            let (flushed, timed_out): (sinks::Flushed, bool) = match receiver.recv_timeout(remaining) {
+                Ok(flushed) => (flushed, false),
+                Err(mpsc::RecvTimeoutError::Timeout) => (sinks::Flushed::default(), true),
+                Err(mpsc::RecvTimeoutError::Disconnected) => (sinks::Flushed::default(), false)
+            };
+
+            SinkReport {
+                name: sink.name.clone(),
+                flushed: flushed.flushed,
+                dropped: flushed.dropped,
+                timed_out
+            }
+        }).collect();
+
+        ShutdownReport {
+            sinks,
+            elapsed: start.elapsed()
+        }
+    }
+}
+
+
+///////////
+// HOOKS //
+///////////
+
+impl Logger {
+    /// Registers the given callback to run when the application shuts down, see [`run_hooks()`].
+    ///
+    /// Callbacks can log final records, like summary statistics or a "service stopped" record, to the logger they
+    /// are registered on. The logger is flushed after its callback ran.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `callback`: The callback, called with a clone of the logger
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default();
+    ///
+    /// logger.on_shutdown(|logger: &logging_rs::Logger| {
+    ///     logging_rs::info!(logger, "Service stopped");
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`run_hooks()`]
+    /// - [`Logger::shutdown()`]
+    pub fn on_shutdown<F: FnOnce(&Logger) + Send + 'static>(&self, callback: F) {
+        HOOKS.lock().unwrap_or_else(|error| error.into_inner()).push((self.clone(), Box::new(callback)));
+    }
+}
+
+/// Runs all registered shutdown hooks and flushes their loggers, waiting at most the given time per logger.
+///
+/// Hooks run once in the order they were registered, later calls only run hooks registered since. Panicking hooks
+/// are reported as shutdown errors without stopping the other hooks.
+///
+/// # Parameters
+///
+/// - `timeout`: The maximum time to wait for the sinks of each logger
+///
+/// # Returns
+///
+/// The [`ShutdownReport`]s of the loggers, in the order the hooks were registered.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logger.on_shutdown(|logger: &logging_rs::Logger| logging_rs::info!(logger, "Service stopped"));
+///
+/// let reports: Vec<logging_rs::shutdown::ShutdownReport> = logging_rs::shutdown::run_hooks(std::time::Duration::from_secs(2));
+/// assert!(!reports.is_empty());
+/// ```
+///
+/// # See also
+///
+/// - [`Logger::on_shutdown()`]
+pub fn run_hooks(timeout: Duration) -> Vec<ShutdownReport> {
+    let hooks: Vec<Hook> = std::mem::take(&mut *HOOKS.lock().unwrap_or_else(|error| error.into_inner()));
+
+    hooks.into_iter().map(|(logger, callback)| {
+        errors::guard_with(|| errors::Error::new("Shutdown error", "A shutdown hook panicked", 12), || callback(&logger));
+        logger.shutdown(timeout)
+    }).collect()
+}
+
+/// Runs the registered shutdown hooks on SIGINT and SIGTERM, then exits with the exit code `130`.
+///
+/// # Parameters
+///
+/// - `timeout`: The maximum time to wait for the sinks of each logger, see [`run_hooks()`]
+///
+/// # Returns
+///
+/// Nothing if the signal handler was installed.
+///
+/// # Errors
+///
+/// Returns an initialization error if a signal handler was already installed, for example by another crate using
+/// `ctrlc`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use logging_rs;
+/// let logger: logging_rs::Logger = logging_rs::Logger::production();
+/// logger.on_shutdown(|logger: &logging_rs::Logger| logging_rs::info!(logger, "Service stopped"));
+///
+/// logging_rs::shutdown::handle_signals(std::time::Duration::from_secs(5)).unwrap();
+/// ```
+///
+/// # See also
+///
+/// - [`run_hooks()`]
+#[cfg(feature = "ctrlc")]
+pub fn handle_signals(timeout: Duration) -> Result<(), errors::Error> {
+    ctrlc::set_handler(move || {
+        run_hooks(timeout);
+        std::process::exit(130);
+    }).map_err(|error: ctrlc::Error| errors::Error::new("Initialization error", &format!("The signal handler could not be installed: {}", error), 7))
+}
@@ -0,0 +1,3065 @@
+#![doc = include_str!("../../.github/sinks.md")]
+// logging-rs sinks
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{codec, errors, ids, json, memory, random, sandbox, Formatter, Level, Output, Record};
+
+
+//////////
+// SINK //
+//////////
+
+/// Destination for log records.
+///
+/// Sinks receive every record the logger logs together with the logger's [`Formatter`]. [`Output`]s are sinks, and
+/// sinks can be wrapped in combinators like [`Tee`], [`Filtered`], [`Leveled`] and [`Mapped`] to build more complex
+/// topologies.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// struct Counter(std::sync::atomic::AtomicUsize);
+///
+/// impl logging_rs::sinks::Sink for Counter {
+///     fn write(&self, _formatter: &logging_rs::Formatter, _record: &logging_rs::Record) {
+///         self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+///     }
+/// }
+/// ```
+pub trait Sink: Send + Sync {
+    /// Writes the given record.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    /// - `formatter`: The [`Formatter`] of the logger
+    /// - `record`: The [`Record`] to write
+    fn write(&self, formatter: &Formatter, record: &Record);
+
+    /// Encodes the given record the way the sink writes it.
+    ///
+    /// Used to measure records, for example by [`Budgeted`]. Defaults to the plain format string of the formatter.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    /// - `formatter`: The [`Formatter`] of the logger
+    /// - `record`: The [`Record`] to encode
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the encoded record.
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        formatter.format_record_with(false, record)
+    }
+
+    /// Writes the given records as one unit, so they are not interleaved with records written concurrently.
+    ///
+    /// Defaults to writing the records one by one. Sinks able to write multiple records at once should override
+    /// this method.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    /// - `formatter`: The [`Formatter`] of the logger
+    /// - `records`: The [`Record`]s to write
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        for record in records {
+            self.write(formatter, record);
+        }
+    }
+
+    /// Writes the given static message without placeholders, without creating a record.
+    ///
+    /// Used by [`crate::Logger::log_static()`] to pass static messages through without allocating. Defaults to
+    /// returning `false`, in which case a record is created and written using [`Sink::write()`]. Sinks able to store
+    /// or write string slices directly should override this method and return `true`.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    /// - `formatter`: The [`Formatter`] of the logger
+    /// - `level`: The log [`Level`] of the message
+    /// - `message`: The message, not containing any placeholders
+    /// - `path`: The path of the calling file
+    ///
+    /// # Returns
+    ///
+    /// Whether the sink handled the message.
+    fn write_static(&self, _formatter: &Formatter, _level: Level, _message: &'static str, _path: &'static str) -> bool {
+        false
+    }
+
+    /// Writes all buffered records, used when shutting down.
+    ///
+    /// Defaults to doing nothing. Sinks buffering or dropping records should override this method and report the
+    /// number of records written and dropped since the last flush.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    /// - `timeout`: The time the sink should take at most
+    ///
+    /// # Returns
+    ///
+    /// A [`Flushed`] object containing the number of flushed and dropped records.
+    fn flush(&self, _timeout: Duration) -> Flushed {
+        Flushed::default()
+    }
+
+    /// Resets the state of the sink in the child process after forking.
+    ///
+    /// Defaults to doing nothing. Sinks holding state like buffers, counters, file descriptors or worker threads
+    /// should override this method, so the child does not write or report records of the parent.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    fn after_fork(&self) {}
+
+    /// Returns the state of the sink to persist across restarts, like spool positions, rotation counters or sequence
+    /// numbers.
+    ///
+    /// Defaults to `None`, persisting nothing. The built-in sinks have no state, the combinators return the state of
+    /// the wrapped sink. See [`crate::Logger::save_state()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    ///
+    /// # Returns
+    ///
+    /// The state as [`json::Value`], or `None` if the sink has no state.
+    fn save_state(&self) -> Option<json::Value> {
+        None
+    }
+
+    /// Restores the state returned by [`Sink::save_state()`] in a previous run.
+    ///
+    /// Defaults to doing nothing. See [`crate::Logger::restore_state()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    /// - `state`: The persisted state
+    fn restore_state(&self, _state: &json::Value) {}
+
+    /// Checks whether the sink writes to the given output.
+    ///
+    /// Defaults to `false`. Used to skip mirroring [`Level::FATAL`] records to stderr if a sink writes there
+    /// already, see [`crate::Logger::mirror_fatal`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sink object
+    /// - `output`: The output to check
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the sink writes to the output.
+    fn writes_to(&self, _output: &Output) -> bool {
+        false
+    }
+}
+
+/// Result of flushing a sink.
+///
+/// # Parameters
+///
+/// - `flushed`: The number of records written by the flush
+/// - `dropped`: The number of records dropped since the last flush
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Flushed {
+///     flushed: 2,
+///     dropped: 0
+/// };
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Flushed {
+    /// The number of records written by the flush
+    pub flushed: usize,
+    /// The number of records dropped since the last flush
+    pub dropped: usize,
+}
+
+impl Flushed {
+    /// Creates a new flushed object.
+    ///
+    /// # Parameters
+    ///
+    /// - `flushed`: The number of records written by the flush
+    /// - `dropped`: The number of records dropped since the last flush
+    ///
+    /// # Returns
+    ///
+    /// A new `Flushed` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Flushed::new(2, 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Flushed`]
+    /// - [`Sink::flush()`]
+    pub fn new(flushed: usize, dropped: usize) -> Flushed {
+        Flushed { flushed, dropped }
+    }
+}
+
+impl std::ops::Add for Flushed {
+    type Output = Flushed;
+
+    fn add(self, other: Flushed) -> Flushed {
+        Flushed::new(self.flushed + other.flushed, self.dropped + other.dropped)
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for Box<S> {
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        (**self).write(formatter, record);
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        (**self).write_batch(formatter, records);
+    }
+
+    fn write_static(&self, formatter: &Formatter, level: Level, message: &'static str, path: &'static str) -> bool {
+        (**self).write_static(formatter, level, message, path)
+    }
+
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        (**self).encode(formatter, record)
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        (**self).flush(timeout)
+    }
+
+    fn after_fork(&self) {
+        (**self).after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        (**self).save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        (**self).restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        (**self).writes_to(output)
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for Arc<S> {
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        (**self).write(formatter, record);
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        (**self).write_batch(formatter, records);
+    }
+
+    fn write_static(&self, formatter: &Formatter, level: Level, message: &'static str, path: &'static str) -> bool {
+        (**self).write_static(formatter, level, message, path)
+    }
+
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        (**self).encode(formatter, record)
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        (**self).flush(timeout)
+    }
+
+    fn after_fork(&self) {
+        (**self).after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        (**self).save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        (**self).restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        (**self).writes_to(output)
+    }
+}
+
+impl Sink for Output {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        formatter.format_record(self, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.write_text(&(self.encode(formatter, record) + "\n"));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if records.is_empty() {
+            return;
+        }
+
+        let text: String = records.iter().map(|record| self.encode(formatter, record) + "\n").collect();
+        self.write_text(&text);
+    }
+
+    fn flush(&self, _timeout: Duration) -> Flushed {
+        match self {
+            Output::STDOUT => drop(std::io::stdout().flush()),
+            Output::STDERR => drop(std::io::stderr().flush()),
+            Output::FILE { .. } => {}
+        }
+
+        Flushed::default()
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self == output
+    }
+}
+
+impl Output {
+    /// Writes the given text, containing one or more newline terminated records, using a single write.
+    fn write_text(&self, text: &str) {
+        match self {
+            Output::STDOUT => print!("{}", text),
+            Output::STDERR => eprint!("{}", text),
+            Output::FILE { path } if sandbox::is_enabled() => {
+                errors::Error::new("File error", "The file could not be opened in sandbox mode", 1).report(format!("Path: {}", path).as_str());
+            },
+            Output::FILE { path } => {
+                match std::fs::OpenOptions::new().create(true).append(true).open(path) {
+                    Ok(mut file) => {
+                        if let Err(error) = file.write_all(text.as_bytes()) {
+                            errors::Error::new("Writing error", "The file could not be edited", 2).report(format!("File: {}\nText: {}\nError: {}", path, text, error).as_str());
+                        }
+                    },
+                    Err(error) => {
+                        errors::Error::new("File error", "The file could not be opened", 1).report(format!("Path: {}\nError: {}", path, error).as_str());
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+/////////////////
+// SHARED SINK //
+/////////////////
+
+/// Shared sink object.
+///
+/// Wraps a [`Sink`] in an [`Arc`], so loggers holding it can be cloned and compared. Shared sinks are compared by
+/// name and identity.
+///
+/// Use [`SharedSink::new()`] or [`SharedSink::named()`] to create shared sink objects instead of using this struct.
+///
+/// # Parameters
+///
+/// - `name`: The name used to send records to this sink only
+/// - `sink`: The shared sink
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::SharedSink {
+///     name: Some("audit".to_owned()),
+///     sink: std::sync::Arc::new(logging_rs::Output::STDOUT)
+/// };
+/// ```
+#[derive(Clone)]
+pub struct SharedSink {
+    /// The name used to send records to this sink only
+    pub name: Option<String>,
+    /// The shared sink
+    pub sink: Arc<dyn Sink>,
+}
+
+impl SharedSink {
+    /// Creates a new unnamed shared sink object.
+    ///
+    /// # Parameters
+    ///
+    /// - `sink`: The [`Sink`] to share
+    ///
+    /// # Returns
+    ///
+    /// A new `SharedSink` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::SharedSink::new(logging_rs::Output::STDOUT);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`SharedSink`]
+    /// - [`SharedSink::named()`]
+    pub fn new<S: Sink + 'static>(sink: S) -> SharedSink {
+        SharedSink {
+            name: None,
+            sink: Arc::new(sink)
+        }
+    }
+
+    /// Creates a new named shared sink object.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name used to send records to this sink only
+    /// - `sink`: The [`Sink`] to share
+    ///
+    /// # Returns
+    ///
+    /// A new `SharedSink` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::SharedSink::named("audit", logging_rs::Output::FILE { path: "audit.log".to_owned() });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`SharedSink`]
+    /// - [`crate::Logger::log_to()`]
+    pub fn named<S: Sink + 'static>(name: &str, sink: S) -> SharedSink {
+        SharedSink {
+            name: Some(name.to_owned()),
+            sink: Arc::new(sink)
+        }
+    }
+
+    /// Returns the address of the shared sink, used for comparisons.
+    fn address(&self) -> usize {
+        Arc::as_ptr(&self.sink) as *const () as usize
+    }
+}
+
+impl Sink for SharedSink {
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.sink.write(formatter, record);
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        self.sink.write_batch(formatter, records);
+    }
+
+    fn write_static(&self, formatter: &Formatter, level: Level, message: &'static str, path: &'static str) -> bool {
+        self.sink.write_static(formatter, level, message, path)
+    }
+
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        self.sink.encode(formatter, record)
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.sink.flush(timeout)
+    }
+
+    fn after_fork(&self) {
+        self.sink.after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        self.sink.save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        self.sink.restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sink.writes_to(output)
+    }
+}
+
+impl fmt::Debug for SharedSink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SharedSink")
+            .field("name", &self.name)
+            .field("sink", &format_args!("{:#x}", self.address()))
+            .finish()
+    }
+}
+
+/// Loggers are used across unwind boundaries, so shared sinks are treated as unwind safe. Sinks are expected to stay
+/// usable after a panic, for example by recovering poisoned locks.
+impl std::panic::RefUnwindSafe for SharedSink {}
+
+impl std::panic::UnwindSafe for SharedSink {}
+
+impl PartialEq for SharedSink {
+    fn eq(&self, other: &SharedSink) -> bool {
+        self.name == other.name && self.address() == other.address()
+    }
+}
+
+impl Eq for SharedSink {}
+
+impl Hash for SharedSink {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.address().hash(state);
+    }
+}
+
+impl PartialOrd for SharedSink {
+    fn partial_cmp(&self, other: &SharedSink) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SharedSink {
+    fn cmp(&self, other: &SharedSink) -> std::cmp::Ordering {
+        self.name.cmp(&other.name).then(self.address().cmp(&other.address()))
+    }
+}
+
+
+/////////
+// TEE //
+/////////
+
+/// Sink writing every record to multiple sinks.
+///
+/// Use [`Tee::new()`] or [`tee()`] to create tee objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Tee::new(vec![Box::new(logging_rs::Output::STDOUT), Box::new(logging_rs::Output::STDERR)]);
+/// ```
+pub struct Tee {
+    /// The sinks to write to.
+    sinks: Vec<Box<dyn Sink>>,
+}
+
+impl Tee {
+    /// Creates a new tee object.
+    ///
+    /// # Parameters
+    ///
+    /// - `sinks`: The sinks to write to
+    ///
+    /// # Returns
+    ///
+    /// A new `Tee` object writing to all given sinks in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Tee::new(vec![Box::new(logging_rs::Output::STDOUT)]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Tee`]
+    /// - [`tee()`]
+    pub fn new(sinks: Vec<Box<dyn Sink>>) -> Tee {
+        Tee { sinks }
+    }
+}
+
+impl Sink for Tee {
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        for sink in &self.sinks {
+            sink.write(formatter, record);
+        }
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        for sink in &self.sinks {
+            sink.write_batch(formatter, records);
+        }
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.sinks.iter().fold(Flushed::default(), |flushed, sink| flushed + sink.flush(timeout))
+    }
+
+    fn after_fork(&self) {
+        for sink in &self.sinks {
+            sink.after_fork();
+        }
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        let states: Vec<json::Value> = self.sinks.iter().map(|sink| sink.save_state().unwrap_or_default()).collect();
+        states.iter().any(|state| *state != json::Value::NULL).then_some(json::Value::ARRAY(states))
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        if let json::Value::ARRAY(states) = state {
+            for (sink, state) in self.sinks.iter().zip(states).filter(|(_, state)| **state != json::Value::NULL) {
+                sink.restore_state(state);
+            }
+        }
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sinks.iter().any(|sink| sink.writes_to(output))
+    }
+}
+
+
+//////////////
+// FILTERED //
+//////////////
+
+/// Sink only writing records matching a predicate.
+///
+/// Use [`Filtered::new()`] or [`filtered()`] to create filtered objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Filtered::new(logging_rs::Output::STDOUT, |record: &logging_rs::Record| record.path.starts_with("src/"));
+/// ```
+pub struct Filtered<S: Sink> {
+    /// The sink to write to.
+    sink: S,
+    /// The predicate records have to match.
+    predicate: Box<dyn Fn(&Record) -> bool + Send + Sync>,
+}
+
+impl<S: Sink> Filtered<S> {
+    /// Creates a new filtered object.
+    ///
+    /// # Parameters
+    ///
+    /// - `sink`: The sink to write to
+    /// - `predicate`: The predicate records have to match
+    ///
+    /// # Returns
+    ///
+    /// A new `Filtered` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Filtered::new(logging_rs::Output::STDOUT, |record: &logging_rs::Record| !record.message.is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Filtered`]
+    /// - [`filtered()`]
+    pub fn new<F: Fn(&Record) -> bool + Send + Sync + 'static>(sink: S, predicate: F) -> Filtered<S> {
+        Filtered {
+            sink,
+            predicate: Box::new(predicate)
+        }
+    }
+}
+
+impl<S: Sink> Sink for Filtered<S> {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        self.sink.encode(formatter, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        if (self.predicate)(record) {
+            self.sink.write(formatter, record);
+        }
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        let records: Vec<Record> = records.iter().filter(|record| (self.predicate)(record)).cloned().collect();
+        self.sink.write_batch(formatter, &records);
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.sink.flush(timeout)
+    }
+
+    fn after_fork(&self) {
+        self.sink.after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        self.sink.save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        self.sink.restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sink.writes_to(output)
+    }
+}
+
+
+/////////////
+// LEVELED //
+/////////////
+
+/// Sink only writing records with at least the given level.
+///
+/// Use [`Leveled::new()`] or [`leveled()`] to create leveled objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Leveled::new(logging_rs::Output::STDERR, logging_rs::Level::ERROR);
+/// ```
+pub struct Leveled<S: Sink> {
+    /// The sink to write to.
+    sink: S,
+    /// The minimum level.
+    level: Level,
+}
+
+impl<S: Sink> Leveled<S> {
+    /// Creates a new leveled object.
+    ///
+    /// # Parameters
+    ///
+    /// - `sink`: The sink to write to
+    /// - `level`: The minimum [`Level`] of written records
+    ///
+    /// # Returns
+    ///
+    /// A new `Leveled` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Leveled::new(logging_rs::Output::STDERR, logging_rs::Level::ERROR);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Leveled`]
+    /// - [`leveled()`]
+    pub fn new(sink: S, level: Level) -> Leveled<S> {
+        Leveled { sink, level }
+    }
+}
+
+impl<S: Sink> Sink for Leveled<S> {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        self.sink.encode(formatter, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        if record.level >= self.level {
+            self.sink.write(formatter, record);
+        }
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        let records: Vec<Record> = records.iter().filter(|record| record.level >= self.level).cloned().collect();
+        self.sink.write_batch(formatter, &records);
+    }
+
+    fn write_static(&self, formatter: &Formatter, level: Level, message: &'static str, path: &'static str) -> bool {
+        level < self.level || self.sink.write_static(formatter, level, message, path)
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.sink.flush(timeout)
+    }
+
+    fn after_fork(&self) {
+        self.sink.after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        self.sink.save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        self.sink.restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sink.writes_to(output)
+    }
+}
+
+
+////////////
+// MAPPED //
+////////////
+
+/// Sink transforming records before writing them.
+///
+/// Use [`Mapped::new()`] or [`mapped()`] to create mapped objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Mapped::new(logging_rs::Output::STDOUT, |mut record: logging_rs::Record| {
+///     record.message = record.message.to_uppercase();
+///     record
+/// });
+/// ```
+pub struct Mapped<S: Sink> {
+    /// The sink to write to.
+    sink: S,
+    /// The function transforming the records.
+    mapper: Box<dyn Fn(Record) -> Record + Send + Sync>,
+}
+
+impl<S: Sink> Mapped<S> {
+    /// Creates a new mapped object.
+    ///
+    /// # Parameters
+    ///
+    /// - `sink`: The sink to write to
+    /// - `mapper`: The function transforming the records
+    ///
+    /// # Returns
+    ///
+    /// A new `Mapped` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Mapped::new(logging_rs::Output::STDOUT, |record: logging_rs::Record| record);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Mapped`]
+    /// - [`mapped()`]
+    pub fn new<F: Fn(Record) -> Record + Send + Sync + 'static>(sink: S, mapper: F) -> Mapped<S> {
+        Mapped {
+            sink,
+            mapper: Box::new(mapper)
+        }
+    }
+}
+
+impl<S: Sink> Sink for Mapped<S> {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        self.sink.encode(formatter, &(self.mapper)(record.clone()))
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.sink.write(formatter, &(self.mapper)(record.clone()));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        let records: Vec<Record> = records.iter().map(|record| (self.mapper)(record.clone())).collect();
+        self.sink.write_batch(formatter, &records);
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.sink.flush(timeout)
+    }
+
+    fn after_fork(&self) {
+        self.sink.after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        self.sink.save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        self.sink.restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sink.writes_to(output)
+    }
+}
+
+
+///////////////
+// FORMATTED //
+///////////////
+
+/// Sink formatting records using its own formatter instead of the formatter of the logger.
+///
+/// Use [`Formatted::new()`] or [`formatted()`] to create formatted objects, for example to write human-readable local
+/// timestamps to the console and UTC timestamps to files.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Formatted::new(logging_rs::Output::STDOUT, logging_rs::Formatter::compact().with_time_zone(logging_rs::TimeZone::LOCAL));
+/// ```
+pub struct Formatted<S: Sink> {
+    /// The sink to write to.
+    sink: S,
+    /// The formatter used instead of the formatter of the logger.
+    formatter: Formatter,
+}
+
+impl<S: Sink> Formatted<S> {
+    /// Creates a new formatted object.
+    ///
+    /// # Parameters
+    ///
+    /// - `sink`: The sink to write to
+    /// - `formatter`: The [`Formatter`] used instead of the formatter of the logger
+    ///
+    /// # Returns
+    ///
+    /// A new `Formatted` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Formatted::new(logging_rs::Output::STDERR, logging_rs::Formatter::json_lines());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Formatted`]
+    /// - [`formatted()`]
+    pub fn new(sink: S, formatter: Formatter) -> Formatted<S> {
+        Formatted { sink, formatter }
+    }
+}
+
+impl<S: Sink> Sink for Formatted<S> {
+    fn encode(&self, _formatter: &Formatter, record: &Record) -> String {
+        self.sink.encode(&self.formatter, record)
+    }
+
+    fn write(&self, _formatter: &Formatter, record: &Record) {
+        self.sink.write(&self.formatter, record);
+    }
+
+    fn write_batch(&self, _formatter: &Formatter, records: &[Record]) {
+        self.sink.write_batch(&self.formatter, records);
+    }
+
+    fn write_static(&self, _formatter: &Formatter, level: Level, message: &'static str, path: &'static str) -> bool {
+        self.sink.write_static(&self.formatter, level, message, path)
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.sink.flush(timeout)
+    }
+
+    fn after_fork(&self) {
+        self.sink.after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        self.sink.save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        self.sink.restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sink.writes_to(output)
+    }
+}
+
+
+//////////////
+// BUDGETED //
+//////////////
+
+/// Behaviors for records exceeding the size budget of a [`Budgeted`] sink.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Overflow {
+    /// Shortens the longest values (message or arguments) until the record fits. The default value
+    #[default]
+    TRUNCATE,
+    /// Drops the record and writes a warning instead
+    DROP,
+    /// Splits the message into multiple records that fit
+    SPLIT,
+}
+
+/// Sink enforcing a maximum encoded record size.
+///
+/// Protects sinks with payload limits from oversized records. The size is measured in bytes of the record encoded
+/// by the wrapped sink.
+///
+/// Use [`Budgeted::new()`] or [`budgeted()`] to create budgeted objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Budgeted::new(logging_rs::Output::STDOUT, 8192, logging_rs::sinks::Overflow::TRUNCATE);
+/// ```
+pub struct Budgeted<S: Sink> {
+    /// The sink to write to.
+    sink: S,
+    /// The maximum encoded record size in bytes.
+    max_size: usize,
+    /// The behavior for records exceeding the budget.
+    overflow: Overflow,
+    /// The number of records dropped since the last flush.
+    dropped: AtomicUsize,
+}
+
+impl<S: Sink> Budgeted<S> {
+    /// Creates a new budgeted object.
+    ///
+    /// # Parameters
+    ///
+    /// - `sink`: The sink to write to
+    /// - `max_size`: The maximum encoded record size in bytes
+    /// - `overflow`: The [`Overflow`] behavior for records exceeding the budget
+    ///
+    /// # Returns
+    ///
+    /// A new `Budgeted` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Budgeted::new(logging_rs::Output::STDOUT, 1024, logging_rs::sinks::Overflow::SPLIT);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Budgeted`]
+    /// - [`budgeted()`]
+    pub fn new(sink: S, max_size: usize, overflow: Overflow) -> Budgeted<S> {
+        Budgeted {
+            sink,
+            max_size,
+            overflow,
+            dropped: AtomicUsize::new(0)
+        }
+    }
+
+    /// Checks whether the given record fits into the budget.
+    fn fits(&self, formatter: &Formatter, record: &Record) -> bool {
+        self.sink.encode(formatter, record).len() <= self.max_size
+    }
+
+    /// Writes a warning about the dropped record instead of the record.
+    fn drop_record(&self, formatter: &Formatter, record: &Record, size: usize) {
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+
+        let warning: Record = Record {
+            level: Level::WARN,
+            message: "Dropped record of {{size}} bytes exceeding the budget of {{max_size}} bytes".to_owned(),
+            path: record.path.clone(),
+            arguments: vec![("size".to_owned(), size.to_string()), ("max_size".to_owned(), self.max_size.to_string())],
+            attachments: vec![],
+            timestamp: record.timestamp,
+            id: ids::generate(record.timestamp)
+        };
+
+        if self.fits(formatter, &warning) {
+            self.sink.write(formatter, &warning);
+        }
+    }
+
+    /// Shortens the longest values of the record until it fits.
+    fn truncate(&self, formatter: &Formatter, record: &Record) -> Option<Record> {
+        let mut truncated: Record = record.clone();
+
+        loop {
+            let size: usize = self.sink.encode(formatter, &truncated).len();
+
+            if size <= self.max_size {
+                return Some(truncated);
+            }
+
+            let longest: &mut String = std::iter::once(&mut truncated.message)
+                .chain(truncated.arguments.iter_mut().map(|(_, value)| value))
+                .max_by_key(|value| value.len())?;
+
+            if longest.is_empty() {
+                return None;
+            }
+
+            let keep: usize = longest.len().saturating_sub(size - self.max_size + TRUNCATION_MARKER.len());
+            let boundary: usize = (0..=keep).rev().find(|index| longest.is_char_boundary(*index)).unwrap_or(0);
+            longest.truncate(boundary);
+
+            if boundary > 0 {
+                longest.push_str(TRUNCATION_MARKER);
+            }
+        }
+    }
+
+    /// Splits the message of the record into multiple records that fit.
+    fn split(&self, formatter: &Formatter, record: &Record) -> Option<Vec<Record>> {
+        let mut empty: Record = record.clone();
+        empty.message = String::new();
+
+        let overhead: usize = self.sink.encode(formatter, &empty).len();
+        let chunk_size: usize = self.max_size.checked_sub(overhead).filter(|size| *size > 0)?;
+        let mut records: Vec<Record> = vec![];
+        let mut chunk: String = String::new();
+
+        for character in record.message.chars() {
+            if chunk.len() + character.len_utf8() > chunk_size {
+                let mut part: Record = empty.clone();
+                part.message = std::mem::take(&mut chunk);
+                records.push(part);
+            }
+
+            chunk.push(character);
+        }
+
+        let mut part: Record = empty;
+        part.message = chunk;
+        records.push(part);
+
+        Some(records)
+    }
+}
+
+/// Marker appended to truncated values.
+const TRUNCATION_MARKER: &str = "...";
+
+impl<S: Sink> Sink for Budgeted<S> {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        self.sink.encode(formatter, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        let size: usize = self.sink.encode(formatter, record).len();
+
+        if size <= self.max_size {
+            return self.sink.write(formatter, record);
+        }
+
+        match self.overflow {
+            Overflow::TRUNCATE => match self.truncate(formatter, record) {
+                Some(truncated) => self.sink.write(formatter, &truncated),
+                None => self.drop_record(formatter, record, size)
+            },
+            Overflow::DROP => self.drop_record(formatter, record, size),
+            Overflow::SPLIT => match self.split(formatter, record) {
+                Some(parts) => {
+                    for part in parts {
+                        self.sink.write(formatter, &part);
+                    }
+                },
+                None => self.drop_record(formatter, record, size)
+            }
+        }
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        let flushed: Flushed = self.sink.flush(timeout);
+        Flushed::new(flushed.flushed, flushed.dropped + self.dropped.swap(0, Ordering::Relaxed))
+    }
+
+    fn after_fork(&self) {
+        self.dropped.store(0, Ordering::Relaxed);
+        self.sink.after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        self.sink.save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        self.sink.restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sink.writes_to(output)
+    }
+}
+
+
+///////////////
+// COALESCED //
+///////////////
+
+/// State of a [`Coalesced`] sink.
+struct CoalescedState {
+    /// The start of the current window.
+    start: Instant,
+    /// The number of records received in the current window.
+    count: usize,
+    /// The first suppressed record of every template and the number of suppressed records.
+    suppressed: Vec<(Record, usize)>,
+    /// The number of suppressed records dropped because they exceeded the memory budget.
+    rejected: usize,
+    /// The formatter of the logger, used for writing summaries.
+    formatter: Option<Formatter>,
+}
+
+/// Sink grouping repeated records during bursts.
+///
+/// Once more than `max_rate` records are written within a window (one second by default), further records are
+/// suppressed and grouped by level, path and unformatted message. When the window ends, one summary per group is
+/// written: the first suppressed record with ` ({{coalesced}} similar records coalesced)` appended to its message.
+/// Meant for keeping interactive terminals usable during event storms.
+///
+/// Summaries are written with the next record after the window ended, using [`Sink::flush()`] or when the sink is
+/// dropped. Flushing reports the summaries as flushed and all other coalesced records as dropped.
+///
+/// Use [`Coalesced::new()`] or [`coalesced()`] to create coalesced objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Coalesced::new(logging_rs::Output::STDOUT, 100);
+/// ```
+pub struct Coalesced<S: Sink> {
+    /// The sink to write to.
+    sink: S,
+    /// The maximum number of records written per window.
+    max_rate: usize,
+    /// The length of a window.
+    window: Duration,
+    /// The state of the current window.
+    state: Mutex<CoalescedState>,
+    /// The memory budget of the suppressed records.
+    memory: memory::Budget,
+}
+
+impl<S: Sink> Coalesced<S> {
+    /// Creates a new coalesced object with a window of one second.
+    ///
+    /// # Parameters
+    ///
+    /// - `sink`: The sink to write to
+    /// - `max_rate`: The maximum number of records per second written before coalescing
+    ///
+    /// # Returns
+    ///
+    /// A new `Coalesced` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Coalesced::new(logging_rs::Output::STDERR, 50);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Coalesced`]
+    /// - [`coalesced()`]
+    pub fn new(sink: S, max_rate: usize) -> Coalesced<S> {
+        Coalesced {
+            sink,
+            max_rate,
+            window: Duration::from_secs(1),
+            state: Mutex::new(CoalescedState {
+                start: crate::now(),
+                count: 0,
+                suppressed: vec![],
+                rejected: 0,
+                formatter: None
+            }),
+            memory: memory::Budget::default()
+        }
+    }
+
+    /// Sets the length of the window.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The coalesced object
+    /// - `window`: The length of the window
+    ///
+    /// # Returns
+    ///
+    /// The coalesced object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Coalesced::new(logging_rs::Output::STDOUT, 500).with_window(std::time::Duration::from_secs(5));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Coalesced`]
+    pub fn with_window(mut self, window: Duration) -> Coalesced<S> {
+        self.window = window;
+        self
+    }
+
+    /// Sets the maximum number of bytes the suppressed records may use.
+    ///
+    /// Suppressed records starting a new group are dropped if they don't fit into the limit. Flushing reports them
+    /// as dropped.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The coalesced object
+    /// - `limit`: The maximum number of bytes, see [`Record::memory_size()`]
+    ///
+    /// # Returns
+    ///
+    /// The coalesced object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Coalesced::new(logging_rs::Output::STDOUT, 100).with_memory_limit(1024 * 1024);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Coalesced::memory()`]
+    /// - [`memory::Budget`]
+    pub fn with_memory_limit(self, limit: usize) -> Coalesced<S> {
+        self.memory.set_limit(limit);
+        self
+    }
+
+    /// Returns the memory usage statistics of the suppressed records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The coalesced object
+    ///
+    /// # Returns
+    ///
+    /// The [`memory::MemoryStats`] of the suppressed records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let sink: logging_rs::sinks::Coalesced<logging_rs::Output> = logging_rs::sinks::coalesced(logging_rs::Output::STDOUT, 100);
+    /// assert_eq!(sink.memory().used, 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Coalesced::with_memory_limit()`]
+    pub fn memory(&self) -> memory::MemoryStats {
+        self.memory.stats()
+    }
+
+    /// Writes the summaries of the suppressed records of the given state and resets it.
+    fn flush_state(&self, state: &mut CoalescedState) -> Flushed {
+        state.start = crate::now();
+        state.count = 0;
+
+        let formatter: Option<Formatter> = state.formatter.take();
+        let mut flushed: Flushed = Flushed::new(0, std::mem::take(&mut state.rejected));
+
+        for (mut record, count) in std::mem::take(&mut state.suppressed) {
+            self.memory.release(record.memory_size());
+            record.message.push_str(" ({{coalesced}} similar records coalesced)");
+            record.arguments.push(("coalesced".to_owned(), count.to_string()));
+
+            if let Some(formatter) = &formatter {
+                self.sink.write(formatter, &record);
+                flushed.flushed += 1;
+                flushed.dropped += count - 1;
+            }
+        }
+
+        flushed
+    }
+}
+
+impl<S: Sink> Sink for Coalesced<S> {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        self.sink.encode(formatter, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+
+        if crate::now().saturating_duration_since(state.start) >= self.window {
+            self.flush_state(&mut state);
+        }
+
+        state.count += 1;
+
+        if state.count <= self.max_rate {
+            return self.sink.write(formatter, record);
+        }
+
+        if state.formatter.is_none() {
+            state.formatter = Some(formatter.clone());
+        }
+
+        match state.suppressed.iter_mut().find(|(first, _)| first.level == record.level && first.message == record.message && first.path == record.path) {
+            Some((_, count)) => *count += 1,
+            None if self.memory.reserve(record.memory_size()) => state.suppressed.push((record.clone(), 1)),
+            None => state.rejected += 1
+        }
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+        self.flush_state(&mut state) + self.sink.flush(timeout)
+    }
+
+    fn after_fork(&self) {
+        if let Ok(mut state) = self.state.try_lock() {
+            state.start = crate::now();
+            state.count = 0;
+            state.rejected = 0;
+            state.suppressed.clear();
+            self.memory.release(self.memory.stats().used);
+        }
+
+        self.sink.after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        self.sink.save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        self.sink.restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sink.writes_to(output)
+    }
+}
+
+impl<S: Sink> Drop for Coalesced<S> {
+    fn drop(&mut self) {
+        Sink::flush(self, Duration::ZERO);
+    }
+}
+
+
+/////////////
+// SAMPLED //
+/////////////
+
+/// The maximum number of keys a [`Sampled`] sink counts separately by default.
+pub const SAMPLED_MAX_KEYS: usize = 10_000;
+
+/// State of a [`Sampled`] sink.
+struct SampledState {
+    start: Instant,
+    counts: HashMap<String, usize>,
+}
+
+/// Sink sampling records of frequent keys while keeping all records of rare keys.
+///
+/// Records are counted per value of the key argument, like an endpoint, within a window (one second by default). The
+/// first `threshold` records of every key per window are written, the `n`-th record after that with a probability of
+/// `threshold / n`. Rare keys stay fully visible, while the volume of frequent keys grows only logarithmically.
+/// Sampled records get a `sample_rate` argument with the probability they were kept with.
+///
+/// Records without the key argument are always written. Once more than the maximum number of keys (see
+/// [`SAMPLED_MAX_KEYS`]) were counted in a window, new keys share a single count, bounding the memory usage for keys
+/// with a high cardinality. Flushing reports the records not written as dropped.
+///
+/// Use [`Sampled::new()`] or [`sampled()`] to create sampled objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::Sampled::new(logging_rs::Output::STDOUT, "endpoint", 10);
+/// ```
+pub struct Sampled<S: Sink> {
+    /// The sink to write to.
+    sink: S,
+    /// The name of the argument records are counted by.
+    key: String,
+    /// The number of records of every key written per window before sampling.
+    threshold: usize,
+    /// The length of a window.
+    window: Duration,
+    /// The maximum number of keys counted separately.
+    max_keys: usize,
+    /// The counts of the current window.
+    state: Mutex<SampledState>,
+    /// The number of records not written since the last flush.
+    dropped: AtomicUsize,
+}
+
+impl<S: Sink> Sampled<S> {
+    /// Creates a new sampled object with a window of one second.
+    ///
+    /// # Parameters
+    ///
+    /// - `sink`: The sink to write to
+    /// - `key`: The name of the argument records are counted by
+    /// - `threshold`: The number of records of every key written per window before sampling
+    ///
+    /// # Returns
+    ///
+    /// A new `Sampled` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Sampled::new(logging_rs::Output::STDOUT, "endpoint", 10);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Sampled`]
+    /// - [`sampled()`]
+    pub fn new(sink: S, key: &str, threshold: usize) -> Sampled<S> {
+        Sampled {
+            sink,
+            key: key.to_owned(),
+            threshold,
+            window: Duration::from_secs(1),
+            max_keys: SAMPLED_MAX_KEYS,
+            state: Mutex::new(SampledState {
+                start: crate::now(),
+                counts: HashMap::new()
+            }),
+            dropped: AtomicUsize::new(0)
+        }
+    }
+
+    /// Sets the length of the windows records are counted in.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sampled object
+    /// - `window`: The length of a window
+    ///
+    /// # Returns
+    ///
+    /// The sampled object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Sampled::new(logging_rs::Output::STDOUT, "endpoint", 100).with_window(std::time::Duration::from_secs(60));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Sampled`]
+    pub fn with_window(mut self, window: Duration) -> Sampled<S> {
+        self.window = window;
+        self
+    }
+
+    /// Sets the maximum number of keys counted separately per window.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The sampled object
+    /// - `max_keys`: The maximum number of keys
+    ///
+    /// # Returns
+    ///
+    /// The sampled object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Sampled::new(logging_rs::Output::STDOUT, "user", 5).with_max_keys(1000);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`SAMPLED_MAX_KEYS`]
+    pub fn with_max_keys(mut self, max_keys: usize) -> Sampled<S> {
+        self.max_keys = max_keys;
+        self
+    }
+
+    /// Counts the given record, returning the probability it is kept with, or `None` if it is not sampled.
+    fn count(&self, record: &Record) -> Option<usize> {
+        let key: &str = record.argument(&self.key)?;
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+
+        if crate::now().saturating_duration_since(state.start) >= self.window {
+            state.start = crate::now();
+            state.counts.clear();
+        }
+
+        let key: String = if state.counts.len() < self.max_keys || state.counts.contains_key(key) {
+            key.to_owned()
+        } else {
+            String::new()
+        };
+
+        let count: &mut usize = state.counts.entry(key).or_insert(0);
+        *count += 1;
+
+        Some(*count)
+    }
+}
+
+impl<S: Sink> Sink for Sampled<S> {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        self.sink.encode(formatter, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        let count: usize = match self.count(record) {
+            Some(count) if count > self.threshold => count,
+            _ => return self.sink.write(formatter, record)
+        };
+
+        if random::next_u64() % count as u64 >= self.threshold as u64 {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+
+        let mut sampled: Record = record.clone();
+        sampled.arguments.push(("sample_rate".to_owned(), format!("{:.6}", self.threshold as f64 / count as f64)));
+        self.sink.write(formatter, &sampled);
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        let flushed: Flushed = self.sink.flush(timeout);
+        Flushed::new(flushed.flushed, flushed.dropped + self.dropped.swap(0, Ordering::Relaxed))
+    }
+
+    fn after_fork(&self) {
+        if let Ok(mut state) = self.state.try_lock() {
+            state.start = crate::now();
+            state.counts.clear();
+        }
+
+        self.dropped.store(0, Ordering::Relaxed);
+        self.sink.after_fork();
+    }
+
+    fn save_state(&self) -> Option<json::Value> {
+        self.sink.save_state()
+    }
+
+    fn restore_state(&self, state: &json::Value) {
+        self.sink.restore_state(state);
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.sink.writes_to(output)
+    }
+}
+
+
+/////////
+// CSV //
+/////////
+
+/// Sink writing records as comma-separated values to an output.
+///
+/// Every record is written as a single row with the configured columns. The columns `timestamp` (RFC 3339), `level`,
+/// `path`, `message` (with the arguments inserted), `uuid` and `ulid` are taken from the record, other columns from
+/// the arguments of the same name, staying empty if the record has no such argument. Values containing the
+/// delimiter, quotes or line breaks are quoted, doubling the quotes inside, as described in RFC 4180. ANSI escape
+/// codes, like colors in the message, are removed. See [`Formatter::csv()`] for a formatter writing the same rows.
+///
+/// With a header, the column names are written before the first row, unless the file output already contains rows.
+///
+/// # Parameters
+///
+/// - `output`: The [`Output`] to write to
+/// - `columns`: The names of the columns, in order
+/// - `delimiter`: The delimiter between values
+/// - `header`: Whether to write the column names before the first row
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::sinks::Csv::new(logging_rs::Output::STDOUT).with_columns(&["timestamp", "level", "user", "message"]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Csv {
+    /// The output to write to
+    pub output: Output,
+    /// The names of the columns, in order
+    pub columns: Vec<String>,
+    /// The delimiter between values
+    pub delimiter: char,
+    /// Whether to write the column names before the first row
+    pub header: bool,
+    /// Whether the first row was written.
+    started: Arc<AtomicBool>,
+}
+
+impl Default for Csv {
+    fn default() -> Csv {
+        Csv::new(Output::default())
+    }
+}
+
+impl Csv {
+    /// Creates a new CSV sink object writing the `timestamp`, `level`, `path` and `message` columns separated by
+    /// commas, with a header.
+    ///
+    /// # Parameters
+    ///
+    /// - `output`: The [`Output`] to write to
+    ///
+    /// # Returns
+    ///
+    /// A new `Csv` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Csv::new(logging_rs::Output::FILE { path: "app.csv".to_owned() });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Csv`]
+    pub fn new(output: Output) -> Csv {
+        Csv {
+            output,
+            columns: ["timestamp", "level", "path", "message"].iter().map(|column| column.to_string()).collect(),
+            delimiter: ',',
+            header: true,
+            started: Arc::new(AtomicBool::new(false))
+        }
+    }
+
+    /// Sets the columns of the rows.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The CSV sink object
+    /// - `columns`: The names of the columns, in order
+    ///
+    /// # Returns
+    ///
+    /// The CSV sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let csv: logging_rs::sinks::Csv = logging_rs::sinks::Csv::new(logging_rs::Output::STDOUT).with_columns(&["level", "status", "message"]);
+    /// assert_eq!(csv.header_row(), "level,status,message");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Csv`]
+    pub fn with_columns(mut self, columns: &[&str]) -> Csv {
+        self.columns = columns.iter().map(|column| column.to_string()).collect();
+        self
+    }
+
+    /// Sets the delimiter between values, like `;` for spreadsheets using decimal commas or `\t` for TSV.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The CSV sink object
+    /// - `delimiter`: The delimiter
+    ///
+    /// # Returns
+    ///
+    /// The CSV sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Csv::new(logging_rs::Output::STDOUT).with_delimiter(';');
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Csv`]
+    pub fn with_delimiter(mut self, delimiter: char) -> Csv {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether to write the column names before the first row.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The CSV sink object
+    /// - `header`: Whether to write the header
+    ///
+    /// # Returns
+    ///
+    /// The CSV sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Csv::new(logging_rs::Output::STDOUT).with_header(false);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Csv`]
+    pub fn with_header(mut self, header: bool) -> Csv {
+        self.header = header;
+        self
+    }
+
+    /// Returns the header row containing the column names.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The CSV sink object
+    ///
+    /// # Returns
+    ///
+    /// The quoted column names separated by the delimiter, without a line break.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::sinks::Csv::new(logging_rs::Output::STDOUT).header_row(), "timestamp,level,path,message");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Csv`]
+    pub fn header_row(&self) -> String {
+        self.row(self.columns.iter().map(String::as_str))
+    }
+
+    /// Joins the given values, quoting them if needed.
+    fn row<'a, I: Iterator<Item = &'a str>>(&self, values: I) -> String {
+        values.map(|value| {
+            if !value.chars().any(|character| character == self.delimiter || character == '"' || character == '\n' || character == '\r') {
+                return value.to_owned();
+            }
+
+            format!("\"{}\"", value.replace('"', "\"\""))
+        }).collect::<Vec<String>>().join(&self.delimiter.to_string())
+    }
+
+    /// Returns the header row followed by a line break if it has to be written before the next row.
+    fn start(&self) -> String {
+        if !self.header || self.started.swap(true, Ordering::SeqCst) {
+            return String::new();
+        }
+
+        if let Output::FILE { path } = &self.output {
+            if std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+                return String::new();
+            }
+        }
+
+        self.header_row() + "\n"
+    }
+}
+
+impl Sink for Csv {
+    fn encode(&self, _formatter: &Formatter, record: &Record) -> String {
+        let values: Vec<String> = self.columns.iter().map(|column| match column.as_str() {
+            "timestamp" => record.timestamp.to_rfc3339(),
+            "level" => record.level.name().to_owned(),
+            "path" => record.path.clone(),
+            "message" => crate::strip_ansi(&Formatter::new("", "{{message}}", "").format_record_with(false, record)),
+            "uuid" => ids::to_uuid(record.id),
+            "ulid" => ids::to_ulid(record.id),
+            column => record.argument(column).unwrap_or_default().to_owned()
+        }).collect();
+
+        self.row(values.iter().map(String::as_str))
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.output.write_text(&(self.start() + &self.encode(formatter, record) + "\n"));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if records.is_empty() {
+            return;
+        }
+
+        let text: String = records.iter().map(|record| self.encode(formatter, record) + "\n").collect();
+        self.output.write_text(&(self.start() + &text));
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.output.flush(timeout)
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.output == *output
+    }
+}
+
+
+//////////
+// JSON //
+//////////
+
+/// Environment variable enabling pretty printing of [`Json`] sinks created using [`Json::from_environment()`].
+///
+/// Pretty printing is enabled if the variable is set to `1` or `true`.
+pub const PRETTY_ENVIRONMENT_VARIABLE: &str = "LOGGING_RS_JSON_PRETTY";
+
+/// Sink writing records as JSON objects to an output.
+///
+/// Records are written as compact JSON, one per line, by default. For development, pretty printing writes indented
+/// multi-line JSON with alphabetically ordered keys instead.
+///
+/// # Parameters
+///
+/// - `output`: The [`Output`] to write to
+/// - `pretty`: Whether to write indented JSON
+/// - `order`: The [`json::KeyOrder`] of the keys
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::sinks::Json::new(logging_rs::Output::STDOUT).with_pretty(cfg!(debug_assertions)));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct Json {
+    /// The output to write to
+    pub output: Output,
+    /// Whether to write indented JSON
+    pub pretty: bool,
+    /// The order of the keys
+    pub order: json::KeyOrder,
+}
+
+impl Json {
+    /// Creates a new JSON sink object writing compact JSON.
+    ///
+    /// # Parameters
+    ///
+    /// - `output`: The [`Output`] to write to
+    ///
+    /// # Returns
+    ///
+    /// A new `Json` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Json::new(logging_rs::Output::FILE { path: "app.jsonl".to_owned() });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Json`]
+    /// - [`Json::from_environment()`]
+    pub fn new(output: Output) -> Json {
+        Json {
+            output,
+            pretty: false,
+            order: json::KeyOrder::INSERTION
+        }
+    }
+
+    /// Creates a new JSON sink object, pretty printing if [`PRETTY_ENVIRONMENT_VARIABLE`] is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `output`: The [`Output`] to write to
+    ///
+    /// # Returns
+    ///
+    /// A new `Json` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// std::env::set_var("LOGGING_RS_JSON_PRETTY", "1");
+    /// assert!(logging_rs::sinks::Json::from_environment(logging_rs::Output::STDOUT).pretty);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Json`]
+    /// - [`Json::new()`]
+    pub fn from_environment(output: Output) -> Json {
+        let pretty: bool = std::env::var(PRETTY_ENVIRONMENT_VARIABLE).is_ok_and(|value| value == "1" || value.eq_ignore_ascii_case("true"));
+        Json::new(output).with_pretty(pretty)
+    }
+
+    /// Sets whether to write indented JSON.
+    ///
+    /// Enabling pretty printing also orders the keys alphabetically. Use [`Json::with_order()`] afterwards to choose
+    /// another order.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The JSON sink object
+    /// - `pretty`: Whether to pretty print
+    ///
+    /// # Returns
+    ///
+    /// The JSON sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Json::new(logging_rs::Output::STDOUT).with_pretty(true);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Json`]
+    pub fn with_pretty(mut self, pretty: bool) -> Json {
+        self.pretty = pretty;
+
+        if pretty {
+            self.order = json::KeyOrder::ALPHABETICAL;
+        }
+
+        self
+    }
+
+    /// Sets the order of the keys.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The JSON sink object
+    /// - `order`: The [`json::KeyOrder`]
+    ///
+    /// # Returns
+    ///
+    /// The JSON sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Json::new(logging_rs::Output::STDOUT).with_order(logging_rs::json::KeyOrder::PRIORITY {
+    ///     keys: vec!["timestamp".to_owned(), "level".to_owned(), "message".to_owned()]
+    /// });
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Json`]
+    pub fn with_order(mut self, order: json::KeyOrder) -> Json {
+        self.order = order;
+        self
+    }
+}
+
+impl Sink for Json {
+    fn encode(&self, _formatter: &Formatter, record: &Record) -> String {
+        let mut value: json::Value = json::Value::from(record);
+        self.order.apply(&mut value);
+
+        if self.pretty {
+            value.to_pretty()
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.output.write_text(&(self.encode(formatter, record) + "\n"));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if records.is_empty() {
+            return;
+        }
+
+        let text: String = records.iter().map(|record| self.encode(formatter, record) + "\n").collect();
+        self.output.write_text(&text);
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.output.flush(timeout)
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.output == *output
+    }
+}
+
+
+////////////
+// LOGFMT //
+////////////
+
+/// Sink writing records in logfmt format to an output.
+///
+/// Every record is written as a single line of `key=value` pairs, starting with the `timestamp` (RFC 3339), `level`,
+/// `path` and `message` (with the arguments inserted) followed by the arguments of the record. Nested arguments keep
+/// their dotted keys, see [`value::ToFields`](crate::value::ToFields). Values containing spaces, quotes, `=` or
+/// control characters are quoted. ANSI escape codes, like colors in the message, are removed.
+///
+/// # Parameters
+///
+/// - `output`: The [`Output`] to write to
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::sinks::Logfmt::new(logging_rs::Output::STDOUT));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Logfmt {
+    /// The output to write to
+    pub output: Output,
+}
+
+impl Logfmt {
+    /// Creates a new logfmt sink object.
+    ///
+    /// # Parameters
+    ///
+    /// - `output`: The [`Output`] to write to
+    ///
+    /// # Returns
+    ///
+    /// A new `Logfmt` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Logfmt::new(logging_rs::Output::STDERR);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logfmt`]
+    pub fn new(output: Output) -> Logfmt {
+        Logfmt { output }
+    }
+}
+
+/// Quotes the given logfmt value if needed.
+fn logfmt_value(value: &str) -> String {
+    if !value.is_empty() && !value.chars().any(|character| character == ' ' || character == '=' || character == '"' || character.is_control()) {
+        return value.to_owned();
+    }
+
+    let mut result: String = String::with_capacity(value.len() + 2);
+    result.push('"');
+
+    for character in value.chars() {
+        match character {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            character if character.is_control() => result.push_str(&format!("\\u{:04x}", character as u32)),
+            character => result.push(character)
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+impl Sink for Logfmt {
+    fn encode(&self, _formatter: &Formatter, record: &Record) -> String {
+        let message: String = crate::strip_ansi(&Formatter::new("", "{{message}}", "").format_record_with(false, record));
+        let mut pairs: Vec<String> = vec![
+            format!("timestamp={}", record.timestamp.to_rfc3339()),
+            format!("level={}", record.level.name()),
+            format!("path={}", logfmt_value(&record.path)),
+            format!("message={}", logfmt_value(&message))
+        ];
+
+        for (key, value) in &record.arguments {
+            let key: String = key.chars().map(|character| if character == ' ' || character == '=' || character == '"' || character.is_control() { '_' } else { character }).collect();
+            pairs.push(format!("{}={}", key, logfmt_value(value)));
+        }
+
+        pairs.join(" ")
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.output.write_text(&(self.encode(formatter, record) + "\n"));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if records.is_empty() {
+            return;
+        }
+
+        let text: String = records.iter().map(|record| self.encode(formatter, record) + "\n").collect();
+        self.output.write_text(&text);
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.output.flush(timeout)
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.output == *output
+    }
+}
+
+
+///////////
+// PLAIN //
+///////////
+
+/// Sink writing to an output without colors.
+///
+/// Records are formatted using the plain format string of the formatter, even for stdout and stderr, for example for
+/// terminals without color support or when the output is captured.
+///
+/// # Parameters
+///
+/// - `output`: The [`Output`] to write to
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::sinks::Plain::new(logging_rs::Output::STDOUT));
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Plain {
+    /// The output to write to
+    pub output: Output,
+}
+
+impl Plain {
+    /// Creates a new plain sink object.
+    ///
+    /// # Parameters
+    ///
+    /// - `output`: The [`Output`] to write to
+    ///
+    /// # Returns
+    ///
+    /// A new `Plain` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Plain::new(logging_rs::Output::STDERR);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Plain`]
+    pub fn new(output: Output) -> Plain {
+        Plain { output }
+    }
+}
+
+impl Sink for Plain {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        formatter.format_record_with(false, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.output.write_text(&(self.encode(formatter, record) + "\n"));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if records.is_empty() {
+            return;
+        }
+
+        let text: String = records.iter().map(|record| self.encode(formatter, record) + "\n").collect();
+        self.output.write_text(&text);
+    }
+
+    fn flush(&self, timeout: Duration) -> Flushed {
+        self.output.flush(timeout)
+    }
+
+    fn writes_to(&self, output: &Output) -> bool {
+        self.output == *output
+    }
+}
+
+
+////////////
+// RECENT //
+////////////
+
+/// Sink keeping the most recent records in a ring buffer.
+///
+/// Meant for crash reporting: the buffered records can be exported as JSON array and bundled with a crash report, for
+/// example as minidump attachment. Add the sink wrapped in an [`Arc`] to keep a handle for exporting the records.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let recent: std::sync::Arc<logging_rs::sinks::Recent> = std::sync::Arc::new(logging_rs::sinks::Recent::new(100));
+///
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logger.add_sink(recent.clone());
+///
+/// std::panic::set_hook(Box::new(move |_info| {
+///     let attachment: Vec<u8> = recent.to_bytes(); // Attach to the crash report
+/// }));
+/// # let _ = std::panic::take_hook();
+/// ```
+#[derive(Debug, Default)]
+pub struct Recent {
+    /// The maximum number of buffered records.
+    capacity: usize,
+    /// The buffered records, oldest first.
+    records: Mutex<VecDeque<Record>>,
+}
+
+impl Recent {
+    /// Creates a new recent records sink object.
+    ///
+    /// # Parameters
+    ///
+    /// - `capacity`: The maximum number of buffered records, older records are dropped
+    ///
+    /// # Returns
+    ///
+    /// A new `Recent` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Recent::new(100);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Recent`]
+    pub fn new(capacity: usize) -> Recent {
+        Recent {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity))
+        }
+    }
+
+    /// Returns the buffered records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The recent records sink object
+    ///
+    /// # Returns
+    ///
+    /// The buffered records, oldest first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// use logging_rs::sinks::Sink;
+    ///
+    /// let recent: logging_rs::sinks::Recent = logging_rs::sinks::Recent::new(1);
+    /// recent.write(&logging_rs::Formatter::default(), &logging_rs::Record::new(logging_rs::Level::INFO, "First", "src/main.rs", vec![]));
+    /// recent.write(&logging_rs::Formatter::default(), &logging_rs::Record::new(logging_rs::Level::INFO, "Second", "src/main.rs", vec![]));
+    ///
+    /// assert_eq!(recent.records()[0].message, "Second");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Recent::to_json()`]
+    pub fn records(&self) -> Vec<Record> {
+        self.records.lock().unwrap_or_else(|error| error.into_inner()).iter().cloned().collect()
+    }
+
+    /// Exports the buffered records as JSON array.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The recent records sink object
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing a JSON array of the buffered records, oldest first, see [`json::Value`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::sinks::Recent::new(100).to_json(), "[]");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Recent::to_bytes()`]
+    pub fn to_json(&self) -> String {
+        let records = self.records.lock().unwrap_or_else(|error| error.into_inner());
+        json::Value::ARRAY(records.iter().map(json::Value::from).collect()).to_string()
+    }
+
+    /// Exports the buffered records as UTF-8 encoded JSON array for attaching them to crash reports.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The recent records sink object
+    ///
+    /// # Returns
+    ///
+    /// The bytes of [`Recent::to_json()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::sinks::Recent::new(100).to_bytes(), b"[]");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Recent::to_json()`]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_json().into_bytes()
+    }
+
+    /// Removes all buffered records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The recent records sink object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Recent::new(100).clear();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Recent`]
+    pub fn clear(&self) {
+        self.records.lock().unwrap_or_else(|error| error.into_inner()).clear();
+    }
+}
+
+impl Sink for Recent {
+    fn write(&self, _formatter: &Formatter, record: &Record) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut records = self.records.lock().unwrap_or_else(|error| error.into_inner());
+
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+
+        records.push_back(record.clone());
+    }
+}
+
+
+//////////////
+// ROTATING //
+//////////////
+
+/// Sink writing to a file that is rotated once it reaches a maximum size.
+///
+/// Rotated files are renamed to `<path>.1`, `<path>.2` and so on, newest first, and compressed using the codec of
+/// the sink, see [`codec::archive()`]. Files beyond the maximum number of rotated files are removed. Records are
+/// formatted like for [`Output::FILE`].
+///
+/// Use [`Rotating::new()`] to create rotating sink objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::sinks::Rotating::new("app.log", 10 * 1024 * 1024, 5));
+/// ```
+pub struct Rotating {
+    /// The path of the current file
+    pub path: String,
+    /// The maximum size of the current file in bytes
+    pub max_size: u64,
+    /// The maximum number of rotated files to keep
+    pub max_files: usize,
+    /// The codec compressing rotated files
+    pub codec: Arc<dyn codec::Codec>,
+    /// Lock serializing writes and rotations.
+    lock: Mutex<()>,
+}
+
+impl fmt::Debug for Rotating {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_struct("Rotating")
+            .field("path", &self.path)
+            .field("max_size", &self.max_size)
+            .field("max_files", &self.max_files)
+            .field("codec", &self.codec.name())
+            .finish()
+    }
+}
+
+impl Rotating {
+    /// Creates a new rotating sink object keeping rotated files uncompressed.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the current file
+    /// - `max_size`: The maximum size of the current file in bytes
+    /// - `max_files`: The maximum number of rotated files to keep
+    ///
+    /// # Returns
+    ///
+    /// A new `Rotating` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Rotating::new("app.log", 10 * 1024 * 1024, 5);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Rotating::with_codec()`]
+    pub fn new(path: &str, max_size: u64, max_files: usize) -> Rotating {
+        Rotating {
+            path: path.to_owned(),
+            max_size,
+            max_files,
+            codec: Arc::new(codec::Identity),
+            lock: Mutex::new(())
+        }
+    }
+
+    /// Sets the codec compressing rotated files.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The rotating sink object
+    /// - `codec`: The [`codec::Codec`] to use
+    ///
+    /// # Returns
+    ///
+    /// The rotating sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Rotating::new("app.log", 10 * 1024 * 1024, 5).with_codec(logging_rs::codec::Identity);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`codec::from_name()`]
+    pub fn with_codec<C: codec::Codec + 'static>(mut self, codec: C) -> Rotating {
+        self.codec = Arc::new(codec);
+        self
+    }
+
+    /// Returns the path of the rotated file with the given index.
+    fn rotated(&self, index: usize) -> String {
+        if self.codec.extension().is_empty() {
+            format!("{}.{}", self.path, index)
+        } else {
+            format!("{}.{}.{}", self.path, index, self.codec.extension())
+        }
+    }
+
+    /// Rotates the current file, shifting and removing older rotated files.
+    fn rotate(&self) {
+        if self.max_files == 0 {
+            drop(std::fs::remove_file(&self.path));
+            return;
+        }
+
+        drop(std::fs::remove_file(self.rotated(self.max_files)));
+
+        for index in (1..self.max_files).rev() {
+            drop(std::fs::rename(self.rotated(index), self.rotated(index + 1)));
+        }
+
+        let first: String = format!("{}.1", self.path);
+
+        if let Err(error) = std::fs::rename(&self.path, &first).map_err(|error| error.to_string()).and_then(|_| {
+            codec::archive(&first, self.codec.as_ref()).map(drop).map_err(|error| error.description)
+        }) {
+            errors::Error::new("File error", "The file could not be rotated", 1).report(format!("Path: {}\nError: {}", self.path, error).as_str());
+        }
+    }
+
+    /// Writes the given text, rotating the current file first if the text does not fit.
+    fn write_text(&self, text: &str) {
+        let _guard = self.lock.lock().unwrap_or_else(|error| error.into_inner());
+        let size: u64 = std::fs::metadata(&self.path).map(|metadata| metadata.len()).unwrap_or(0);
+
+        if size > 0 && size + text.len() as u64 > self.max_size && !sandbox::is_enabled() {
+            self.rotate();
+        }
+
+        Output::FILE { path: self.path.clone() }.write_text(text);
+    }
+}
+
+impl Sink for Rotating {
+    fn encode(&self, formatter: &Formatter, record: &Record) -> String {
+        formatter.format_record_with(false, record)
+    }
+
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.write_text(&(self.encode(formatter, record) + "\n"));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if records.is_empty() {
+            return;
+        }
+
+        let text: String = records.iter().map(|record| self.encode(formatter, record) + "\n").collect();
+        self.write_text(&text);
+    }
+}
+
+
+/////////
+// UDP //
+/////////
+
+/// Sink sending every formatted record as a single UDP datagram, for example to a syslog server.
+///
+/// The socket is opened when writing the first record. Use [`Formatter::syslog()`] to send RFC 5424 syslog messages.
+/// With a codec other than [`codec::Identity`], every batch is sent as one datagram compressed using
+/// [`codec::encode_batch()`].
+///
+/// # Parameters
+///
+/// - `address`: The address to send to, like `logs.example.org:514`
+/// - `codec`: The codec compressing the datagrams
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::syslog(logging_rs::syslog::Facility::DAEMON), vec![]);
+/// logger.add_sink(logging_rs::sinks::Udp::new("127.0.0.1:514"));
+/// ```
+#[derive(Clone)]
+pub struct Udp {
+    /// The address to send to
+    pub address: String,
+    /// The codec compressing the datagrams
+    pub codec: Arc<dyn codec::Codec>,
+    /// The socket, opened when writing the first record
+    socket: Arc<Mutex<Option<std::net::UdpSocket>>>,
+}
+
+impl fmt::Debug for Udp {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_struct("Udp")
+            .field("address", &self.address)
+            .field("codec", &self.codec.name())
+            .finish()
+    }
+}
+
+impl Udp {
+    /// Creates a new UDP sink object.
+    ///
+    /// # Parameters
+    ///
+    /// - `address`: The address to send to, like `logs.example.org:514`
+    ///
+    /// # Returns
+    ///
+    /// A new `Udp` object sending uncompressed datagrams.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Udp::new("logs.example.org:514");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Udp::with_codec()`]
+    pub fn new(address: &str) -> Udp {
+        Udp {
+            address: address.to_owned(),
+            codec: Arc::new(codec::Identity),
+            socket: Arc::new(Mutex::new(None))
+        }
+    }
+
+    /// Sets the codec compressing the datagrams.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The UDP sink object
+    /// - `codec`: The [`codec::Codec`] to use
+    ///
+    /// # Returns
+    ///
+    /// The UDP sink object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::sinks::Udp::new("logs.example.org:514").with_codec(logging_rs::codec::Identity);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`codec::from_name()`]
+    pub fn with_codec<C: codec::Codec + 'static>(mut self, codec: C) -> Udp {
+        self.codec = Arc::new(codec);
+        self
+    }
+
+    /// Sends the given records, as one compressed datagram if the sink has a codec.
+    fn send(&self, formatter: &Formatter, records: &[Record]) {
+        if self.codec.extension().is_empty() {
+            let datagrams: Vec<Vec<u8>> = records.iter().map(|record| self.encode(formatter, record).into_bytes()).collect();
+            send_datagrams(&self.socket, &self.address, &datagrams);
+            return;
+        }
+
+        match codec::encode_batch(self.codec.as_ref(), formatter, records) {
+            Ok(datagram) => send_datagrams(&self.socket, &self.address, &[datagram]),
+            Err(error) => error.report(format!("Address: {}", self.address).as_str())
+        }
+    }
+}
+
+impl Sink for Udp {
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        self.send(formatter, std::slice::from_ref(record));
+    }
+
+    fn write_batch(&self, formatter: &Formatter, records: &[Record]) {
+        if !records.is_empty() {
+            self.send(formatter, records);
+        }
+    }
+
+    fn after_fork(&self) {
+        *self.socket.lock().unwrap_or_else(|error| error.into_inner()) = None;
+    }
+}
+
+/// Sends the given datagrams to the address, opening the socket if it is not open yet.
+///
+/// Errors are reported using [`errors::Error::report()`].
+pub(crate) fn send_datagrams(socket: &Mutex<Option<std::net::UdpSocket>>, address: &str, datagrams: &[Vec<u8>]) {
+    let mut socket: std::sync::MutexGuard<Option<std::net::UdpSocket>> = socket.lock().unwrap_or_else(|error| error.into_inner());
+
+    if socket.is_none() {
+        if sandbox::is_enabled() {
+            errors::Error::new("Writing error", "The socket could not be opened in sandbox mode", 2).report(format!("Address: {}", address).as_str());
+            return;
+        }
+
+        match connect_udp(address) {
+            Ok(connected) => *socket = Some(connected),
+            Err(error) => {
+                errors::Error::new("Writing error", "The socket could not be opened", 2).report(format!("Address: {}\nError: {}", address, error).as_str());
+                return;
+            }
+        }
+    }
+
+    if let Some(connected) = socket.as_ref() {
+        if let Some(error) = datagrams.iter().find_map(|datagram| connected.send(datagram).err()) {
+            errors::Error::new("Writing error", "The datagram could not be sent", 2).report(format!("Address: {}\nError: {}", address, error).as_str());
+        }
+    }
+}
+
+/// Opens a UDP socket connected to the given address.
+fn connect_udp(address: &str) -> std::io::Result<std::net::UdpSocket> {
+    let target: std::net::SocketAddr = std::net::ToSocketAddrs::to_socket_addrs(address)?.next().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "The address did not resolve to any socket address")
+    })?;
+
+    let socket: std::net::UdpSocket = std::net::UdpSocket::bind(if target.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" })?;
+    socket.connect(target)?;
+    Ok(socket)
+}
+
+/////////////////
+// COMBINATORS //
+/////////////////
+
+/// Creates a sink writing every record to all given sinks.
+///
+/// # Parameters
+///
+/// - `sinks`: The sinks to write to
+///
+/// # Returns
+///
+/// A new [`Tee`] object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::tee(vec![Box::new(logging_rs::Output::STDOUT), Box::new(logging_rs::Output::STDERR)]);
+/// ```
+///
+/// # See also
+///
+/// - [`Tee`]
+pub fn tee(sinks: Vec<Box<dyn Sink>>) -> Tee {
+    Tee::new(sinks)
+}
+
+/// Creates a sink only writing records matching the given predicate.
+///
+/// # Parameters
+///
+/// - `sink`: The sink to write to
+/// - `predicate`: The predicate records have to match
+///
+/// # Returns
+///
+/// A new [`Filtered`] object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::filtered(logging_rs::Output::STDOUT, |record: &logging_rs::Record| record.argument("audit").is_none());
+/// ```
+///
+/// # See also
+///
+/// - [`Filtered`]
+pub fn filtered<S: Sink, F: Fn(&Record) -> bool + Send + Sync + 'static>(sink: S, predicate: F) -> Filtered<S> {
+    Filtered::new(sink, predicate)
+}
+
+/// Creates a sink only writing records with at least the given level.
+///
+/// # Parameters
+///
+/// - `sink`: The sink to write to
+/// - `level`: The minimum [`Level`] of written records
+///
+/// # Returns
+///
+/// A new [`Leveled`] object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::leveled(logging_rs::Output::STDERR, logging_rs::Level::WARN);
+/// ```
+///
+/// # See also
+///
+/// - [`Leveled`]
+pub fn leveled<S: Sink>(sink: S, level: Level) -> Leveled<S> {
+    Leveled::new(sink, level)
+}
+
+/// Creates a sink formatting records using the given formatter instead of the formatter of the logger.
+///
+/// # Parameters
+///
+/// - `sink`: The sink to write to
+/// - `formatter`: The [`Formatter`] to use
+///
+/// # Returns
+///
+/// A new [`Formatted`] object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let logger: logging_rs::Logger = logging_rs::Logger::builder()
+///     .formatter(logging_rs::Formatter::json_lines())
+///     .output_file("app.log")
+///     .sink(logging_rs::sinks::formatted(logging_rs::Output::STDOUT, logging_rs::Formatter::compact().with_time_zone(logging_rs::TimeZone::LOCAL)))
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// # See also
+///
+/// - [`Formatted`]
+pub fn formatted<S: Sink>(sink: S, formatter: Formatter) -> Formatted<S> {
+    Formatted::new(sink, formatter)
+}
+
+/// Creates a sink enforcing a maximum encoded record size.
+///
+/// # Parameters
+///
+/// - `sink`: The sink to write to
+/// - `max_size`: The maximum encoded record size in bytes
+/// - `overflow`: The [`Overflow`] behavior for records exceeding the budget
+///
+/// # Returns
+///
+/// A new [`Budgeted`] object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::budgeted(logging_rs::Output::STDOUT, 8192, logging_rs::sinks::Overflow::DROP);
+/// ```
+///
+/// # See also
+///
+/// - [`Budgeted`]
+/// - [`Overflow`]
+pub fn budgeted<S: Sink>(sink: S, max_size: usize, overflow: Overflow) -> Budgeted<S> {
+    Budgeted::new(sink, max_size, overflow)
+}
+
+/// Creates a sink grouping repeated records once more than the given number of records per second is written.
+///
+/// # Parameters
+///
+/// - `sink`: The sink to write to
+/// - `max_rate`: The maximum number of records per second written before coalescing
+///
+/// # Returns
+///
+/// A new [`Coalesced`] object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::sinks::coalesced(logging_rs::Output::STDOUT, 100));
+/// ```
+///
+/// # See also
+///
+/// - [`Coalesced`]
+pub fn coalesced<S: Sink>(sink: S, max_rate: usize) -> Coalesced<S> {
+    Coalesced::new(sink, max_rate)
+}
+
+/// Creates a sink transforming records before writing them.
+///
+/// # Parameters
+///
+/// - `sink`: The sink to write to
+/// - `mapper`: The function transforming the records
+///
+/// # Returns
+///
+/// A new [`Mapped`] object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::sinks::mapped(logging_rs::Output::STDOUT, |mut record: logging_rs::Record| {
+///     record.arguments.push(("service".to_owned(), "api".to_owned()));
+///     record
+/// });
+/// ```
+///
+/// # See also
+///
+/// - [`Mapped`]
+pub fn mapped<S: Sink, F: Fn(Record) -> Record + Send + Sync + 'static>(sink: S, mapper: F) -> Mapped<S> {
+    Mapped::new(sink, mapper)
+}
+
+/// Creates a sink sampling records of frequent keys while keeping all records of rare keys.
+///
+/// # Parameters
+///
+/// - `sink`: The sink to write to
+/// - `key`: The name of the argument records are counted by
+/// - `threshold`: The number of records of every key written per second before sampling
+///
+/// # Returns
+///
+/// A new [`Sampled`] object.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::sinks::sampled(logging_rs::Output::STDOUT, "endpoint", 10));
+/// ```
+///
+/// # See also
+///
+/// - [`Sampled`]
+pub fn sampled<S: Sink>(sink: S, key: &str, threshold: usize) -> Sampled<S> {
+    Sampled::new(sink, key, threshold)
+}
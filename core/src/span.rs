@@ -0,0 +1,206 @@
+#![doc = include_str!("../../.github/span.md")]
+// logging-rs span
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+use crate::{global, Level, Logger};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// Separator between the names of nested spans in the `span` argument.
+pub const SEPARATOR: &str = ":";
+
+thread_local! {
+    /// The names of the entered spans of the current thread, outermost first.
+    static SPANS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+
+//////////
+// SPAN //
+//////////
+
+/// Span guard object.
+///
+/// Logs a `DEBUG` record when the span is entered and when it is dropped, the latter with the elapsed time in
+/// microseconds as `elapsed_us` argument. While the span is entered, all records created on the same thread carry the
+/// `span` argument containing the names of the entered spans separated by [`SEPARATOR`], so `{{span}}` can be used in
+/// format strings.
+///
+/// Spans are bound to the thread they were entered on. Use the [`crate::span!`] macro to create span objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// {
+///     let _span: logging_rs::span::Span = logging_rs::span!(logger, "request");
+///     logging_rs::info!(logger, "Handling request"); // Has the argument `span` set to `request`
+/// }
+/// ```
+#[derive(Debug)]
+#[must_use = "the span is exited when the guard is dropped"]
+pub struct Span<'a> {
+    /// The logger to log the enter and exit records with, or `None` to log nothing.
+    logger: Option<&'a Logger>,
+    /// The path of the file the span was entered in.
+    path: &'static str,
+    /// The number of spans entered before this one.
+    depth: usize,
+    /// When the span was entered.
+    start: Instant,
+    /// Marker keeping the span on the thread it was entered on.
+    thread: PhantomData<*const ()>,
+}
+
+impl<'a> Span<'a> {
+    /// Enters a new span.
+    ///
+    /// # Parameters
+    ///
+    /// - `logger`: The [`Logger`] to log the enter and exit records with
+    /// - `name`: The name of the span
+    ///
+    /// # Returns
+    ///
+    /// A new `Span` object, exiting the span when dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let _span: logging_rs::span::Span = logging_rs::span::Span::new(&logger, "request");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`crate::span!`]
+    #[track_caller]
+    pub fn new(logger: &'a Logger, name: &str) -> Span<'a> {
+        Span::enter(Some(logger), name, std::panic::Location::caller().file())
+    }
+
+    /// Enters the given span and logs the enter record.
+    fn enter(logger: Option<&'a Logger>, name: &str, path: &'static str) -> Span<'a> {
+        let depth: usize = SPANS.with(|spans| {
+            let mut spans = spans.borrow_mut();
+            spans.push(name.to_owned());
+            spans.len() - 1
+        });
+
+        if let Some(logger) = logger {
+            logger.log("Entering {{span}}", Level::DEBUG, path, vec![]);
+        }
+
+        Span {
+            logger,
+            path,
+            depth,
+            start: crate::now(),
+            thread: PhantomData
+        }
+    }
+}
+
+impl Span<'static> {
+    /// Enters a new span logging with the [`global`] logger.
+    ///
+    /// If the global logger is not initialized, the span is entered without logging the enter and exit records.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the span
+    ///
+    /// # Returns
+    ///
+    /// A new `Span` object, exiting the span when dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let _span: logging_rs::span::Span = logging_rs::span::Span::global("startup");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`crate::span!`]
+    #[track_caller]
+    pub fn global(name: &str) -> Span<'static> {
+        Span::enter(global::logger(), name, std::panic::Location::caller().file())
+    }
+}
+
+impl Drop for Span<'_> {
+    fn drop(&mut self) {
+        if let Some(logger) = self.logger {
+            logger.log("Exiting {{span}}", Level::DEBUG, self.path, vec![("elapsed_us", crate::now().saturating_duration_since(self.start).as_micros().to_string())]);
+        }
+
+        SPANS.with(|spans| spans.borrow_mut().truncate(self.depth));
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Returns the names of the entered spans of the current thread.
+///
+/// # Returns
+///
+/// The names of the entered spans separated by [`SEPARATOR`], or `None` if no span is entered.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// let _outer: logging_rs::span::Span = logging_rs::span!(logger, "request");
+/// let _inner: logging_rs::span::Span = logging_rs::span!(logger, "database");
+///
+/// assert_eq!(logging_rs::span::current(), Some("request:database".to_owned()));
+/// ```
+///
+/// # See also
+///
+/// - [`Span`]
+pub fn current() -> Option<String> {
+    SPANS.with(|spans| {
+        let spans = spans.borrow();
+        (!spans.is_empty()).then(|| spans.join(SEPARATOR))
+    })
+}
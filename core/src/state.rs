@@ -0,0 +1,149 @@
+#![doc = include_str!("../../.github/state.md")]
+// logging-rs state
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{errors, json, sandbox, sinks, Logger};
+
+
+///////////
+// STATE //
+///////////
+
+impl Logger {
+    /// Writes the state of all named sinks to the given state file, see [`sinks::Sink::save_state()`].
+    ///
+    /// The state file contains a JSON object mapping the names of the sinks to their states. It is written to a
+    /// temporary file first and then renamed, so a crash while saving does not corrupt the previous state. Call this
+    /// after [`Logger::shutdown()`], so the state includes all flushed records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `path`: The path of the state file
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating whether the state file was written.
+    ///
+    /// # Errors
+    ///
+    /// Returns a file error if the state file could not be written or the logger is in [`sandbox`] mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_doc_save_state.json");
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default();
+    ///
+    /// logger.shutdown(std::time::Duration::from_secs(2));
+    /// logger.save_state(&path).unwrap();
+    /// # std::fs::remove_file(path).ok();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::restore_state()`]
+    pub fn save_state<P: AsRef<Path>>(&self, path: P) -> Result<(), errors::Error> {
+        let path: &Path = path.as_ref();
+
+        if sandbox::is_enabled() {
+            return Err(errors::Error::new("File error", &format!("The state file {} can not be written in sandbox mode", path.display()), 1));
+        }
+
+        let states: Vec<(String, json::Value)> = self.sinks.iter()
+            .filter_map(|sink| Some((sink.name.clone()?, sinks::Sink::save_state(sink)?)))
+            .collect();
+        let temporary: std::path::PathBuf = path.with_extension("tmp");
+
+        fs::write(&temporary, json::Value::OBJECT(states).to_pretty() + "\n")
+            .and_then(|_| fs::rename(&temporary, path))
+            .map_err(|error: io::Error| errors::Error::new("File error", &format!("The state file {} could not be written: {}", path.display(), error), 1))
+    }
+
+    /// Restores the state of all named sinks from the given state file, see [`sinks::Sink::restore_state()`].
+    ///
+    /// Call this after adding the sinks and before logging the first record. Sinks without a state in the state file
+    /// are left unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    /// - `path`: The path of the state file written by [`Logger::save_state()`]
+    ///
+    /// # Returns
+    ///
+    /// The number of restored sinks, `0` if the state file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns a file error if the state file could not be read, or a JSON error if it is not a JSON object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// assert_eq!(logger.restore_state("missing_state.json").unwrap(), 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::save_state()`]
+    pub fn restore_state<P: AsRef<Path>>(&self, path: P) -> Result<usize, errors::Error> {
+        let path: &Path = path.as_ref();
+
+        let text: String = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(error) => return Err(errors::Error::new("File error", &format!("The state file {} could not be read: {}", path.display(), error), 1))
+        };
+
+        let json::Value::OBJECT(states) = json::Value::parse(&text)? else {
+            return Err(errors::Error::new("JSON error", &format!("The state file {} does not contain a JSON object", path.display()), 5));
+        };
+
+        let mut restored: usize = 0;
+
+        for sink in &self.sinks {
+            let Some(name) = &sink.name else {
+                continue;
+            };
+
+            if let Some((_, state)) = states.iter().find(|(key, _)| key == name) {
+                errors::guard(|| sinks::Sink::restore_state(sink, state));
+                restored += 1;
+            }
+        }
+
+        Ok(restored)
+    }
+}
@@ -0,0 +1,329 @@
+#![doc = include_str!("../../.github/syslog.md")]
+
+// logging-rs syslog
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::{attachments, Formatter, Record};
+
+
+///////////////
+// CONSTANTS //
+///////////////
+
+/// The id of the structured data element containing the arguments of the record.
+///
+/// Uses the private enterprise number `32473` reserved for documentation by RFC 5424.
+pub const STRUCTURED_DATA_ID: &str = "args@32473";
+
+/// The value written for empty header fields.
+const NIL: &str = "-";
+
+
+//////////////
+// FACILITY //
+//////////////
+
+/// Syslog facilities, combined with the [syslog severity](crate::Level::syslog_severity()) of the level into the
+/// priority of syslog messages.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::syslog::Facility::LOCAL0.code(), 16);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Facility {
+    /// Kernel messages
+    KERN,
+    /// User-level messages. The default facility
+    #[default]
+    USER,
+    /// Mail system
+    MAIL,
+    /// System daemons
+    DAEMON,
+    /// Security and authorization messages
+    AUTH,
+    /// Messages generated internally by syslogd
+    SYSLOG,
+    /// Line printer subsystem
+    LPR,
+    /// Network news subsystem
+    NEWS,
+    /// UUCP subsystem
+    UUCP,
+    /// Clock daemon
+    CRON,
+    /// Private security and authorization messages
+    AUTHPRIV,
+    /// FTP daemon
+    FTP,
+    /// NTP subsystem
+    NTP,
+    /// Log audit
+    AUDIT,
+    /// Log alert
+    ALERT,
+    /// Clock daemon
+    CLOCK,
+    /// Local use 0
+    LOCAL0,
+    /// Local use 1
+    LOCAL1,
+    /// Local use 2
+    LOCAL2,
+    /// Local use 3
+    LOCAL3,
+    /// Local use 4
+    LOCAL4,
+    /// Local use 5
+    LOCAL5,
+    /// Local use 6
+    LOCAL6,
+    /// Local use 7
+    LOCAL7,
+}
+
+impl Facility {
+    /// All facilities, ordered by their code.
+    pub const ALL: [Facility; 24] = [
+        Facility::KERN,
+        Facility::USER,
+        Facility::MAIL,
+        Facility::DAEMON,
+        Facility::AUTH,
+        Facility::SYSLOG,
+        Facility::LPR,
+        Facility::NEWS,
+        Facility::UUCP,
+        Facility::CRON,
+        Facility::AUTHPRIV,
+        Facility::FTP,
+        Facility::NTP,
+        Facility::AUDIT,
+        Facility::ALERT,
+        Facility::CLOCK,
+        Facility::LOCAL0,
+        Facility::LOCAL1,
+        Facility::LOCAL2,
+        Facility::LOCAL3,
+        Facility::LOCAL4,
+        Facility::LOCAL5,
+        Facility::LOCAL6,
+        Facility::LOCAL7
+    ];
+
+    /// Returns the numerical code of the facility.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The facility object
+    ///
+    /// # Returns
+    ///
+    /// The code of the facility, from `0` ([`Facility::KERN`]) to `23` ([`Facility::LOCAL7`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::syslog::Facility::USER.code(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Facility`]
+    pub const fn code(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the lowercase name of the facility.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The facility object
+    ///
+    /// # Returns
+    ///
+    /// The name of the facility, like `local0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::syslog::Facility::DAEMON.name(), "daemon");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Facility`]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Facility::KERN => "kern",
+            Facility::USER => "user",
+            Facility::MAIL => "mail",
+            Facility::DAEMON => "daemon",
+            Facility::AUTH => "auth",
+            Facility::SYSLOG => "syslog",
+            Facility::LPR => "lpr",
+            Facility::NEWS => "news",
+            Facility::UUCP => "uucp",
+            Facility::CRON => "cron",
+            Facility::AUTHPRIV => "authpriv",
+            Facility::FTP => "ftp",
+            Facility::NTP => "ntp",
+            Facility::AUDIT => "audit",
+            Facility::ALERT => "alert",
+            Facility::CLOCK => "clock",
+            Facility::LOCAL0 => "local0",
+            Facility::LOCAL1 => "local1",
+            Facility::LOCAL2 => "local2",
+            Facility::LOCAL3 => "local3",
+            Facility::LOCAL4 => "local4",
+            Facility::LOCAL5 => "local5",
+            Facility::LOCAL6 => "local6",
+            Facility::LOCAL7 => "local7"
+        }
+    }
+
+    /// Returns the placeholder of the formatting argument containing syslog messages using the facility.
+    pub(crate) const fn placeholder(&self) -> &'static str {
+        match self {
+            Facility::KERN => "{{record.syslog.kern}}",
+            Facility::USER => "{{record.syslog.user}}",
+            Facility::MAIL => "{{record.syslog.mail}}",
+            Facility::DAEMON => "{{record.syslog.daemon}}",
+            Facility::AUTH => "{{record.syslog.auth}}",
+            Facility::SYSLOG => "{{record.syslog.syslog}}",
+            Facility::LPR => "{{record.syslog.lpr}}",
+            Facility::NEWS => "{{record.syslog.news}}",
+            Facility::UUCP => "{{record.syslog.uucp}}",
+            Facility::CRON => "{{record.syslog.cron}}",
+            Facility::AUTHPRIV => "{{record.syslog.authpriv}}",
+            Facility::FTP => "{{record.syslog.ftp}}",
+            Facility::NTP => "{{record.syslog.ntp}}",
+            Facility::AUDIT => "{{record.syslog.audit}}",
+            Facility::ALERT => "{{record.syslog.alert}}",
+            Facility::CLOCK => "{{record.syslog.clock}}",
+            Facility::LOCAL0 => "{{record.syslog.local0}}",
+            Facility::LOCAL1 => "{{record.syslog.local1}}",
+            Facility::LOCAL2 => "{{record.syslog.local2}}",
+            Facility::LOCAL3 => "{{record.syslog.local3}}",
+            Facility::LOCAL4 => "{{record.syslog.local4}}",
+            Facility::LOCAL5 => "{{record.syslog.local5}}",
+            Facility::LOCAL6 => "{{record.syslog.local6}}",
+            Facility::LOCAL7 => "{{record.syslog.local7}}"
+        }
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Converts the given record into an RFC 5424 syslog message.
+///
+/// The message has the form `<PRI>1 TIMESTAMP HOSTNAME APP-NAME PROCID MSGID [SD] MSG`, where
+///
+/// - `PRI` is the facility code multiplied by 8 plus the [syslog severity](crate::Level::syslog_severity())
+/// - `TIMESTAMP` is the UTC timestamp of the record with microsecond precision
+/// - `PROCID` is the id of the current process and `MSGID` is empty (`-`)
+/// - `SD` is the [`STRUCTURED_DATA_ID`] element containing the arguments and attachments of the record, or `-` if
+///   the record has none
+/// - `MSG` is the message with the arguments inserted, prefixed with the UTF-8 byte order mark
+///
+/// Header fields are truncated to their maximum length, characters not allowed in them are replaced with `_`.
+///
+/// # Parameters
+///
+/// - `record`: The record to convert
+/// - `facility`: The [`Facility`] of the message
+/// - `host`: The name of the host sending the message
+/// - `app_name`: The name of the application sending the message
+///
+/// # Returns
+///
+/// A `String` containing the syslog message.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::WARN, "Disk {{disk}} almost full", "src/main.rs", vec![("disk", "sda".to_owned())]);
+/// let message: String = logging_rs::syslog::message(&record, logging_rs::syslog::Facility::DAEMON, "web-1", "server");
+///
+/// assert!(message.starts_with("<28>1 "));
+/// assert!(message.ends_with(&format!(" web-1 server {} - [args@32473 disk=\"sda\"] \u{feff}Disk sda almost full", std::process::id())));
+/// ```
+///
+/// # See also
+///
+/// - [`crate::Formatter::syslog()`]
+pub fn message(record: &Record, facility: Facility, host: &str, app_name: &str) -> String {
+    let arguments = record.arguments.iter().map(|(key, value)| (key.as_str(), value.clone()));
+    let attachments = record.attachments.iter().map(|attachment| (attachment.name.as_str(), attachment.to_hex(attachments::PREVIEW_SIZE)));
+
+    let mut structured_data: String = String::new();
+
+    for (key, value) in arguments.chain(attachments) {
+        let name: String = key.chars().take(32).map(|character| if character.is_ascii_graphic() && !"=]\"".contains(character) { character } else { '_' }).collect();
+        structured_data.push_str(&format!(" {}=\"{}\"", name, value.replace('\\', "\\\\").replace('"', "\\\"").replace(']', "\\]")));
+    }
+
+    let structured_data: String = if structured_data.is_empty() { NIL.to_owned() } else { format!("[{}{}]", STRUCTURED_DATA_ID, structured_data) };
+
+    format!(
+        "<{}>1 {} {} {} {} {} {} \u{feff}{}",
+        u16::from(facility.code()) * 8 + u16::from(record.level.syslog_severity()),
+        record.timestamp.to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+        header(host, 255),
+        header(app_name, 48),
+        header(&std::process::id().to_string(), 128),
+        NIL,
+        structured_data,
+        Formatter::new("", "{{message}}", "").format_record_with(false, record)
+    )
+}
+
+/// Returns the name of the current executable, used as application name of syslog messages.
+pub(crate) fn app_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|path| path.file_stem().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_default()
+}
+
+/// Converts the given value into a header field of at most the given length, using `-` for empty values.
+fn header(value: &str, length: usize) -> String {
+    if value.is_empty() {
+        return NIL.to_owned();
+    }
+
+    value.chars().take(length).map(|character| if character.is_ascii_graphic() { character } else { '_' }).collect()
+}
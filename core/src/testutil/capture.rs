@@ -0,0 +1,369 @@
+#![doc = include_str!("../../../.github/capture.md")]
+// logging-rs capture
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use crate::sinks::{Flushed, Sink};
+use crate::{Formatter, Level, Record};
+
+
+/////////////
+// CAPTURE //
+/////////////
+
+/// In-memory sink object capturing all written records.
+///
+/// Every record is stored together with the line the plain format string of the logger's formatter renders for it.
+/// Clones share the captured records, so a clone can be added to a logger and inspected afterwards.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "{{level}} {{message}}", ""), vec![]);
+/// logger.add_sink(capture.clone());
+///
+/// logging_rs::warn!(logger, "Disk at {{usage}}%", "usage" = 91);
+///
+/// assert_eq!(capture.lines(), vec!["WARNING Disk at 91%".to_owned()]);
+/// assert!(capture.contains(logging_rs::Level::WARN, "Disk at"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Capture {
+    /// The captured records with their formatted lines.
+    records: Arc<Mutex<Vec<(Record, String)>>>,
+    /// The number of flushes.
+    flushes: Arc<Mutex<usize>>,
+}
+
+impl Capture {
+    /// Creates a new capture object.
+    ///
+    /// # Returns
+    ///
+    /// A new `Capture` object without any records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::testutil::capture::Capture::new().is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Capture`]
+    pub fn new() -> Capture {
+        Capture::default()
+    }
+
+    /// Locks the captured records, ignoring poisoning.
+    fn lock(&self) -> MutexGuard<'_, Vec<(Record, String)>> {
+        self.records.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Returns the captured records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The capture object
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the [`Record`]s in the order they were written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+    /// let records: Vec<logging_rs::Record> = capture.records();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Capture::lines()`]
+    /// - [`Capture::messages()`]
+    pub fn records(&self) -> Vec<Record> {
+        self.lock().iter().map(|(record, _)| record.clone()).collect()
+    }
+
+    /// Returns the formatted lines of the captured records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The capture object
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the records formatted with the plain format string of the logger's formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+    /// let lines: Vec<String> = capture.lines();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Capture::records()`]
+    pub fn lines(&self) -> Vec<String> {
+        self.lock().iter().map(|(_, line)| line.clone()).collect()
+    }
+
+    /// Returns the messages of the captured records with their arguments inserted.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The capture object
+    ///
+    /// # Returns
+    ///
+    /// A vector containing the formatted messages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+    /// let messages: Vec<String> = capture.messages();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Record::formatted_message()`]
+    pub fn messages(&self) -> Vec<String> {
+        self.lock().iter().map(|(record, _)| record.formatted_message()).collect()
+    }
+
+    /// Checks whether a record with the given level and a message containing the given text was captured.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The capture object
+    /// - `level`: The [`Level`] of the record
+    /// - `text`: The text the formatted message has to contain
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether a matching record was captured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+    /// assert!(!capture.contains(logging_rs::Level::ERROR, "failed"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Capture::messages()`]
+    pub fn contains(&self, level: Level, text: &str) -> bool {
+        self.lock().iter().any(|(record, _)| record.level == level && record.formatted_message().contains(text))
+    }
+
+    /// Returns the number of captured records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The capture object
+    ///
+    /// # Returns
+    ///
+    /// The number of records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert_eq!(logging_rs::testutil::capture::Capture::new().len(), 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Capture::is_empty()`]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Checks whether no records were captured.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The capture object
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether no records were captured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// assert!(logging_rs::testutil::capture::Capture::new().is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Capture::len()`]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns the number of times the sink was flushed.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The capture object
+    ///
+    /// # Returns
+    ///
+    /// The number of flushes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+    /// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+    /// logger.add_sink(capture.clone());
+    ///
+    /// logger.shutdown(std::time::Duration::from_secs(1));
+    /// assert_eq!(capture.flushes(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Capture`]
+    pub fn flushes(&self) -> usize {
+        *self.flushes.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Removes all captured records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The capture object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+    /// capture.clear();
+    /// assert!(capture.is_empty());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Capture`]
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+}
+
+impl Sink for Capture {
+    fn write(&self, formatter: &Formatter, record: &Record) {
+        let line: String = formatter.format_record_with(false, record);
+        self.lock().push((record.clone(), line));
+    }
+
+    fn flush(&self, _timeout: Duration) -> Flushed {
+        *self.flushes.lock().unwrap_or_else(|error| error.into_inner()) += 1;
+        Flushed::default()
+    }
+}
+
+
+/////////////
+// FAILING //
+/////////////
+
+/// Sink object panicking on every write.
+///
+/// Used to test that failing sinks do not affect the caller or the other sinks of a logger.
+///
+/// # Parameters
+///
+/// - `message`: The panic message
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # logging_rs::errors::set_handler(|_error: &logging_rs::errors::Error, _details: &str| {});
+/// let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+/// let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+/// logger.add_sink(logging_rs::testutil::capture::Failing::new("Disk full"));
+/// logger.add_sink(capture.clone());
+///
+/// logging_rs::info!(logger, "Still written");
+/// assert_eq!(capture.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Failing {
+    /// The panic message
+    pub message: String,
+}
+
+impl Failing {
+    /// Creates a new failing sink object.
+    ///
+    /// # Parameters
+    ///
+    /// - `message`: The panic message
+    ///
+    /// # Returns
+    ///
+    /// A new `Failing` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::testutil::capture::Failing::new("Connection refused");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Failing`]
+    pub fn new(message: &str) -> Failing {
+        Failing { message: message.to_owned() }
+    }
+}
+
+impl Sink for Failing {
+    fn write(&self, _formatter: &Formatter, _record: &Record) {
+        panic!("{}", self.message);
+    }
+}
@@ -0,0 +1,294 @@
+#![doc = include_str!("../../../.github/clock.md")]
+// logging-rs clock
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+
+///////////
+// STATE //
+///////////
+
+thread_local! {
+    /// The virtual clock installed on the current thread.
+    static CURRENT: RefCell<Option<VirtualClock>> = const { RefCell::new(None) };
+}
+
+/// Returns the virtual clock installed on the current thread, if any.
+pub(crate) fn current() -> Option<VirtualClock> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+
+///////////
+// CLOCK //
+///////////
+
+/// Virtual clock object.
+///
+/// The clock only moves when it is advanced, so time windows of sinks like [`crate::sinks::Coalesced`] and
+/// [`crate::sinks::Sampled`], span durations and record timestamps can be tested without sleeping. Clones share their
+/// time.
+///
+/// Use [`VirtualClock::new()`] to create virtual clock objects and [`VirtualClock::install()`] to use them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+/// let _guard: logging_rs::testutil::clock::ClockGuard = clock.install();
+///
+/// clock.advance(std::time::Duration::from_secs(90));
+/// assert_eq!(clock.timestamp().to_rfc3339(), "2023-01-01T00:01:30+00:00");
+/// ```
+#[derive(Clone, Debug)]
+pub struct VirtualClock {
+    /// The instant the clock was created at.
+    origin: Instant,
+    /// The timestamp the clock started with.
+    start: chrono::DateTime<chrono::Utc>,
+    /// The time the clock was advanced by, shared by all clones.
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl Default for VirtualClock {
+    fn default() -> VirtualClock {
+        VirtualClock::new()
+    }
+}
+
+impl VirtualClock {
+    /// Creates a new virtual clock object.
+    ///
+    /// # Returns
+    ///
+    /// A new `VirtualClock` object starting at `2023-01-01T00:00:00Z`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::testutil::clock::VirtualClock::new();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`VirtualClock`]
+    /// - [`VirtualClock::with_timestamp()`]
+    pub fn new() -> VirtualClock {
+        VirtualClock::with_timestamp(chrono::DateTime::<chrono::Utc>::from(std::time::UNIX_EPOCH + Duration::from_secs(1_672_531_200)))
+    }
+
+    /// Creates a new virtual clock object starting at the given timestamp.
+    ///
+    /// # Parameters
+    ///
+    /// - `timestamp`: The timestamp to start at
+    ///
+    /// # Returns
+    ///
+    /// A new `VirtualClock` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::with_timestamp(chrono::Utc::now());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`VirtualClock::new()`]
+    pub fn with_timestamp(timestamp: chrono::DateTime<chrono::Utc>) -> VirtualClock {
+        VirtualClock {
+            origin: Instant::now(),
+            start: timestamp,
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Advances the clock by the given duration.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The virtual clock object
+    /// - `duration`: The duration to advance by
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+    /// clock.advance(std::time::Duration::from_millis(250));
+    /// assert_eq!(clock.elapsed(), std::time::Duration::from_millis(250));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`VirtualClock::elapsed()`]
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap_or_else(|error| error.into_inner()) += duration;
+    }
+
+    /// Returns the duration the clock was advanced by since it was created.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The virtual clock object
+    ///
+    /// # Returns
+    ///
+    /// The advanced [`Duration`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+    /// assert_eq!(clock.elapsed(), std::time::Duration::ZERO);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`VirtualClock::advance()`]
+    pub fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// Returns the current instant of the clock.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The virtual clock object
+    ///
+    /// # Returns
+    ///
+    /// The [`Instant`] the clock was created at, advanced by [`VirtualClock::elapsed()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+    /// let start: std::time::Instant = clock.now();
+    /// clock.advance(std::time::Duration::from_secs(1));
+    ///
+    /// assert_eq!(clock.now() - start, std::time::Duration::from_secs(1));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`VirtualClock::timestamp()`]
+    pub fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+
+    /// Returns the current timestamp of the clock.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The virtual clock object
+    ///
+    /// # Returns
+    ///
+    /// The timestamp the clock started with, advanced by [`VirtualClock::elapsed()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+    /// assert_eq!(clock.timestamp().to_rfc3339(), "2023-01-01T00:00:00+00:00");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`VirtualClock::now()`]
+    pub fn timestamp(&self) -> chrono::DateTime<chrono::Utc> {
+        self.start
+            .checked_add_signed(chrono::Duration::from_std(self.elapsed()).unwrap_or(chrono::Duration::MAX))
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC)
+    }
+
+    /// Installs the clock on the current thread until the returned guard is dropped.
+    ///
+    /// While installed, the crate reads the time from this clock instead of the system clock on the current thread.
+    /// Other threads, like the worker of [`crate::maintenance::Maintenance`], keep using the system clock.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The virtual clock object
+    ///
+    /// # Returns
+    ///
+    /// A [`ClockGuard`] restoring the previously installed clock when dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+    /// let guard: logging_rs::testutil::clock::ClockGuard = clock.install();
+    /// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Message", "src/main.rs", vec![]);
+    ///
+    /// assert_eq!(record.timestamp, clock.timestamp());
+    /// drop(guard);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`ClockGuard`]
+    pub fn install(&self) -> ClockGuard {
+        ClockGuard {
+            previous: CURRENT.with(|current| current.replace(Some(self.clone()))),
+        }
+    }
+}
+
+
+/////////////////
+// CLOCK GUARD //
+/////////////////
+
+/// Guard object restoring the previously installed clock when dropped.
+///
+/// Returned by [`VirtualClock::install()`].
+#[derive(Debug)]
+#[must_use = "the clock is uninstalled when the guard is dropped"]
+pub struct ClockGuard {
+    /// The clock installed before.
+    previous: Option<VirtualClock>,
+}
+
+impl Drop for ClockGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| *current.borrow_mut() = self.previous.take());
+    }
+}
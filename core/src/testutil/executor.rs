@@ -0,0 +1,419 @@
+#![doc = include_str!("../../../.github/executor.md")]
+// logging-rs executor
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::time::Duration;
+
+use crate::testutil::clock::VirtualClock;
+
+
+//////////
+// TASK //
+//////////
+
+/// Scheduled task object.
+///
+/// Tasks are created by [`Executor::spawn()`], [`Executor::spawn_after()`] and [`Executor::every()`].
+struct Task {
+    /// The tasks name.
+    name: String,
+    /// The time since the clock was created the task is due at.
+    due: Duration,
+    /// The order the task was scheduled in, breaking ties between tasks due at the same time.
+    sequence: u64,
+    /// The interval of repeating tasks.
+    interval: Option<Duration>,
+    /// The function to run.
+    function: Box<dyn FnMut()>,
+}
+
+
+//////////////
+// EXECUTOR //
+//////////////
+
+/// Deterministic executor object.
+///
+/// Runs scheduled tasks on the calling thread, driven by a [`VirtualClock`]. Tasks due at the same time run in the
+/// order they were scheduled, so tests of deferred and periodic work, like maintenance ticks, always see the same
+/// order of events.
+///
+/// Use [`Executor::new()`] to create executor objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+/// let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::new(clock.clone());
+///
+/// let maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::new(std::time::Duration::from_secs(60));
+/// maintenance.add_task("cleanup", || {});
+/// executor.every(std::time::Duration::from_secs(60), "maintenance", move || {
+///     maintenance.tick();
+/// });
+///
+/// assert_eq!(executor.advance(std::time::Duration::from_secs(180)), 3);
+/// assert_eq!(clock.elapsed(), std::time::Duration::from_secs(180));
+/// ```
+pub struct Executor {
+    /// The clock driving the executor.
+    clock: VirtualClock,
+    /// The scheduled tasks.
+    tasks: Vec<Task>,
+    /// The sequence number of the next scheduled task.
+    sequence: u64,
+    /// The names of the tasks run so far with the time they ran at.
+    history: Vec<(Duration, String)>,
+}
+
+impl std::fmt::Debug for Executor {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Executor")
+            .field("clock", &self.clock)
+            .field("tasks", &self.tasks.iter().map(|task| task.name.as_str()).collect::<Vec<&str>>())
+            .field("history", &self.history)
+            .finish()
+    }
+}
+
+impl Default for Executor {
+    fn default() -> Executor {
+        Executor::new(VirtualClock::new())
+    }
+}
+
+impl Executor {
+    /// Creates a new executor object.
+    ///
+    /// # Parameters
+    ///
+    /// - `clock`: The [`VirtualClock`] driving the executor
+    ///
+    /// # Returns
+    ///
+    /// A new `Executor` object without any tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::testutil::executor::Executor::new(logging_rs::testutil::clock::VirtualClock::new());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Executor`]
+    pub fn new(clock: VirtualClock) -> Executor {
+        Executor {
+            clock,
+            tasks: vec![],
+            sequence: 0,
+            history: vec![],
+        }
+    }
+
+    /// Returns the clock driving the executor.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The executor object
+    ///
+    /// # Returns
+    ///
+    /// The [`VirtualClock`] of the executor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+    /// let _guard: logging_rs::testutil::clock::ClockGuard = executor.clock().install();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`VirtualClock::install()`]
+    pub fn clock(&self) -> &VirtualClock {
+        &self.clock
+    }
+
+    /// Schedules a task to run once at the given time since the clock was created.
+    fn schedule(&mut self, name: &str, due: Duration, interval: Option<Duration>, function: Box<dyn FnMut()>) {
+        self.tasks.push(Task {
+            name: name.to_owned(),
+            due,
+            sequence: self.sequence,
+            interval,
+            function,
+        });
+        self.sequence += 1;
+    }
+
+    /// Schedules a task to run at the current time.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The executor object
+    /// - `name`: The tasks name
+    /// - `task`: The function to run
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+    /// executor.spawn("flush", || {});
+    /// assert_eq!(executor.run_until_idle(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Executor::run_until_idle()`]
+    pub fn spawn<F: FnOnce() + 'static>(&mut self, name: &str, task: F) {
+        self.spawn_after(Duration::ZERO, name, task);
+    }
+
+    /// Schedules a task to run once after the given delay.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The executor object
+    /// - `delay`: The time to wait before running the task
+    /// - `name`: The tasks name
+    /// - `task`: The function to run
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+    /// executor.spawn_after(std::time::Duration::from_secs(5), "retry", || {});
+    ///
+    /// assert_eq!(executor.advance(std::time::Duration::from_secs(4)), 0);
+    /// assert_eq!(executor.advance(std::time::Duration::from_secs(1)), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Executor::advance()`]
+    pub fn spawn_after<F: FnOnce() + 'static>(&mut self, delay: Duration, name: &str, task: F) {
+        let mut task: Option<F> = Some(task);
+        let due: Duration = self.clock.elapsed() + delay;
+
+        self.schedule(name, due, None, Box::new(move || {
+            if let Some(task) = task.take() {
+                task();
+            }
+        }));
+    }
+
+    /// Schedules a task to run repeatedly, first after the given interval.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The executor object
+    /// - `interval`: The time between two runs, must not be zero
+    /// - `name`: The tasks name
+    /// - `task`: The function to run
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+    /// executor.every(std::time::Duration::from_secs(10), "stats", || {});
+    /// assert_eq!(executor.advance(std::time::Duration::from_secs(35)), 3);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Executor::advance()`]
+    pub fn every<F: FnMut() + 'static>(&mut self, interval: Duration, name: &str, task: F) {
+        let interval: Duration = interval.max(Duration::from_nanos(1));
+        let due: Duration = self.clock.elapsed() + interval;
+
+        self.schedule(name, due, Some(interval), Box::new(task));
+    }
+
+    /// Runs the next task due at or before the given time, advancing the clock to the time it is due at.
+    fn run_next(&mut self, until: Duration) -> bool {
+        let Some(index) = self.tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, task)| task.due <= until)
+            .min_by_key(|(_, task)| (task.due, task.sequence))
+            .map(|(index, _)| index) else {
+            return false;
+        };
+
+        let mut task: Task = self.tasks.remove(index);
+        let elapsed: Duration = self.clock.elapsed();
+
+        if task.due > elapsed {
+            self.clock.advance(task.due - elapsed);
+        }
+
+        self.history.push((self.clock.elapsed(), task.name.clone()));
+        (task.function)();
+
+        if let Some(interval) = task.interval {
+            task.due += interval;
+            task.sequence = self.sequence;
+            self.sequence += 1;
+            self.tasks.push(task);
+        }
+
+        true
+    }
+
+    /// Runs all tasks due at the current time, including tasks they schedule for the current time.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The executor object
+    ///
+    /// # Returns
+    ///
+    /// The number of tasks run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+    /// assert_eq!(executor.run_until_idle(), 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Executor::advance()`]
+    pub fn run_until_idle(&mut self) -> usize {
+        let now: Duration = self.clock.elapsed();
+        let mut count: usize = 0;
+
+        while self.run_next(now) {
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Advances the clock by the given duration, running every task that becomes due in order.
+    ///
+    /// The clock is advanced to the time each task is due at before running it, so the task sees the time it was
+    /// scheduled for.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The executor object
+    /// - `duration`: The duration to advance by
+    ///
+    /// # Returns
+    ///
+    /// The number of tasks run.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+    /// executor.spawn_after(std::time::Duration::from_secs(1), "first", || {});
+    /// executor.spawn_after(std::time::Duration::from_secs(2), "second", || {});
+    ///
+    /// assert_eq!(executor.advance(std::time::Duration::from_secs(3)), 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Executor::run_until_idle()`]
+    pub fn advance(&mut self, duration: Duration) -> usize {
+        let until: Duration = self.clock.elapsed() + duration;
+        let mut count: usize = 0;
+
+        while self.run_next(until) {
+            count += 1;
+        }
+
+        self.clock.advance(until.saturating_sub(self.clock.elapsed()));
+        count
+    }
+
+    /// Returns the number of scheduled tasks, including repeating ones.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The executor object
+    ///
+    /// # Returns
+    ///
+    /// The number of tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+    /// executor.spawn("flush", || {});
+    /// assert_eq!(executor.pending(), 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Executor`]
+    pub fn pending(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns the names of the tasks run so far with the time since the clock was created they ran at.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The executor object
+    ///
+    /// # Returns
+    ///
+    /// A slice of the times and names in the order the tasks ran.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+    /// executor.spawn("flush", || {});
+    /// executor.run_until_idle();
+    ///
+    /// assert_eq!(executor.history(), [(std::time::Duration::ZERO, "flush".to_owned())]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Executor`]
+    pub fn history(&self) -> &[(Duration, String)] {
+        &self.history
+    }
+}
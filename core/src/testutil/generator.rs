@@ -0,0 +1,418 @@
+#![doc = include_str!("../../../.github/generator.md")]
+// logging-rs generator
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::time::{Duration, Instant};
+
+use crate::{random, Level, Logger, Record};
+
+
+///////////////
+// GENERATOR //
+///////////////
+
+/// Synthetic record generator object.
+///
+/// Generates records with the message `Synthetic record {{sequence}}`, a level chosen by weight and a value for
+/// every field. Field values are named after the field and numbered, so a field with a cardinality of `3` has the
+/// values `user-0`, `user-1` and `user-2`. The same seed always generates the same records.
+///
+/// Use [`Generator::new()`] to create generator objects.
+///
+/// # Parameters
+///
+/// - `levels`: The levels of the records with their weights
+/// - `fields`: The names of the fields with their number of distinct values
+/// - `rate`: The number of records logged per second, unlimited if `None`
+/// - `seed`: The seed of the pseudo random choices
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::testutil::generator::Generator {
+///     levels: vec![(logging_rs::Level::INFO, 1)],
+///     fields: vec![("endpoint".to_owned(), 20)],
+///     rate: Some(1000.0),
+///     seed: 42
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Generator {
+    /// The levels of the records with their weights
+    pub levels: Vec<(Level, u32)>,
+    /// The names of the fields with their number of distinct values
+    pub fields: Vec<(String, usize)>,
+    /// The number of records logged per second, unlimited if `None`
+    pub rate: Option<f64>,
+    /// The seed of the pseudo random choices
+    pub seed: u64,
+}
+
+impl Default for Generator {
+    fn default() -> Generator {
+        Generator::new()
+    }
+}
+
+impl Generator {
+    /// Creates a new generator object.
+    ///
+    /// # Returns
+    ///
+    /// A new `Generator` object generating [`Level::INFO`] records without fields as fast as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::testutil::generator::Generator::new();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Generator`]
+    pub fn new() -> Generator {
+        Generator {
+            levels: vec![],
+            fields: vec![],
+            rate: None,
+            seed: 0
+        }
+    }
+
+    /// Adds a level to the mix of levels.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The generator object
+    /// - `level`: The [`Level`] to add
+    /// - `weight`: The weight of the level relative to the other levels
+    ///
+    /// # Returns
+    ///
+    /// The generator object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// // Generates one warning for every nine info records
+    /// logging_rs::testutil::generator::Generator::new()
+    ///     .with_level(logging_rs::Level::INFO, 9)
+    ///     .with_level(logging_rs::Level::WARN, 1);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Generator`]
+    pub fn with_level(mut self, level: Level, weight: u32) -> Generator {
+        self.levels.push((level, weight));
+        self
+    }
+
+    /// Adds a field to the generated records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The generator object
+    /// - `name`: The name of the field
+    /// - `cardinality`: The number of distinct values of the field
+    ///
+    /// # Returns
+    ///
+    /// The generator object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::testutil::generator::Generator::new().with_field("tenant", 50);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Generator`]
+    pub fn with_field(mut self, name: &str, cardinality: usize) -> Generator {
+        self.fields.push((name.to_owned(), cardinality.max(1)));
+        self
+    }
+
+    /// Sets the number of records logged per second by [`Generator::run()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The generator object
+    /// - `rate`: The number of records per second
+    ///
+    /// # Returns
+    ///
+    /// The generator object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::testutil::generator::Generator::new().with_rate(500.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Generator::run()`]
+    pub fn with_rate(mut self, rate: f64) -> Generator {
+        self.rate = Some(rate).filter(|rate| rate.is_finite() && *rate > 0.0);
+        self
+    }
+
+    /// Sets the seed of the pseudo random choices.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The generator object
+    /// - `seed`: The seed
+    ///
+    /// # Returns
+    ///
+    /// The generator object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::testutil::generator::Generator::new().with_seed(7);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Generator`]
+    pub fn with_seed(mut self, seed: u64) -> Generator {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns an endless iterator over the generated records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The generator object
+    ///
+    /// # Returns
+    ///
+    /// A [`Records`] iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let records: Vec<logging_rs::Record> = logging_rs::testutil::generator::Generator::new().with_field("user", 3).records().take(5).collect();
+    ///
+    /// assert_eq!(records[4].message, "Synthetic record {{sequence}}");
+    /// assert_eq!(records[4].argument("sequence"), Some("4"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Generator::run()`]
+    pub fn records(&self) -> Records<'_> {
+        Records {
+            generator: self,
+            sequence: 0,
+            state: self.seed
+        }
+    }
+
+    /// Logs the given number of generated records and measures the throughput.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The generator object
+    /// - `logger`: The [`Logger`] to log the records with
+    /// - `count`: The number of records to log
+    ///
+    /// # Returns
+    ///
+    /// The measured [`Throughput`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+    /// let throughput: logging_rs::testutil::generator::Throughput = logging_rs::testutil::generator::Generator::new().run(&logger, 100);
+    ///
+    /// assert_eq!(throughput.records, 100);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Throughput`]
+    pub fn run(&self, logger: &Logger, count: usize) -> Throughput {
+        let start: Instant = Instant::now();
+
+        for (index, record) in self.records().take(count).enumerate() {
+            if let Some(rate) = self.rate {
+                let due: Instant = start + Duration::from_secs_f64(index as f64 / rate);
+
+                if let Some(remaining) = due.checked_duration_since(Instant::now()) {
+                    std::thread::sleep(remaining);
+                }
+            }
+
+            logger.log_record(&record);
+        }
+
+        Throughput {
+            records: count,
+            elapsed: start.elapsed()
+        }
+    }
+}
+
+
+/////////////
+// RECORDS //
+/////////////
+
+/// Endless iterator over the records of a [`Generator`].
+///
+/// Use [`Generator::records()`] to create record iterators.
+#[derive(Clone, Debug)]
+pub struct Records<'a> {
+    /// The generator of the records.
+    generator: &'a Generator,
+    /// The sequence number of the next record.
+    sequence: u64,
+    /// The state of the pseudo random number generator.
+    state: u64,
+}
+
+impl Records<'_> {
+    /// Returns the next pseudo random number using SplitMix64.
+    fn next_random(&mut self) -> u64 {
+        random::next_split_mix(&mut self.state)
+    }
+
+    /// Chooses the level of the next record by weight.
+    fn next_level(&mut self) -> Level {
+        let total: u64 = self.generator.levels.iter().map(|(_, weight)| u64::from(*weight)).sum();
+
+        if total == 0 {
+            return Level::INFO;
+        }
+
+        let mut choice: u64 = self.next_random() % total;
+
+        for (level, weight) in &self.generator.levels {
+            if choice < u64::from(*weight) {
+                return *level;
+            }
+
+            choice -= u64::from(*weight);
+        }
+
+        Level::INFO
+    }
+}
+
+impl Iterator for Records<'_> {
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Record> {
+        let level: Level = self.next_level();
+        let mut arguments: Vec<(&str, String)> = vec![("sequence", self.sequence.to_string())];
+
+        for (name, cardinality) in &self.generator.fields {
+            let value: u64 = self.next_random() % *cardinality as u64;
+            arguments.push((name, format!("{}-{}", name, value)));
+        }
+
+        self.sequence += 1;
+        Some(Record::new(level, "Synthetic record {{sequence}}", "testutil/generator", arguments))
+    }
+}
+
+
+////////////////
+// THROUGHPUT //
+////////////////
+
+/// Throughput measured by [`Generator::run()`].
+///
+/// # Parameters
+///
+/// - `records`: The number of logged records
+/// - `elapsed`: The time logging took
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let throughput: logging_rs::testutil::generator::Throughput = logging_rs::testutil::generator::Throughput {
+///     records: 1000,
+///     elapsed: std::time::Duration::from_millis(250)
+/// };
+///
+/// assert_eq!(throughput.per_second(), 4000.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Throughput {
+    /// The number of logged records
+    pub records: usize,
+    /// The time logging took
+    pub elapsed: Duration,
+}
+
+impl Throughput {
+    /// Returns the number of records logged per second.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The throughput object
+    ///
+    /// # Returns
+    ///
+    /// The number of records per second, or infinity if no time elapsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// let throughput: logging_rs::testutil::generator::Throughput = logging_rs::testutil::generator::Throughput {
+    ///     records: 10,
+    ///     elapsed: std::time::Duration::from_secs(2)
+    /// };
+    ///
+    /// assert_eq!(throughput.per_second(), 5.0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Generator::run()`]
+    pub fn per_second(&self) -> f64 {
+        self.records as f64 / self.elapsed.as_secs_f64()
+    }
+}
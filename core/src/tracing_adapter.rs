@@ -0,0 +1,279 @@
+#![doc = include_str!("../../.github/tracing_adapter.md")]
+// logging-rs tracing adapter
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::fmt;
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record as SpanRecord};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::{errors, Level, Logger};
+
+
+/////////////////
+// SPAN FIELDS //
+/////////////////
+
+/// Fields of a span, stored in the extensions of the span.
+#[derive(Debug, Default)]
+struct SpanFields(Vec<(&'static str, String)>);
+
+impl Visit for SpanFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name(), value.to_owned()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push((field.name(), format!("{:?}", value)));
+    }
+}
+
+
+//////////////////
+// EVENT FIELDS //
+//////////////////
+
+/// Message and fields of an event.
+#[derive(Debug, Default)]
+struct EventFields {
+    /// The value of the `message` field
+    message: String,
+    /// All other fields
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Visit for EventFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message = value.to_owned(),
+            name => self.fields.push((name, value.to_owned()))
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{:?}", value),
+            name => self.fields.push((name, format!("{:?}", value)))
+        }
+    }
+}
+
+
+///////////////////
+// LOGGING LAYER //
+///////////////////
+
+/// Layer logging `tracing` events using a logger.
+///
+/// Events are logged using [`Logger::log()`] with their level, message and file. The fields of the event and of all
+/// spans it is in become formatting arguments, fields of inner spans and the event overriding fields of outer spans
+/// with the same name. The target is added as `target` argument and the names of the spans, separated by `:`, as
+/// `spans` argument. [`tracing::Level::TRACE`] events are logged as [`Level::DEBUG`].
+///
+/// Use [`LoggingLayer::new()`] to create logging layer objects.
+///
+/// # Parameters
+///
+/// - `logger`: The [`Logger`] to log the events with
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::registry().with(logging_rs::tracing_adapter::LoggingLayer::new(logging_rs::Logger::default()));
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::info!(user = 42, "Signed in");
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct LoggingLayer {
+    /// The logger to log the events with
+    pub logger: Logger,
+}
+
+impl LoggingLayer {
+    /// Creates a new logging layer object.
+    ///
+    /// # Parameters
+    ///
+    /// - `logger`: The [`Logger`] to log the events with
+    ///
+    /// # Returns
+    ///
+    /// A new `LoggingLayer` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// logging_rs::tracing_adapter::LoggingLayer::new(logging_rs::Logger::default());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`install()`]
+    pub fn new(logger: Logger) -> LoggingLayer {
+        LoggingLayer {
+            logger
+        }
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for LoggingLayer {
+    fn on_new_span(&self, attributes: &Attributes<'_>, id: &Id, context: Context<'_, S>) {
+        if let Some(span) = context.span(id) {
+            let mut fields: SpanFields = SpanFields::default();
+            attributes.record(&mut fields);
+
+            span.extensions_mut().insert(fields);
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &SpanRecord<'_>, context: Context<'_, S>) {
+        if let Some(span) = context.span(id) {
+            if let Some(fields) = span.extensions_mut().get_mut::<SpanFields>() {
+                values.record(fields);
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, context: Context<'_, S>) {
+        let metadata: &tracing::Metadata = event.metadata();
+        let level: Level = level(*metadata.level());
+
+        if !self.logger.is_enabled(level) {
+            return;
+        }
+
+        let mut fields: EventFields = EventFields::default();
+        event.record(&mut fields);
+
+        let mut arguments: Vec<(&str, String)> = vec![("target", metadata.target().to_owned())];
+        let mut spans: Vec<&str> = vec![];
+
+        if let Some(scope) = context.event_scope(event) {
+            for span in scope.from_root() {
+                spans.push(span.name());
+
+                if let Some(span_fields) = span.extensions().get::<SpanFields>() {
+                    arguments.extend(span_fields.0.iter().cloned());
+                }
+            }
+        }
+
+        if !spans.is_empty() {
+            arguments.push(("spans", spans.join(":")));
+        }
+
+        arguments.extend(fields.fields);
+
+        let mut seen: Vec<&str> = vec![];
+        arguments.reverse();
+        arguments.retain(|(key, _)| if seen.contains(key) {
+            false
+        } else {
+            seen.push(key);
+            true
+        });
+        arguments.reverse();
+
+        self.logger.log(&fields.message, level, metadata.file().unwrap_or(metadata.target()), arguments);
+    }
+}
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Returns the level matching the given level of the `tracing` crate.
+///
+/// # Parameters
+///
+/// - `level`: The [`tracing::Level`]
+///
+/// # Returns
+///
+/// The matching [`Level`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::tracing_adapter::level(tracing::Level::WARN), logging_rs::Level::WARN);
+/// assert_eq!(logging_rs::tracing_adapter::level(tracing::Level::TRACE), logging_rs::Level::DEBUG);
+/// ```
+///
+/// # See also
+///
+/// - [`LoggingLayer`]
+pub fn level(level: tracing::Level) -> Level {
+    match level {
+        tracing::Level::ERROR => Level::ERROR,
+        tracing::Level::WARN => Level::WARN,
+        tracing::Level::INFO => Level::INFO,
+        _ => Level::DEBUG
+    }
+}
+
+/// Installs a subscriber logging all `tracing` events using the given logger as global default subscriber.
+///
+/// # Parameters
+///
+/// - `logger`: The [`Logger`] to log the events with
+///
+/// # Returns
+///
+/// A `Result` indicating whether the subscriber was installed.
+///
+/// # Errors
+///
+/// Returns an initialization error if a global default subscriber was already installed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// logging_rs::tracing_adapter::install(logging_rs::Logger::default()).unwrap();
+///
+/// tracing::info!("Logged using tracing");
+/// ```
+///
+/// # See also
+///
+/// - [`LoggingLayer`]
+pub fn install(logger: Logger) -> Result<(), errors::Error> {
+    tracing::subscriber::set_global_default(tracing_subscriber::registry().with(LoggingLayer::new(logger)))
+        .map_err(|error: tracing::subscriber::SetGlobalDefaultError| errors::Error::new("Initialization error", &error.to_string(), 7))
+}
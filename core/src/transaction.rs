@@ -0,0 +1,396 @@
+#![doc = include_str!("../../.github/transaction.md")]
+// logging-rs transaction
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::{errors, memory, Level, Logger, Record};
+
+
+/////////////////
+// TRANSACTION //
+/////////////////
+
+/// Counter used to create unique group ids.
+static NEXT_GROUP: AtomicU64 = AtomicU64::new(1);
+
+/// Transaction object.
+///
+/// Groups the records of a unit of work and logs them at once when the transaction is committed or dropped. Every
+/// output receives the whole group in a single write, so records of concurrent requests are not interleaved. All
+/// records carry the `group` argument containing the id of the transaction.
+///
+/// Transactions can be used with the logging macros like loggers.
+///
+/// Use [`Logger::transaction()`] to create transaction objects.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// let transaction: logging_rs::transaction::Transaction = logger.transaction();
+///
+/// logging_rs::info!(transaction, "Handling request");
+/// logging_rs::info!(transaction, "Request handled");
+/// transaction.commit();
+/// ```
+#[derive(Debug)]
+pub struct Transaction<'a> {
+    /// The logger to log the records with.
+    logger: &'a Logger,
+    /// The id of the group.
+    id: String,
+    /// The collected records.
+    records: Mutex<Vec<Record>>,
+    /// The memory budget of the collected records.
+    memory: memory::Budget,
+}
+
+impl<'a> Transaction<'a> {
+    /// Creates a new transaction object.
+    ///
+    /// # Parameters
+    ///
+    /// - `logger`: The [`Logger`] to log the records with
+    ///
+    /// # Returns
+    ///
+    /// A new `Transaction` object with a unique group id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// logging_rs::transaction::Transaction::new(&logger);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Transaction`]
+    /// - [`Logger::transaction()`]
+    pub fn new(logger: &'a Logger) -> Transaction<'a> {
+        Transaction {
+            logger,
+            id: format!("{:x}-{:x}", std::process::id(), NEXT_GROUP.fetch_add(1, Ordering::Relaxed)),
+            records: Mutex::new(vec![]),
+            memory: memory::Budget::default()
+        }
+    }
+
+    /// Sets the maximum number of bytes the collected records may use.
+    ///
+    /// Records not fitting into the limit are dropped and counted as rejected.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    /// - `limit`: The maximum number of bytes, see [`Record::memory_size()`]
+    ///
+    /// # Returns
+    ///
+    /// The transaction object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let transaction: logging_rs::transaction::Transaction = logger.transaction().with_memory_limit(64 * 1024);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Transaction::memory()`]
+    /// - [`memory::Budget`]
+    pub fn with_memory_limit(self, limit: usize) -> Transaction<'a> {
+        self.memory.set_limit(limit);
+        self
+    }
+
+    /// Returns the memory usage statistics of the collected records.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    ///
+    /// # Returns
+    ///
+    /// The [`memory::MemoryStats`] of the collected records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// assert_eq!(transaction.memory().rejected, 0);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Transaction::with_memory_limit()`]
+    pub fn memory(&self) -> memory::MemoryStats {
+        self.memory.stats()
+    }
+
+    /// Returns the id of the group.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    ///
+    /// # Returns
+    ///
+    /// The group id, also available as `group` argument of the records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// println!("{}", transaction.id());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Transaction`]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns whether records with the given level are logged by the logger.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    /// - `level`: The [`Level`] to check
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the level is at least the minimum level of the logger.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// assert!(transaction.is_enabled(logging_rs::Level::INFO));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::is_enabled()`]
+    /// - [`Transaction`]
+    pub fn is_enabled(&self, level: Level) -> bool {
+        self.logger.is_enabled(level)
+    }
+
+    /// Adds the given message to the transaction.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// transaction.log("Some message", logging_rs::Level::INFO, "src/lib.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log()`]
+    /// - [`Transaction`]
+    pub fn log(&self, message: &str, level: Level, path: &str, arguments: Vec<(&str, String)>) {
+        self.log_record(&Record::new(level, message, path, arguments));
+    }
+
+    /// Adds the given static message without arguments to the transaction.
+    ///
+    /// Records of transactions are always created, so this is the same as calling [`Transaction::log()`] without
+    /// arguments. Used by the logging macros for string literals without arguments.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    /// - `message`: The message to log
+    /// - `level`: The log [`Level`] to use for logging
+    /// - `path`: The path of the calling file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// transaction.log_static("Some message", logging_rs::Level::DEBUG, "src/lib.rs");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_static()`]
+    /// - [`Transaction`]
+    pub fn log_static(&self, message: &'static str, level: Level, path: &'static str) {
+        self.log(message, level, path, vec![]);
+    }
+
+    /// Adds the given error with logging level [`Level::ERROR`] to the transaction.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    /// - `message`: The message to log
+    /// - `error`: The error to log
+    /// - `path`: The path of the calling file
+    /// - `arguments`: A list of arguments to use when formatting the message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::Other, "disk full");
+    /// transaction.log_error("Saving failed", &error, "src/lib.rs", vec![]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_error()`]
+    /// - [`Transaction`]
+    pub fn log_error<E: std::error::Error + ?Sized>(&self, message: &str, error: &E, path: &str, mut arguments: Vec<(&str, String)>) {
+        arguments.append(&mut errors::error_arguments(error));
+        self.log(message, Level::ERROR, path, arguments);
+    }
+
+    /// Adds the given record to the transaction.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    /// - `record`: The [`Record`] to log
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// transaction.log_record(&logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/lib.rs", vec![]));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_record()`]
+    /// - [`Transaction`]
+    pub fn log_record(&self, record: &Record) {
+        let mut record: Record = record.clone();
+        record.arguments.push(("group".to_owned(), self.id.clone()));
+
+        if !self.memory.reserve(record.memory_size()) {
+            return;
+        }
+
+        self.records.lock().unwrap_or_else(|error| error.into_inner()).push(record);
+    }
+
+    /// Logs all collected records at once and ends the transaction.
+    ///
+    /// Dropping the transaction commits it as well.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The transaction object
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// # let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// transaction.commit();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Logger::log_records()`]
+    /// - [`Transaction`]
+    pub fn commit(self) {
+        drop(self);
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        let records: Vec<Record> = std::mem::take(&mut *self.records.lock().unwrap_or_else(|error| error.into_inner()));
+        self.memory.release(self.memory.stats().used);
+
+        if !records.is_empty() {
+            self.logger.log_records(&records);
+        }
+    }
+}
+
+impl Logger {
+    /// Creates a transaction grouping records of a unit of work.
+    ///
+    /// # Parameters
+    ///
+    /// - `self`: The logger object
+    ///
+    /// # Returns
+    ///
+    /// A new [`Transaction`] object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use logging_rs;
+    /// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+    /// let transaction: logging_rs::transaction::Transaction = logger.transaction();
+    /// logging_rs::info!(transaction, "Grouped message");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`Transaction`]
+    pub fn transaction(&self) -> Transaction<'_> {
+        Transaction::new(self)
+    }
+}
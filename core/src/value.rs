@@ -0,0 +1,294 @@
+#![doc = include_str!("../../.github/value.md")]
+// logging-rs value
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::{OsStr, OsString};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::json;
+
+
+//////////////
+// TO VALUE //
+//////////////
+
+/// Trait for rendering values of logging macro arguments.
+///
+/// Arguments implementing this trait are rendered using it instead of their [`Display`] implementation, so types
+/// without a useful `Display` implementation can be logged without formatting them at every call site. It is
+/// implemented for [`Duration`] (`1.2s`), [`SystemTime`] (RFC 3339 in UTC) and paths and OS strings (lossless,
+/// invalid UTF-8 bytes are escaped as `\xNN`).
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// struct Celsius(f64);
+///
+/// impl logging_rs::value::ToValue for Celsius {
+///     fn to_value(&self) -> String {
+///         format!("{:.1}°C", self.0)
+///     }
+/// }
+///
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::info!(logger, "Temperature is {{temperature}}", "temperature" = Celsius(21.5));
+/// logging_rs::info!(logger, "Request took {{elapsed}}", "elapsed" = std::time::Duration::from_millis(1200));
+/// ```
+pub trait ToValue {
+    /// Renders the value as argument value.
+    fn to_value(&self) -> String;
+}
+
+impl<T: ToValue + ?Sized> ToValue for &T {
+    fn to_value(&self) -> String {
+        (**self).to_value()
+    }
+}
+
+impl ToValue for Duration {
+    fn to_value(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl ToValue for SystemTime {
+    fn to_value(&self) -> String {
+        chrono::DateTime::<chrono::Utc>::from(*self).to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+    }
+}
+
+impl ToValue for OsStr {
+    fn to_value(&self) -> String {
+        lossless(self)
+    }
+}
+
+impl ToValue for OsString {
+    fn to_value(&self) -> String {
+        lossless(self)
+    }
+}
+
+impl ToValue for Path {
+    fn to_value(&self) -> String {
+        lossless(self.as_os_str())
+    }
+}
+
+impl ToValue for PathBuf {
+    fn to_value(&self) -> String {
+        lossless(self.as_os_str())
+    }
+}
+
+/// Converts the given OS string to a string, escaping invalid UTF-8 bytes as `\xNN`.
+#[cfg(unix)]
+fn lossless(string: &OsStr) -> String {
+    let mut bytes: &[u8] = std::os::unix::ffi::OsStrExt::as_bytes(string);
+    let mut result: String = String::with_capacity(bytes.len());
+
+    loop {
+        match std::str::from_utf8(bytes) {
+            Ok(valid) => {
+                result.push_str(valid);
+                return result;
+            }
+            Err(error) => {
+                let (valid, invalid) = bytes.split_at(error.valid_up_to());
+                let length: usize = error.error_len().unwrap_or(invalid.len());
+
+                result.push_str(std::str::from_utf8(valid).unwrap_or_default());
+
+                for byte in &invalid[..length] {
+                    result.push_str(&format!("\\x{:02X}", byte));
+                }
+
+                bytes = &invalid[length..];
+            }
+        }
+    }
+}
+
+/// Converts the given OS string to a string, replacing unpaired surrogates.
+#[cfg(not(unix))]
+fn lossless(string: &OsStr) -> String {
+    string.to_string_lossy().into_owned()
+}
+
+
+///////////////
+// TO FIELDS //
+///////////////
+
+/// Trait for flattening nested values of logging macro arguments into multiple arguments.
+///
+/// Arguments prefixed with `#` are flattened using this trait. Map entries and array elements become separate
+/// arguments with dotted keys, like `headers.host` or `tags.0`, which [`sinks::Logfmt`](crate::sinks::Logfmt) writes
+/// as they are and [`json::Value`] nests into objects and arrays again. Empty maps and arrays add no arguments.
+///
+/// It is implemented for strings, numbers, booleans, vectors, slices, arrays, [`HashMap`]s and [`BTreeMap`]s with
+/// keys implementing [`Display`], options (`None` adds no arguments) and [`json::Value`]s.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let mut headers: std::collections::BTreeMap<&str, &str> = std::collections::BTreeMap::new();
+/// headers.insert("host", "example.com");
+/// headers.insert("accept", "*/*");
+///
+/// let mut fields: Vec<(String, String)> = vec![];
+/// logging_rs::value::ToFields::to_fields(&headers, "headers", &mut fields);
+/// assert_eq!(fields, vec![("headers.accept".to_owned(), "*/*".to_owned()), ("headers.host".to_owned(), "example.com".to_owned())]);
+///
+/// # let logger: logging_rs::Logger = logging_rs::Logger::default();
+/// logging_rs::info!(logger, "Request to {{headers.host}}", "headers" = #headers, "tags" = #["api", "v2"]);
+/// ```
+pub trait ToFields {
+    /// Appends the key-value pairs of the value to the given fields, using the given key as prefix.
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>);
+}
+
+/// Implements [`ToFields`] for leaf values using their [`Display`] implementation.
+macro_rules! leaf_fields {
+    ($($type:ty),+) => {
+        $(
+            impl ToFields for $type {
+                fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+                    fields.push((key.to_owned(), self.to_string()));
+                }
+            }
+        )+
+    };
+}
+
+leaf_fields!(str, String, bool, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T: ToFields + ?Sized> ToFields for &T {
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+        (**self).to_fields(key, fields);
+    }
+}
+
+impl<T: ToFields> ToFields for Option<T> {
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+        if let Some(value) = self {
+            value.to_fields(key, fields);
+        }
+    }
+}
+
+impl<T: ToFields> ToFields for [T] {
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+        for (index, value) in self.iter().enumerate() {
+            value.to_fields(&format!("{}.{}", key, index), fields);
+        }
+    }
+}
+
+impl<T: ToFields, const N: usize> ToFields for [T; N] {
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+        self[..].to_fields(key, fields);
+    }
+}
+
+impl<T: ToFields> ToFields for Vec<T> {
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+        self[..].to_fields(key, fields);
+    }
+}
+
+impl<K: Display, V: ToFields> ToFields for BTreeMap<K, V> {
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+        for (name, value) in self {
+            value.to_fields(&format!("{}.{}", key, name), fields);
+        }
+    }
+}
+
+impl<K: Display, V: ToFields, S> ToFields for HashMap<K, V, S> {
+    /// Appends the entries sorted by key, so the order of the arguments does not depend on the hasher.
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+        let mut entries: Vec<(String, &V)> = self.iter().map(|(name, value)| (name.to_string(), value)).collect();
+        entries.sort_by(|(first, _), (second, _)| first.cmp(second));
+
+        for (name, value) in entries {
+            value.to_fields(&format!("{}.{}", key, name), fields);
+        }
+    }
+}
+
+impl ToFields for json::Value {
+    fn to_fields(&self, key: &str, fields: &mut Vec<(String, String)>) {
+        match self {
+            json::Value::ARRAY(values) => values.to_fields(key, fields),
+            json::Value::OBJECT(entries) => {
+                for (name, value) in entries {
+                    value.to_fields(&format!("{}.{}", key, name), fields);
+                }
+            }
+            value => fields.push((key.to_owned(), value.to_text()))
+        }
+    }
+}
+
+
+/////////////////////
+// MACRO RENDERING //
+/////////////////////
+
+/// Wrapper selecting the rendering of logging macro arguments, see [`ToValue`].
+#[doc(hidden)]
+pub struct Wrap<'a, T: ?Sized>(pub &'a T);
+
+/// Renders arguments implementing [`ToValue`], preferred over [`ViaDisplay`].
+#[doc(hidden)]
+pub trait ViaToValue {
+    fn __value(&self) -> String;
+}
+
+impl<T: ToValue + ?Sized> ViaToValue for Wrap<'_, T> {
+    fn __value(&self) -> String {
+        self.0.to_value()
+    }
+}
+
+/// Renders arguments implementing [`Display`].
+#[doc(hidden)]
+pub trait ViaDisplay {
+    fn __value(&self) -> String;
+}
+
+impl<T: Display + ?Sized> ViaDisplay for &Wrap<'_, T> {
+    fn __value(&self) -> String {
+        self.0.to_string()
+    }
+}
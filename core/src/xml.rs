@@ -0,0 +1,134 @@
+#![doc = include_str!("../../.github/xml.md")]
+
+// logging-rs xml
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use crate::{attachments, ids, Formatter, Record};
+
+
+///////////////
+// FUNCTIONS //
+///////////////
+
+/// Escapes the given string as XML character data or attribute value.
+///
+/// Line breaks and tabs are escaped as character references, so elements stay on a single line. Characters not
+/// allowed in XML 1.0 documents, like most control characters, are replaced with `U+FFFD`.
+///
+/// # Parameters
+///
+/// - `string`: The string to escape
+///
+/// # Returns
+///
+/// A `String` containing the escaped string.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// assert_eq!(logging_rs::xml::escape("<a href=\"/\">Tom & Jerry</a>\n"), "&lt;a href=&quot;/&quot;&gt;Tom &amp; Jerry&lt;/a&gt;&#10;");
+/// ```
+///
+/// # See also
+///
+/// - [`element()`]
+pub fn escape(string: &str) -> String {
+    let mut result: String = String::with_capacity(string.len());
+
+    for character in string.chars() {
+        match character {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            '\n' => result.push_str("&#10;"),
+            '\r' => result.push_str("&#13;"),
+            '\t' => result.push_str("&#9;"),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => result.push('\u{fffd}'),
+            character => result.push(character)
+        }
+    }
+
+    result
+}
+
+/// Converts the given record into a `<record>` element on a single line.
+///
+/// The element has the `id` (UUID) attribute and contains the `timestamp` (RFC 3339), `level`, `path` and `message`
+/// (with the arguments inserted) elements, followed by an `arguments` element containing one `argument` element per
+/// argument and attachment of the record.
+///
+/// # Parameters
+///
+/// - `record`: The record to convert
+///
+/// # Returns
+///
+/// A `String` containing the element.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs;
+/// let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Hello {{user}}", "src/main.rs", vec![("user", "42".to_owned())]);
+/// let element: String = logging_rs::xml::element(&record);
+///
+/// assert!(element.starts_with("<record id=\""));
+/// assert!(element.contains("<level>INFO</level><path>src/main.rs</path><message>Hello 42</message>"));
+/// assert!(element.ends_with("<arguments><argument name=\"user\">42</argument></arguments></record>"));
+/// ```
+///
+/// # See also
+///
+/// - [`crate::Formatter::xml()`]
+pub fn element(record: &Record) -> String {
+    element_with(record, &record.timestamp.to_rfc3339())
+}
+
+/// Converts the given record into a `<record>` element using the given formatted timestamp.
+pub(crate) fn element_with(record: &Record, timestamp: &str) -> String {
+    let mut result: String = format!(
+        "<record id=\"{}\"><timestamp>{}</timestamp><level>{}</level><path>{}</path><message>{}</message><arguments>",
+        ids::to_uuid(record.id),
+        escape(timestamp),
+        escape(record.level.name()),
+        escape(&record.path),
+        escape(&Formatter::new("", "{{message}}", "").format_record_with(false, record))
+    );
+
+    let arguments = record.arguments.iter().map(|(key, value)| (key.as_str(), value.clone()));
+    let attachments = record.attachments.iter().map(|attachment| (attachment.name.as_str(), attachment.to_hex(attachments::PREVIEW_SIZE)));
+
+    for (key, value) in arguments.chain(attachments) {
+        result.push_str(&format!("<argument name=\"{}\">{}</argument>", escape(key), escape(&value)));
+    }
+
+    result.push_str("</arguments></record>");
+    result
+}
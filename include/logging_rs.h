@@ -0,0 +1,61 @@
+#ifndef LOGGING_RS_H
+#define LOGGING_RS_H
+
+/* This file is generated by cbindgen. Do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Value of [`Level::DEBUG`] for [`logging_rs_log()`].
+ */
+#define LOGGING_RS_DEBUG 0
+
+/**
+ * Value of [`Level::INFO`] for [`logging_rs_log()`].
+ */
+#define LOGGING_RS_INFO 1
+
+/**
+ * Value of [`Level::WARN`] for [`logging_rs_log()`].
+ */
+#define LOGGING_RS_WARN 2
+
+/**
+ * Value of [`Level::ERROR`] for [`logging_rs_log()`].
+ */
+#define LOGGING_RS_ERROR 3
+
+/**
+ * Value of [`Level::FATAL`] for [`logging_rs_log()`].
+ */
+#define LOGGING_RS_FATAL 4
+
+/**
+ * Value of [`Level::MESSAGE`] for [`logging_rs_log()`].
+ */
+#define LOGGING_RS_MESSAGE 5
+
+/**
+ * Return value of successful calls.
+ */
+#define LOGGING_RS_OK 0
+
+/**
+ * Return value of calls with invalid arguments, like null pointers, invalid UTF-8 or unknown levels.
+ */
+#define LOGGING_RS_INVALID_ARGUMENT -1
+
+typedef struct LoggingRsLogger LoggingRsLogger;
+
+LoggingRsLogger *logging_rs_new(void);
+
+int logging_rs_add_file(LoggingRsLogger *logger, const char *path);
+
+int logging_rs_log(const LoggingRsLogger *logger, int level, const char *message, const char *path);
+
+void logging_rs_free(LoggingRsLogger *logger);
+
+#endif /* LOGGING_RS_H */
@@ -0,0 +1,412 @@
+//! Derive and attribute macros for [logging-rs](https://docs.rs/logging_rs/).
+// logging-rs macros
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+
+
+//////////////
+// LOGGABLE //
+//////////////
+
+/// Derives `logging_rs::Loggable` and a redaction-aware [`std::fmt::Display`] implementation.
+///
+/// The generated `Display` implementation renders the value like its `Debug` representation, but replaces every
+/// field marked with `#[loggable(redact)]` with `[REDACTED]`. Using the alternate flag (`{:#}`) pretty-prints the value.
+/// Fields using generic type parameters are required to implement `Debug`, unless they are redacted.
+///
+/// If logging-rs is renamed or re-exported, set its path using `#[loggable(crate = "path::to::logging_rs")]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs_macros::Loggable;
+/// #[derive(Loggable)]
+/// struct Config {
+///     user: String,
+///     #[loggable(redact)]
+///     password: String
+/// }
+///
+/// let config: Config = Config { user: "user".to_owned(), password: "secret".to_owned() };
+/// assert_eq!(config.to_string(), "Config { user: \"user\", password: [REDACTED] }");
+/// ```
+#[proc_macro_derive(Loggable, attributes(loggable))]
+pub fn derive_loggable(input: TokenStream) -> TokenStream {
+    let input: syn::DeriveInput = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    match expand_loggable(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Expands the `Loggable` derive for the given input.
+fn expand_loggable(input: &syn::DeriveInput) -> syn::Result<TokenStream2> {
+    let name: &syn::Ident = &input.ident;
+    let krate: syn::Path = crate_path(&input.attrs)?;
+    let generics: syn::Generics = bounded_generics(input)?;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let body: TokenStream2 = match &input.data {
+        syn::Data::Struct(data) => {
+            let (pattern, fields) = expand_fields(&name.to_string(), &data.fields)?;
+
+            quote! {
+                let #name #pattern = self;
+                #fields
+            }
+        },
+        syn::Data::Enum(data) => {
+            let mut arms: Vec<TokenStream2> = vec![];
+
+            for variant in &data.variants {
+                let variant_name: &syn::Ident = &variant.ident;
+                let (pattern, fields) = expand_fields(&variant_name.to_string(), &variant.fields)?;
+
+                arms.push(quote! {
+                    #name::#variant_name #pattern => { #fields }
+                });
+            }
+
+            quote! {
+                match self {
+                    #(#arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => ::std::result::Result::Ok(())
+                }
+            }
+        },
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new_spanned(input, "Loggable can not be derived for unions"));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::fmt::Display for #name #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #body
+            }
+        }
+
+        impl #impl_generics #krate::Loggable for #name #type_generics #where_clause {}
+    })
+}
+
+/// Returns the path of the logging-rs crate set using `#[loggable(crate = "...")]`, `::logging_rs` by default.
+fn crate_path(attributes: &[syn::Attribute]) -> syn::Result<syn::Path> {
+    let mut krate: syn::Path = syn::parse_quote! { ::logging_rs };
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("loggable") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                krate = meta.value()?.parse::<syn::LitStr>()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported loggable attribute, expected `crate`"))
+            }
+        })?;
+    }
+
+    Ok(krate)
+}
+
+/// Returns the generics of the input with a `Debug` bound for every logged field type using a type parameter.
+fn bounded_generics(input: &syn::DeriveInput) -> syn::Result<syn::Generics> {
+    let mut generics: syn::Generics = input.generics.clone();
+    let parameters: Vec<syn::Ident> = generics.type_params().map(|parameter| parameter.ident.clone()).collect();
+    let fields: Vec<&syn::Field> = match &input.data {
+        syn::Data::Struct(data) => data.fields.iter().collect(),
+        syn::Data::Enum(data) => data.variants.iter().flat_map(|variant| variant.fields.iter()).collect(),
+        syn::Data::Union(_) => vec![]
+    };
+
+    for field in fields {
+        if !is_redacted(field)? && uses_type_parameter(field.ty.to_token_stream(), &parameters) {
+            let ty: &syn::Type = &field.ty;
+            generics.make_where_clause().predicates.push(syn::parse_quote! { #ty: ::std::fmt::Debug });
+        }
+    }
+
+    Ok(generics)
+}
+
+/// Checks whether the given tokens contain one of the given type parameters.
+fn uses_type_parameter(tokens: TokenStream2, parameters: &[syn::Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => parameters.contains(&ident),
+        proc_macro2::TokenTree::Group(group) => uses_type_parameter(group.stream(), parameters),
+        _ => false
+    })
+}
+
+/// Expands the destructuring pattern and the formatting code for the given fields.
+fn expand_fields(name: &str, fields: &syn::Fields) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let mut bindings: Vec<TokenStream2> = vec![];
+    let mut calls: Vec<TokenStream2> = vec![];
+
+    for (index, field) in fields.iter().enumerate() {
+        let redact: bool = is_redacted(field)?;
+        let binding: syn::Ident = format_ident!("__field_{}", index);
+        let value: TokenStream2 = if redact {
+            quote! { &::std::format_args!("[REDACTED]") }
+        } else {
+            quote! { #binding }
+        };
+
+        match &field.ident {
+            Some(ident) => {
+                let ident_name: String = ident.to_string();
+
+                bindings.push(quote! { #ident: #binding });
+                calls.push(quote! { .field(#ident_name, #value) });
+            },
+            None => {
+                bindings.push(quote! { #binding });
+                calls.push(quote! { .field(#value) });
+            }
+        }
+    }
+
+    Ok(match fields {
+        syn::Fields::Named(_) => (
+            quote! { { #(#bindings),* } },
+            quote! { f.debug_struct(#name) #(#calls)* .finish() }
+        ),
+        syn::Fields::Unnamed(_) => (
+            quote! { ( #(#bindings),* ) },
+            quote! { f.debug_tuple(#name) #(#calls)* .finish() }
+        ),
+        syn::Fields::Unit => (
+            quote! {},
+            quote! { f.write_str(#name) }
+        )
+    })
+}
+
+/// Checks whether the given field is marked with `#[loggable(redact)]`.
+fn is_redacted(field: &syn::Field) -> syn::Result<bool> {
+    let mut redact: bool = false;
+
+    for attribute in &field.attrs {
+        if !attribute.path().is_ident("loggable") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("redact") {
+                redact = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported loggable attribute, expected `redact`"))
+            }
+        })?;
+    }
+
+    Ok(redact)
+}
+
+
+////////////
+// LOGGED //
+////////////
+
+/// Logs the entry, arguments, return value and elapsed time of a function using the global logger.
+///
+/// On entry, a record with the name of the function and the [`Debug`] representation of every argument is logged. On
+/// exit, a record with the `Debug` representation of the return value and the elapsed time in microseconds is logged.
+/// Nothing is logged and no arguments are formatted if the global logger is not initialized or the level is disabled,
+/// see `Logger::is_enabled()`.
+///
+/// # Parameters
+///
+/// - `level`: The name of the level of the records, `debug` by default. `trace` is logged as `debug`
+/// - `skip`: The names of arguments not to log, for example arguments not implementing `Debug`
+/// - `skip_return`: Does not log the return value, for example if it does not implement `Debug`. Unit return values
+///   are never logged
+/// - `crate`: The path of logging-rs if it is renamed or re-exported, `::logging_rs` by default
+///
+/// # Examples
+///
+/// ```rust
+/// # use logging_rs_macros::logged;
+/// #[logged(level = "info", skip(password))]
+/// fn login(user: &str, password: &str) -> bool {
+///     user == "admin" && password == "secret"
+/// }
+///
+/// assert!(login("admin", "secret"));
+/// ```
+#[proc_macro_attribute]
+pub fn logged(attributes: TokenStream, item: TokenStream) -> TokenStream {
+    let mut options: LoggedOptions = LoggedOptions::default();
+    let parser = syn::meta::parser(|meta| options.parse(meta));
+
+    syn::parse_macro_input!(attributes with parser);
+    let function: syn::ItemFn = syn::parse_macro_input!(item as syn::ItemFn);
+
+    match expand_logged(&options, function) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Options of the `logged` attribute.
+#[derive(Default)]
+struct LoggedOptions {
+    /// The level of the records
+    level: Option<syn::LitStr>,
+    /// The names of arguments not to log
+    skip: Vec<syn::Ident>,
+    /// Whether not to log the return value
+    skip_return: bool,
+    /// The path of the logging-rs crate
+    krate: Option<syn::Path>,
+}
+
+impl LoggedOptions {
+    /// Parses a single option of the `logged` attribute.
+    fn parse(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("level") {
+            self.level = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("skip") {
+            meta.parse_nested_meta(|meta| {
+                self.skip.push(meta.path.require_ident()?.clone());
+                Ok(())
+            })
+        } else if meta.path.is_ident("skip_return") {
+            self.skip_return = true;
+            Ok(())
+        } else if meta.path.is_ident("crate") {
+            self.krate = Some(meta.value()?.parse::<syn::LitStr>()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported logged attribute, expected `level`, `skip`, `skip_return` or `crate`"))
+        }
+    }
+
+    /// Returns the path of the logging-rs crate, `::logging_rs` by default.
+    fn krate(&self) -> syn::Path {
+        self.krate.clone().unwrap_or_else(|| syn::parse_quote! { ::logging_rs })
+    }
+
+    /// Returns the path of the level constant.
+    fn level(&self) -> syn::Result<TokenStream2> {
+        let krate: syn::Path = self.krate();
+        let Some(level) = &self.level else {
+            return Ok(quote! { #krate::Level::DEBUG });
+        };
+
+        let variant: syn::Ident = match level.value().to_lowercase().as_str() {
+            "trace" | "debug" => format_ident!("DEBUG"),
+            "info" => format_ident!("INFO"),
+            "warn" => format_ident!("WARN"),
+            "error" => format_ident!("ERROR"),
+            "fatal" => format_ident!("FATAL"),
+            "message" => format_ident!("MESSAGE"),
+            _ => return Err(syn::Error::new_spanned(level, "unknown level, expected `trace`, `debug`, `info`, `warn`, `error`, `fatal` or `message`"))
+        };
+
+        Ok(quote! { #krate::Level::#variant })
+    }
+}
+
+/// Expands the `logged` attribute for the given function.
+fn expand_logged(options: &LoggedOptions, mut function: syn::ItemFn) -> syn::Result<TokenStream2> {
+    let krate: syn::Path = options.krate();
+    let level: TokenStream2 = options.level()?;
+    let name: String = function.sig.ident.to_string();
+    let mut arguments: Vec<TokenStream2> = vec![];
+
+    for input in &function.sig.inputs {
+        let syn::FnArg::Typed(argument) = input else {
+            continue;
+        };
+        let syn::Pat::Ident(pattern) = &*argument.pat else {
+            continue;
+        };
+
+        if options.skip.contains(&pattern.ident) {
+            continue;
+        }
+
+        let ident: &syn::Ident = &pattern.ident;
+        let key: String = ident.to_string();
+        arguments.push(quote! { (#key, ::std::format!("{:?}", #ident)) });
+    }
+
+    let block: &syn::Block = &function.block;
+    let result: TokenStream2 = match (&function.sig.asyncness, &function.sig.output) {
+        (Some(_), _) => quote! { let __logged_result = async move #block.await; },
+        (None, syn::ReturnType::Type(_, output)) if !matches!(output.as_ref(), syn::Type::ImplTrait(_)) => {
+            let output: TokenStream2 = output.to_token_stream();
+            quote! {
+                #[allow(clippy::redundant_closure_call)]
+                let __logged_result: #output = (move || #block)();
+            }
+        },
+        _ => quote! {
+            #[allow(clippy::redundant_closure_call)]
+            let __logged_result = (move || #block)();
+        }
+    };
+
+    let unit: bool = match &function.sig.output {
+        syn::ReturnType::Default => true,
+        syn::ReturnType::Type(_, output) => matches!(output.as_ref(), syn::Type::Tuple(tuple) if tuple.elems.is_empty())
+    };
+    let output: Option<TokenStream2> = (!options.skip_return && !unit).then(|| quote! { ("return", ::std::format!("{:?}", __logged_result)), });
+
+    function.block = Box::new(syn::parse_quote! {{
+        let __logged_start: ::std::time::Instant = ::std::time::Instant::now();
+
+        if let ::std::option::Option::Some(logger) = #krate::global::logger().filter(|logger| logger.is_enabled(#level)) {
+            logger.log("Entering {{function}}", #level, ::std::file!(), ::std::vec![("function", #name.to_owned()) #(, #arguments)*]);
+        }
+
+        #result
+
+        if let ::std::option::Option::Some(logger) = #krate::global::logger().filter(|logger| logger.is_enabled(#level)) {
+            logger.log("Exiting {{function}}", #level, ::std::file!(), ::std::vec![
+                ("function", #name.to_owned()),
+                #output
+                ("elapsed_us", __logged_start.elapsed().as_micros().to_string())
+            ]);
+        }
+
+        __logged_result
+    }});
+
+    Ok(function.into_token_stream())
+}
@@ -0,0 +1,104 @@
+// logging-rs derive tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use logging_rs::Loggable;
+
+    #[derive(Loggable)]
+    struct Config {
+        user: String,
+        #[loggable(redact)]
+        password: String
+    }
+
+    #[derive(Loggable)]
+    struct Credentials(String, #[loggable(redact)] String);
+
+    #[derive(Loggable)]
+    enum Secret {
+        Plain,
+        Token {
+            #[loggable(redact)]
+            value: String
+        }
+    }
+
+    #[derive(Loggable)]
+    struct Wrapper<T> {
+        inner: T
+    }
+
+    struct Key;
+
+    #[derive(Loggable)]
+    struct Sealed<T> {
+        label: &'static str,
+        #[loggable(redact)]
+        key: T
+    }
+
+    mod renamed {
+        pub use logging_rs::*;
+    }
+
+    #[derive(Loggable)]
+    #[loggable(crate = "renamed")]
+    struct Renamed(u8);
+
+    #[test]
+    fn test_loggable_struct() {
+        let config: Config = Config { user: "user".to_owned(), password: "secret".to_owned() };
+
+        assert_eq!(config.to_string(), "Config { user: \"user\", password: [REDACTED] }");
+        assert_eq!(config.log_value(), "Config { user: \"user\", password: [REDACTED] }");
+    }
+
+    #[test]
+    fn test_loggable_tuple_struct() {
+        let credentials: Credentials = Credentials("user".to_owned(), "secret".to_owned());
+
+        assert_eq!(credentials.to_string(), "Credentials(\"user\", [REDACTED])");
+    }
+
+    #[test]
+    fn test_loggable_enum() {
+        assert_eq!(Secret::Plain.to_string(), "Plain");
+        assert_eq!(Secret::Token { value: "secret".to_owned() }.to_string(), "Token { value: [REDACTED] }");
+    }
+
+    #[test]
+    fn test_loggable_generic() {
+        assert_eq!(Wrapper { inner: 5 }.to_string(), "Wrapper { inner: 5 }");
+        assert_eq!(Sealed { label: "api", key: Key }.log_value(), "Sealed { label: \"api\", key: [REDACTED] }");
+    }
+
+    #[test]
+    fn test_loggable_crate() {
+        assert_eq!(Renamed(1).log_value(), "Renamed(1)");
+    }
+}
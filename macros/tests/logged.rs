@@ -0,0 +1,135 @@
+// logging-rs logged tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+#[path = "../../tests/common/mod.rs"]
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    struct Token;
+
+    struct Unformattable;
+
+    impl std::fmt::Debug for Unformattable {
+        fn fmt(&self, _formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            panic!("Formatted a disabled argument")
+        }
+    }
+
+    struct Account {
+        balance: u32
+    }
+
+    impl Account {
+        #[logging_rs::logged(level = "info")]
+        fn withdraw(&mut self, amount: u32) -> Result<u32, String> {
+            let balance: u32 = self.balance.checked_sub(amount).ok_or("Insufficient funds")?;
+            self.balance = balance;
+            Ok(balance)
+        }
+    }
+
+    #[logging_rs::logged(level = "warn", skip(token))]
+    fn authorize(token: Token, amount: u32) -> bool {
+        let _token: Token = token;
+        amount < 100
+    }
+
+    #[logging_rs::logged]
+    fn inspect(value: Unformattable) -> Unformattable {
+        value
+    }
+
+    #[logging_rs::logged(level = "info", skip_return)]
+    fn issue(amount: u32) -> Token {
+        let _amount: u32 = amount;
+        Token
+    }
+
+    #[logging_rs::logged(level = "info")]
+    fn reset(amount: u32) {
+        let _amount: u32 = amount;
+    }
+
+    mod renamed {
+        pub use logging_rs::*;
+    }
+
+    #[logging_rs::logged(crate = "renamed")]
+    fn ping() -> bool {
+        true
+    }
+
+    #[test]
+    fn test_logged() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).with_level(logging_rs::Level::INFO);
+        logger.add_sink(collector.clone());
+
+        let mut account: Account = Account { balance: 50 };
+        assert_eq!(account.withdraw(20), Ok(30));
+        assert!(ping());
+
+        logging_rs::init(logger).unwrap();
+
+        assert_eq!(account.withdraw(40), Err("Insufficient funds".to_owned()));
+        assert!(authorize(Token, 20));
+        let _value: Unformattable = inspect(Unformattable);
+        let _token: Token = issue(10);
+        reset(5);
+
+        let records: Vec<logging_rs::Record> = collector.records();
+        let levels: Vec<logging_rs::Level> = records.iter().map(|record: &logging_rs::Record| record.level).collect();
+        let fields: Vec<(Option<&str>, Option<&str>, Option<&str>)> = records.iter()
+            .map(|record: &logging_rs::Record| (record.argument("function"), record.argument("amount"), record.argument("return")))
+            .collect();
+
+        assert_eq!(levels.iter().filter(|level: &&logging_rs::Level| **level == logging_rs::Level::WARN).count(), 2);
+
+        assert_eq!(
+            fields,
+            vec![
+                (Some("withdraw"), Some("40"), None),
+                (Some("withdraw"), None, Some("Err(\"Insufficient funds\")")),
+                (Some("authorize"), Some("20"), None),
+                (Some("authorize"), None, Some("true")),
+                (Some("issue"), Some("10"), None),
+                (Some("issue"), None, None),
+                (Some("reset"), Some("5"), None),
+                (Some("reset"), None, None)
+            ]
+        );
+        assert!(records[1].argument("elapsed_us").is_some());
+    }
+}
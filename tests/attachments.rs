@@ -0,0 +1,87 @@
+// logging-rs attachments tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_attachment_new() {
+        let data: Vec<u8> = vec![0; logging_rs::attachments::MAX_ATTACHMENT_SIZE + 1];
+        let attachment: logging_rs::attachments::Attachment = logging_rs::attachments::Attachment::new("data", &data);
+
+        assert_eq!(attachment.data.len(), logging_rs::attachments::MAX_ATTACHMENT_SIZE);
+        assert_eq!(attachment.size, logging_rs::attachments::MAX_ATTACHMENT_SIZE + 1);
+    }
+
+    #[test]
+    fn test_attachment_to_hex() {
+        let attachment: logging_rs::attachments::Attachment = logging_rs::attachments::Attachment::new("data", &[0x00, 0x0f, 0xff]);
+
+        assert_eq!(attachment.to_hex(3), "00 0f ff");
+        assert_eq!(attachment.to_hex(1), "00 ... (+2 bytes)");
+    }
+
+    #[test]
+    fn test_encode_base64() {
+        assert_eq!(logging_rs::attachments::encode_base64(b""), "");
+        assert_eq!(logging_rs::attachments::encode_base64(b"f"), "Zg==");
+        assert_eq!(logging_rs::attachments::encode_base64(b"fo"), "Zm8=");
+        assert_eq!(logging_rs::attachments::encode_base64(b"foo"), "Zm9v");
+        assert_eq!(logging_rs::attachments::encode_base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_record_attachment() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{message}}", "{{message}}", "");
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::DEBUG, "Frame: {{frame}}", "tests/attachments.rs", vec![])
+            .with_attachment("frame", &[0xde, 0xad]);
+
+        assert_eq!(formatter.format_record(&logging_rs::Output::STDOUT, &record), "Frame: de ad");
+    }
+
+    #[test]
+    fn test_hexdump() {
+        let data: Vec<u8> = (0x30..0x50).chain([b'{', 0x00]).collect();
+
+        assert_eq!(
+            logging_rs::attachments::hexdump(&data),
+            "00000000  30 31 32 33 34 35 36 37  38 39 3a 3b 3c 3d 3e 3f  |0123456789:;<=>?|\n\
+             00000010  40 41 42 43 44 45 46 47  48 49 4a 4b 4c 4d 4e 4f  |@ABCDEFGHIJKLMNO|\n\
+             00000020  7b 00                                             |..|"
+        );
+    }
+
+    #[test]
+    fn test_hexdump_macro() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_hexdump.log");
+        let _ = std::fs::remove_file(&path);
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "{{message}}", ""), vec![logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }]);
+
+        logging_rs::hexdump!(logger, logging_rs::Level::DEBUG, b"Hi");
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Hex dump of 2 bytes:\n00000000  48 69                                             |Hi|\n");
+    }
+}
@@ -0,0 +1,66 @@
+// logging-rs benchmark tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct Counter(Arc<AtomicUsize>);
+
+    impl logging_rs::sinks::Sink for Counter {
+        fn write(&self, _formatter: &logging_rs::Formatter, _record: &logging_rs::Record) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_self_benchmark() {
+        let counter: Counter = Counter::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(counter.clone());
+
+        let report: logging_rs::benchmark::BenchmarkReport = logger.self_benchmark(200);
+
+        assert_eq!(report.records, 200);
+        assert_eq!(counter.0.load(Ordering::Relaxed), 200);
+        assert!(report.p50 <= report.p99 && report.p99 <= report.max && report.max <= report.elapsed);
+
+        let record: logging_rs::Record = report.to_record();
+        assert_eq!(record.argument("records"), Some("200"));
+        assert_eq!(record.argument("p99_us"), Some(report.p99.as_micros().to_string().as_str()));
+    }
+
+    #[test]
+    fn test_empty_benchmark() {
+        let report: logging_rs::benchmark::BenchmarkReport = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).self_benchmark(0);
+
+        assert_eq!(report.records, 0);
+        assert_eq!(report.max, std::time::Duration::ZERO);
+    }
+}
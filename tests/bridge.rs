@@ -0,0 +1,84 @@
+// logging-rs bridge tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "log")]
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(logging_rs::bridge::level(log::Level::Error), logging_rs::Level::ERROR);
+        assert_eq!(logging_rs::bridge::level(log::Level::Trace), logging_rs::Level::DEBUG);
+        assert_eq!(logging_rs::bridge::level_filter(logging_rs::Level::DEBUG), log::LevelFilter::Trace);
+        assert_eq!(logging_rs::bridge::level_filter(logging_rs::Level::WARN), log::LevelFilter::Warn);
+        assert_eq!(logging_rs::bridge::level_filter(logging_rs::Level::FATAL), log::LevelFilter::Error);
+        assert_eq!(logging_rs::bridge::level_filter(logging_rs::filter::OFF), log::LevelFilter::Off);
+    }
+
+    #[test]
+    fn test_install_as_log_backend() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).with_level(logging_rs::Level::INFO);
+        logger.add_sink(collector.clone());
+
+        assert_eq!(logging_rs::bridge::reconfigure(|_logger: &mut logging_rs::Logger| {}).unwrap_err().exit_code, 7);
+
+        logging_rs::bridge::install_as_log_backend(logger).unwrap();
+        assert_eq!(logging_rs::bridge::install_as_log_backend(logging_rs::Logger::default()).unwrap_err().exit_code, 7);
+        assert_eq!(log::max_level(), log::LevelFilter::Info);
+
+        log::debug!("Skipped");
+        log::info!("Request {} handled", 42);
+        log::warn!(target: "hyper::client", "Connection reset");
+        log::logger().flush();
+
+        logging_rs::bridge::reconfigure(|logger: &mut logging_rs::Logger| logger.level = logging_rs::Level::DEBUG).unwrap();
+        assert_eq!(log::max_level(), log::LevelFilter::Trace);
+        log::debug!("Not skipped");
+
+        logging_rs::bridge::reconfigure(|logger: &mut logging_rs::Logger| logger.filter = logging_rs::filter::EnvFilter::parse("error").unwrap()).unwrap();
+        assert_eq!(log::max_level(), log::LevelFilter::Error);
+
+        let records: Vec<(logging_rs::Level, String, String)> = collector.records().iter().map(|record: &logging_rs::Record| {
+            (record.level, record.message.clone(), record.argument("target").unwrap_or_default().to_owned())
+        }).collect();
+
+        assert_eq!(records, vec![
+            (logging_rs::Level::INFO, "Request 42 handled".to_owned(), "bridge::tests".to_owned()),
+            (logging_rs::Level::WARN, "Connection reset".to_owned(), "hyper::client".to_owned()),
+            (logging_rs::Level::DEBUG, "Not skipped".to_owned(), "bridge::tests".to_owned())
+        ]);
+    }
+}
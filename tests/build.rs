@@ -0,0 +1,43 @@
+// logging-rs build tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_build_info_macro() {
+        assert_eq!(logging_rs::build_info!(), logging_rs::build::BuildInfo::new("unknown", "unknown", "unknown"));
+    }
+
+    #[test]
+    fn test_set_build_info() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("", "{{build.sha}} {{build.profile}} {{message}}", "");
+        logging_rs::build::set_build_info(logging_rs::build::BuildInfo::new("8e3bb57", "2023-11-27T20:49:47Z", "release"));
+
+        assert_eq!(logging_rs::build::build_info().unwrap().time, "2023-11-27T20:49:47Z");
+        assert_eq!(formatter.format(logging_rs::Output::FILE { path: String::new() }, logging_rs::Level::INFO, "Test", vec![]), "8e3bb57 release Test");
+    }
+}
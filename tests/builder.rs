@@ -0,0 +1,196 @@
+// logging-rs builder tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_builder() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{message}}", "{{message}}", "%Y");
+        let logger: logging_rs::Logger = logging_rs::Logger::builder()
+            .level(logging_rs::Level::INFO)
+            .output(logging_rs::Output::STDERR)
+            .output_file("app.log")
+            .formatter(formatter.clone())
+            .mirror_fatal(false)
+            .build()
+            .unwrap();
+
+        let mut expected: logging_rs::Logger = logging_rs::Logger::new(
+            formatter,
+            vec![logging_rs::Output::STDERR, logging_rs::Output::FILE { path: "app.log".to_owned() }]
+        ).with_level(logging_rs::Level::INFO);
+        expected.mirror_fatal = false;
+
+        assert_eq!(logger, expected);
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let logger: logging_rs::Logger = logging_rs::Logger::builder().build().unwrap();
+        assert_eq!(logger.writable_list, vec![logging_rs::Output::STDOUT]);
+
+        let logger: logging_rs::Logger = logging_rs::Logger::builder().named_sink("audit", logging_rs::Output::STDERR).build().unwrap();
+        assert!(logger.writable_list.is_empty());
+        assert_eq!(logger.sinks.len(), 1);
+
+        let logger: logging_rs::Logger = logging_rs::builder::LoggerBuilder::default().output(logging_rs::Output::STDERR).build().unwrap();
+        assert_eq!(logger.writable_list, logging_rs::builder::LoggerBuilder::new().output(logging_rs::Output::STDERR).build().unwrap().writable_list);
+        assert_eq!(logging_rs::builder::LoggerBuilder::default().build().unwrap(), logging_rs::builder::LoggerBuilder::new().build().unwrap());
+    }
+
+    #[test]
+    fn test_builder_empty_outputs() {
+        let logger: logging_rs::Logger = logging_rs::Logger::builder().empty_outputs(logging_rs::builder::EmptyOutputs::STDERR).build().unwrap();
+        assert_eq!(logger.writable_list, vec![logging_rs::Output::STDERR]);
+
+        let logger: logging_rs::Logger = logging_rs::Logger::builder().empty_outputs(logging_rs::builder::EmptyOutputs::DROP).build().unwrap();
+        assert!(logger.writable_list.is_empty());
+        assert!(logger.sinks.is_empty());
+
+        let error: logging_rs::errors::Error = logging_rs::Logger::builder().empty_outputs(logging_rs::builder::EmptyOutputs::ERROR).build().unwrap_err();
+        assert_eq!(error.exit_code, 10);
+
+        let logger: logging_rs::Logger = logging_rs::Logger::builder()
+            .empty_outputs(logging_rs::builder::EmptyOutputs::ERROR)
+            .output(logging_rs::Output::STDOUT)
+            .build()
+            .unwrap();
+        assert_eq!(logger.writable_list, vec![logging_rs::Output::STDOUT]);
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        let duplicate: logging_rs::errors::Error = logging_rs::Logger::builder()
+            .output_file("app.log")
+            .output(logging_rs::Output::FILE { path: "app.log".to_owned() })
+            .build()
+            .unwrap_err();
+        assert_eq!(duplicate.exit_code, 10);
+
+        let empty: logging_rs::errors::Error = logging_rs::Logger::builder().output_file("").build().unwrap_err();
+        assert_eq!(empty.exit_code, 10);
+
+        let format: logging_rs::errors::Error = logging_rs::Logger::builder()
+            .formatter(logging_rs::Formatter::new("{{message}}", "{{message}}", "%Q"))
+            .build()
+            .unwrap_err();
+        assert_eq!(format.exit_code, 9);
+    }
+
+    #[test]
+    fn test_formatter_builder() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::builder()
+            .timestamp_format("%H:%M")
+            .text("[")
+            .timestamp()
+            .text("] ")
+            .level()
+            .field("uuid")
+            .separator(" | ")
+            .path()
+            .build()
+            .unwrap();
+
+        assert_eq!(formatter.color_format_string, "[{{color.bright_blue}}{{timestamp}}{{end}}] {{level}} | {{uuid}} | {{path}}");
+        assert_eq!(formatter.format_string, "[{{timestamp}}] {{level}} | {{uuid}} | {{path}}");
+        assert_eq!(formatter.timestamp_format, "%H:%M");
+
+        assert_eq!(logging_rs::Formatter::builder().timestamp_format("%Q").build().unwrap_err().exit_code, 9);
+
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::builder()
+            .separator(" - ")
+            .level()
+            .message()
+            .level_format(logging_rs::Level::ERROR, logging_rs::Formatter::builder().level().message().field("backtrace"))
+            .build()
+            .unwrap();
+
+        assert_eq!(formatter.level_formats, vec![logging_rs::LevelFormat {
+            level: logging_rs::Level::ERROR,
+            color_format_string: "{{level}} - {{message}} - {{backtrace}}".into(),
+            format_string: "{{level}} - {{message}} - {{backtrace}}".into()
+        }]);
+    }
+
+    #[test]
+    fn test_formatter_presets() {
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::WARN, "Say \"{{word}}\"", "src/main.rs", vec![("word", "hi\n".to_owned())]);
+
+        for formatter in [logging_rs::Formatter::compact(), logging_rs::Formatter::detailed(), logging_rs::Formatter::json(), logging_rs::Formatter::json_lines(), logging_rs::Formatter::gelf(), logging_rs::Formatter::syslog(logging_rs::syslog::Facility::USER), logging_rs::Formatter::xml(), logging_rs::Formatter::csv()] {
+            assert!(formatter.validate().is_ok());
+        }
+
+        assert!(logging_rs::Formatter::compact().format_record(&logging_rs::Output::FILE { path: String::new() }, &record).ends_with(" WARNING Say \"hi\n\""));
+        assert!(logging_rs::Formatter::detailed().format_record(&logging_rs::Output::FILE { path: String::new() }, &record).contains("] [WARNING] ["));
+        assert_eq!(logging_rs::Formatter::csv().format_record(&logging_rs::Output::STDOUT, &record), format!("{},WARNING,src/main.rs,\"Say \"\"hi\n\"\"\"", record.timestamp.to_rfc3339()));
+
+        let json: logging_rs::json::Value = logging_rs::json::Value::parse(&logging_rs::Formatter::json().format_record(&logging_rs::Output::STDOUT, &record)).unwrap();
+        assert_eq!(json.get("level").and_then(|level: &logging_rs::json::Value| level.as_str()), Some("WARNING"));
+        assert_eq!(json.get("path").and_then(|path: &logging_rs::json::Value| path.as_str()), Some("src/main.rs"));
+        assert_eq!(json.get("message").and_then(|message: &logging_rs::json::Value| message.as_str()), Some("Say \"hi\n\""));
+
+        let mut formatter: logging_rs::Formatter = logging_rs::Formatter::json_lines();
+        formatter.timestamp_format = "%Y".into();
+
+        let line: String = formatter.format_record(&logging_rs::Output::STDOUT, &record.clone().with_attachment("payload", b"ok"));
+        assert!(!line.contains('\n'));
+
+        let json: logging_rs::json::Value = logging_rs::json::Value::parse(&line).unwrap();
+        assert_eq!(json.get("timestamp").and_then(|timestamp: &logging_rs::json::Value| timestamp.as_str()), Some(record.timestamp.format("%Y").to_string().as_str()));
+        assert_eq!(json.get("level").and_then(|level: &logging_rs::json::Value| level.as_str()), Some("WARNING"));
+        assert_eq!(json.get("message").and_then(|message: &logging_rs::json::Value| message.as_str()), Some("Say \"hi\n\""));
+        assert_eq!(json.get("word").and_then(|word: &logging_rs::json::Value| word.as_str()), Some("hi\n"));
+        assert!(json.get("payload").is_some());
+    }
+
+    #[test]
+    fn test_formatter_placeholder_values() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_placeholder_values.log");
+        std::fs::remove_file(&path).ok();
+
+        for formatter in [logging_rs::Formatter::json(), logging_rs::Formatter::json_lines()] {
+            let logger: logging_rs::Logger = logging_rs::Logger::new(formatter, vec![logging_rs::Output::FILE { path: path.to_string_lossy().into_owned() }]);
+
+            logging_rs::info!(logger, "{{red}} \"x\"");
+            logging_rs::info!(logger, "Login by {{user}}", user = "{{color.red}}{{user}}{{message.json}}\"");
+        }
+
+        let lines: String = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines.lines().count(), 4);
+
+        for line in lines.lines() {
+            let json: serde_json::Value = serde_json::from_str(line).unwrap();
+            let message: &str = json["message"].as_str().unwrap();
+
+            assert!(message == "{{red}} \"x\"" || message == "Login by {{color.red}}{{user}}{{message.json}}\"", "{}", message);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// logging-rs callsites tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "callsites")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[allow(dead_code)]
+    fn never_called(logger: &logging_rs::Logger, level: logging_rs::Level) {
+        logging_rs::info!(logger, "Never called {{value}}", value = 1);
+        logging_rs::error!(logger, "Never called either", error = std::fmt::Error);
+        logging_rs::log_at!(logger, level, "Runtime level");
+        logging_rs::warn!(pending: logger, to: "audit", r#"Raw "template""#);
+    }
+
+    fn find(template: &str) -> &'static logging_rs::callsites::Callsite {
+        logging_rs::callsites::callsites()
+            .iter()
+            .find(|callsite: &&logging_rs::callsites::Callsite| callsite.template().as_deref() == Some(template))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_callsites() {
+        let info: &logging_rs::callsites::Callsite = find("Never called {{value}}");
+        assert_eq!(info.file, "tests/callsites.rs");
+        assert_eq!(info.line, 33);
+        assert_eq!(info.module_path, "callsites::tests");
+        assert_eq!(info.level(), Some(logging_rs::Level::INFO));
+
+        assert_eq!(find("Never called either").level(), Some(logging_rs::Level::ERROR));
+        assert_eq!(find("Runtime level").level(), None);
+        assert_eq!(find("Raw \"template\"").level(), Some(logging_rs::Level::WARN));
+    }
+
+    #[test]
+    fn test_template() {
+        let callsite = |message_expression: &'static str| logging_rs::callsites::Callsite {
+            file: "tests/callsites.rs",
+            line: 1,
+            column: 1,
+            module_path: "callsites",
+            level_expression: "level",
+            message_expression
+        };
+
+        assert_eq!(callsite(r#""Tab\tnew\nline \x41 \u{e9} \"quoted\"""#).template().as_deref(), Some("Tab\tnew\nline A é \"quoted\""));
+        assert_eq!(callsite("\"Continued \\\n    line\"").template().as_deref(), Some("Continued line"));
+        assert_eq!(callsite("r##\"Raw \"#\"##").template().as_deref(), Some("Raw \"#"));
+        assert_eq!(callsite("message").template(), None);
+        assert_eq!(callsite("format!(\"{}\", 1)").template(), None);
+    }
+
+    #[test]
+    fn test_toggles() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_callsite_toggles.log");
+        let _ = std::fs::remove_file(&path);
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "{{message}}", ""), vec![logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }]);
+        let log = |attempt: u32| {
+            logging_rs::info!(logger, "Noisy {{attempt}}", attempt = attempt);
+            logging_rs::warn!(pending: logger, "Pending {{attempt}}", attempt = attempt);
+            logging_rs::error!(logger, "Error {{attempt}}", error = std::fmt::Error, attempt = attempt);
+        };
+        let ids: Vec<String> = ["Noisy {{attempt}}", "Pending {{attempt}}", "Error {{attempt}}"].iter().map(|template: &&str| find(template).id()).collect();
+
+        log(1);
+        assert!(ids.iter().all(|id: &String| logging_rs::callsites::disable(id)));
+        log(2);
+        assert!(logging_rs::callsites::enable(&ids[0]));
+        log(3);
+
+        assert!(!logging_rs::callsites::disable("invalid"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Noisy 1\nPending 1\nError 1\nNoisy 3\n");
+        assert!(ids[0].starts_with("tests/callsites.rs:"));
+    }
+}
@@ -0,0 +1,73 @@
+// logging-rs capture tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "testutil")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_capture() {
+        let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "[{{level}}] {{message}}", ""), vec![]);
+        logger.add_sink(capture.clone());
+
+        logging_rs::info!(logger, "Started {{name}}", "name" = "worker");
+        logging_rs::error!(logger, "Failed");
+
+        assert_eq!(capture.len(), 2);
+        assert_eq!(capture.lines(), vec!["[INFO] Started worker".to_owned(), "[ERROR] Failed".to_owned()]);
+        assert_eq!(capture.messages(), vec!["Started worker".to_owned(), "Failed".to_owned()]);
+        assert_eq!(capture.records()[0].argument("name"), Some("worker"));
+        assert!(capture.contains(logging_rs::Level::INFO, "worker"));
+        assert!(!capture.contains(logging_rs::Level::ERROR, "worker"));
+
+        logger.shutdown(std::time::Duration::from_secs(1));
+        assert_eq!(capture.flushes(), 1);
+
+        capture.clear();
+        assert!(capture.is_empty());
+    }
+
+    #[test]
+    fn test_failing() {
+        let errors: std::sync::Arc<std::sync::Mutex<Vec<String>>> = std::sync::Arc::default();
+        let reported: std::sync::Arc<std::sync::Mutex<Vec<String>>> = errors.clone();
+        logging_rs::errors::set_handler(move |_error: &logging_rs::errors::Error, details: &str| {
+            reported.lock().unwrap().push(details.to_owned());
+        });
+
+        let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(logging_rs::testutil::capture::Failing::new("Disk full"));
+        logger.add_sink(capture.clone());
+
+        logging_rs::info!(logger, "Written anyway");
+        logging_rs::errors::reset_handler();
+
+        assert_eq!(capture.messages(), vec!["Written anyway".to_owned()]);
+        assert!(errors.lock().unwrap().iter().any(|details: &String| details.contains("Disk full")));
+    }
+}
@@ -0,0 +1,71 @@
+// logging-rs categories tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_categories() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        logging_rs::debug_cat!(logger, "wire", "Before");
+        logger.enable_category("wire");
+        logging_rs::debug_cat!(logger, "wire", "Enabled", "bytes" = 42);
+        logging_rs::debug_cat!(logger, "cache", "Other category");
+        logger.disable_category("wire");
+        logging_rs::debug_cat!(logger, "wire", "After");
+
+        let records: Vec<logging_rs::Record> = collector.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message, "Enabled");
+        assert_eq!(records[0].level, logging_rs::Level::DEBUG);
+        assert_eq!(records[0].argument("category"), Some("wire"));
+        assert_eq!(records[0].argument("bytes"), Some("42"));
+    }
+
+    #[test]
+    fn test_environment() {
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+
+        std::env::set_var(logging_rs::categories::ENVIRONMENT_VARIABLE, "sql, dns");
+        assert!(logger.is_category_enabled("dns"));
+        assert!(!logger.is_category_enabled("http"));
+
+        std::env::remove_var(logging_rs::categories::ENVIRONMENT_VARIABLE);
+        assert!(!logger.is_category_enabled("dns"));
+    }
+}
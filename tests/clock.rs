@@ -0,0 +1,143 @@
+// logging-rs clock tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "testutil")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    fn record(message: &str) -> logging_rs::Record {
+        logging_rs::Record::new(logging_rs::Level::INFO, message, "tests/clock.rs", vec![])
+    }
+
+    #[test]
+    fn test_clock() {
+        let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+        let start: std::time::Instant = clock.now();
+
+        clock.clone().advance(std::time::Duration::from_millis(1500));
+
+        assert_eq!(clock.elapsed(), std::time::Duration::from_millis(1500));
+        assert_eq!(clock.now() - start, std::time::Duration::from_millis(1500));
+        assert_eq!(clock.timestamp().to_rfc3339(), "2023-01-01T00:00:01.500+00:00");
+    }
+
+    #[test]
+    fn test_install() {
+        let outer: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+        let inner: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+        inner.advance(std::time::Duration::from_secs(60));
+
+        let outer_guard: logging_rs::testutil::clock::ClockGuard = outer.install();
+        assert_eq!(record("outer").timestamp, outer.timestamp());
+
+        {
+            let _inner_guard: logging_rs::testutil::clock::ClockGuard = inner.install();
+            assert_eq!(record("inner").timestamp, inner.timestamp());
+
+            let other: chrono::DateTime<chrono::Utc> = std::thread::spawn(|| record("other").timestamp).join().unwrap();
+            assert_ne!(other, inner.timestamp());
+        }
+
+        assert_eq!(record("outer").timestamp, outer.timestamp());
+        drop(outer_guard);
+        assert_ne!(record("system").timestamp, outer.timestamp());
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+        let guard: logging_rs::testutil::clock::ClockGuard = clock.install();
+
+        assert_eq!(record("before").argument("clock_skew"), None);
+        clock.advance(std::time::Duration::from_secs(10));
+        assert_eq!(record("later").argument("clock_skew"), None);
+        drop(guard);
+
+        let behind: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::with_timestamp(clock.timestamp() - chrono::Duration::seconds(5));
+        let _guard: logging_rs::testutil::clock::ClockGuard = behind.install();
+
+        assert_eq!(record("behind").argument("clock_skew"), Some("true"));
+        behind.advance(std::time::Duration::from_secs(4));
+        assert_eq!(record("still behind").argument("clock_skew"), Some("true"));
+        behind.advance(std::time::Duration::from_secs(2));
+        assert_eq!(record("caught up").argument("clock_skew"), None);
+    }
+
+    #[test]
+    fn test_coalesced_window() {
+        let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+        let _guard: logging_rs::testutil::clock::ClockGuard = clock.install();
+
+        let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+        let coalesced = logging_rs::sinks::coalesced(capture.clone(), 1).with_window(std::time::Duration::from_secs(10));
+
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record("first"));
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record("first"));
+        clock.advance(std::time::Duration::from_secs(9));
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record("first"));
+        assert_eq!(capture.len(), 1);
+
+        clock.advance(std::time::Duration::from_secs(1));
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record("second"));
+
+        assert_eq!(capture.messages(), vec!["first".to_owned(), "first (2 similar records coalesced)".to_owned(), "second".to_owned()]);
+    }
+
+    #[test]
+    fn test_sampled_window() {
+        let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+        let _guard: logging_rs::testutil::clock::ClockGuard = clock.install();
+
+        let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+        let sampled = logging_rs::sinks::sampled(capture.clone(), "user", 2).with_window(std::time::Duration::from_secs(5));
+        let login: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Login", "tests/clock.rs", vec![("user", "alice".to_owned())]);
+
+        for _ in 0..10 {
+            logging_rs::sinks::Sink::write(&sampled, &logging_rs::Formatter::default(), &login);
+            clock.advance(std::time::Duration::from_secs(5));
+        }
+
+        assert_eq!(capture.len(), 10);
+        assert!(capture.records().iter().all(|record: &logging_rs::Record| record.argument("sample_rate").is_none()));
+    }
+
+    #[test]
+    fn test_span_elapsed() {
+        let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+        let _guard: logging_rs::testutil::clock::ClockGuard = clock.install();
+
+        let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(capture.clone());
+
+        {
+            let _span: logging_rs::span::Span = logging_rs::span::Span::new(&logger, "request");
+            clock.advance(std::time::Duration::from_millis(250));
+        }
+
+        assert_eq!(capture.records()[1].argument("elapsed_us"), Some("250000"));
+    }
+}
@@ -0,0 +1,118 @@
+// logging-rs codec tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    fn codecs() -> Vec<Box<dyn logging_rs::codec::Codec>> {
+        ["none", "gzip", "zstd", "lz4"].iter().filter_map(|name: &&str| logging_rs::codec::from_name(name)).collect()
+    }
+
+    #[test]
+    fn test_codecs() {
+        let data: Vec<u8> = "Some message\n".repeat(100).into_bytes();
+
+        for codec in codecs() {
+            let compressed: Vec<u8> = codec.compress(&data).unwrap();
+
+            assert_eq!(codec.decompress(&compressed).unwrap(), data, "{}", codec.name());
+            assert_eq!(logging_rs::codec::from_name(codec.name()).unwrap().name(), codec.name());
+
+            if !codec.extension().is_empty() {
+                assert!(compressed.len() < data.len(), "{}", codec.name());
+                assert_eq!(codec.decompress(b"not compressed").unwrap_err().exit_code, 11, "{}", codec.name());
+            }
+        }
+
+        assert!(logging_rs::codec::from_name("rar").is_none());
+    }
+
+    #[test]
+    fn test_archive() {
+        for codec in codecs() {
+            let path: std::path::PathBuf = std::env::temp_dir().join(format!("logging_rs_test_archive_{}.log", codec.name()));
+            std::fs::write(&path, "Some message\n").unwrap();
+
+            let archive: std::path::PathBuf = logging_rs::codec::archive(&path, codec.as_ref()).unwrap();
+
+            assert_eq!(codec.decompress(&std::fs::read(&archive).unwrap()).unwrap(), b"Some message\n");
+            assert_eq!(path.exists(), codec.extension().is_empty());
+            assert_eq!(archive.extension().unwrap(), if codec.extension().is_empty() { "log" } else { codec.extension() });
+
+            std::fs::remove_file(archive).unwrap();
+        }
+
+        assert_eq!(logging_rs::codec::archive("missing.log", &logging_rs::codec::Identity).unwrap(), std::path::PathBuf::from("missing.log"));
+    }
+
+    #[test]
+    fn test_encode_batch() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{level}} {{message}}", "{{level}} {{message}}", "");
+        let records: Vec<logging_rs::Record> = vec![
+            logging_rs::Record::new(logging_rs::Level::INFO, "First", "src/main.rs", vec![]),
+            logging_rs::Record::new(logging_rs::Level::ERROR, "Second", "src/main.rs", vec![])
+        ];
+
+        for codec in codecs() {
+            let batch: Vec<u8> = logging_rs::codec::encode_batch(codec.as_ref(), &formatter, &records).unwrap();
+            assert_eq!(codec.decompress(&batch).unwrap(), b"INFO First\nERROR Second\n");
+        }
+    }
+    #[test]
+    fn test_udp() {
+        let receiver: std::net::UdpSocket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver.set_read_timeout(Some(std::time::Duration::from_secs(5))).unwrap();
+
+        for name in ["none", "gzip", "zstd", "lz4"] {
+            let Some(codec) = logging_rs::codec::from_name(name) else {
+                continue;
+            };
+
+            let mut sink: logging_rs::sinks::Udp = logging_rs::sinks::Udp::new(&receiver.local_addr().unwrap().to_string());
+            sink.codec = codec.into();
+
+            let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{level}} {{message}}", "{{level}} {{message}}", ""), vec![]);
+            logger.add_sink(sink.clone());
+
+            logger.log_records(&[
+                logging_rs::Record::new(logging_rs::Level::INFO, "First", "src/main.rs", vec![]),
+                logging_rs::Record::new(logging_rs::Level::ERROR, "Second", "src/main.rs", vec![])
+            ]);
+
+            let mut buffer: [u8; 1024] = [0; 1024];
+            let size: usize = receiver.recv(&mut buffer).unwrap();
+
+            if name == "none" {
+                assert_eq!(&buffer[..size], b"INFO First");
+                assert_eq!(receiver.recv(&mut buffer).map(|size: usize| buffer[..size].to_vec()).unwrap(), b"ERROR Second");
+            } else {
+                assert_eq!(sink.codec.decompress(&buffer[..size]).unwrap(), b"INFO First\nERROR Second\n", "{}", name);
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+// logging-rs common test utilities
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+#![allow(dead_code)]
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+use std::sync::{Arc, Mutex};
+
+
+///////////////
+// COLLECTOR //
+///////////////
+
+/// Sink collecting the written records, shared by the integration tests.
+#[derive(Clone, Default)]
+pub struct Collector(pub Arc<Mutex<Vec<logging_rs::Record>>>);
+
+impl logging_rs::sinks::Sink for Collector {
+    fn write(&self, _formatter: &logging_rs::Formatter, record: &logging_rs::Record) {
+        self.0.lock().unwrap().push(record.clone());
+    }
+}
+
+impl Collector {
+    /// Returns the collected records.
+    pub fn records(&self) -> Vec<logging_rs::Record> {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Returns the messages of the collected records, without replacing placeholders.
+    pub fn messages(&self) -> Vec<String> {
+        self.0.lock().unwrap().iter().map(|record: &logging_rs::Record| record.message.clone()).collect()
+    }
+
+    /// Returns the messages of the collected records with their placeholders replaced.
+    pub fn formatted_messages(&self) -> Vec<String> {
+        self.0.lock().unwrap().iter().map(|record: &logging_rs::Record| record.formatted_message()).collect()
+    }
+}
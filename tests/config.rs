@@ -0,0 +1,122 @@
+// logging-rs config tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+#![cfg(feature = "toml")]
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_config() {
+        let config: logging_rs::config::Config = logging_rs::config::Config::from_toml(r#"
+            level = "warning"
+            filter = "info,hyper=off"
+            mirror_fatal = false
+
+            [format]
+            preset = "compact"
+            format = "{{level}} {{message}}"
+            time_zone = "+01:00"
+
+            [[outputs]]
+            type = "stderr"
+
+            [[outputs]]
+            type = "file"
+            path = "app.log"
+        "#).unwrap();
+
+        let logger: logging_rs::Logger = config.build().unwrap();
+
+        assert_eq!(logger.level, logging_rs::Level::WARN);
+        assert_eq!(logger.filter, logging_rs::filter::EnvFilter::parse("info,hyper=off").unwrap());
+        assert!(!logger.mirror_fatal);
+        assert_eq!(
+            logger.formatter,
+            logging_rs::Formatter::new("{{level}} {{message}}", "{{level}} {{message}}", "%H:%M:%S").with_time_zone(logging_rs::TimeZone::OFFSET { seconds: 3600 })
+        );
+        assert_eq!(logger.writable_list, vec![logging_rs::Output::STDERR, logging_rs::Output::FILE { path: "app.log".to_owned() }]);
+        assert!(logger.sinks.is_empty());
+    }
+
+    #[test]
+    fn test_config_rotation() {
+        let config: logging_rs::config::Config = logging_rs::config::Config::from_toml(r#"
+            [[outputs]]
+            type = "file"
+            path = "app.log"
+            max_size = 1024
+            compression = "none"
+        "#).unwrap();
+
+        assert_eq!(config.outputs, vec![logging_rs::config::OutputConfig::File {
+            path: "app.log".to_owned(),
+            level: None,
+            max_size: Some(1024),
+            max_files: None,
+            compression: Some("none".to_owned())
+        }]);
+
+        let logger: logging_rs::Logger = config.build().unwrap();
+
+        assert!(logger.writable_list.is_empty());
+        assert_eq!(logger.sinks.len(), 1);
+    }
+
+    #[test]
+    fn test_config_errors() {
+        assert_eq!(logging_rs::config::Config::from_toml("levle = \"info\"").unwrap_err().exit_code, 10);
+        assert_eq!(logging_rs::config::Config::from_toml("level = \"verbose\"").unwrap_err().exit_code, 10);
+        assert_eq!(logging_rs::config::Config::from_toml("[[outputs]]\ntype = \"socket\"").unwrap_err().exit_code, 10);
+
+        let invalid: [&str; 5] = [
+            "[format]\npreset = \"fancy\"",
+            "[[outputs]]\ntype = \"file\"\npath = \"app.log\"\nmax_files = 3",
+            "[[outputs]]\ntype = \"file\"\npath = \"app.log\"\nmax_size = 1024\ncompression = \"rar\"",
+            "[[outputs]]\ntype = \"stdout\"\n\n[[outputs]]\ntype = \"stdout\"",
+            "empty_outputs = \"error\""
+        ];
+
+        for text in invalid {
+            assert_eq!(logging_rs::config::Config::from_toml(text).unwrap().build().unwrap_err().exit_code, 10, "{}", text);
+        }
+
+        assert_eq!(logging_rs::config::Config::from_toml("[format]\ntimestamp_format = \"%Q\"").unwrap().build().unwrap_err().exit_code, 9);
+        assert_eq!(logging_rs::config::Config::from_toml("[format]\npreset = \"csv\"").unwrap().formatter().unwrap(), logging_rs::Formatter::csv());
+    }
+
+    #[test]
+    fn test_from_file() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_from_file.toml");
+        std::fs::write(&path, "level = \"error\"\n").unwrap();
+
+        assert_eq!(logging_rs::Logger::from_file(&path).unwrap().level, logging_rs::Level::ERROR);
+        assert_eq!(logging_rs::Logger::from_file(path.with_extension("ini")).unwrap_err().exit_code, 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
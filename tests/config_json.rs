@@ -0,0 +1,95 @@
+// logging-rs config-json tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "config-json")]
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_from_json_str() {
+        let logger: logging_rs::Logger = logging_rs::Logger::from_json_str(r#"{
+            "level": "info",
+            "mirror_fatal": false,
+            "format": {"format": "{{level}} {{message}}", "timestamp_format": "%H:%M"},
+            "outputs": [
+                {"type": "stdout"},
+                {"type": "stderr", "level": "error"},
+                {"type": "file", "path": "app.log", "level": "warn", "max_size": 1024, "compression": "none"}
+            ]
+        }"#).unwrap();
+
+        assert_eq!(logger.level, logging_rs::Level::INFO);
+        assert!(!logger.mirror_fatal);
+        assert_eq!(logger.formatter, logging_rs::Formatter::new("{{level}} {{message}}", "{{level}} {{message}}", "%H:%M"));
+        assert_eq!(logger.writable_list, vec![logging_rs::Output::STDOUT]);
+        assert_eq!(logger.sinks.len(), 2);
+    }
+
+    #[test]
+    fn test_output_level() {
+        let config: logging_rs::config::Config = logging_rs::config::Config::from_json(r#"{"outputs": [{"type": "stderr", "level": "warning"}]}"#).unwrap();
+        assert_eq!(config.outputs, vec![logging_rs::config::OutputConfig::Stderr { level: Some(logging_rs::Level::WARN) }]);
+
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::from_json_str(r#"{"outputs": [{"type": "stdout", "level": "fatal"}]}"#).unwrap();
+        logger.add_sink(collector.clone());
+
+        logging_rs::info!(logger, "Written to the collector only");
+        assert_eq!(collector.messages(), vec!["Written to the collector only".to_owned()]);
+    }
+
+    #[test]
+    fn test_json_errors() {
+        let unknown_key: logging_rs::errors::Error = logging_rs::Logger::from_json_str(r#"{"outputs": [{"type": "stdout", "levle": "info"}]}"#).unwrap_err();
+        assert_eq!(unknown_key.exit_code, 10);
+        assert!(unknown_key.description.contains("unknown field `levle`"), "{}", unknown_key.description);
+        assert!(unknown_key.description.contains("line 1"), "{}", unknown_key.description);
+
+        assert_eq!(logging_rs::Logger::from_json_str("{").unwrap_err().exit_code, 10);
+        assert_eq!(logging_rs::Logger::from_json_str(r#"{"level": "verbose"}"#).unwrap_err().exit_code, 10);
+        assert_eq!(logging_rs::Logger::from_json_file("missing_config.json").unwrap_err().exit_code, 1);
+    }
+
+    #[test]
+    fn test_from_json_file() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_from_json_file.json");
+        std::fs::write(&path, r#"{"level": "error"}"#).unwrap();
+
+        assert_eq!(logging_rs::Logger::from_json_file(&path).unwrap().level, logging_rs::Level::ERROR);
+        assert_eq!(logging_rs::Logger::from_file(&path).unwrap().level, logging_rs::Level::ERROR);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
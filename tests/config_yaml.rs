@@ -0,0 +1,199 @@
+// logging-rs config-yaml tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "config-yaml")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    const DOCUMENT: &str = "
+formatters:
+  plain:
+    preset: compact
+    format: '{{level}} {{message}}'
+outputs:
+  console:
+    type: stderr
+  file:
+    type: file
+    path: app.log
+loggers:
+  app:
+    level: info
+    formatter: plain
+    outputs: [console, file]
+  audit:
+    level: warning
+    mirror_fatal: false
+    outputs: [file]
+  default: {}
+";
+
+    #[test]
+    fn test_document() {
+        let loggers: std::collections::BTreeMap<String, logging_rs::Logger> = logging_rs::config::Document::from_yaml(DOCUMENT).unwrap().build().unwrap();
+        let file: logging_rs::Output = logging_rs::Output::FILE { path: "app.log".to_owned() };
+
+        assert_eq!(loggers.keys().collect::<Vec<&String>>(), vec!["app", "audit", "default"]);
+
+        assert_eq!(loggers["app"].level, logging_rs::Level::INFO);
+        assert_eq!(loggers["app"].formatter.format_string, "{{level}} {{message}}");
+        assert_eq!(loggers["app"].formatter.timestamp_format, "%H:%M:%S");
+        assert_eq!(loggers["app"].writable_list, vec![logging_rs::Output::STDERR, file.clone()]);
+
+        assert_eq!(loggers["audit"].level, logging_rs::Level::WARN);
+        assert_eq!(loggers["audit"].formatter, logging_rs::Formatter::default());
+        assert_eq!(loggers["audit"].writable_list, vec![file]);
+        assert!(!loggers["audit"].mirror_fatal);
+
+        assert_eq!(loggers["default"].writable_list, vec![logging_rs::Output::STDOUT]);
+    }
+
+    #[test]
+    fn test_document_errors() {
+        let unknown_key: logging_rs::errors::Error = logging_rs::config::Document::from_yaml("loggers:\n  app:\n    levle: info\n").unwrap_err();
+        assert_eq!(unknown_key.exit_code, 10);
+        assert!(unknown_key.description.contains("unknown field `levle`"), "{}", unknown_key.description);
+        assert!(unknown_key.description.contains("line 3"), "{}", unknown_key.description);
+
+        let unknown_output: logging_rs::errors::Error = logging_rs::config::Document::from_yaml("outputs:\n  console:\n    type: stdout\nloggers:\n  app:\n    outputs: [consle]\n")
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert_eq!(unknown_output.description, "Unknown output `consle` in logger `app`, expected one of console");
+
+        let unknown_formatter: logging_rs::errors::Error = logging_rs::config::Document::from_yaml("loggers:\n  app:\n    formatter: plain\n").unwrap().config("app").unwrap_err();
+        assert_eq!(unknown_formatter.description, "Unknown formatter `plain` in logger `app`, no formatters are defined");
+
+        let invalid: logging_rs::errors::Error = logging_rs::config::Document::from_yaml("formatters:\n  broken:\n    preset: fancy\nloggers:\n  app:\n    formatter: broken\n")
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert!(invalid.description.ends_with("(logger `app`)"), "{}", invalid.description);
+    }
+
+    #[test]
+    fn test_log4rs() {
+        let document: logging_rs::config::Document = logging_rs::config::Document::from_log4rs_yaml("
+refresh_rate: 30 seconds
+appenders:
+  stdout:
+    kind: console
+    encoder:
+      pattern: '{d(%H:%M:%S)} {h({l:<5})} {t} [{X(request_id)}] - {m}{n}'
+  requests:
+    kind: rolling_file
+    path: requests.log
+    filters:
+      - kind: threshold
+        level: info
+    policy:
+      trigger:
+        kind: size
+        limit: 10 mb
+      roller:
+        kind: fixed_window
+        pattern: requests.{}.log.gz
+        count: 3
+    encoder:
+      kind: json
+root:
+  level: warn
+  appenders: [stdout]
+loggers:
+  app:
+    level: trace
+  app::db: {}
+  app::requests:
+    appenders: [requests]
+    additive: false
+  noisy:
+    level: off
+").unwrap();
+
+        assert_eq!(document.formatters["stdout"].format.as_deref(), Some("{{timestamp}} {{level}} {{path}} [{{request_id}}] - {{message}}"));
+        assert_eq!(document.formatters["stdout"].timestamp_format.as_deref(), Some("%H:%M:%S"));
+        assert_eq!(document.formatters["requests"].preset.as_deref(), Some("jsonl"));
+        assert_eq!(document.outputs["requests"], logging_rs::config::OutputConfig::File {
+            path: "requests.log".to_owned(),
+            level: Some(logging_rs::Level::INFO),
+            max_size: Some(10 * 1024 * 1024),
+            max_files: Some(3),
+            compression: Some("gzip".to_owned())
+        });
+
+        assert_eq!(document.loggers.keys().collect::<Vec<&String>>(), vec!["app", "app::db", "app::requests", "noisy", "root"]);
+        assert_eq!(document.loggers["root"].level, Some(logging_rs::Level::WARN));
+        assert_eq!(document.loggers["app::db"].level, Some(logging_rs::Level::DEBUG));
+        assert_eq!(document.loggers["app::db"].outputs, vec!["stdout".to_owned()]);
+        assert_eq!(document.loggers["app::requests"].outputs, vec!["requests".to_owned()]);
+        assert_eq!(document.loggers["app::requests"].formatter.as_deref(), Some("requests"));
+        assert!(document.loggers["noisy"].outputs.is_empty());
+
+        let config: logging_rs::config::Config = document.config("noisy").unwrap();
+        assert!(config.build().unwrap().is_noop());
+    }
+
+    #[test]
+    fn test_log4rs_errors() {
+        let invalid: [(&str, &str); 5] = [
+            ("appenders:\n  socket:\n    kind: tcp\n", "Unsupported appender `tcp`"),
+            ("appenders:\n  stdout:\n    kind: console\n    encoder:\n      pattern: '{L} {m}'\n", "Unsupported formatter `{L}`"),
+            ("appenders:\n  stdout:\n    kind: console\n    encoder:\n      pattern: '{m'\n", "Unclosed `{m`"),
+            ("root:\n  appenders: [stdout]\n", "Unknown appender `stdout` in logger `root`, no appenders are defined"),
+            (
+                "appenders:\n  stdout:\n    kind: console\n  json:\n    kind: console\n    target: stderr\n    encoder:\n      kind: json\nroot:\n  appenders: [stdout, json]\n",
+                "use different encoders"
+            )
+        ];
+
+        for (text, description) in invalid {
+            let error: logging_rs::errors::Error = logging_rs::config::Document::from_log4rs_yaml(text).unwrap_err();
+            assert_eq!(error.exit_code, 10);
+            assert!(error.description.contains(description), "{}", error.description);
+        }
+
+        assert!(logging_rs::config::Document::from_log4rs_yaml("root:\n  levle: info\n").unwrap_err().description.contains("unknown field `levle`"));
+    }
+
+    #[test]
+    fn test_config_yaml() {
+        let config: logging_rs::config::Config = logging_rs::config::Config::from_yaml("
+level: error
+outputs:
+  - type: file
+    path: app.log
+    max_size: 1024
+").unwrap();
+
+        assert_eq!(config.level, Some(logging_rs::Level::ERROR));
+        assert_eq!(config.build().unwrap().sinks.len(), 1);
+
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_from_file.yml");
+        std::fs::write(&path, "level: fatal\n").unwrap();
+        assert_eq!(logging_rs::Logger::from_file(&path).unwrap().level, logging_rs::Level::FATAL);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
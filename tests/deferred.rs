@@ -0,0 +1,109 @@
+// logging-rs deferred tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    fn logger(collector: &Collector) -> logging_rs::Logger {
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+        logger
+    }
+
+    #[test]
+    fn test_deferred_discarded() {
+        let collector: Collector = Collector::default();
+        let logger: logging_rs::Logger = logger(&collector);
+
+        {
+            let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+            logging_rs::debug!(operation, "debug");
+            logging_rs::info!(operation, "info");
+            assert_eq!(operation.pending(), 1);
+        }
+
+        assert_eq!(collector.messages(), vec!["info".to_owned()]);
+    }
+
+    #[test]
+    fn test_deferred_emitted_on_error() {
+        let collector: Collector = Collector::default();
+        let logger: logging_rs::Logger = logger(&collector);
+        let operation: logging_rs::deferred::DeferredDebug = logger.deferred();
+
+        logging_rs::debug!(operation, "first");
+        logging_rs::debug!(operation, "second");
+        logging_rs::error!(operation, "error");
+        logging_rs::debug!(operation, "after");
+
+        assert_eq!(operation.pending(), 0);
+        assert_eq!(collector.messages(), vec!["first".to_owned(), "second".to_owned(), "error".to_owned(), "after".to_owned()]);
+    }
+
+    #[test]
+    fn test_deferred_capacity() {
+        let collector: Collector = Collector::default();
+        let logger: logging_rs::Logger = logger(&collector);
+        let operation: logging_rs::deferred::DeferredDebug = logging_rs::deferred::DeferredDebug::new(&logger, 1);
+
+        logging_rs::debug!(operation, "dropped");
+        logging_rs::debug!(operation, "kept");
+        operation.flush();
+
+        assert_eq!(collector.messages(), vec!["kept".to_owned()]);
+    }
+
+    #[test]
+    fn test_deferred_memory_limit() {
+        let collector: Collector = Collector::default();
+        let logger: logging_rs::Logger = logger(&collector);
+        let record = |message: &str| logging_rs::Record::new(logging_rs::Level::DEBUG, message, "src/main.rs", vec![]);
+        let size: usize = record("one").memory_size();
+
+        let operation: logging_rs::deferred::DeferredDebug = logger.deferred().with_memory_limit(2 * size);
+        operation.log_record(&record("one"));
+        operation.log_record(&record("two"));
+        operation.log_record(&record("six"));
+
+        assert_eq!(operation.pending(), 2);
+        assert_eq!(operation.memory().used, 2 * size);
+
+        operation.flush();
+
+        assert_eq!(operation.memory().used, 0);
+        assert_eq!(operation.memory().peak, 2 * size);
+        assert_eq!(collector.messages(), vec!["two".to_owned(), "six".to_owned()]);
+    }
+}
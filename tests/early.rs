@@ -0,0 +1,62 @@
+// logging-rs early tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_early() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).with_level(logging_rs::Level::INFO);
+        logger.add_sink(collector.clone());
+
+        logging_rs::debug!(logging_rs::early(), "Below level");
+        logging_rs::info!(logging_rs::early(), "Loading {{file}}", file = "config.toml");
+        let before: chrono::DateTime<chrono::Utc> = chrono::Utc::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+
+        assert!(collector.0.lock().unwrap().is_empty());
+
+        logging_rs::init(logger).unwrap();
+        logging_rs::warn!(logging_rs::early(), "After init");
+
+        let records: Vec<logging_rs::Record> = collector.records();
+        let messages: Vec<&str> = records.iter().map(|record: &logging_rs::Record| record.message.as_str()).collect();
+
+        assert_eq!(messages, vec!["Loading {{file}}", "After init"]);
+        assert!(records[0].timestamp <= before);
+        assert!(!logging_rs::early().is_enabled(logging_rs::Level::DEBUG));
+    }
+}
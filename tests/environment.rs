@@ -0,0 +1,141 @@
+// logging-rs environment tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_is_secret() {
+        assert!(logging_rs::environment::is_secret("GITHUB_TOKEN"));
+        assert!(logging_rs::environment::is_secret("db_password"));
+        assert!(!logging_rs::environment::is_secret("HOME"));
+    }
+
+    #[test]
+    fn test_environment_arguments() {
+        std::env::set_var("LOGGING_RS_TEST_VISIBLE", "visible");
+        std::env::set_var("LOGGING_RS_TEST_API_KEY", "hunter2");
+        std::env::set_var("LOGGING_RS_OTHER", "other");
+
+        let arguments: Vec<(String, String)> = logging_rs::environment::environment_arguments(&["LOGGING_RS_TEST_*"]);
+
+        assert_eq!(arguments[0].0, "cwd");
+        assert_eq!(arguments[1].0, "args");
+        assert_eq!(arguments[2..], [
+            ("env.LOGGING_RS_TEST_API_KEY".to_owned(), "[REDACTED]".to_owned()),
+            ("env.LOGGING_RS_TEST_VISIBLE".to_owned(), "visible".to_owned())
+        ]);
+    }
+
+    #[test]
+    fn test_log_environment() {
+        std::env::set_var("LOGGING_RS_LOGGED", "logged");
+
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+        logger.log_environment(&["LOGGING_RS_LOGGED"]);
+
+        let records: Vec<logging_rs::Record> = collector.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].level, logging_rs::Level::INFO);
+        assert_eq!(records[0].path, file!());
+        assert_eq!(records[0].argument("env.LOGGING_RS_LOGGED"), Some("logged"));
+        assert_eq!(records[0].argument("cwd"), Some(std::env::current_dir().unwrap().to_string_lossy().as_ref()));
+    }
+
+    #[test]
+    fn test_from_env() {
+        std::env::set_var("LOGGING_RS_OUTPUT", "stderr, file:environment.log");
+        std::env::set_var("LOGGING_RS_FORMAT", "json");
+        std::env::set_var("LOGGING_RS_COLOR", "never");
+
+        let logger: logging_rs::Logger = logging_rs::Logger::try_from_env().unwrap();
+        assert_eq!(logger.formatter, logging_rs::Formatter::json());
+        assert_eq!(logger.writable_list, vec![logging_rs::Output::FILE { path: "environment.log".to_owned() }]);
+        assert_eq!(logger.sinks.len(), 1);
+
+        std::env::set_var("LOGGING_RS_FORMAT", "{{level}}: {{message}}");
+        std::env::set_var("LOGGING_RS_COLOR", "always");
+        std::env::set_var("LOGGING_RS_TIMESTAMP_FORMAT", "%H:%M");
+
+        let logger: logging_rs::Logger = logging_rs::Logger::try_from_env().unwrap();
+        assert_eq!(logger.formatter.format_string, "{{level}}: {{message}}");
+        assert_eq!(logger.formatter.timestamp_format, "%H:%M");
+        assert_eq!(logger.writable_list, vec![logging_rs::Output::STDERR, logging_rs::Output::FILE { path: "environment.log".to_owned() }]);
+        assert!(logger.sinks.is_empty());
+
+        for (name, value) in [("LOGGING_RS_OUTPUT", "syslog"), ("LOGGING_RS_FORMAT", "fancy"), ("LOGGING_RS_COLOR", "sometimes"), ("LOGGING_RS_TIMESTAMP_FORMAT", "%Q")] {
+            let previous: String = std::env::var(name).unwrap();
+            std::env::set_var(name, value);
+            assert!(logging_rs::Logger::try_from_env().is_err(), "{}={}", name, value);
+            std::env::set_var(name, previous);
+        }
+
+        for name in ["LOGGING_RS_OUTPUT", "LOGGING_RS_FORMAT", "LOGGING_RS_COLOR", "LOGGING_RS_TIMESTAMP_FORMAT"] {
+            std::env::remove_var(name);
+        }
+
+        let logger: logging_rs::Logger = logging_rs::Logger::try_from_env().unwrap();
+        assert_eq!(logger.formatter, logging_rs::Formatter::default());
+        assert!(logger.writable_list.len() + logger.sinks.len() == 1);
+    }
+
+    #[test]
+    fn test_from_env_piped_child() {
+        if std::env::var_os("LOGGING_RS_PIPED").is_none() {
+            return;
+        }
+
+        let logger: logging_rs::Logger = logging_rs::Logger::try_from_env().unwrap();
+        assert!(logger.writable_list.is_empty());
+        assert_eq!(logger.sinks.len(), 2);
+    }
+
+    #[test]
+    fn test_from_env_piped() {
+        let output: std::process::Output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["tests::test_from_env_piped_child", "--exact"])
+            .env("LOGGING_RS_PIPED", "1")
+            .env("LOGGING_RS_OUTPUT", "stdout,stderr")
+            .env("LOGGING_RS_COLOR", "auto")
+            .env("TERM", "xterm-256color")
+            .env_remove("NO_COLOR")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+    }
+}
@@ -35,6 +35,12 @@ use logging_rs;
 
 #[cfg(test)]
 mod tests {
+    static GLOBAL: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    fn lock_global() -> std::sync::MutexGuard<'static, ()> {
+        GLOBAL.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
     #[test]
     fn test_error() {
         let error: logging_rs::errors::Error =
@@ -52,6 +58,7 @@ mod tests {
 
     #[test]
     #[ignore]
+    #[allow(deprecated)]
     fn raise_helper() {
         let error: logging_rs::errors::Error =
             logging_rs::errors::Error::new("name", "description", 1);
@@ -69,4 +76,143 @@ mod tests {
 
         assert_eq!(Some(1), status.code())
     }
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(logging_rs::errors::Error::new("name", "description", 2).exit_code(), std::process::ExitCode::from(2));
+        assert_eq!(logging_rs::errors::Error::new("name", "description", 300).exit_code(), std::process::ExitCode::from(1));
+    }
+
+    #[test]
+    fn test_try_from_io_error() {
+        let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        assert_eq!(logging_rs::errors::Error::try_from(error).unwrap(), logging_rs::errors::Error::new("File error", "denied", 1));
+
+        let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::WriteZero, "write zero");
+        assert_eq!(logging_rs::errors::Error::try_from(error).unwrap(), logging_rs::errors::Error::new("Writing error", "write zero", 2));
+
+        let error: std::io::Error = std::io::Error::new(std::io::ErrorKind::Other, "other");
+        assert!(logging_rs::errors::Error::try_from(error).is_err());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_exit_strategy() {
+        let _global = lock_global();
+        logging_rs::errors::set_exit_strategy(logging_rs::errors::ExitStrategy::PANIC);
+
+        let error: logging_rs::errors::Error = logging_rs::errors::Error::new("name", "description", 1);
+        assert!(std::panic::catch_unwind(|| error.raise("details")).is_err());
+
+        logging_rs::errors::set_exit_strategy(logging_rs::errors::ExitStrategy::default());
+    }
+
+    #[test]
+    fn test_handler() {
+        let _global = lock_global();
+        let reported: std::sync::Arc<std::sync::Mutex<Vec<String>>> = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let handler_reported: std::sync::Arc<std::sync::Mutex<Vec<String>>> = std::sync::Arc::clone(&reported);
+
+        logging_rs::errors::set_handler(move |error: &logging_rs::errors::Error, details: &str| {
+            handler_reported.lock().unwrap().push(format!("{}: {}", error.name, details));
+        });
+
+        let directory: String = std::env::temp_dir().to_string_lossy().to_string();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![logging_rs::Output::FILE { path: directory }]);
+        logging_rs::info!(logger, "Not writable");
+
+        logger.writable_list.clear();
+        logger.add_sink(logging_rs::sinks::mapped(logging_rs::Output::STDOUT, |_record: logging_rs::Record| panic!("Broken sink")));
+        logging_rs::info!(logger, "Panicking sink");
+
+        logging_rs::errors::reset_handler();
+
+        let reported: Vec<String> = reported.lock().unwrap().clone();
+        assert_eq!(reported.len(), 2);
+        assert!(reported[0].starts_with("File error: Path: "));
+        assert_eq!(reported[1], "Sink error: Broken sink");
+    }
+
+    #[test]
+    fn test_guard_without_handler() {
+        let _global = lock_global();
+        logging_rs::errors::reset_handler();
+
+        let written: std::sync::Arc<std::sync::Mutex<Vec<String>>> = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let sink_written: std::sync::Arc<std::sync::Mutex<Vec<String>>> = std::sync::Arc::clone(&written);
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(logging_rs::sinks::mapped(logging_rs::Output::STDOUT, |_record: logging_rs::Record| panic!("Broken sink")));
+        logger.add_filter(move |record: &logging_rs::Record| {
+            sink_written.lock().unwrap().push(record.message.clone());
+            true
+        });
+
+        logging_rs::info!(logger, "First");
+        logging_rs::info!(logger, "Second");
+
+        assert_eq!(*written.lock().unwrap(), vec!["First".to_owned(), "Second".to_owned()]);
+    }
+
+    #[test]
+    fn test_guard_panic_strategy() {
+        let _global = lock_global();
+        logging_rs::errors::reset_handler();
+        logging_rs::errors::set_exit_strategy(logging_rs::errors::ExitStrategy::PANIC);
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(logging_rs::sinks::mapped(logging_rs::Output::STDOUT, |_record: logging_rs::Record| panic!("Broken sink")));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| logging_rs::info!(logger, "Panicking sink")));
+
+        logging_rs::errors::set_exit_strategy(logging_rs::errors::ExitStrategy::default());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_panicking_handler() {
+        let _global = lock_global();
+        let calls: std::sync::Arc<std::sync::atomic::AtomicUsize> = std::sync::Arc::default();
+        let handler_calls: std::sync::Arc<std::sync::atomic::AtomicUsize> = std::sync::Arc::clone(&calls);
+
+        logging_rs::errors::set_handler(move |_error: &logging_rs::errors::Error, _details: &str| {
+            handler_calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            panic!("Broken handler");
+        });
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(logging_rs::sinks::mapped(logging_rs::Output::STDOUT, |_record: logging_rs::Record| panic!("Broken sink")));
+        logging_rs::info!(logger, "First");
+        logging_rs::info!(logger, "Second");
+
+        let error: logging_rs::errors::Error = logging_rs::errors::Error::new("name", "description", 1);
+        assert!(std::panic::catch_unwind(|| error.report("details")).is_err());
+        assert!(std::panic::catch_unwind(|| error.report("details")).is_err());
+
+        logging_rs::errors::reset_handler();
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_error_arguments() {
+        #[derive(Debug)]
+        struct Wrapper(logging_rs::errors::Error);
+
+        impl std::fmt::Display for Wrapper {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "wrapper")
+            }
+        }
+
+        impl std::error::Error for Wrapper {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+
+        let error: Wrapper = Wrapper(logging_rs::errors::Error::new("Inner", "inner description", 1));
+        let arguments: Vec<(&str, String)> = logging_rs::errors::error_arguments(&error);
+
+        assert!(arguments[0].1.ends_with("Wrapper"));
+        assert_eq!(arguments[1], ("error.message", "wrapper".to_owned()));
+        assert_eq!(arguments[2], ("error.stack", "\x1b[31;1mInner\x1b[0m: inner description".to_owned()));
+    }
 }
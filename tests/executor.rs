@@ -0,0 +1,77 @@
+// logging-rs executor tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "testutil")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_order() {
+        let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::default();
+        let seen: Rc<RefCell<Vec<std::time::Duration>>> = Rc::default();
+
+        let clock: logging_rs::testutil::clock::VirtualClock = executor.clock().clone();
+        let times: Rc<RefCell<Vec<std::time::Duration>>> = seen.clone();
+        executor.every(std::time::Duration::from_secs(10), "tick", move || times.borrow_mut().push(clock.elapsed()));
+        executor.spawn_after(std::time::Duration::from_secs(10), "once", || {});
+        executor.spawn("now", || {});
+
+        assert_eq!(executor.run_until_idle(), 1);
+        assert_eq!(executor.advance(std::time::Duration::from_secs(25)), 3);
+        assert_eq!(executor.clock().elapsed(), std::time::Duration::from_secs(25));
+        assert_eq!(*seen.borrow(), vec![std::time::Duration::from_secs(10), std::time::Duration::from_secs(20)]);
+        assert_eq!(executor.pending(), 1);
+
+        let names: Vec<&str> = executor.history().iter().map(|(_, name): &(std::time::Duration, String)| name.as_str()).collect();
+        assert_eq!(names, ["now", "tick", "once", "tick"]);
+    }
+
+    #[test]
+    fn test_maintenance() {
+        let clock: logging_rs::testutil::clock::VirtualClock = logging_rs::testutil::clock::VirtualClock::new();
+        let _guard: logging_rs::testutil::clock::ClockGuard = clock.install();
+        let mut executor: logging_rs::testutil::executor::Executor = logging_rs::testutil::executor::Executor::new(clock.clone());
+
+        let capture: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(capture.clone());
+
+        let maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::new(std::time::Duration::from_secs(60));
+        let task_logger: logging_rs::Logger = logger.clone();
+        maintenance.add_task("cleanup", move || logging_rs::info!(task_logger, "Cleanup"));
+        executor.every(std::time::Duration::from_secs(60), "maintenance", move || {
+            maintenance.tick();
+        });
+
+        executor.advance(std::time::Duration::from_secs(150));
+
+        let timestamps: Vec<String> = capture.records().iter().map(|record: &logging_rs::Record| record.timestamp.to_rfc3339()).collect();
+        assert_eq!(timestamps, vec!["2023-01-01T00:01:00+00:00".to_owned(), "2023-01-01T00:02:00+00:00".to_owned()]);
+    }
+}
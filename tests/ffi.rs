@@ -0,0 +1,59 @@
+// logging-rs ffi tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "ffi")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    #[test]
+    fn test_ffi() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_ffi.log");
+        let _ = std::fs::remove_file(&path);
+
+        let file: CString = CString::new(path.to_string_lossy().to_string()).unwrap();
+        let message: CString = CString::new("Hello from C").unwrap();
+        let caller: CString = CString::new("main.c").unwrap();
+
+        unsafe {
+            let logger: *mut logging_rs::Logger = logging_rs::ffi::logging_rs_new();
+            (*logger).writable_list.clear();
+
+            assert_eq!(logging_rs::ffi::logging_rs_add_file(logger, file.as_ptr()), logging_rs::ffi::LOGGING_RS_OK);
+            assert_eq!(logging_rs::ffi::logging_rs_log(logger, logging_rs::ffi::LOGGING_RS_WARN, message.as_ptr(), caller.as_ptr()), logging_rs::ffi::LOGGING_RS_OK);
+            assert_eq!(logging_rs::ffi::logging_rs_log(logger, 42, message.as_ptr(), caller.as_ptr()), logging_rs::ffi::LOGGING_RS_INVALID_ARGUMENT);
+            assert_eq!(logging_rs::ffi::logging_rs_log(logger, logging_rs::ffi::LOGGING_RS_INFO, std::ptr::null(), caller.as_ptr()), logging_rs::ffi::LOGGING_RS_INVALID_ARGUMENT);
+
+            logging_rs::ffi::logging_rs_free(logger);
+            logging_rs::ffi::logging_rs_free(std::ptr::null_mut());
+        }
+
+        let contents: String = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 1);
+        assert!(contents.contains("[WARNING] main.c: Hello from C"));
+    }
+}
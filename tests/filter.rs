@@ -0,0 +1,276 @@
+// logging-rs filter tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_target() {
+        assert_eq!(logging_rs::filter::target("src/main.rs"), "");
+        assert_eq!(logging_rs::filter::target("src/db/mod.rs"), "db");
+        assert_eq!(logging_rs::filter::target("src\\db\\pool.rs"), "db::pool");
+        assert_eq!(logging_rs::filter::target("/home/user/.cargo/registry/src/index/hyper-1.2.0/src/client/conn.rs"), "hyper::client::conn");
+        assert_eq!(logging_rs::filter::target("crates/my-app/src/payments.rs"), "my_app::payments");
+        assert_eq!(logging_rs::filter::target("examples/demo.rs"), "examples::demo");
+    }
+
+    #[test]
+    fn test_parse() {
+        let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse(" info , my_crate::db=TRACE,hyper=off,tokio ").unwrap();
+
+        assert_eq!(
+            filter.directives,
+            vec![
+                logging_rs::filter::Directive { target: None, level: logging_rs::Level::INFO },
+                logging_rs::filter::Directive { target: Some("my_crate::db".to_owned()), level: logging_rs::Level::DEBUG },
+                logging_rs::filter::Directive { target: Some("hyper".to_owned()), level: logging_rs::filter::OFF },
+                logging_rs::filter::Directive { target: Some("tokio".to_owned()), level: logging_rs::Level::DEBUG }
+            ]
+        );
+
+        let error: logging_rs::errors::Error = logging_rs::filter::EnvFilter::parse("db=loud").unwrap_err();
+        assert!(error.description.contains("`loud`"));
+    }
+
+    #[test]
+    fn test_level_for() {
+        let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse("warn,db=debug,db::pool=error,hyper=off").unwrap();
+
+        assert_eq!(filter.level_for("src/main.rs"), Some(logging_rs::Level::WARN));
+        assert_eq!(filter.level_for("src/db/mod.rs"), Some(logging_rs::Level::DEBUG));
+        assert_eq!(filter.level_for("src/db/pool.rs"), Some(logging_rs::Level::ERROR));
+        assert_eq!(filter.level_for("src/dbus.rs"), Some(logging_rs::Level::WARN));
+        assert!(!filter.is_enabled(logging_rs::Level::MESSAGE, "/registry/hyper-1.2.0/src/lib.rs"));
+        assert_eq!(filter.min_level(), Some(logging_rs::Level::DEBUG));
+
+        assert_eq!(logging_rs::filter::EnvFilter::parse("db=debug").unwrap().min_level(), Some(logging_rs::Level::DEBUG));
+        assert_eq!(logging_rs::filter::EnvFilter::default().min_level(), None);
+    }
+
+    #[test]
+    fn test_logger_filter() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![])
+            .with_filter(logging_rs::filter::EnvFilter::parse("info,db=debug").unwrap());
+        logger.add_sink(collector.clone());
+
+        assert!(logger.is_enabled(logging_rs::Level::DEBUG));
+
+        logger.log("main debug", logging_rs::Level::DEBUG, "src/main.rs", vec![]);
+        logger.log("main info", logging_rs::Level::INFO, "src/main.rs", vec![]);
+        logger.log("db debug", logging_rs::Level::DEBUG, "src/db.rs", vec![]);
+        logger.log_records(&[
+            logging_rs::Record::new(logging_rs::Level::DEBUG, "batch debug", "src/main.rs", vec![]),
+            logging_rs::Record::new(logging_rs::Level::WARN, "batch warn", "src/main.rs", vec![])
+        ]);
+
+        assert_eq!(collector.messages(), vec!["main info".to_owned(), "db debug".to_owned(), "batch warn".to_owned()]);
+    }
+
+    #[test]
+    fn test_from_env() {
+        std::env::remove_var("LOGGING_RS");
+        std::env::set_var("RUST_LOG", "error");
+        assert_eq!(logging_rs::Logger::from_env().filter.min_level(), Some(logging_rs::Level::ERROR));
+
+        std::env::set_var("LOGGING_RS", "warn");
+        assert_eq!(logging_rs::Logger::from_env().filter.min_level(), Some(logging_rs::Level::WARN));
+
+        std::env::remove_var("LOGGING_RS");
+        std::env::remove_var("RUST_LOG");
+        assert_eq!(logging_rs::Logger::from_env().filter, logging_rs::filter::EnvFilter::default());
+    }
+
+    #[test]
+    fn test_directives() {
+        let directives: logging_rs::filter::Directives = logging_rs::filter::Directives::parse("warn, db=TRACE,hyper=off,tokio=,mio,a=b=c,x=loud");
+
+        assert_eq!(directives.filter.level_for("src/main.rs"), Some(logging_rs::Level::WARN));
+        assert_eq!(directives.filter.level_for("src/db/pool.rs"), Some(logging_rs::Level::DEBUG));
+        assert_eq!(directives.filter.level_for("/registry/hyper-1.2.0/src/lib.rs"), Some(logging_rs::filter::OFF));
+        assert_eq!(directives.filter.level_for("/registry/tokio-1.0.0/src/lib.rs"), Some(logging_rs::Level::DEBUG));
+        assert_eq!(directives.filter.level_for("/registry/mio-1.0.0/src/lib.rs"), Some(logging_rs::Level::DEBUG));
+        assert_eq!(directives.invalid, vec!["a=b=c".to_owned(), "x=loud".to_owned()]);
+        assert_eq!(directives.pattern, None);
+
+        let directives: logging_rs::filter::Directives = logging_rs::filter::Directives::parse("db=info");
+        assert!(!directives.filter.is_enabled(logging_rs::Level::FATAL, "src/main.rs"));
+
+        let directives: logging_rs::filter::Directives = logging_rs::filter::Directives::parse("");
+        assert_eq!(directives.filter.min_level(), Some(logging_rs::Level::ERROR));
+
+        let directives: logging_rs::filter::Directives = logging_rs::filter::Directives::parse("info/a/b");
+        assert_eq!(directives.filter.min_level(), Some(logging_rs::Level::ERROR));
+        assert_eq!(directives.invalid, vec!["info/a/b".to_owned()]);
+    }
+
+    #[test]
+    fn test_directives_filter() {
+        let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse("warn,db=info").unwrap();
+        let directives: logging_rs::filter::Directives = logging_rs::filter::Directives::parse("warn,db=info");
+
+        assert_eq!(directives.filter, filter);
+
+        for level in [logging_rs::Level::INFO, logging_rs::Level::WARN] {
+            assert_eq!(directives.filter.is_enabled(level, "/registry/hyper-1.2.0/src/lib.rs"), filter.is_enabled(level, "/registry/hyper-1.2.0/src/lib.rs"));
+        }
+
+        let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse("db=info,tokio=").unwrap();
+        let directives: logging_rs::filter::Directives = logging_rs::filter::Directives::parse("db=info,tokio=");
+
+        assert_eq!(directives.filter, filter);
+        assert!(!filter.is_enabled(logging_rs::Level::FATAL, "/registry/hyper-1.2.0/src/lib.rs"));
+        assert!(filter.is_enabled(logging_rs::Level::DEBUG, "/registry/tokio-1.0.0/src/lib.rs"));
+    }
+
+    #[test]
+    fn test_directives_pattern() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![])
+            .with_directives(logging_rs::filter::Directives::parse("info/user=admin"));
+        logger.add_sink(collector.clone());
+
+        logger.log("Login by user={{user}}", logging_rs::Level::INFO, "src/main.rs", vec![("user", "admin".to_owned())]);
+        logger.log("Login by user={{user}}", logging_rs::Level::INFO, "src/main.rs", vec![("user", "guest".to_owned())]);
+        logger.log("Debug user=admin", logging_rs::Level::DEBUG, "src/main.rs", vec![]);
+
+        assert_eq!(collector.messages(), vec!["Login by user={{user}}".to_owned()]);
+    }
+
+    #[test]
+    fn test_wildcards() {
+        let filter: logging_rs::filter::EnvFilter = logging_rs::filter::EnvFilter::parse("hyper::*=off,*_worker=error").unwrap();
+
+        assert_eq!(filter.level_for("/registry/hyper-1.2.0/src/client/conn.rs"), Some(logging_rs::filter::OFF));
+        assert_eq!(filter.level_for("/registry/hyper-1.2.0/src/lib.rs"), None);
+        assert_eq!(filter.level_for("src/mail_worker.rs"), Some(logging_rs::Level::ERROR));
+        assert_eq!(filter.level_for("src/worker.rs"), None);
+    }
+
+    #[test]
+    fn test_module_rules() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![])
+            .with_level(logging_rs::Level::INFO)
+            .with_module_level("my_app::payments", logging_rs::Level::DEBUG)
+            .with_module_silenced("hyper");
+        logger.add_sink(collector.clone());
+
+        assert_eq!(logger.level, logging_rs::Level::DEBUG);
+
+        logger.log("app debug", logging_rs::Level::DEBUG, "crates/my_app/src/main.rs", vec![]);
+        logger.log("payments debug", logging_rs::Level::DEBUG, "crates/my_app/src/payments/mod.rs", vec![]);
+        logger.log("hyper error", logging_rs::Level::ERROR, "/registry/hyper-1.2.0/src/lib.rs", vec![]);
+        logger.log("app info", logging_rs::Level::INFO, "crates/my_app/src/main.rs", vec![]);
+
+        assert_eq!(collector.messages(), vec!["payments debug".to_owned(), "app info".to_owned()]);
+    }
+
+    #[test]
+    fn test_closure_filters() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+        logger.add_filter(|record: &logging_rs::Record| record.argument("tenant") != Some("load-test"));
+        logger.add_named_filter("maintenance", |_record: &logging_rs::Record| false);
+
+        logging_rs::info!(logger, "maintenance");
+
+        assert!(logger.remove_filter("maintenance"));
+        assert!(!logger.remove_filter("maintenance"));
+
+        logger.log("tenant {{tenant}}", logging_rs::Level::INFO, "src/main.rs", vec![("tenant", "load-test".to_owned())]);
+        logger.log("tenant {{tenant}}", logging_rs::Level::INFO, "src/main.rs", vec![("tenant", "acme".to_owned())]);
+        logging_rs::info!(logger, "static");
+
+        assert_eq!(collector.messages(), vec!["tenant {{tenant}}".to_owned(), "static".to_owned()]);
+    }
+
+    #[test]
+    fn test_static_level() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        logging_rs::debug!(logger, "debug");
+        logging_rs::debug!(logger, "debug {{value}}", value = 1);
+        logging_rs::error!(logger, "error", error = std::fmt::Error);
+        logging_rs::message!(logger, "message");
+        logger.log("direct", logging_rs::Level::DEBUG, "src/main.rs", vec![]);
+
+        let mut expected: Vec<String> = vec![];
+
+        if logging_rs::STATIC_LEVEL <= logging_rs::Level::DEBUG {
+            expected.extend(["debug".to_owned(), "debug {{value}}".to_owned()]);
+        }
+
+        if logging_rs::STATIC_LEVEL <= logging_rs::Level::ERROR {
+            expected.push("error".to_owned());
+        }
+
+        if logging_rs::STATIC_LEVEL <= logging_rs::Level::MESSAGE {
+            expected.push("message".to_owned());
+        }
+
+        expected.push("direct".to_owned());
+
+        assert_eq!(collector.messages(), expected);
+    }
+
+    #[test]
+    fn test_error_level() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).with_level(logging_rs::Level::FATAL);
+        logger.add_sink(collector.clone());
+
+        logging_rs::error!(logger, "error {{value}}", error = std::fmt::Error, value = 1);
+
+        assert!(collector.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "max_level_off")]
+    fn test_max_level_off() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        logging_rs::error!(logger, "error", error = std::fmt::Error);
+        logging_rs::error!(logger, "error {{value}}", error = std::fmt::Error, value = 1);
+        logging_rs::fatal!(logger, "fatal");
+
+        assert_eq!(collector.0.lock().unwrap().is_empty(), logging_rs::STATIC_LEVEL > logging_rs::Level::FATAL);
+    }
+}
@@ -0,0 +1,63 @@
+// logging-rs filter panic tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+// Kept in its own test binary, as it installs a global error handler receiving the errors of all tests.
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::common::Collector;
+
+    #[test]
+    fn test_panicking_filter() {
+        let reported: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(vec![]));
+        let handler_reported: Arc<Mutex<Vec<String>>> = Arc::clone(&reported);
+
+        logging_rs::errors::set_handler(move |error: &logging_rs::errors::Error, details: &str| {
+            handler_reported.lock().unwrap().push(format!("{}: {}", error.name, details));
+        });
+
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+        logger.add_filter(|_record: &logging_rs::Record| panic!("Broken filter"));
+
+        logger.log("passed", logging_rs::Level::INFO, "src/main.rs", vec![]);
+
+        logging_rs::errors::reset_handler();
+
+        assert_eq!(collector.messages(), vec!["passed".to_owned()]);
+        assert_eq!(*reported.lock().unwrap(), vec!["Filter error: Broken filter".to_owned()]);
+    }
+}
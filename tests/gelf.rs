@@ -0,0 +1,140 @@
+// logging-rs gelf tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_message() {
+        let record: logging_rs::Record = logging_rs::Record::new(
+            logging_rs::Level::ERROR,
+            "Request {{id}} failed\n{{trace}}",
+            "src/main.rs",
+            vec![("id", "7".to_owned()), ("trace", "at main".to_owned()), ("user name", "alice".to_owned())]
+        );
+
+        let message: logging_rs::json::Value = logging_rs::gelf::message(&record, "web-1");
+        let field = |name: &str| -> Option<String> { message.get(name).and_then(|value: &logging_rs::json::Value| value.as_str()).map(|value: &str| value.to_owned()) };
+
+        assert_eq!(field("version").as_deref(), Some("1.1"));
+        assert_eq!(field("host").as_deref(), Some("web-1"));
+        assert_eq!(field("short_message").as_deref(), Some("Request 7 failed"));
+        assert_eq!(field("full_message").as_deref(), Some("Request 7 failed\nat main"));
+        assert_eq!(message.get("level"), Some(&logging_rs::json::Value::NUMBER("3".to_owned())));
+        assert_eq!(
+            message.get("timestamp"),
+            Some(&logging_rs::json::Value::NUMBER(format!("{}.{:03}", record.timestamp.timestamp(), record.timestamp.timestamp_subsec_millis())))
+        );
+        assert_eq!(field("_level_name").as_deref(), Some("ERROR"));
+        assert_eq!(field("_path").as_deref(), Some("src/main.rs"));
+        assert_eq!(field("_uuid"), Some(logging_rs::ids::to_uuid(record.id)));
+        assert_eq!(field("_id"), None);
+        assert_eq!(field("__id").as_deref(), Some("7"));
+        assert_eq!(field("_user_name").as_deref(), Some("alice"));
+
+        let single: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Single line", "src/main.rs", vec![]);
+        assert_eq!(logging_rs::gelf::message(&single, "web-1").get("full_message"), None);
+
+        let line: String = logging_rs::Formatter::gelf().format_record(&logging_rs::Output::STDOUT, &record);
+        assert!(!line.contains('\n'));
+        assert_eq!(logging_rs::json::Value::parse(&line).unwrap().get("host").and_then(|host: &logging_rs::json::Value| host.as_str()), Some(logging_rs::environment::hostname().as_str()));
+
+        assert_eq!(logging_rs::Level::DEBUG.syslog_severity(), 7);
+        assert_eq!(logging_rs::Level::INFO.syslog_severity(), 6);
+        assert_eq!(logging_rs::Level::FATAL.syslog_severity(), 2);
+        assert_eq!(logging_rs::Level::MESSAGE.syslog_severity(), 5);
+    }
+
+    #[test]
+    fn test_chunks() {
+        assert_eq!(logging_rs::gelf::chunks(b"small", 1420, 1), Some(vec![b"small".to_vec()]));
+
+        let payload: Vec<u8> = (0..100).collect();
+        let chunks: Vec<Vec<u8>> = logging_rs::gelf::chunks(&payload, 52, 0x0102030405060708).unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|chunk: &Vec<u8>| chunk.len() <= 52));
+        assert_eq!(chunks[1][..12], [0x1e, 0x0f, 1, 2, 3, 4, 5, 6, 7, 8, 1, 3]);
+        assert_eq!(chunks.iter().flat_map(|chunk: &Vec<u8>| chunk[12..].to_vec()).collect::<Vec<u8>>(), payload);
+
+        assert!(logging_rs::gelf::chunks(&[0; 129], 13, 1).is_none());
+        assert_eq!(logging_rs::gelf::chunks(&[0; 128], 13, 1).map(|chunks: Vec<Vec<u8>>| chunks.len()), Some(128));
+    }
+
+    #[test]
+    fn test_udp() {
+        let receiver: std::net::UdpSocket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver.set_read_timeout(Some(std::time::Duration::from_secs(5))).unwrap();
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(logging_rs::gelf::Udp::new(&receiver.local_addr().unwrap().to_string()).with_host("web-1").with_chunk_size(64));
+
+        logging_rs::warn!(logger, "Disk {{disk}} almost full", disk = "sda");
+
+        let mut datagrams: Vec<Vec<u8>> = vec![];
+        let mut buffer: [u8; 128] = [0; 128];
+
+        loop {
+            let size: usize = receiver.recv(&mut buffer).unwrap();
+            datagrams.push(buffer[..size].to_vec());
+
+            if datagrams.len() == usize::from(datagrams[0][11]) {
+                break;
+            }
+        }
+
+        assert!(datagrams.len() > 1);
+        assert!(datagrams.iter().all(|datagram: &Vec<u8>| datagram[..2] == logging_rs::gelf::CHUNK_MAGIC && datagram.len() <= 64));
+
+        datagrams.sort_by_key(|datagram: &Vec<u8>| datagram[10]);
+        let payload: String = String::from_utf8(datagrams.iter().flat_map(|datagram: &Vec<u8>| datagram[12..].to_vec()).collect()).unwrap();
+        let message: logging_rs::json::Value = logging_rs::json::Value::parse(&payload).unwrap();
+
+        assert_eq!(message.get("host").and_then(|host: &logging_rs::json::Value| host.as_str()), Some("web-1"));
+        assert_eq!(message.get("short_message").and_then(|message: &logging_rs::json::Value| message.as_str()), Some("Disk sda almost full"));
+        assert_eq!(message.get("_disk").and_then(|disk: &logging_rs::json::Value| disk.as_str()), Some("sda"));
+    }
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn test_udp_gzip() {
+        let receiver: std::net::UdpSocket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver.set_read_timeout(Some(std::time::Duration::from_secs(5))).unwrap();
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(logging_rs::gelf::Udp::new(&receiver.local_addr().unwrap().to_string()).with_host("web-1").with_codec(logging_rs::codec::Gzip::default()));
+
+        logging_rs::warn!(logger, "Disk {{disk}} almost full", disk = "sda");
+
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let size: usize = receiver.recv(&mut buffer).unwrap();
+        let payload: Vec<u8> = logging_rs::codec::Codec::decompress(&logging_rs::codec::Gzip::default(), &buffer[..size]).unwrap();
+        let message: logging_rs::json::Value = logging_rs::json::Value::parse(&String::from_utf8(payload).unwrap()).unwrap();
+
+        assert_eq!(&buffer[..2], [0x1f, 0x8b]);
+        assert_eq!(message.get("short_message").and_then(|message: &logging_rs::json::Value| message.as_str()), Some("Disk sda almost full"));
+    }
+}
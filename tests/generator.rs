@@ -0,0 +1,77 @@
+// logging-rs generator tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "testutil")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct Counter(Arc<AtomicUsize>);
+
+    impl logging_rs::sinks::Sink for Counter {
+        fn write(&self, _formatter: &logging_rs::Formatter, _record: &logging_rs::Record) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_records() {
+        let generator: logging_rs::testutil::generator::Generator = logging_rs::testutil::generator::Generator::new()
+            .with_level(logging_rs::Level::INFO, 3)
+            .with_level(logging_rs::Level::ERROR, 1)
+            .with_field("endpoint", 4)
+            .with_seed(7);
+
+        let records: Vec<logging_rs::Record> = generator.records().take(1000).collect();
+        let errors: usize = records.iter().filter(|record: &&logging_rs::Record| record.level == logging_rs::Level::ERROR).count();
+        let mut endpoints: Vec<&str> = records.iter().filter_map(|record: &logging_rs::Record| record.argument("endpoint")).collect();
+        endpoints.sort_unstable();
+        endpoints.dedup();
+
+        assert!(errors > 150 && errors < 350);
+        assert_eq!(endpoints, vec!["endpoint-0", "endpoint-1", "endpoint-2", "endpoint-3"]);
+        assert_eq!(records[999].argument("sequence"), Some("999"));
+
+        let repeated: Vec<logging_rs::Record> = generator.records().take(1000).collect();
+        assert!(records.iter().zip(&repeated).all(|(first, second): (&logging_rs::Record, &logging_rs::Record)| first.level == second.level && first.arguments == second.arguments));
+    }
+
+    #[test]
+    fn test_run() {
+        let counter: Counter = Counter::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(counter.clone());
+
+        let throughput: logging_rs::testutil::generator::Throughput = logging_rs::testutil::generator::Generator::new().with_rate(200.0).run(&logger, 21);
+
+        assert_eq!(throughput.records, 21);
+        assert_eq!(counter.0.load(Ordering::Relaxed), 21);
+        assert!(throughput.elapsed >= std::time::Duration::from_millis(100));
+    }
+}
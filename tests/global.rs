@@ -0,0 +1,61 @@
+// logging-rs global tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_global_logger() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).with_level(logging_rs::Level::INFO);
+        logger.add_sink(collector.clone());
+
+        logging_rs::info!("Dropped");
+        assert!(logging_rs::global::logger().is_none());
+
+        logging_rs::init(logger).unwrap();
+        assert_eq!(logging_rs::init(logging_rs::Logger::default()).unwrap_err().exit_code, 7);
+
+        logging_rs::debug!("Below level");
+        logging_rs::info!("Static");
+        logging_rs::warn!("With {{value}}", value = 1, "other" = ?2);
+        logging_rs::error!("Trailing comma",);
+
+        let local: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logging_rs::info!(local, "Explicit logger");
+
+        assert_eq!(collector.messages(), vec!["Static".to_owned(), "With {{value}}".to_owned(), "Trailing comma".to_owned()]);
+    }
+}
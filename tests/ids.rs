@@ -0,0 +1,89 @@
+// logging-rs ids tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use logging_rs::ids::IdGenerator;
+
+    struct Fixed;
+
+    impl logging_rs::ids::IdGenerator for Fixed {
+        fn generate(&self, _timestamp: chrono::DateTime<chrono::Utc>) -> u128 {
+            42
+        }
+    }
+
+    #[test]
+    fn test_to_uuid() {
+        assert_eq!(logging_rs::ids::to_uuid(0), "00000000-0000-0000-0000-000000000000");
+        assert_eq!(logging_rs::ids::to_uuid(0x0123456789abcdef0123456789abcdef), "01234567-89ab-cdef-0123-456789abcdef");
+    }
+
+    #[test]
+    fn test_to_ulid() {
+        assert_eq!(logging_rs::ids::to_ulid(0), "00000000000000000000000000");
+        assert_eq!(logging_rs::ids::to_ulid(42), "0000000000000000000000001A");
+    }
+
+    #[test]
+    fn test_random_generator() {
+        let first: u128 = logging_rs::ids::RandomGenerator.generate(chrono::Utc::now());
+        let second: u128 = logging_rs::ids::RandomGenerator.generate(chrono::Utc::now());
+
+        assert_ne!(first, second);
+        assert_eq!(logging_rs::ids::to_uuid(first).chars().nth(14), Some('4'));
+        assert_eq!(first >> 62 & 0x3, 0x2);
+    }
+
+    #[test]
+    fn test_ulid_generator() {
+        let timestamp: chrono::DateTime<chrono::Utc> = chrono::Utc::now();
+        let first: u128 = logging_rs::ids::UlidGenerator.generate(timestamp);
+        let second: u128 = logging_rs::ids::UlidGenerator.generate(timestamp);
+
+        assert_ne!(first, second);
+        assert_eq!(first >> 80, timestamp.timestamp_millis() as u128);
+    }
+
+    #[test]
+    fn test_set_generator() {
+        logging_rs::ids::set_generator(Fixed);
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/lib.rs", vec![]);
+        logging_rs::ids::reset_generator();
+
+        assert_eq!(record.id, 42);
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{uuid}} {{ulid}}", "{{uuid}} {{ulid}}", "");
+        let mut record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Some message", "src/lib.rs", vec![]);
+        record.id = 42;
+
+        assert_eq!(formatter.format_record(&logging_rs::Output::STDOUT, &record), "00000000-0000-0000-0000-00000000002a 0000000000000000000000001A");
+    }
+}
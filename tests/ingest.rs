@@ -0,0 +1,94 @@
+// logging-rs ingest tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_parse_line() {
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        let ingest: logging_rs::ingest::Ingest = logging_rs::ingest::Ingest::new(&logger, "source.log").with_level(logging_rs::Level::DEBUG);
+
+        let plain: logging_rs::Record = ingest.parse_line("Plain {\"line\"}\r\n");
+        assert_eq!(plain.level, logging_rs::Level::DEBUG);
+        assert_eq!(plain.message, "Plain {\"line\"}");
+        assert_eq!(plain.path, "source.log");
+
+        let json: logging_rs::Record = ingest.parse_line(r#"{"ts": "2024-01-02T03:04:05Z", "severity": "error", "msg": "Failed", "file": "app.rs", "user": {"id": 1}, "retry": true}"#);
+        assert_eq!(json.level, logging_rs::Level::ERROR);
+        assert_eq!(json.message, "Failed");
+        assert_eq!(json.path, "app.rs");
+        assert_eq!(json.timestamp.to_rfc3339(), "2024-01-02T03:04:05+00:00");
+        assert_eq!(json.arguments, vec![("user".to_owned(), "{\"id\":1}".to_owned()), ("retry".to_owned(), "true".to_owned())]);
+
+        let unknown: logging_rs::Record = ingest.parse_line(r#"{"level": "verbose", "message": "Unknown level"}"#);
+        assert_eq!(unknown.level, logging_rs::Level::DEBUG);
+    }
+
+    #[test]
+    fn test_ingest() {
+        let collector: Collector = Collector::default();
+        let other: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_named_sink("collector", collector.clone());
+        logger.add_named_sink("other", other.clone());
+
+        let ingest: logging_rs::ingest::Ingest = logging_rs::ingest::Ingest::new(&logger, "stdin")
+            .with_targets(&["collector"])
+            .with_filter(|record: &logging_rs::Record| record.level >= logging_rs::Level::INFO);
+        let input: &[u8] = b"first\n\n{\"level\": \"debug\", \"message\": \"skipped\"}\n{\"level\": \"WARNING\", \"message\": \"second\"}\n";
+
+        assert_eq!(ingest.ingest(input).unwrap(), 2);
+        assert_eq!(
+            collector.records().iter().map(|record: &logging_rs::Record| (record.level, record.message.as_str())).collect::<Vec<(logging_rs::Level, &str)>>(),
+            vec![(logging_rs::Level::INFO, "first"), (logging_rs::Level::WARN, "second")]
+        );
+        assert!(other.records().is_empty());
+    }
+
+    #[test]
+    fn test_ingest_file() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_ingest_file.log");
+        std::fs::write(&path, "one\ntwo\n").unwrap();
+
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        assert_eq!(logging_rs::ingest::Ingest::new(&logger, "file").ingest_file(&path).unwrap(), 2);
+        assert_eq!(collector.records().len(), 2);
+        assert!(logging_rs::ingest::Ingest::new(&logger, "file").ingest_file(path.with_extension("missing")).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+// logging-rs init tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    #[test]
+    fn test_try_init() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        logging_rs::try_init(logger).unwrap();
+        assert_eq!(logging_rs::init_default().unwrap_err().exit_code, 7);
+        logging_rs::global::install_panic_hook();
+
+        let result: std::thread::Result<()> = std::thread::Builder::new()
+            .name("worker".to_owned())
+            .spawn(|| panic!("Worker failed with {}", 42))
+            .unwrap()
+            .join();
+        assert!(result.is_err());
+
+        let records: Vec<logging_rs::Record> = collector.records();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].level, logging_rs::Level::ERROR);
+        assert_eq!(records[0].argument("thread"), Some("worker"));
+        assert_eq!(records[0].argument("payload"), Some("Worker failed with 42"));
+        assert!(records[0].argument("location").unwrap().starts_with(file!()));
+    }
+}
@@ -0,0 +1,146 @@
+// logging-rs json tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_parse() {
+        let value: logging_rs::json::Value = logging_rs::json::Value::parse(r#" {"a": [1, -2.5e3, true, null], "b": "x\"é\n"} "#).unwrap();
+
+        assert_eq!(
+            value,
+            logging_rs::json::Value::OBJECT(vec![
+                ("a".to_owned(), logging_rs::json::Value::ARRAY(vec![
+                    logging_rs::json::Value::NUMBER("1".to_owned()),
+                    logging_rs::json::Value::NUMBER("-2.5e3".to_owned()),
+                    logging_rs::json::Value::BOOL(true),
+                    logging_rs::json::Value::NULL
+                ])),
+                ("b".to_owned(), logging_rs::json::Value::STRING("x\"é\n".to_owned()))
+            ])
+        );
+        assert_eq!(value.get("b").and_then(logging_rs::json::Value::as_str), Some("x\"é\n"));
+        assert_eq!(value.get("c"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let text: &str = r#"{"a":[1,-2.5e3,true,null],"b":"x\"\n"}"#;
+        let value: logging_rs::json::Value = logging_rs::json::Value::parse(text).unwrap();
+
+        assert_eq!(value.to_string(), text);
+        assert_eq!(value.get("a").unwrap().to_text(), "[1,-2.5e3,true,null]");
+        assert_eq!(value.get("b").unwrap().to_text(), "x\"\n");
+        assert_eq!(logging_rs::json::escape("tab\there"), "\"tab\\there\"");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for text in ["", "{", "[1,]", "{\"a\" 1}", "\"open", "true false", "nul"] {
+            let error: logging_rs::errors::Error = logging_rs::json::Value::parse(text).unwrap_err();
+            assert_eq!(error.name, "JSON error");
+            assert_eq!(error.exit_code, 5);
+        }
+    }
+
+    #[test]
+    fn test_pretty() {
+        let mut value: logging_rs::json::Value = logging_rs::json::Value::parse(r#"{"b": [1, {"d": null, "c": []}], "a": "x"}"#).unwrap();
+        value.sort_keys();
+
+        assert_eq!(value.to_pretty(), "{\n  \"a\": \"x\",\n  \"b\": [\n    1,\n    {\n      \"c\": [],\n      \"d\": null\n    }\n  ]\n}");
+        assert_eq!(logging_rs::json::Value::parse(&value.to_pretty()).unwrap(), value);
+    }
+
+    #[test]
+    fn test_record() {
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::WARN, "Hello {{name}}", "tests/json.rs", vec![("name", "world".to_owned())]);
+        let value: logging_rs::json::Value = logging_rs::json::Value::from(&record);
+
+        assert_eq!(value.get("level").and_then(logging_rs::json::Value::as_str), Some("WARNING"));
+        assert_eq!(value.get("message").and_then(logging_rs::json::Value::as_str), Some("Hello world"));
+        assert_eq!(value.get("name").and_then(logging_rs::json::Value::as_str), Some("world"));
+        assert_eq!(value.get("timestamp").and_then(logging_rs::json::Value::as_str), Some(record.timestamp.to_rfc3339().as_str()));
+    }
+
+    #[test]
+    fn test_record_nested() {
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Request", "tests/json.rs", vec![
+            ("headers.host", "example.com".to_owned()),
+            ("tags.0", "api".to_owned()),
+            ("tags.1", "v2".to_owned()),
+            ("headers.accept", "*/*".to_owned()),
+            ("message.extra", "kept".to_owned())
+        ]);
+        let value: logging_rs::json::Value = logging_rs::json::Value::from(&record);
+
+        assert_eq!(value.get("headers").map(ToString::to_string), Some(r#"{"host":"example.com","accept":"*/*"}"#.to_owned()));
+        assert_eq!(value.get("tags").map(ToString::to_string), Some(r#"["api","v2"]"#.to_owned()));
+        assert_eq!(value.get("message.extra").and_then(logging_rs::json::Value::as_str), Some("kept"));
+    }
+
+    #[test]
+    fn test_json_sink() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_json_sink.log");
+        let _ = std::fs::remove_file(&path);
+        let output: logging_rs::Output = logging_rs::Output::FILE { path: path.to_string_lossy().to_string() };
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Message", "tests/json.rs", vec![]);
+
+        let compact: logging_rs::sinks::Json = logging_rs::sinks::Json::new(output.clone());
+        let pretty: logging_rs::sinks::Json = logging_rs::sinks::Json::new(output).with_pretty(true);
+        logging_rs::sinks::Sink::write(&compact, &logging_rs::Formatter::default(), &record);
+        logging_rs::sinks::Sink::write(&pretty, &logging_rs::Formatter::default(), &record);
+
+        let contents: String = std::fs::read_to_string(&path).unwrap();
+        let (first, rest): (&str, &str) = contents.split_once('\n').unwrap();
+
+        assert!(first.starts_with("{\"timestamp\":"));
+        assert!(rest.starts_with("{\n  \"id\": "));
+        assert!(rest.ends_with("\n}\n"));
+
+        let mut compact: logging_rs::json::Value = logging_rs::json::Value::parse(first).unwrap();
+        compact.sort_keys();
+        assert_eq!(logging_rs::json::Value::parse(rest).unwrap(), compact);
+    }
+
+    #[test]
+    fn test_key_order() {
+        let text: &str = r#"{"user": {"name": "a", "id": 1}, "message": "Hi", "level": "INFO"}"#;
+        let order = |order: logging_rs::json::KeyOrder| {
+            let mut value: logging_rs::json::Value = logging_rs::json::Value::parse(text).unwrap();
+            order.apply(&mut value);
+            value.to_string()
+        };
+
+        assert_eq!(order(logging_rs::json::KeyOrder::INSERTION), r#"{"user":{"name":"a","id":1},"message":"Hi","level":"INFO"}"#);
+        assert_eq!(order(logging_rs::json::KeyOrder::ALPHABETICAL), r#"{"level":"INFO","message":"Hi","user":{"id":1,"name":"a"}}"#);
+        assert_eq!(
+            order(logging_rs::json::KeyOrder::PRIORITY { keys: vec!["message".to_owned(), "missing".to_owned(), "user".to_owned()] }),
+            r#"{"message":"Hi","user":{"id":1,"name":"a"},"level":"INFO"}"#
+        );
+    }
+}
@@ -0,0 +1,107 @@
+// logging-rs lazy tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use crate::common::Collector;
+
+    static CREATED: AtomicUsize = AtomicUsize::new(0);
+    static MESSAGES: Mutex<Vec<String>> = Mutex::new(vec![]);
+
+    struct StaticCollector;
+
+    impl logging_rs::sinks::Sink for StaticCollector {
+        fn write(&self, _formatter: &logging_rs::Formatter, record: &logging_rs::Record) {
+            MESSAGES.lock().unwrap().push(record.message.clone());
+        }
+    }
+
+    static LOGGER: logging_rs::lazy::LazyLogger = logging_rs::lazy::LazyLogger::new(|| {
+        CREATED.fetch_add(1, Ordering::SeqCst);
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(StaticCollector);
+
+        logger
+    });
+
+    fn assert_shareable<T: Send + Sync>() {}
+
+    #[test]
+    fn test_shareable() {
+        assert_shareable::<logging_rs::Logger>();
+        assert_shareable::<logging_rs::lazy::LazyLogger>();
+    }
+
+    #[test]
+    fn test_lazy_logger() {
+        let threads: Vec<std::thread::JoinHandle<()>> = (0..4).map(|index: usize| std::thread::spawn(move || {
+            logging_rs::info!(LOGGER, "Thread {{index}}", index = index);
+        })).collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        logging_rs::info!(LOGGER, "Main thread");
+
+        assert!(LOGGER.is_initialized());
+        assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+        assert_eq!(MESSAGES.lock().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_shared_reference() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        let shared: &logging_rs::Logger = &logger;
+        std::thread::scope(|scope: &std::thread::Scope| {
+            scope.spawn(|| logging_rs::warn!(shared, "Scoped"));
+            scope.spawn(|| logging_rs::warn!(pending: shared, "Pending").with("key", "value"));
+        });
+
+        let transaction: logging_rs::transaction::Transaction = shared.transaction();
+        logging_rs::error!(transaction, "Committed");
+        transaction.commit();
+
+        let mut messages: Vec<String> = collector.messages();
+        messages.sort();
+        assert_eq!(messages, vec!["Committed".to_owned(), "Pending".to_owned(), "Scoped".to_owned()]);
+    }
+}
@@ -0,0 +1,90 @@
+// logging-rs levels tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    const NOTICE: logging_rs::Level = logging_rs::Level::custom("NOTICE", 25, "{{color.cyan}}");
+
+    #[test]
+    fn test_ordering() {
+        assert!(logging_rs::Level::DEBUG < logging_rs::Level::INFO);
+        assert!(logging_rs::Level::FATAL < logging_rs::Level::MESSAGE);
+        assert!(logging_rs::Level::INFO < NOTICE);
+        assert!(NOTICE < logging_rs::Level::WARN);
+        assert!(logging_rs::Level::custom("LOW", 20, "") > logging_rs::Level::INFO);
+    }
+
+    #[test]
+    fn test_format() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{level}} {{message}}", "{{level}} {{message}}", "%H");
+
+        assert_eq!(formatter.format(logging_rs::Output::FILE { path: "app.log".to_owned() }, NOTICE, "Message", vec![]), "NOTICE Message");
+        assert_eq!(formatter.format(logging_rs::Output::STDOUT, NOTICE, "Message", vec![]), "\x1b[36mNOTICE\x1b[0m Message");
+    }
+
+    #[test]
+    fn test_logger_level() {
+        let logger: logging_rs::Logger = logging_rs::Logger::default().with_level(NOTICE);
+
+        assert!(!logger.is_enabled(logging_rs::Level::INFO));
+        assert!(logger.is_enabled(NOTICE));
+        assert!(logger.is_enabled(logging_rs::Level::WARN));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", logging_rs::Level::WARN), "WARNING");
+        assert_eq!(format!("{}", NOTICE), "NOTICE");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("warn".parse::<logging_rs::Level>().unwrap(), logging_rs::Level::WARN);
+        assert_eq!(" Fatal ".parse::<logging_rs::Level>().unwrap(), logging_rs::Level::FATAL);
+        assert_eq!(logging_rs::Level::ERROR.to_string().parse::<logging_rs::Level>().unwrap(), logging_rs::Level::ERROR);
+
+        let error: logging_rs::errors::Error = "verbose".parse::<logging_rs::Level>().unwrap_err();
+        assert_eq!(error.name, "Level error");
+        assert!(error.description.contains("`verbose`"));
+        assert!(error.description.contains("DEBUG, INFO, WARN, ERROR, FATAL, MESSAGE"));
+    }
+
+    #[test]
+    fn test_registry() {
+        let audit: logging_rs::Level = logging_rs::Level::custom("AUDIT_TEST", 45, "");
+
+        assert!(logging_rs::levels::register(audit));
+        assert!(!logging_rs::levels::register(logging_rs::Level::custom("warning", 30, "")));
+        assert!(!logging_rs::levels::register(logging_rs::Level::ERROR));
+        assert_eq!(logging_rs::levels::find("audit_test"), Some(audit));
+        assert_eq!(logging_rs::levels::find("Warn"), Some(logging_rs::Level::WARN));
+
+        assert!(logging_rs::levels::unregister("AUDIT_TEST"));
+        assert!(!logging_rs::levels::unregister("AUDIT_TEST"));
+        assert_eq!(logging_rs::levels::find("AUDIT_TEST"), None);
+    }
+}
@@ -53,18 +53,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_level_from_http_status() {
+        assert_eq!(logging_rs::Level::from_http_status(599), logging_rs::Level::ERROR);
+        assert_eq!(logging_rs::Level::from_http_status(400), logging_rs::Level::WARN);
+        assert_eq!(logging_rs::Level::from_http_status(302), logging_rs::Level::INFO);
+    }
+
     #[test]
     fn test_formatter_default() {
         assert_eq!(
             logging_rs::Formatter::default(),
             logging_rs::Formatter {
-                color_format_string: "[{{color.bright_blue}}{{timestamp}}{{end}}] [{{level}}] {{path}}: {{message}}".to_owned(),
-                format_string: "[{{timestamp}}] [{{level}}] {{path}}: {{message}}".to_owned(),
-                timestamp_format: "%Y-%m-%d %H:%M:%S".to_owned()
+                color_format_string: "[{{color.bright_blue}}{{timestamp}}{{end}}] [{{level}}] {{path}}: {{message}}".into(),
+                format_string: "[{{timestamp}}] [{{level}}] {{path}}: {{message}}".into(),
+                timestamp_format: "%Y-%m-%d %H:%M:%S".into(),
+                level_formats: vec![],
+                time_zone: logging_rs::TimeZone::UTC
             }
         );
     }
 
+    #[test]
+    fn test_formatter_static() {
+        static FORMATTER: logging_rs::Formatter = logging_rs::Formatter::DEFAULT;
+
+        assert_eq!(FORMATTER, logging_rs::Formatter::default());
+        assert!(matches!(logging_rs::Formatter::default().format_string, std::borrow::Cow::Borrowed(_)));
+        assert!(matches!(logging_rs::Formatter::from_static("{{message}}", "{{message}}", "%H").timestamp_format, std::borrow::Cow::Borrowed("%H")));
+    }
+
     #[test]
     fn test_formatter_new() {
         assert_eq!(
@@ -74,9 +92,11 @@ mod tests {
                 "%Y-%m-%d %H:%M:%S"
             ),
             logging_rs::Formatter {
-                color_format_string: "[{{color.bright_blue}}{{timestamp}}{{end}}] [{{level}}] {{path}}: {{message}}".to_owned(),
-                format_string: "[{{timestamp}}] [{{level}}] {{path}}: {{message}}".to_owned(),
-                timestamp_format: "%Y-%m-%d %H:%M:%S".to_owned()
+                color_format_string: "[{{color.bright_blue}}{{timestamp}}{{end}}] [{{level}}] {{path}}: {{message}}".into(),
+                format_string: "[{{timestamp}}] [{{level}}] {{path}}: {{message}}".into(),
+                timestamp_format: "%Y-%m-%d %H:%M:%S".into(),
+                level_formats: vec![],
+                time_zone: logging_rs::TimeZone::UTC
             }
         );
     }
@@ -91,13 +111,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_formatter_invalid_timestamp_format() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{timestamp}}", "{{timestamp}}", "%Q");
+        let timestamp: String = formatter.format(logging_rs::Output::default(), logging_rs::Level::default(), "Test", vec![]);
+
+        assert_eq!(timestamp.len(), "2000-01-01T00:00:00.000Z".len());
+        assert!(chrono::DateTime::parse_from_rfc3339(&timestamp).is_ok());
+    }
+
+    #[test]
+    fn test_formatter_time_zone() {
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Test", "src/main.rs", vec![]);
+        let output: logging_rs::Output = logging_rs::Output::FILE { path: "app.log".to_owned() };
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{timestamp}}", "{{timestamp}}", "%Y-%m-%dT%H:%M:%S%:z");
+
+        assert_eq!(formatter.format_record(&output, &record), record.timestamp.format("%Y-%m-%dT%H:%M:%S+00:00").to_string());
+        assert_eq!(
+            formatter.clone().with_time_zone(logging_rs::TimeZone::OFFSET { seconds: -19800 }).format_record(&output, &record),
+            (record.timestamp - chrono::Duration::minutes(330)).format("%Y-%m-%dT%H:%M:%S-05:30").to_string()
+        );
+        assert_eq!(
+            formatter.with_time_zone(logging_rs::TimeZone::LOCAL).format_record(&output, &record),
+            record.timestamp.with_timezone(&chrono::Local).format("%Y-%m-%dT%H:%M:%S%:z").to_string()
+        );
+
+        for name in ["utc", "local", "+02:00", "-05:30"] {
+            assert_eq!(name.parse::<logging_rs::TimeZone>().unwrap().to_string(), name);
+        }
+
+        for name in ["Europe/Berlin", "+2", "+24:00", "-01:60"] {
+            assert_eq!(name.parse::<logging_rs::TimeZone>().unwrap_err().exit_code, 10, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_formatter_level_format() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{level}}: {{message}}", "{{level}}: {{message}}", "")
+            .with_level_format(logging_rs::Level::DEBUG, "ignored", "ignored")
+            .with_level_format(logging_rs::Level::DEBUG, "{{message}}", "{{message}}")
+            .with_level_format(logging_rs::Level::ERROR, "{{color.red}}{{message}}{{end}}\n{{backtrace}}", "{{message}}\n{{backtrace}}");
+        let output: logging_rs::Output = logging_rs::Output::FILE { path: "app.log".to_owned() };
+
+        assert_eq!(formatter.level_formats.len(), 2);
+        assert_eq!(formatter.format(output.clone(), logging_rs::Level::DEBUG, "Debug", vec![]), "Debug");
+        assert_eq!(formatter.format(output.clone(), logging_rs::Level::INFO, "Info", vec![]), "INFO: Info");
+        assert_eq!(formatter.format(logging_rs::Output::STDOUT, logging_rs::Level::ERROR, "Error", vec![]).lines().next(), Some("\x1b[31mError\x1b[0m"));
+
+        let error: String = formatter.format(output, logging_rs::Level::ERROR, "Error", vec![]);
+        assert!(error.starts_with("Error\n"));
+        assert!(!error.contains("{{backtrace}}"));
+    }
+
+    #[test]
+    fn test_formatter_validate() {
+        assert!(logging_rs::Formatter::try_new("", "", "%Y-%m-%dT%H:%M:%S%.3f%:z %-d %% %e").is_ok());
+
+        for (format, specifier) in [("%Q", "%Q"), ("%H:%M %", "%"), ("%-", "%-"), ("%.9X", "%.9X")] {
+            let error: logging_rs::errors::Error = logging_rs::Formatter::try_new("", "", format).unwrap_err();
+
+            assert_eq!(error.exit_code, 9);
+            assert!(error.description.contains(&format!("`{}`", specifier)), "{}", error.description);
+        }
+    }
+
     #[test]
     fn test_logger_default() {
         assert_eq!(
             logging_rs::Logger::default(),
             logging_rs::Logger {
                 formatter: logging_rs::Formatter::default(),
-                writable_list: vec![logging_rs::Output::STDOUT]
+                writable_list: vec![logging_rs::Output::STDOUT],
+                level: logging_rs::Level::DEBUG,
+                sinks: vec![],
+                mirror_fatal: true,
+                categories: vec![],
+                filter: logging_rs::filter::EnvFilter::default(),
+                filters: vec![]
             }
         );
     }
@@ -108,8 +198,257 @@ mod tests {
             logging_rs::Logger::new(logging_rs::Formatter::default(), vec![logging_rs::Output::STDOUT]),
             logging_rs::Logger {
                 formatter: logging_rs::Formatter::default(),
-                writable_list: vec![logging_rs::Output::STDOUT]
+                writable_list: vec![logging_rs::Output::STDOUT],
+                level: logging_rs::Level::DEBUG,
+                sinks: vec![],
+                mirror_fatal: true,
+                categories: vec![],
+                filter: logging_rs::filter::EnvFilter::default(),
+                filters: vec![]
             }
         );
     }
+
+    #[test]
+    fn test_logger_with_level() {
+        struct Counted<'a>(&'a std::cell::Cell<u32>);
+
+        impl std::fmt::Display for Counted<'_> {
+            fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.set(self.0.get() + 1);
+                write!(formatter, "{}", self.0.get())
+            }
+        }
+
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_logger_with_level.log");
+        let _ = std::fs::remove_file(&path);
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "{{message}}", ""), vec![logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }])
+            .with_level(logging_rs::Level::INFO);
+        let count: std::cell::Cell<u32> = std::cell::Cell::new(0);
+
+        logging_rs::debug!(logger, "Debug {{count}}", count = Counted(&count));
+        logging_rs::info!(logger, "Info {{count}}", count = Counted(&count));
+        logger.log_record(&logging_rs::Record::new(logging_rs::Level::DEBUG, "Debug record", "tests/lib.rs", vec![]));
+        logger.log_records(&[
+            logging_rs::Record::new(logging_rs::Level::DEBUG, "Debug batch", "tests/lib.rs", vec![]),
+            logging_rs::Record::new(logging_rs::Level::ERROR, "Error batch", "tests/lib.rs", vec![])
+        ]);
+
+        assert_eq!(count.get(), 1);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Info 1\nError batch\n");
+    }
+
+    #[test]
+    fn test_logger_noop() {
+        struct Counted<'a>(&'a std::cell::Cell<u32>);
+
+        impl std::fmt::Display for Counted<'_> {
+            fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.set(self.0.get() + 1);
+                write!(formatter, "{}", self.0.get())
+            }
+        }
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::noop();
+        let count: std::cell::Cell<u32> = std::cell::Cell::new(0);
+
+        assert!(logger.is_noop());
+        assert!(!logger.is_enabled(logging_rs::Level::FATAL));
+
+        logging_rs::fatal!(logger, "Fatal {{count}}", count = Counted(&count));
+        logging_rs::message!(logger, "Message {{count}}", count = Counted(&count));
+        assert_eq!(count.get(), 0);
+
+        logger.add_sink(logging_rs::Output::STDERR);
+        assert!(!logger.is_noop());
+        assert!(logger.is_enabled(logging_rs::Level::DEBUG));
+    }
+
+    #[test]
+    fn test_logger_presets() {
+        let development: logging_rs::Logger = logging_rs::Logger::development();
+        assert_eq!(development.formatter, logging_rs::Formatter::detailed());
+        assert!(development.is_enabled(logging_rs::Level::DEBUG));
+        assert_eq!(development.writable_list, vec![logging_rs::Output::STDOUT]);
+
+        let production: logging_rs::Logger = logging_rs::Logger::production();
+        assert_eq!(production.formatter, logging_rs::Formatter::json_lines());
+        assert!(!production.is_enabled(logging_rs::Level::DEBUG));
+        assert!(production.is_enabled(logging_rs::Level::INFO));
+        assert_eq!(production.writable_list, vec![logging_rs::Output::STDERR]);
+        assert_eq!(production.sinks.len(), 1);
+    }
+
+    #[test]
+    fn test_level_macros() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_level_macros.log");
+        let _ = std::fs::remove_file(&path);
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "[{{level}}] {{message}}", ""), vec![logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }]);
+
+        logging_rs::message!(logger, "Message {{value}}", "value" = 1);
+        logging_rs::event!(logger, logging_rs::Level::WARN, "Event {{value}}", "value" = 2);
+        logging_rs::log_at!(logger, logging_rs::Level::from_http_status(500), "Status {{status}}", "status" = 500);
+        #[allow(deprecated)]
+        {
+            logging_rs::log!(logger, "Deprecated");
+        }
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[MESSAGE] Message 1\n[WARNING] Event 2\n[ERROR] Status 500\n[MESSAGE] Deprecated\n");
+    }
+
+    #[test]
+    fn test_macro_arguments() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_macro_arguments.log");
+        let _ = std::fs::remove_file(&path);
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "{{message}}", ""), vec![logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }]);
+        let user: (&str, u32) = ("alice", 42);
+
+        logging_rs::info!(logger, "{{literal}} {{user_id}}", "literal" = "old", user_id = user.1 + 1);
+        logging_rs::info!(logger, "{{user}} {{name}}", user = ?user, name = %user.0,);
+        logging_rs::info!(logger, to: "none", "Not written", user_id = 1);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "old 43\n(\"alice\", 42) alice\n");
+    }
+
+    #[test]
+    fn test_pending_record() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_pending_record.log");
+        let _ = std::fs::remove_file(&path);
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "{{message}}", ""), vec![logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }]);
+
+        logging_rs::info!(pending: logger, "Attempt {{attempt}}").with("attempt", 1);
+
+        let pending = logging_rs::warn!(pending: logger, "Attempt {{attempt}} of {{max}}", max = 3);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Attempt 1\n");
+
+        let record: logging_rs::Record = pending.with("attempt", 2).emit();
+        assert_eq!(record.level, logging_rs::Level::WARN);
+        assert_eq!(record.argument("attempt"), Some("2"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Attempt 1\nAttempt 2 of 3\n");
+    }
+
+    #[test]
+    fn test_unwind_safe() {
+        fn assert_unwind_safe<T: std::panic::UnwindSafe + std::panic::RefUnwindSafe>() {}
+
+        assert_unwind_safe::<logging_rs::Logger>();
+        assert_unwind_safe::<logging_rs::Record>();
+        assert_unwind_safe::<logging_rs::Formatter>();
+        assert_unwind_safe::<logging_rs::transaction::Transaction>();
+        assert_unwind_safe::<logging_rs::deferred::DeferredDebug>();
+    }
+
+    #[test]
+    fn test_deprecation() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_deprecation.log");
+        let _ = std::fs::remove_file(&path);
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }]);
+
+        for _ in 0..3 {
+            logging_rs::deprecation!(logger, "old_function()", "use new_function() instead");
+        }
+
+        let contents: String = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.matches("old_function() is deprecated, use new_function() instead").count(), 1);
+        assert!(contents.contains("[WARNING]"));
+    }
+
+    #[test]
+    fn test_mirror_fatal_child() {
+        if std::env::var_os("LOGGING_RS_MIRROR_FATAL").is_none() {
+            return;
+        }
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{message}}", "{{message}}", ""), vec![]);
+        logging_rs::error!(logger, "Not mirrored");
+        logging_rs::fatal!(logger, "Mirrored");
+
+        logger.mirror_fatal = false;
+        logging_rs::fatal!(logger, "Disabled");
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{message}}", "{{message}}", ""), vec![]);
+        logger.add_sink(logging_rs::sinks::leveled(logging_rs::Output::STDERR, logging_rs::Level::FATAL));
+        logging_rs::fatal!(logger, "Sink");
+    }
+
+    #[test]
+    fn test_mirror_fatal() {
+        let output: std::process::Output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["tests::test_mirror_fatal_child", "--exact", "--nocapture"])
+            .env("LOGGING_RS_MIRROR_FATAL", "1")
+            .output()
+            .unwrap();
+
+        let stderr: String = String::from_utf8_lossy(&output.stderr).to_string();
+        assert!(output.status.success());
+        assert!(stderr.contains("Mirrored"));
+        assert!(!stderr.contains("Not mirrored"));
+        assert!(!stderr.contains("Disabled"));
+        assert_eq!(stderr.matches("Sink").count(), 1);
+    }
+
+    #[test]
+    fn test_log_assert() {
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+
+        logging_rs::log_assert!(logger, 1 + 1 == 2, "Math works");
+        assert!(std::panic::catch_unwind(|| logging_rs::log_assert!(logger, 1 + 1 == 3, "Math is broken", "value" = 3)).is_err());
+
+        let evaluated: std::cell::Cell<usize> = std::cell::Cell::new(0);
+        let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| logging_rs::log_assert!(logger, 1 + 1 == 3, { evaluated.set(evaluated.get() + 1); String::from("Got {{value}}") }, "value" = 3))).unwrap_err();
+
+        assert_eq!(evaluated.get(), 1);
+        assert_eq!(payload.downcast_ref::<String>().unwrap(), "Assertion `1 + 1 == 3` failed: Got 3");
+    }
+
+    #[test]
+    fn test_log_ensure() {
+        fn check(logger: &logging_rs::Logger, value: i32) -> Result<i32, logging_rs::errors::Error> {
+            logging_rs::log_ensure!(logger, value > 0, "Value must be positive");
+            Ok(value)
+        }
+
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+
+        assert_eq!(check(&logger, 1), Ok(1));
+        assert_eq!(check(&logger, -1), Err(logging_rs::errors::Error::new("Assertion error", "Assertion `value > 0` failed: Value must be positive", 3)));
+
+        fn limit(logger: &logging_rs::Logger, value: i32) -> Result<i32, logging_rs::errors::Error> {
+            logging_rs::log_ensure!(logger, value < 10, String::from("Value must be smaller than {{max}}"), "max" = 10);
+            Ok(value)
+        }
+
+        assert_eq!(limit(&logger, 20).unwrap_err().description, "Assertion `value < 10` failed: Value must be smaller than 10");
+    }
+
+    #[test]
+    fn test_bail_logged() {
+        fn withdraw(logger: &logging_rs::Logger, balance: u64, amount: u64) -> Result<u64, logging_rs::errors::Error> {
+            if amount > balance {
+                logging_rs::bail_logged!(logger, "Can not withdraw {{amount}} from {{balance}}", amount = amount, balance = balance);
+            }
+
+            Ok(balance - amount)
+        }
+
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+
+        assert_eq!(withdraw(&logger, 20, 10), Ok(10));
+        assert_eq!(withdraw(&logger, 10, 20), Err(logging_rs::errors::Error::new("Logged error", "Can not withdraw 20 from 10", 8)));
+    }
+
+    #[test]
+    fn test_return_logged() {
+        fn port(logger: &logging_rs::Logger, value: &str) -> Result<u16, Box<dyn std::error::Error>> {
+            match value.parse() {
+                Ok(port) => Ok(port),
+                Err(error) => logging_rs::return_logged!(logger, error, "Invalid port {{value}}", value = value)
+            }
+        }
+
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+
+        assert_eq!(port(&logger, "80").unwrap(), 80);
+        assert_eq!(port(&logger, "http").unwrap_err().to_string(), "invalid digit found in string");
+    }
 }
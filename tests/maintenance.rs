@@ -0,0 +1,110 @@
+// logging-rs maintenance tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_maintenance_tick() {
+        let maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+        let counter: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+        let task_counter: Arc<AtomicUsize> = Arc::clone(&counter);
+
+        maintenance.add_task("count", move || {
+            task_counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(maintenance.task_names(), vec!["count".to_owned()]);
+        assert_eq!(maintenance.tick(), 1);
+        assert_eq!(maintenance.tick(), 1);
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_maintenance_worker() {
+        let mut maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::new(std::time::Duration::from_millis(1));
+        let counter: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+        let task_counter: Arc<AtomicUsize> = Arc::clone(&counter);
+
+        maintenance.add_task("count", move || {
+            task_counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        maintenance.start();
+        assert!(maintenance.is_running());
+
+        while counter.load(Ordering::SeqCst) == 0 {
+            std::thread::yield_now();
+        }
+
+        maintenance.stop();
+        assert!(!maintenance.is_running());
+    }
+
+    #[test]
+    fn test_maintenance_after_fork() {
+        let mut maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::new(std::time::Duration::from_millis(1));
+        maintenance.after_fork();
+        assert!(!maintenance.is_running());
+
+        maintenance.start();
+        maintenance.after_fork();
+        assert!(maintenance.is_running());
+
+        maintenance.stop();
+        assert!(!maintenance.is_running());
+    }
+
+    #[test]
+    fn test_maintenance_panicking_task() {
+        let reported: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(vec![]));
+        let handler_reported: Arc<Mutex<Vec<String>>> = Arc::clone(&reported);
+
+        logging_rs::errors::set_handler(move |error: &logging_rs::errors::Error, details: &str| {
+            handler_reported.lock().unwrap().push(format!("{}: {}: {}", error.name, error.description, details));
+        });
+
+        let maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::default();
+        let counter: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+        let task_counter: Arc<AtomicUsize> = Arc::clone(&counter);
+
+        maintenance.add_task("retention", || panic!("Disk gone"));
+        maintenance.add_task("count", move || {
+            task_counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(maintenance.tick(), 2);
+        assert_eq!(maintenance.tick(), 2);
+        logging_rs::errors::reset_handler();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+        assert_eq!(reported.lock().unwrap().len(), 2);
+        assert_eq!(reported.lock().unwrap()[0], "Maintenance error: The maintenance task `retention` panicked: Disk gone");
+    }
+}
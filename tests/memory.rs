@@ -0,0 +1,56 @@
+// logging-rs memory tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_budget() {
+        let budget: logging_rs::memory::Budget = logging_rs::memory::Budget::new(100);
+
+        assert!(budget.reserve(60));
+        assert!(budget.reserve(40));
+        assert!(!budget.reserve(1));
+
+        budget.release(60);
+        assert!(budget.reserve(10));
+
+        assert_eq!(budget.stats(), logging_rs::memory::MemoryStats { used: 50, peak: 100, limit: 100, rejected: 1 });
+        budget.release(50);
+
+        // Reservations are also made in the global budget, so the global limit is tested in the same test
+        let budget: logging_rs::memory::Budget = logging_rs::memory::Budget::default();
+        logging_rs::memory::set_global_limit(logging_rs::memory::global().stats().used + 100);
+
+        assert!(budget.reserve(100));
+        assert!(!budget.reserve(1));
+        assert_eq!(budget.stats().rejected, 1);
+        assert_eq!(budget.stats().used, 100);
+
+        budget.release(100);
+        logging_rs::memory::set_global_limit(usize::MAX);
+    }
+}
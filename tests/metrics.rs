@@ -0,0 +1,62 @@
+// logging-rs metrics tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_metrics() {
+        let collector: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+        let logger: logging_rs::Logger = logging_rs::Logger::builder().sink(collector.clone()).build().unwrap();
+
+        logger.count("cache_miss", 3);
+        logger.gauge("queue_length", 4.5);
+        logging_rs::info!(logger, "Not a metric");
+
+        let records: Vec<logging_rs::Record> = collector.records();
+        assert_eq!(records.len(), 3);
+
+        assert_eq!(records[0].formatted_message(), "cache_miss += 3");
+        assert_eq!(records[0].path, "tests/metrics.rs");
+        assert_eq!(records[0].argument("kind"), Some("counter"));
+        assert_eq!(records[1].formatted_message(), "queue_length = 4.5");
+
+        assert_eq!(
+            logging_rs::metrics::Metric::from_record(&records[0]),
+            Some(logging_rs::metrics::Metric { kind: logging_rs::metrics::Kind::COUNTER, name: "cache_miss".to_owned(), value: 3.0 })
+        );
+        assert_eq!(
+            logging_rs::metrics::Metric::from_record(&records[1]),
+            Some(logging_rs::metrics::Metric { kind: logging_rs::metrics::Kind::GAUGE, name: "queue_length".to_owned(), value: 4.5 })
+        );
+        assert_eq!(logging_rs::metrics::Metric::from_record(&records[2]), None);
+
+        let quiet: logging_rs::Logger = logging_rs::Logger::builder().level(logging_rs::Level::WARN).sink(collector.clone()).build().unwrap();
+        quiet.count("cache_miss", 1);
+        assert_eq!(collector.len(), 3);
+    }
+}
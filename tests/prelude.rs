@@ -0,0 +1,46 @@
+// logging-rs prelude tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use logging_rs::prelude::*;
+
+    #[test]
+    fn test_prelude() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_prelude.log");
+        let _ = std::fs::remove_file(&path);
+
+        let logger: Logger = Logger::new(Formatter::new("", "[{{level}}] {{message}}", ""), vec![Output::FILE { path: path.to_string_lossy().to_string() }]);
+        let record: Record = Record::new(Level::WARN, "Encoded", "src/lib.rs", vec![]);
+
+        info!(logger, "Hello {{name}}", "name" = "world");
+        error!(logger, "Failure");
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[INFO] Hello world\n[ERROR] Failure\n");
+        assert_eq!(logger.writable_list[0].encode(&logger.formatter, &record), "[WARNING] Encoded");
+    }
+}
@@ -0,0 +1,64 @@
+// logging-rs python tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "python")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn test_python() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_python.log");
+        let _ = std::fs::remove_file(&path);
+
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py: Python<'_>| {
+            let module: Py<PyModule> = pyo3::wrap_pymodule!(logging_rs::python::logging_rs)(py).extract(py).unwrap();
+            let locals: Bound<'_, PyDict> = PyDict::new(py);
+            locals.set_item("logging_rs", module).unwrap();
+            locals.set_item("path", path.to_string_lossy().to_string()).unwrap();
+
+            py.run(pyo3::ffi::c_str!(r#"
+formatter = logging_rs.Formatter(format_string="[{{level}}] {{path}}: {{message}}")
+logger = logging_rs.Logger(formatter, stdout=False, files=[path])
+
+logger.info("Hello from {{language}}", language="Python")
+logger.log("Computed {{count}}", logging_rs.Level.WARN, path="main.py", count=3)
+
+logger.level = logging_rs.Level.WARN
+logger.info("Hidden")
+assert logger.level == logging_rs.Level.WARN
+assert logging_rs.Logger(level=logging_rs.Level.ERROR, stdout=False).level == logging_rs.Level.ERROR
+
+assert logger.formatter.timestamp_format == "%Y-%m-%d %H:%M:%S"
+"#), None, Some(&locals)).unwrap();
+        });
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[INFO] <string>: Hello from Python\n[WARNING] main.py: Computed 3\n");
+    }
+}
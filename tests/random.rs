@@ -0,0 +1,59 @@
+// logging-rs random tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use logging_rs::random::RandomSource;
+
+    #[test]
+    fn test_seeded_random() {
+        let first: logging_rs::random::SeededRandom = logging_rs::random::SeededRandom::new(42);
+        let second: logging_rs::random::SeededRandom = logging_rs::random::SeededRandom::new(42);
+
+        let values: Vec<u64> = (0..4).map(|_| first.next_u64()).collect();
+
+        assert_eq!(values, (0..4).map(|_| second.next_u64()).collect::<Vec<u64>>());
+        assert_ne!(values[0], values[1]);
+        assert_ne!(values[0], logging_rs::random::SeededRandom::new(43).next_u64());
+    }
+
+    #[test]
+    fn test_source() {
+        logging_rs::random::set_source(logging_rs::random::SeededRandom::new(7));
+        let first: u128 = logging_rs::ids::IdGenerator::generate(&logging_rs::ids::RandomGenerator, chrono::Utc::now());
+
+        logging_rs::random::set_source(logging_rs::random::SeededRandom::new(7));
+        assert_eq!(logging_rs::ids::IdGenerator::generate(&logging_rs::ids::RandomGenerator, chrono::Utc::now()), first);
+
+        logging_rs::random::set_source(|| u64::MAX);
+        assert_eq!(logging_rs::random::next_u64(), u64::MAX);
+
+        logging_rs::random::reset_source();
+        assert_ne!(logging_rs::random::next_u64(), logging_rs::random::next_u64());
+    }
+}
@@ -0,0 +1,79 @@
+// logging-rs regex tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "regex")]
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    fn collector(logger: &mut logging_rs::Logger) -> Collector {
+        let collector: Collector = Collector::default();
+        logger.add_sink(collector.clone());
+
+        collector
+    }
+
+    #[test]
+    fn test_message_filter() {
+        let filter: logging_rs::filter::MessageFilter = logging_rs::filter::MessageFilter::new()
+            .with_include(logging_rs::filter::Regex::new("^(Order|Ping)").unwrap())
+            .with_exclude(logging_rs::filter::Regex::new("^Ping").unwrap());
+
+        assert!(filter.accepts(&logging_rs::Record::new(logging_rs::Level::INFO, "Order placed", "src/main.rs", vec![])));
+        assert!(!filter.accepts(&logging_rs::Record::new(logging_rs::Level::INFO, "Ping received", "src/main.rs", vec![])));
+        assert!(!filter.accepts(&logging_rs::Record::new(logging_rs::Level::INFO, "User created", "src/main.rs", vec![])));
+    }
+
+    #[test]
+    fn test_formatted() {
+        let pattern: logging_rs::filter::Regex = logging_rs::filter::Regex::new("user=guest").unwrap();
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "user={{user}}", "src/main.rs", vec![("user", "guest".to_owned())]);
+
+        assert!(logging_rs::filter::MessageFilter::new().with_exclude(pattern.clone()).accepts(&record));
+        assert!(!logging_rs::filter::MessageFilter::new().with_exclude(pattern).with_formatted(true).accepts(&record));
+    }
+
+    #[test]
+    fn test_logger() {
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).with_message_filter(
+            logging_rs::filter::MessageFilter::new().with_exclude(logging_rs::filter::Regex::new("^Connection reset").unwrap())
+        );
+        let collector: Collector = collector(&mut logger);
+
+        logging_rs::info!(logger, "Connection reset by peer");
+        logging_rs::info!(logger, "Request handled");
+
+        assert_eq!(collector.messages(), vec!["Request handled".to_owned()]);
+    }
+}
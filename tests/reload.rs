@@ -0,0 +1,87 @@
+// logging-rs reload tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    fn collecting(collector: &logging_rs::testutil::capture::Capture, level: logging_rs::Level) -> logging_rs::Logger {
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).with_level(level);
+        logger.add_named_sink("collector", collector.clone());
+        logger
+    }
+
+    #[test]
+    fn test_replace() {
+        let collector: logging_rs::testutil::capture::Capture = logging_rs::testutil::capture::Capture::new();
+        let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::new(collecting(&collector, logging_rs::Level::INFO));
+        let clone: logging_rs::reload::Reloadable = logger.clone();
+
+        logging_rs::debug!(logger, "first debug");
+        logging_rs::info!(logger, "first {{value}}", "value" = 1);
+
+        let previous: Arc<logging_rs::Logger> = clone.replace(collecting(&collector, logging_rs::Level::DEBUG));
+        assert_eq!(previous.level, logging_rs::Level::INFO);
+        assert_eq!(logger.generation(), 1);
+
+        logging_rs::debug!(logger, "second debug");
+        logging_rs::info!(logger, to: "collector", "second targeted");
+        logging_rs::info!(logger, "static");
+
+        assert_eq!(collector.messages(), vec!["first 1".to_owned(), "second debug".to_owned(), "second targeted".to_owned(), "static".to_owned()]);
+    }
+
+    #[cfg(feature = "config-json")]
+    #[test]
+    fn test_watcher() {
+        let path: std::path::PathBuf = std::env::temp_dir().join(format!("logging_rs_test_reload_{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"level": "warn", "outputs": [{"type": "stderr"}]}"#).unwrap();
+
+        let logger: logging_rs::reload::Reloadable = logging_rs::reload::Reloadable::default();
+        let watcher: logging_rs::reload::Watcher = logging_rs::reload::Watcher::new(logger.clone(), &path);
+
+        assert!(watcher.check().unwrap());
+        assert!(!watcher.check().unwrap());
+        assert_eq!(logger.current().level, logging_rs::Level::WARN);
+        assert_eq!(logger.current().writable_list, vec![logging_rs::Output::STDERR]);
+
+        std::fs::write(&path, r#"{"level": "debug", "outputs": [{"type": "stderr"}, {"type": "stdout"}]}"#).unwrap();
+        assert!(watcher.check().unwrap());
+        assert!(logger.is_enabled(logging_rs::Level::DEBUG));
+        assert_eq!(logger.generation(), 2);
+
+        std::fs::write(&path, r#"{"level": "verbose"}"#).unwrap();
+        assert!(watcher.check().is_err());
+        assert!(!watcher.check().unwrap());
+        assert!(logger.is_enabled(logging_rs::Level::DEBUG));
+        assert_eq!(logger.generation(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(watcher.check().is_err());
+    }
+}
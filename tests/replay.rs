@@ -0,0 +1,72 @@
+// logging-rs replay tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<(logging_rs::Record, std::time::Instant)>>>);
+
+    impl logging_rs::sinks::Sink for Collector {
+        fn write(&self, _formatter: &logging_rs::Formatter, record: &logging_rs::Record) {
+            self.0.lock().unwrap().push((record.clone(), std::time::Instant::now()));
+        }
+    }
+
+    const SESSION: &[u8] = b"{\"time\": \"2024-01-01T12:00:00Z\", \"msg\": \"Started\"}\n\n{\"time\": \"2024-01-01T12:00:00.100Z\", \"level\": \"debug\", \"msg\": \"Skipped\"}\n{\"time\": \"2024-01-01T12:00:00.200Z\", \"level\": \"warn\", \"msg\": \"Slow\"}\n";
+
+    #[test]
+    fn test_replay() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        let replay: logging_rs::replay::Replay = logging_rs::replay::Replay::new(
+            logging_rs::ingest::Ingest::new(&logger, "session.log").with_filter(|record: &logging_rs::Record| record.level >= logging_rs::Level::INFO)
+        );
+
+        assert_eq!(replay.replay(SESSION).unwrap(), 2);
+
+        let records: Vec<(logging_rs::Record, std::time::Instant)> = collector.0.lock().unwrap().clone();
+        assert_eq!(records.iter().map(|(record, _)| record.message.as_str()).collect::<Vec<&str>>(), vec!["Started", "Slow"]);
+        assert_eq!(records[1].0.timestamp.to_rfc3339(), "2024-01-01T12:00:00.200+00:00");
+    }
+
+    #[test]
+    fn test_replay_timing() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        let replay: logging_rs::replay::Replay = logging_rs::replay::Replay::new(logging_rs::ingest::Ingest::new(&logger, "session.log")).with_speed(2.0);
+        assert_eq!(replay.replay(SESSION).unwrap(), 3);
+
+        let records: Vec<(logging_rs::Record, std::time::Instant)> = collector.0.lock().unwrap().clone();
+        assert!(records[2].1.duration_since(records[0].1) >= std::time::Duration::from_millis(100));
+    }
+}
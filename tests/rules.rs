@@ -0,0 +1,97 @@
+// logging-rs rules tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    fn record() -> logging_rs::Record {
+        logging_rs::Record::new(logging_rs::Level::INFO, "Request handled", "tests/rules.rs", vec![
+            ("user_id", "42".to_owned()),
+            ("http", r#"{"status": 200, "client": {"ip": "127.0.0.1"}, "tags": ["a"]}"#.to_owned()),
+            ("password", "hunter2".to_owned())
+        ])
+    }
+
+    #[test]
+    fn test_rules() {
+        let rules: logging_rs::rules::Rules = logging_rs::rules::Rules::new(vec![
+            logging_rs::rules::Rule::RENAME { from: "user_id".to_owned(), to: "usr.id".to_owned() },
+            logging_rs::rules::Rule::FLATTEN { separator: ".".to_owned() },
+            logging_rs::rules::Rule::REMOVE { key: "password".to_owned() },
+            logging_rs::rules::Rule::MESSAGE { key: "msg".to_owned() }
+        ]);
+        let record: logging_rs::Record = rules.apply(record());
+
+        assert_eq!(record.message, "");
+        assert_eq!(
+            record.arguments,
+            vec![
+                ("usr.id".to_owned(), "42".to_owned()),
+                ("http.status".to_owned(), "200".to_owned()),
+                ("http.client.ip".to_owned(), "127.0.0.1".to_owned()),
+                ("http.tags".to_owned(), "[\"a\"]".to_owned()),
+                ("msg".to_owned(), "Request handled".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rules_per_sink() {
+        let plain: Collector = Collector::default();
+        let renamed: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(plain.clone());
+        logger.add_sink(logging_rs::rules::Rules::new(vec![logging_rs::rules::Rule::RENAME { from: "user_id".to_owned(), to: "user".to_owned() }]).wrap(renamed.clone()));
+
+        logger.log_record(&record());
+
+        assert_eq!(plain.0.lock().unwrap()[0].argument("user_id"), Some("42"));
+        assert_eq!(renamed.0.lock().unwrap()[0].argument("user"), Some("42"));
+        assert_eq!(renamed.0.lock().unwrap()[0].argument("user_id"), None);
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let rule: logging_rs::rules::Rule = logging_rs::rules::Rule::FINGERPRINT { fields: vec!["user_id".to_owned()] };
+        let first: logging_rs::Record = rule.apply(record());
+        let second: logging_rs::Record = rule.apply(record().with_attachment("body", b"ignored"));
+        let other: logging_rs::Record = rule.apply(logging_rs::Record::new(logging_rs::Level::INFO, "Request handled", "tests/rules.rs", vec![("user_id", "7".to_owned())]));
+
+        assert_eq!(first.argument("fingerprint").map(str::len), Some(16));
+        assert_eq!(first.argument("fingerprint"), second.argument("fingerprint"));
+        assert_ne!(first.argument("fingerprint"), other.argument("fingerprint"));
+        assert_eq!(logging_rs::Record::new(logging_rs::Level::INFO, "", "", vec![]).fingerprint(&[]), "08328807b4eb6fed");
+    }
+}
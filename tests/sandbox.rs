@@ -0,0 +1,73 @@
+// logging-rs sandbox tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_open_file() {
+        let path: String = std::env::temp_dir().join("logging_rs_test_open_file.log").to_str().unwrap().to_owned();
+        let _ = std::fs::remove_file(&path);
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{level}} {{message}}", "{{level}} {{message}}", "%H"), vec![]);
+        logger.add_sink(logging_rs::sandbox::OpenFile::open(&path).unwrap());
+        logger.log("First", logging_rs::Level::INFO, "src/main.rs", vec![]);
+        logger.log("Second", logging_rs::Level::WARN, "src/main.rs", vec![]);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "INFO First\nWARNING Second\n");
+        assert!(logging_rs::sandbox::OpenFile::open("/nonexistent/directory/app.log").is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_sandboxed() {
+        let path: String = std::env::temp_dir().join("logging_rs_test_sandboxed.log").to_str().unwrap().to_owned();
+        let _ = std::fs::remove_file(&path);
+
+        let logger: logging_rs::Logger = logging_rs::Logger::new(
+            logging_rs::Formatter::new("{{message}}", "{{message}}", "%H"),
+            vec![logging_rs::Output::FILE { path: path.clone() }]
+        ).sandboxed().unwrap();
+
+        assert!(logging_rs::sandbox::is_enabled());
+        assert!(logger.writable_list.is_empty());
+
+        logger.log("Sandboxed", logging_rs::Level::INFO, "src/main.rs", vec![]);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Sandboxed\n");
+
+        let report: logging_rs::shutdown::ShutdownReport = logger.shutdown(std::time::Duration::from_secs(1));
+        assert_eq!(report.sinks.len(), 1);
+        assert!(!report.timed_out());
+
+        let mut maintenance: logging_rs::maintenance::Maintenance = logging_rs::maintenance::Maintenance::new(std::time::Duration::from_millis(10));
+        maintenance.start();
+        assert!(!maintenance.is_running());
+
+        logging_rs::sandbox::disable();
+        std::fs::remove_file(&path).unwrap();
+    }
+}
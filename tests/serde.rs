@@ -0,0 +1,143 @@
+// logging-rs serde tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "serde")]
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_output() {
+        let output: logging_rs::Output = logging_rs::Output::FILE { path: "app.log".to_owned() };
+        let json: String = serde_json::to_string(&output).unwrap();
+
+        assert_eq!(json, r#"{"FILE":{"path":"app.log"}}"#);
+        assert_eq!(serde_json::from_str::<logging_rs::Output>(&json).unwrap(), output);
+    }
+
+    #[test]
+    fn test_level() {
+        let notice: logging_rs::Level = logging_rs::Level::custom("SERDE_NOTICE", 25, "");
+
+        assert_eq!(serde_json::to_string(&logging_rs::Level::WARN).unwrap(), r#""WARN""#);
+        assert_eq!(serde_json::from_str::<logging_rs::Level>(r#""INFO""#).unwrap(), logging_rs::Level::INFO);
+        assert!(serde_json::from_str::<logging_rs::Level>(r#""SERDE_NOTICE""#).is_err());
+
+        logging_rs::levels::register(notice);
+        assert_eq!(serde_json::from_str::<logging_rs::Level>(&serde_json::to_string(&notice).unwrap()).unwrap(), notice);
+        logging_rs::levels::unregister("SERDE_NOTICE");
+    }
+
+    #[test]
+    fn test_logger() {
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{message}}", "{{level}} {{message}}", "%H:%M"), vec![logging_rs::Output::STDERR]);
+        logger.enable_category("wire");
+
+        let json: String = serde_json::to_string(&logger).unwrap();
+        assert_eq!(serde_json::from_str::<logging_rs::Logger>(&json).unwrap(), logger);
+    }
+
+    #[test]
+    fn test_defaults() {
+        let logger: logging_rs::Logger = serde_json::from_str(r#"{"formatter": {"timestamp_format": "%H:%M"}}"#).unwrap();
+
+        assert_eq!(logger.writable_list, vec![logging_rs::Output::STDOUT]);
+        assert_eq!(logger.formatter.timestamp_format, "%H:%M");
+        assert_eq!(logger.formatter.format_string, logging_rs::Formatter::default().format_string);
+        assert!(logger.mirror_fatal);
+    }
+
+    #[test]
+    fn test_sinks_skipped() {
+        let mut logger: logging_rs::Logger = logging_rs::Logger::default();
+        logger.add_sink(logging_rs::Output::STDERR);
+
+        let restored: logging_rs::Logger = serde_json::from_str(&serde_json::to_string(&logger).unwrap()).unwrap();
+        assert!(restored.sinks.is_empty());
+    }
+
+    #[test]
+    fn test_output_names() {
+        let outputs: Vec<logging_rs::Output> = serde_json::from_str(r#"["stdout", "STDERR", "file:app.log", {"FILE": {"path": "other.log"}}]"#).unwrap();
+
+        assert_eq!(outputs, vec![
+            logging_rs::Output::STDOUT,
+            logging_rs::Output::STDERR,
+            logging_rs::Output::FILE { path: "app.log".to_owned() },
+            logging_rs::Output::FILE { path: "other.log".to_owned() }
+        ]);
+        assert!(serde_json::from_str::<logging_rs::Output>(r#""syslog""#).is_err());
+        assert!(serde_json::from_str::<logging_rs::Output>(r#""file:""#).is_err());
+    }
+
+    #[test]
+    fn test_formatter() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::compact().with_level_format(logging_rs::Level::ERROR, "{{level}}! {{message}}", "{{level}}! {{message}}");
+        let json: String = serde_json::to_string(&formatter).unwrap();
+
+        assert_eq!(serde_json::from_str::<logging_rs::Formatter>(&json).unwrap(), formatter);
+    }
+
+    #[test]
+    fn test_embedded() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Settings {
+            name: String,
+            level: logging_rs::Level,
+            outputs: Vec<logging_rs::Output>,
+            #[serde(default)]
+            logger: logging_rs::Logger
+        }
+
+        let settings: Settings = serde_json::from_str(r#"{
+            "name": "app",
+            "level": "warning",
+            "outputs": ["stderr", "file:app.log"],
+            "logger": {"level": "INFO", "writable_list": ["stderr"], "mirror_fatal": false}
+        }"#).unwrap();
+
+        assert_eq!(settings.level, logging_rs::Level::WARN);
+        assert_eq!(settings.outputs[1], logging_rs::Output::FILE { path: "app.log".to_owned() });
+        assert_eq!(settings.logger.level, logging_rs::Level::INFO);
+        assert_eq!(settings.logger.writable_list, vec![logging_rs::Output::STDERR]);
+        assert!(!settings.logger.mirror_fatal);
+
+        let json: String = serde_json::to_string(&settings).unwrap();
+        assert_eq!(serde_json::from_str::<Settings>(&json).unwrap(), settings);
+    }
+
+    #[test]
+    fn test_rules() {
+        let rules: logging_rs::rules::Rules = serde_json::from_str(r#"{"rules": [{"RENAME": {"from": "user_id", "to": "user"}}, {"FLATTEN": {"separator": "."}}]}"#).unwrap();
+
+        assert_eq!(
+            rules,
+            logging_rs::rules::Rules::new(vec![
+                logging_rs::rules::Rule::RENAME { from: "user_id".to_owned(), to: "user".to_owned() },
+                logging_rs::rules::Rule::FLATTEN { separator: ".".to_owned() }
+            ])
+        );
+    }
+}
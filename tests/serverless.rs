@@ -0,0 +1,73 @@
+// logging-rs serverless tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_serverless() {
+        std::env::remove_var(logging_rs::serverless::TRACE_ID_VARIABLE);
+        std::env::set_var(logging_rs::serverless::LEVEL_VARIABLE, "trace");
+
+        let logger: logging_rs::Logger = logging_rs::Logger::serverless();
+        assert_eq!(logger.formatter, logging_rs::Formatter::json_lines());
+        assert!(logger.is_enabled(logging_rs::Level::DEBUG));
+        assert!(logger.writable_list.is_empty());
+
+        std::env::set_var(logging_rs::serverless::LEVEL_VARIABLE, "invalid");
+        assert!(!logging_rs::Logger::serverless().is_enabled(logging_rs::Level::DEBUG));
+        std::env::remove_var(logging_rs::serverless::LEVEL_VARIABLE);
+        assert!(logging_rs::Logger::serverless().is_enabled(logging_rs::Level::INFO));
+
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Handling request", "src/main.rs", vec![]);
+        let encode = |record: &logging_rs::Record| -> String {
+            logging_rs::sinks::Sink::encode(&logging_rs::serverless::Lambda, &logging_rs::Formatter::json_lines(), record)
+        };
+
+        assert_eq!(logging_rs::serverless::request_id(), None);
+        assert!(!encode(&record).contains("aws_request_id"));
+
+        logging_rs::serverless::set_request_id(Some("context-id"));
+        assert_eq!(logging_rs::serverless::request_id().as_deref(), Some("context-id"));
+        std::env::set_var(logging_rs::serverless::TRACE_ID_VARIABLE, "Root=1-5759e988-bd862e3fe1be46a994272793");
+        let line: String = encode(&record);
+        assert!(line.contains("\"aws_request_id\":\"context-id\""));
+        assert!(line.contains("\"xray_trace_id\":\"Root=1-5759e988-bd862e3fe1be46a994272793\""));
+        assert!(!line.contains('\n'));
+
+        let explicit: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Handling request", "src/main.rs", vec![("aws_request_id", "explicit-id".to_owned())]);
+        assert_eq!(encode(&explicit).matches("aws_request_id").count(), 1);
+
+        let multiline: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "First\nSecond", "src/main.rs", vec![]);
+        assert!(!logging_rs::sinks::Sink::encode(&logging_rs::serverless::Lambda, &logging_rs::Formatter::new("", "{{message}}", ""), &multiline).contains('\n'));
+
+        logging_rs::serverless::set_request_id(None);
+        std::env::remove_var(logging_rs::serverless::TRACE_ID_VARIABLE);
+        assert_eq!(logging_rs::serverless::request_id(), None);
+        assert!(!encode(&record).contains("aws_request_id"));
+    }
+}
@@ -0,0 +1,124 @@
+// logging-rs shutdown tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    struct Slow;
+
+    impl logging_rs::sinks::Sink for Slow {
+        fn write(&self, _formatter: &logging_rs::Formatter, _record: &logging_rs::Record) {}
+
+        fn flush(&self, _timeout: std::time::Duration) -> logging_rs::sinks::Flushed {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            logging_rs::sinks::Flushed::new(1, 0)
+        }
+    }
+
+    #[test]
+    fn test_shutdown() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{message}}", "{{message}}", ""), vec![]);
+        logger.add_named_sink("console", logging_rs::sinks::coalesced(collector.clone(), 2).with_window(std::time::Duration::from_secs(3600)));
+        logger.add_sink(logging_rs::sinks::budgeted(collector.clone(), 8, logging_rs::sinks::Overflow::DROP));
+
+        for _ in 0..5 {
+            logging_rs::info!(logger, "Burst");
+        }
+        logging_rs::info!(logger, "Way too long for the budget");
+
+        let report: logging_rs::shutdown::ShutdownReport = logger.shutdown(std::time::Duration::from_secs(5));
+
+        assert_eq!(
+            report.sinks,
+            vec![
+                logging_rs::shutdown::SinkReport { name: Some("console".to_owned()), flushed: 2, dropped: 2, timed_out: false },
+                logging_rs::shutdown::SinkReport { name: None, flushed: 0, dropped: 1, timed_out: false }
+            ]
+        );
+        assert_eq!((report.flushed(), report.dropped(), report.timed_out()), (2, 3, false));
+        assert_eq!(logger.shutdown(std::time::Duration::from_secs(5)).dropped(), 0);
+    }
+
+    #[test]
+    fn test_shutdown_timeout() {
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_named_sink("slow", Slow);
+
+        let report: logging_rs::shutdown::ShutdownReport = logger.shutdown(std::time::Duration::from_millis(50));
+
+        assert!(report.timed_out());
+        assert!(report.elapsed < std::time::Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_hooks() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{message}}", "{{message}}", ""), vec![]);
+        logger.add_named_sink("console", logging_rs::sinks::coalesced(collector.clone(), 1).with_window(std::time::Duration::from_secs(3600)));
+
+        logging_rs::errors::set_handler(|_error: &logging_rs::errors::Error, _details: &str| {});
+
+        logger.on_shutdown(|_logger: &logging_rs::Logger| panic!("Hook failed"));
+        logger.on_shutdown(|logger: &logging_rs::Logger| {
+            logging_rs::info!(logger, "Service stopped");
+            logging_rs::info!(logger, "Service stopped");
+        });
+
+        let reports: Vec<logging_rs::shutdown::ShutdownReport> = logging_rs::shutdown::run_hooks(std::time::Duration::from_secs(5));
+        logging_rs::errors::reset_handler();
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!((reports[1].flushed(), reports[1].dropped()), (1, 0));
+        assert_eq!(collector.0.lock().unwrap().len(), 2);
+        assert!(logging_rs::shutdown::run_hooks(std::time::Duration::from_secs(5)).is_empty());
+    }
+
+    #[test]
+    fn test_after_fork() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{message}}", "{{message}}", ""), vec![]);
+        logger.add_sink(logging_rs::sinks::coalesced(collector.clone(), 1).with_window(std::time::Duration::from_secs(3600)));
+        logger.add_sink(logging_rs::sinks::budgeted(collector.clone(), 8, logging_rs::sinks::Overflow::DROP));
+
+        logging_rs::info!(logger, "Parent");
+        logging_rs::info!(logger, "Parent record");
+        logger.after_fork();
+
+        let report: logging_rs::shutdown::ShutdownReport = logger.shutdown(std::time::Duration::from_secs(5));
+        assert_eq!((report.flushed(), report.dropped()), (0, 0));
+        assert_eq!(collector.messages(), vec!["Parent".to_owned(), "Parent".to_owned()]);
+    }
+}
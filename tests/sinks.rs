@@ -0,0 +1,433 @@
+// logging-rs sinks tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::common::Collector;
+
+    #[derive(Clone, Default)]
+    struct StaticCollector(Arc<Mutex<Vec<&'static str>>>, Arc<Mutex<Vec<String>>>);
+
+    impl logging_rs::sinks::Sink for StaticCollector {
+        fn write(&self, _formatter: &logging_rs::Formatter, record: &logging_rs::Record) {
+            self.1.lock().unwrap().push(record.message.clone());
+        }
+
+        fn write_static(&self, _formatter: &logging_rs::Formatter, _level: logging_rs::Level, message: &'static str, _path: &'static str) -> bool {
+            self.0.lock().unwrap().push(message);
+            true
+        }
+    }
+
+    fn record(level: logging_rs::Level, message: &str) -> logging_rs::Record {
+        logging_rs::Record::new(level, message, "tests/sinks.rs", vec![])
+    }
+
+    #[test]
+    fn test_tee() {
+        let first: Collector = Collector::default();
+        let second: Collector = Collector::default();
+        let tee: logging_rs::sinks::Tee = logging_rs::sinks::tee(vec![Box::new(first.clone()), Box::new(second.clone())]);
+
+        logging_rs::sinks::Sink::write(&tee, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "message"));
+
+        assert_eq!(first.messages(), vec!["message".to_owned()]);
+        assert_eq!(second.messages(), vec!["message".to_owned()]);
+    }
+
+    #[test]
+    fn test_filtered() {
+        let collector: Collector = Collector::default();
+        let filtered = logging_rs::sinks::filtered(collector.clone(), |record: &logging_rs::Record| record.message.starts_with("keep"));
+
+        logging_rs::sinks::Sink::write(&filtered, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "keep this"));
+        logging_rs::sinks::Sink::write(&filtered, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "drop this"));
+
+        assert_eq!(collector.messages(), vec!["keep this".to_owned()]);
+    }
+
+    #[test]
+    fn test_leveled() {
+        let collector: Collector = Collector::default();
+        let leveled = logging_rs::sinks::leveled(collector.clone(), logging_rs::Level::WARN);
+
+        logging_rs::sinks::Sink::write(&leveled, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "info"));
+        logging_rs::sinks::Sink::write(&leveled, &logging_rs::Formatter::default(), &record(logging_rs::Level::ERROR, "error"));
+
+        assert_eq!(collector.messages(), vec!["error".to_owned()]);
+    }
+
+    #[test]
+    fn test_mapped() {
+        let collector: Collector = Collector::default();
+        let mapped = logging_rs::sinks::mapped(collector.clone(), |mut record: logging_rs::Record| {
+            record.message = record.message.to_uppercase();
+            record
+        });
+
+        logging_rs::sinks::Sink::write(&mapped, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "message"));
+
+        assert_eq!(collector.messages(), vec!["MESSAGE".to_owned()]);
+    }
+
+    #[test]
+    fn test_formatted() {
+        #[derive(Clone, Default)]
+        struct Lines(Arc<Mutex<Vec<String>>>);
+
+        impl logging_rs::sinks::Sink for Lines {
+            fn write(&self, formatter: &logging_rs::Formatter, record: &logging_rs::Record) {
+                self.0.lock().unwrap().push(formatter.format_record(&logging_rs::Output::FILE { path: String::new() }, record));
+            }
+        }
+
+        let lines: Lines = Lines::default();
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{level}}: {{message}}", "{{level}}: {{message}}", "");
+        let logger: logging_rs::Logger = logging_rs::Logger::builder()
+            .formatter(logging_rs::Formatter::json_lines())
+            .sink(lines.clone())
+            .sink(logging_rs::sinks::formatted(lines.clone(), formatter))
+            .build()
+            .unwrap();
+
+        logging_rs::info!(logger, "Started");
+
+        let lines: Vec<String> = lines.0.lock().unwrap().clone();
+        assert!(lines[0].starts_with('{'));
+        assert_eq!(lines[1], "INFO: Started");
+    }
+
+    #[test]
+    fn test_logger_sinks() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(logging_rs::sinks::leveled(collector.clone(), logging_rs::Level::INFO));
+
+        logging_rs::debug!(logger, "debug");
+        logging_rs::info!(logger, "info");
+
+        assert_eq!(collector.messages(), vec!["info".to_owned()]);
+        assert_eq!(logger.clone(), logger);
+    }
+
+    #[test]
+    fn test_logger_clone() {
+        struct Counter(std::sync::atomic::AtomicUsize);
+
+        impl logging_rs::sinks::Sink for Counter {
+            fn write(&self, _formatter: &logging_rs::Formatter, _record: &logging_rs::Record) {
+                self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
+        }
+
+        let counter: Arc<Counter> = Arc::new(Counter(std::sync::atomic::AtomicUsize::new(0)));
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(counter.clone());
+
+        let mut clone: logging_rs::Logger = logger.clone();
+        clone.add_sink(collector.clone());
+
+        logging_rs::info!(logger, "original");
+        logging_rs::info!(clone, "clone");
+
+        assert!(Arc::ptr_eq(&logger.sinks[0].sink, &clone.sinks[0].sink));
+        assert_eq!(counter.0.load(std::sync::atomic::Ordering::Relaxed), 2);
+        assert_eq!(logger.sinks.len(), 1);
+        assert_eq!(collector.messages(), vec!["clone".to_owned()]);
+    }
+
+    #[test]
+    fn test_logger_targets() {
+        let audit: Collector = Collector::default();
+        let other: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_named_sink("audit", audit.clone());
+        logger.add_named_sink("other", other.clone());
+
+        logging_rs::info!(logger, to: "audit", "audit only");
+        logging_rs::warn!(logger, to: ["audit", "other"], "both {{what}}", "what" = "sinks");
+        logging_rs::error!(logger, "everywhere");
+
+        assert_eq!(audit.messages(), vec!["audit only".to_owned(), "both {{what}}".to_owned(), "everywhere".to_owned()]);
+        assert_eq!(other.messages(), vec!["both {{what}}".to_owned(), "everywhere".to_owned()]);
+    }
+
+    #[test]
+    fn test_budgeted() {
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{message}}", "{{message}}", "");
+
+        let collector: Collector = Collector::default();
+        let truncating = logging_rs::sinks::budgeted(collector.clone(), 10, logging_rs::sinks::Overflow::TRUNCATE);
+        logging_rs::sinks::Sink::write(&truncating, &formatter, &record(logging_rs::Level::INFO, "short"));
+        logging_rs::sinks::Sink::write(&truncating, &formatter, &record(logging_rs::Level::INFO, "a very long message"));
+        assert_eq!(collector.messages(), vec!["short".to_owned(), "a very ...".to_owned()]);
+
+        let collector: Collector = Collector::default();
+        let splitting = logging_rs::sinks::budgeted(collector.clone(), 10, logging_rs::sinks::Overflow::SPLIT);
+        logging_rs::sinks::Sink::write(&splitting, &formatter, &record(logging_rs::Level::INFO, "a very long message"));
+        assert_eq!(collector.messages(), vec!["a very lon".to_owned(), "g message".to_owned()]);
+
+        let collector: Collector = Collector::default();
+        let dropping = logging_rs::sinks::budgeted(collector.clone(), 100, logging_rs::sinks::Overflow::DROP);
+        logging_rs::sinks::Sink::write(&dropping, &formatter, &record(logging_rs::Level::INFO, &"x".repeat(101)));
+        assert_eq!(collector.messages(), vec!["Dropped record of {{size}} bytes exceeding the budget of {{max_size}} bytes".to_owned()]);
+    }
+
+    #[test]
+    fn test_coalesced() {
+        let collector: Collector = Collector::default();
+        let coalesced = logging_rs::sinks::coalesced(collector.clone(), 2).with_window(std::time::Duration::from_secs(3600));
+
+        for message in ["first", "second", "first", "first", "third"] {
+            logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, message));
+        }
+
+        assert_eq!(collector.messages(), vec!["first".to_owned(), "second".to_owned()]);
+
+        logging_rs::sinks::Sink::flush(&coalesced, std::time::Duration::ZERO);
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "fourth"));
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "fifth"));
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "sixth"));
+        drop(coalesced);
+
+        assert_eq!(
+            collector.messages(),
+            vec![
+                "first".to_owned(),
+                "second".to_owned(),
+                "first ({{coalesced}} similar records coalesced)".to_owned(),
+                "third ({{coalesced}} similar records coalesced)".to_owned(),
+                "fourth".to_owned(),
+                "fifth".to_owned(),
+                "sixth ({{coalesced}} similar records coalesced)".to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coalesced_window() {
+        let collector: Collector = Collector::default();
+        let coalesced = logging_rs::sinks::coalesced(collector.clone(), 1).with_window(std::time::Duration::from_millis(20));
+
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "first"));
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "first"));
+        std::thread::sleep(std::time::Duration::from_millis(40));
+        logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "second"));
+
+        assert_eq!(collector.messages(), vec!["first".to_owned(), "first ({{coalesced}} similar records coalesced)".to_owned(), "second".to_owned()]);
+    }
+
+    #[test]
+    fn test_coalesced_memory_limit() {
+        let collector: Collector = Collector::default();
+        let size: usize = record(logging_rs::Level::INFO, "second").memory_size();
+        let coalesced = logging_rs::sinks::coalesced(collector.clone(), 1).with_window(std::time::Duration::from_secs(3600)).with_memory_limit(size);
+
+        for message in ["first", "second", "third", "second"] {
+            logging_rs::sinks::Sink::write(&coalesced, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, message));
+        }
+
+        assert_eq!(coalesced.memory().used, size);
+        assert_eq!(coalesced.memory().rejected, 1);
+        assert_eq!(logging_rs::sinks::Sink::flush(&coalesced, std::time::Duration::ZERO), logging_rs::sinks::Flushed::new(1, 2));
+        assert_eq!(coalesced.memory().used, 0);
+    }
+
+    #[test]
+    fn test_write_static() {
+        let sink: StaticCollector = StaticCollector::default();
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(sink.clone());
+        logger.add_sink(logging_rs::sinks::leveled(collector.clone(), logging_rs::Level::INFO));
+
+        logging_rs::debug!(logger, "static debug");
+        logging_rs::info!(logger, "static info");
+        logging_rs::info!(logger, "with {{value}}", "value" = 1);
+        logging_rs::info!(logger, "placeholder {{path}}");
+
+        assert_eq!(*sink.0.lock().unwrap(), vec!["static debug", "static info"]);
+        assert_eq!(*sink.1.lock().unwrap(), vec!["with {{value}}".to_owned(), "placeholder {{path}}".to_owned()]);
+        assert_eq!(collector.messages(), vec!["static info".to_owned(), "with {{value}}".to_owned(), "placeholder {{path}}".to_owned()]);
+    }
+
+    #[test]
+    fn test_csv() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_csv.csv");
+        let _ = std::fs::remove_file(&path);
+
+        let csv: logging_rs::sinks::Csv = logging_rs::sinks::Csv::new(logging_rs::Output::FILE { path: path.to_string_lossy().to_string() })
+            .with_columns(&["level", "message", "user", "missing"]);
+        let quoted: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::WARN, "Say \"{{word}}\"", "tests/sinks.rs", vec![("word", "hi,\nthere".to_owned()), ("user", "42".to_owned())]);
+
+        assert_eq!(logging_rs::sinks::Sink::encode(&csv, &logging_rs::Formatter::default(), &quoted), "WARNING,\"Say \"\"hi,\nthere\"\"\",42,");
+        assert_eq!(logging_rs::sinks::Sink::encode(&csv.clone().with_delimiter(';'), &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "a,b")), "INFO;a,b;;");
+        assert_eq!(logging_rs::sinks::Sink::encode(&csv, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "{{color.red}}red{{end}} \x1b[1;32mgreen\x1b[0m")), "INFO,red green,,");
+
+        logging_rs::sinks::Sink::write(&csv, &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "first"));
+        logging_rs::sinks::Sink::write_batch(&csv, &logging_rs::Formatter::default(), &[record(logging_rs::Level::INFO, "second")]);
+        logging_rs::sinks::Sink::write(&logging_rs::sinks::Csv::new(logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }), &logging_rs::Formatter::default(), &record(logging_rs::Level::INFO, "third"));
+
+        let text: String = std::fs::read_to_string(&path).unwrap();
+        assert!(text.starts_with("level,message,user,missing\nINFO,first,,\nINFO,second,,\n"), "{}", text);
+        assert_eq!(text.lines().count(), 4);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_logfmt() {
+        let sink: logging_rs::sinks::Logfmt = logging_rs::sinks::Logfmt::new(logging_rs::Output::STDOUT);
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "Hello {{name}}", "src/main.rs", vec![
+            ("name", "world".to_owned()),
+            ("headers.host", "example.com".to_owned()),
+            ("quote", "say \"hi\"".to_owned()),
+            ("empty", String::new())
+        ]);
+        let encoded: String = logging_rs::sinks::Sink::encode(&sink, &logging_rs::Formatter::default(), &record);
+
+        assert_eq!(
+            encoded,
+            format!(r#"timestamp={} level=INFO path=src/main.rs message="Hello world" name=world headers.host=example.com quote="say \"hi\"" empty="""#, record.timestamp.to_rfc3339())
+        );
+
+        let colored: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::INFO, "{{bold}}Done{{end}}", "src/main.rs", vec![]);
+        assert!(logging_rs::sinks::Sink::encode(&sink, &logging_rs::Formatter::default(), &colored).ends_with(" message=Done"));
+    }
+
+    #[test]
+    fn test_plain() {
+        let sink: logging_rs::sinks::Plain = logging_rs::sinks::Plain::new(logging_rs::Output::STDOUT);
+        let record: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::WARN, "plain {{value}}", "src/main.rs", vec![("value", "1".to_owned())]);
+        let encoded: String = logging_rs::sinks::Sink::encode(&sink, &logging_rs::Formatter::compact(), &record);
+
+        assert!(!encoded.contains('\x1b'));
+        assert!(encoded.ends_with(" WARNING plain 1"));
+    }
+
+    #[test]
+    fn test_recent() {
+        let recent: std::sync::Arc<logging_rs::sinks::Recent> = std::sync::Arc::new(logging_rs::sinks::Recent::new(2));
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(recent.clone());
+
+        logging_rs::info!(logger, "first");
+        logging_rs::warn!(logger, "second {{value}}", "value" = 2);
+        logging_rs::error!(logger, "third \"quoted\"");
+
+        let messages: Vec<String> = recent.records().into_iter().map(|record: logging_rs::Record| record.message).collect();
+        assert_eq!(messages, vec!["second {{value}}".to_owned(), "third \"quoted\"".to_owned()]);
+
+        let exported: logging_rs::json::Value = logging_rs::json::Value::parse(&recent.to_json()).unwrap();
+        let logging_rs::json::Value::ARRAY(records) = exported else {
+            panic!("Expected an array");
+        };
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].get("message"), Some(&logging_rs::json::Value::STRING("second 2".to_owned())));
+        assert_eq!(records[1].get("level"), Some(&logging_rs::json::Value::STRING("ERROR".to_owned())));
+        assert_eq!(recent.to_bytes(), recent.to_json().into_bytes());
+
+        recent.clear();
+        assert_eq!(recent.to_json(), "[]");
+    }
+
+    #[test]
+    fn test_rotating() {
+        let directory: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_rotating");
+        std::fs::remove_dir_all(&directory).ok();
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let path: String = directory.join("app.log").to_str().unwrap().to_owned();
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::new("{{message}}", "{{message}}", "");
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(formatter, vec![]);
+        logger.add_sink(logging_rs::sinks::Rotating::new(&path, 10, 2));
+
+        for message in ["first", "second", "third", "fourth"] {
+            logger.log(message, logging_rs::Level::INFO, file!(), vec![]);
+        }
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "fourth\n");
+        assert_eq!(std::fs::read_to_string(format!("{}.1", path)).unwrap(), "third\n");
+        assert_eq!(std::fs::read_to_string(format!("{}.2", path)).unwrap(), "second\n");
+        assert!(!std::path::Path::new(&format!("{}.3", path)).exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_sampled() {
+        let recent: Arc<logging_rs::sinks::Recent> = Arc::new(logging_rs::sinks::Recent::new(1000));
+        let sampled = logging_rs::sinks::sampled(recent.clone(), "endpoint", 10).with_window(std::time::Duration::from_secs(3600));
+        let formatter: logging_rs::Formatter = logging_rs::Formatter::default();
+
+        for index in 0..500 {
+            let endpoint: &str = if index % 100 == 0 { "/rare" } else { "/frequent" };
+            logging_rs::sinks::Sink::write(&sampled, &formatter, &logging_rs::Record::new(logging_rs::Level::INFO, "Request", "src/main.rs", vec![("endpoint", endpoint.to_owned())]));
+        }
+
+        logging_rs::sinks::Sink::write(&sampled, &formatter, &logging_rs::Record::new(logging_rs::Level::INFO, "Without key", "src/main.rs", vec![]));
+
+        let records: Vec<logging_rs::Record> = recent.records();
+        let frequent: Vec<&logging_rs::Record> = records.iter().filter(|record: &&logging_rs::Record| record.argument("endpoint") == Some("/frequent")).collect();
+
+        assert_eq!(records.iter().filter(|record: &&logging_rs::Record| record.argument("endpoint") == Some("/rare")).count(), 5);
+        assert_eq!(records.iter().filter(|record: &&logging_rs::Record| record.argument("endpoint").is_none()).count(), 1);
+        assert!(frequent.len() > 10 && frequent.len() < 200, "{}", frequent.len());
+        assert!(frequent[..10].iter().all(|record: &&logging_rs::Record| record.argument("sample_rate").is_none()));
+        assert!(frequent[10..].iter().all(|record: &&logging_rs::Record| record.argument("sample_rate").is_some()));
+
+        let flushed: logging_rs::sinks::Flushed = logging_rs::sinks::Sink::flush(&sampled, std::time::Duration::ZERO);
+        assert_eq!(flushed.dropped, 495 - frequent.len());
+    }
+
+    #[test]
+    fn test_sampled_max_keys() {
+        let recent: Arc<logging_rs::sinks::Recent> = Arc::new(logging_rs::sinks::Recent::new(1000));
+        let sampled = logging_rs::sinks::sampled(recent.clone(), "user", 1).with_max_keys(2).with_window(std::time::Duration::from_secs(3600));
+
+        for user in ["a", "b", "c", "d", "a"] {
+            logging_rs::sinks::Sink::write(&sampled, &logging_rs::Formatter::default(), &logging_rs::Record::new(logging_rs::Level::INFO, "Login", "src/main.rs", vec![("user", user.to_owned())]));
+        }
+
+        let users: Vec<String> = recent.records().iter()
+            .filter(|record: &&logging_rs::Record| record.argument("sample_rate").is_none())
+            .filter_map(|record: &logging_rs::Record| record.argument("user").map(str::to_owned))
+            .collect();
+
+        assert_eq!(users, vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]);
+    }
+}
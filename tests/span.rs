@@ -0,0 +1,103 @@
+// logging-rs span tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::common;
+
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<String>>>);
+
+    impl logging_rs::sinks::Sink for Collector {
+        fn write(&self, formatter: &logging_rs::Formatter, record: &logging_rs::Record) {
+            self.0.lock().unwrap().push(formatter.format_record(&logging_rs::Output::STDOUT, record));
+        }
+    }
+
+    #[test]
+    fn test_span() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("[{{span}}] {{message}}", "[{{span}}] {{message}}", ""), vec![]);
+        logger.add_sink(collector.clone());
+
+        logging_rs::info!(logger, "Before");
+
+        {
+            let _outer: logging_rs::span::Span = logging_rs::span!(logger, "request");
+            logging_rs::info!(logger, "Outer");
+
+            {
+                let _inner: logging_rs::span::Span = logging_rs::span!(logger, "database");
+                logging_rs::info!(logger, "Inner");
+                assert_eq!(logging_rs::span::current(), Some("request:database".to_owned()));
+            }
+
+            logger.log_static("Static", logging_rs::Level::INFO, "src/main.rs");
+        }
+
+        logging_rs::info!(logger, "After");
+        assert_eq!(logging_rs::span::current(), None);
+
+        assert_eq!(
+            *collector.0.lock().unwrap(),
+            vec![
+                "[] Before".to_owned(),
+                "[request] Entering request".to_owned(),
+                "[request] Outer".to_owned(),
+                "[request:database] Entering request:database".to_owned(),
+                "[request:database] Inner".to_owned(),
+                "[request:database] Exiting request:database".to_owned(),
+                "[request] Static".to_owned(),
+                "[request] Exiting request".to_owned(),
+                "[] After".to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_span_elapsed() {
+        let collector: common::Collector = common::Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        drop(logging_rs::span!(logger, "job"));
+
+        let records: Vec<logging_rs::Record> = collector.records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].argument("span"), Some("job"));
+        assert!(records[1].argument("elapsed_us").is_some());
+    }
+}
@@ -0,0 +1,102 @@
+// logging-rs state tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Default)]
+    struct Sequenced(AtomicU64);
+
+    impl logging_rs::sinks::Sink for Sequenced {
+        fn write(&self, _formatter: &logging_rs::Formatter, _record: &logging_rs::Record) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn save_state(&self) -> Option<logging_rs::json::Value> {
+            Some(logging_rs::json::Value::NUMBER(self.0.load(Ordering::Relaxed).to_string()))
+        }
+
+        fn restore_state(&self, state: &logging_rs::json::Value) {
+            if let logging_rs::json::Value::NUMBER(sequence) = state {
+                self.0.store(sequence.parse().unwrap(), Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn logger(sequenced: &Arc<Sequenced>, teed: &Arc<Sequenced>) -> logging_rs::Logger {
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_named_sink("sequenced", logging_rs::sinks::leveled(sequenced.clone(), logging_rs::Level::INFO));
+        logger.add_named_sink("tee", logging_rs::sinks::tee(vec![Box::new(logging_rs::Output::STDERR), Box::new(teed.clone())]));
+        logger.add_sink(Arc::new(Sequenced::default()));
+        logger
+    }
+
+    #[test]
+    fn test_save_and_restore_state() {
+        let path: std::path::PathBuf = std::env::temp_dir().join(format!("logging_rs_test_state_{}.json", std::process::id()));
+        let (sequenced, teed): (Arc<Sequenced>, Arc<Sequenced>) = (Arc::default(), Arc::default());
+        let first: logging_rs::Logger = logger(&sequenced, &teed);
+
+        assert_eq!(first.restore_state(&path).unwrap(), 0);
+
+        logging_rs::info!(first, "First");
+        logging_rs::debug!(first, "Second");
+        logging_rs::warn!(first, "Third");
+        first.save_state(&path).unwrap();
+
+        let (restored_sequenced, restored_teed): (Arc<Sequenced>, Arc<Sequenced>) = (Arc::default(), Arc::default());
+        let second: logging_rs::Logger = logger(&restored_sequenced, &restored_teed);
+
+        assert_eq!(second.restore_state(&path).unwrap(), 2);
+        assert_eq!(restored_sequenced.0.load(Ordering::Relaxed), 2);
+        assert_eq!(restored_teed.0.load(Ordering::Relaxed), 3);
+
+        std::fs::write(&path, "[1, 2]").unwrap();
+        assert_eq!(second.restore_state(&path).unwrap_err().exit_code, 5);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_built_in_sinks_have_no_state() {
+        let path: std::path::PathBuf = std::env::temp_dir().join(format!("logging_rs_test_state_built_in_{}.json", std::process::id()));
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_named_sink("rotating", logging_rs::sinks::Rotating::new("logging_rs_test_state.log", 1024, 2));
+        logger.add_named_sink("udp", logging_rs::sinks::Udp::new("127.0.0.1:514"));
+        logger.add_named_sink("gelf", logging_rs::gelf::Udp::new("127.0.0.1:12201"));
+        logger.add_named_sink("coalesced", logging_rs::sinks::coalesced(logging_rs::Output::STDERR, 100));
+        logger.save_state(&path).unwrap();
+
+        assert_eq!(logging_rs::json::Value::parse(&std::fs::read_to_string(&path).unwrap()).unwrap(), logging_rs::json::Value::OBJECT(vec![]));
+        assert_eq!(logger.restore_state(&path).unwrap(), 0);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+// logging-rs syslog tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_facility() {
+        for (code, facility) in logging_rs::syslog::Facility::ALL.iter().enumerate() {
+            assert_eq!(usize::from(facility.code()), code);
+        }
+
+        assert_eq!(logging_rs::syslog::Facility::default(), logging_rs::syslog::Facility::USER);
+        assert_eq!(logging_rs::syslog::Facility::LOCAL7.name(), "local7");
+    }
+
+    #[test]
+    fn test_message() {
+        let record: logging_rs::Record = logging_rs::Record::new(
+            logging_rs::Level::ERROR,
+            "Request {{id}} failed",
+            "src/main.rs",
+            vec![("id", "7".to_owned()), ("query", "a=\"[b]\" \\ c".to_owned()), ("user name", "alice".to_owned())]
+        );
+
+        assert_eq!(
+            logging_rs::syslog::message(&record, logging_rs::syslog::Facility::LOCAL4, "web 1", "my server"),
+            format!(
+                "<163>1 {} web_1 my_server {} - [args@32473 id=\"7\" query=\"a=\\\"[b\\]\\\" \\\\ c\" user_name=\"alice\"] \u{feff}Request 7 failed",
+                record.timestamp.to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+                std::process::id()
+            )
+        );
+
+        let empty: logging_rs::Record = logging_rs::Record::new(logging_rs::Level::DEBUG, "Plain", "src/main.rs", vec![]);
+        let message: String = logging_rs::syslog::message(&empty, logging_rs::syslog::Facility::KERN, "", &"x".repeat(60));
+
+        assert!(message.starts_with("<7>1 "));
+        assert!(message.contains(&format!(" - {} ", "x".repeat(48))));
+        assert!(message.ends_with(" - - \u{feff}Plain"));
+
+        let line: String = logging_rs::Formatter::syslog(logging_rs::syslog::Facility::LOCAL4).format_record(&logging_rs::Output::STDOUT, &record);
+        assert!(line.starts_with("<163>1 "));
+        assert!(line.contains(&format!(" {} ", logging_rs::environment::hostname())));
+        assert!(line.ends_with("\u{feff}Request 7 failed"));
+
+        let mut formatter: logging_rs::Formatter = logging_rs::Formatter::DEFAULT;
+        formatter.format_string = "{{record.syslog.daemon}} / {{record.syslog.local0}}".into();
+        let line: String = formatter.format_record(&logging_rs::Output::FILE { path: String::new() }, &empty);
+        assert!(line.starts_with("<31>1 "));
+        assert!(line.contains(" / <135>1 "));
+    }
+
+    #[test]
+    fn test_udp() {
+        let receiver: std::net::UdpSocket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver.set_read_timeout(Some(std::time::Duration::from_secs(5))).unwrap();
+
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::syslog(logging_rs::syslog::Facility::DAEMON), vec![]);
+        logger.add_sink(logging_rs::sinks::Udp::new(&receiver.local_addr().unwrap().to_string()));
+
+        logging_rs::warn!(logger, "Disk {{disk}} almost full", disk = "sda");
+
+        let mut buffer: [u8; 1024] = [0; 1024];
+        let size: usize = receiver.recv(&mut buffer).unwrap();
+        let datagram: String = String::from_utf8(buffer[..size].to_vec()).unwrap();
+
+        assert!(datagram.starts_with("<28>1 "));
+        assert!(datagram.ends_with(" [args@32473 disk=\"sda\"] \u{feff}Disk sda almost full"));
+    }
+}
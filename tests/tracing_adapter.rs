@@ -0,0 +1,80 @@
+// logging-rs tracing adapter tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+#![cfg(feature = "tracing")]
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(logging_rs::tracing_adapter::level(tracing::Level::ERROR), logging_rs::Level::ERROR);
+        assert_eq!(logging_rs::tracing_adapter::level(tracing::Level::INFO), logging_rs::Level::INFO);
+        assert_eq!(logging_rs::tracing_adapter::level(tracing::Level::DEBUG), logging_rs::Level::DEBUG);
+    }
+
+    #[test]
+    fn test_logging_layer() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]).with_level(logging_rs::Level::INFO);
+        logger.add_sink(collector.clone());
+
+        let subscriber = tracing_subscriber::registry().with(logging_rs::tracing_adapter::LoggingLayer::new(logger));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let request: tracing::Span = tracing::info_span!("request", path = "/users", user = tracing::field::Empty);
+            let _request = request.enter();
+            request.record("user", 42);
+
+            let query: tracing::Span = tracing::info_span!("query", path = "users");
+            let _query = query.enter();
+
+            tracing::debug!("Skipped");
+            tracing::warn!(rows = 3, "Slow query");
+        });
+
+        let records: Vec<logging_rs::Record> = collector.records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].level, logging_rs::Level::WARN);
+        assert_eq!(records[0].message, "Slow query");
+        assert_eq!(records[0].argument("target"), Some("tracing_adapter::tests"));
+        assert_eq!(records[0].argument("spans"), Some("request:query"));
+        assert_eq!(records[0].argument("path"), Some("users"));
+        assert_eq!(records[0].argument("user"), Some("42"));
+        assert_eq!(records[0].argument("rows"), Some("3"));
+        assert!(records[0].path.ends_with("tracing_adapter.rs"));
+    }
+}
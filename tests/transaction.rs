@@ -0,0 +1,89 @@
+// logging-rs transaction tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<Vec<String>>>>);
+
+    impl logging_rs::sinks::Sink for Collector {
+        fn write(&self, _formatter: &logging_rs::Formatter, record: &logging_rs::Record) {
+            self.0.lock().unwrap().push(vec![record.message.clone()]);
+        }
+
+        fn write_batch(&self, _formatter: &logging_rs::Formatter, records: &[logging_rs::Record]) {
+            self.0.lock().unwrap().push(records.iter().map(|record| record.message.clone()).collect());
+        }
+    }
+
+    #[test]
+    fn test_transaction() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        let transaction: logging_rs::transaction::Transaction = logger.transaction();
+        logging_rs::info!(transaction, "first");
+        logging_rs::info!(logger, "outside");
+        logging_rs::info!(transaction, "second");
+        transaction.commit();
+
+        assert_eq!(*collector.0.lock().unwrap(), vec![vec!["outside".to_owned()], vec!["first".to_owned(), "second".to_owned()]]);
+    }
+
+    #[test]
+    fn test_transaction_group() {
+        let path: std::path::PathBuf = std::env::temp_dir().join("logging_rs_test_transaction.log");
+        let _ = std::fs::remove_file(&path);
+        let logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("", "{{group}} {{message}}", ""), vec![logging_rs::Output::FILE { path: path.to_string_lossy().to_string() }]);
+
+        let transaction: logging_rs::transaction::Transaction = logger.transaction();
+        let id: String = transaction.id().to_owned();
+        logging_rs::info!(transaction, "first");
+        logging_rs::info!(transaction, "second");
+        drop(transaction);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), format!("{id} first\n{id} second\n"));
+    }
+
+    #[test]
+    fn test_transaction_memory_limit() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        let transaction: logging_rs::transaction::Transaction = logger.transaction().with_memory_limit(0);
+        logging_rs::info!(transaction, "dropped");
+
+        assert_eq!(transaction.memory().rejected, 1);
+        transaction.commit();
+
+        assert!(collector.0.lock().unwrap().is_empty());
+    }
+}
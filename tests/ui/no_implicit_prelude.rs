@@ -0,0 +1,43 @@
+#![no_implicit_prelude]
+
+extern crate logging_rs;
+
+fn ensure(logger: &logging_rs::Logger) -> ::std::result::Result<(), logging_rs::errors::Error> {
+    logging_rs::log_ensure!(logger, 1 + 1 == 2, "Math works", "value" = 2);
+    ::std::result::Result::Ok(())
+}
+
+fn bail(logger: &logging_rs::Logger) -> ::std::result::Result<(), logging_rs::errors::Error> {
+    logging_rs::bail_logged!(logger, "Bailed with {{value}}", "value" = 6);
+}
+
+fn return_error(logger: &logging_rs::Logger) -> ::std::result::Result<(), ::std::io::Error> {
+    logging_rs::return_logged!(logger, ::std::io::Error::new(::std::io::ErrorKind::Other, "closed"), "Returned", "value" = 7);
+}
+
+fn main() {
+    let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::new("{{message}}", "{{message}}", ""), ::std::vec![]);
+    logger.enable_category("wire");
+    let error: ::std::io::Error = ::std::io::Error::new(::std::io::ErrorKind::Other, "disk full");
+
+    logging_rs::debug!(logger, "Debug {{value}}", "value" = 1);
+    logging_rs::info!(logger, "Fields {{id}} {{path}}", id = 1 + 1, path = ?"/", name = %"alice");
+    logging_rs::debug_cat!(logger, "wire", "Wire {{value}}", "value" = 2);
+    logging_rs::info!(logger, to: "audit", "Info");
+    logging_rs::warn!(logger, to: ["audit", "metrics"], "Warning");
+    logging_rs::error!(logger, "Error", error = error, "value" = 3);
+    logging_rs::fatal!(logger, "Fatal");
+    logging_rs::info!(pending: logger, to: "audit", "Pending").with("attempt", 1);
+    logging_rs::log_at!(logger, logging_rs::Level::WARN, "Runtime {{value}}", "value" = 4);
+    logging_rs::message!(logger, "Message");
+    logging_rs::info!("Global {{value}}", "value" = 5);
+    logging_rs::deprecation!(logger, "old()", "use new() instead");
+    logging_rs::log_assert!(logger, true, "Never fails");
+    logging_rs::hexdump!(logger, logging_rs::Level::DEBUG, &[0xde, 0xad, 0xbe, 0xef]);
+    let _span: logging_rs::span::Span = logging_rs::span!(logger, "span");
+    let _global_span: logging_rs::span::Span = logging_rs::span!("global span");
+    let _ = logging_rs::build_info!();
+    let _ = ensure(&logger);
+    let _ = bail(&logger);
+    let _ = return_error(&logger);
+}
@@ -0,0 +1,9 @@
+extern crate logging_rs as logging;
+
+fn main() {
+    let logger: logging::Logger = logging::Logger::new(logging::Formatter::default(), vec![]);
+
+    logging::info!(logger, "Info {{value}}", "value" = 1);
+    logging::error!(logger, "Error", error = std::fmt::Error);
+    logging::deprecation!(logger, "old()", "use new() instead");
+}
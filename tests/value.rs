@@ -0,0 +1,142 @@
+// logging-rs value tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+////////////////////////////////
+// IMPORTS AND USE STATEMENTS //
+////////////////////////////////
+
+mod common;
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Collector;
+
+    struct Celsius(f64);
+
+    impl logging_rs::value::ToValue for Celsius {
+        fn to_value(&self) -> String {
+            format!("{:.1}°C", self.0)
+        }
+    }
+
+    impl std::fmt::Display for Celsius {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "{} degrees", self.0)
+        }
+    }
+
+    fn log_generic<T: std::fmt::Display>(logger: &logging_rs::Logger, value: T) {
+        logging_rs::info!(logger, "{{value}}", "value" = value);
+    }
+
+    #[test]
+    fn test_to_value() {
+        use logging_rs::value::ToValue;
+
+        assert_eq!(std::time::Duration::from_millis(1200).to_value(), "1.2s");
+        assert_eq!(std::time::Duration::from_micros(150).to_value(), "150µs");
+        assert_eq!((std::time::UNIX_EPOCH + std::time::Duration::from_millis(1500)).to_value(), "1970-01-01T00:00:01.500Z");
+        assert_eq!(std::time::UNIX_EPOCH.to_value(), "1970-01-01T00:00:00Z");
+        assert_eq!(std::path::Path::new("/var/log/app.log").to_value(), "/var/log/app.log");
+        assert_eq!(std::path::PathBuf::from("app.log").to_value(), "app.log");
+        assert_eq!(std::ffi::OsString::from("name").to_value(), "name");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_to_value_invalid_utf8() {
+        use logging_rs::value::ToValue;
+        use std::os::unix::ffi::OsStrExt;
+
+        assert_eq!(std::path::Path::new(std::ffi::OsStr::from_bytes(b"/tmp/\xFFlog\xC3")).to_value(), "/tmp/\\xFFlog\\xC3");
+        assert_eq!(std::ffi::OsStr::from_bytes("caf\u{e9}".as_bytes()).to_value(), "caf\u{e9}");
+    }
+
+    #[test]
+    fn test_macro_values() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        let path: std::path::PathBuf = std::path::PathBuf::from("app.log");
+
+        logging_rs::info!(logger, "{{elapsed}} {{path}} {{time}}", "elapsed" = std::time::Duration::from_millis(1200), "path" = path, "time" = std::time::UNIX_EPOCH);
+        logging_rs::info!(logger, "{{path}} {{name}}", "path" = path.as_path(), "name" = "text");
+        logging_rs::info!(logger, "{{temperature}} {{display}} {{number}}", "temperature" = Celsius(21.54), "display" = %Celsius(1.0), "number" = 42);
+        log_generic(&logger, Celsius(2.0));
+
+        assert_eq!(
+            collector.formatted_messages(),
+            vec![
+                "1.2s app.log 1970-01-01T00:00:00Z".to_owned(),
+                "app.log text".to_owned(),
+                "21.5°C 1 degrees 42".to_owned(),
+                "2 degrees".to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_fields() {
+        use logging_rs::value::ToFields;
+
+        let mut headers: std::collections::HashMap<String, Vec<u16>> = std::collections::HashMap::new();
+        headers.insert("ports".to_owned(), vec![80, 443]);
+        headers.insert("empty".to_owned(), vec![]);
+        headers.insert("host".to_owned(), vec![1]);
+
+        let mut fields: Vec<(String, String)> = vec![];
+        headers.to_fields("config", &mut fields);
+        Some("value").to_fields("option", &mut fields);
+        None::<u8>.to_fields("missing", &mut fields);
+        logging_rs::json::Value::parse(r#"{"a": {"b": [true, null]}}"#).unwrap().to_fields("json", &mut fields);
+
+        assert_eq!(fields, vec![
+            ("config.host.0".to_owned(), "1".to_owned()),
+            ("config.ports.0".to_owned(), "80".to_owned()),
+            ("config.ports.1".to_owned(), "443".to_owned()),
+            ("option".to_owned(), "value".to_owned()),
+            ("json.a.b.0".to_owned(), "true".to_owned()),
+            ("json.a.b.1".to_owned(), "null".to_owned())
+        ]);
+    }
+
+    #[test]
+    fn test_macro_fields() {
+        let collector: Collector = Collector::default();
+        let mut logger: logging_rs::Logger = logging_rs::Logger::new(logging_rs::Formatter::default(), vec![]);
+        logger.add_sink(collector.clone());
+
+        let mut headers: std::collections::BTreeMap<&str, &str> = std::collections::BTreeMap::new();
+        headers.insert("host", "example.com");
+
+        logging_rs::info!(logger, "{{headers.host}} {{tags.1}} {{count}}", "headers" = #headers, "tags" = #["api", "v2"], "count" = 2);
+
+        assert_eq!(collector.formatted_messages(), vec!["example.com v2 2".to_owned()]);
+    }
+}
@@ -0,0 +1,67 @@
+// logging-rs xml tests
+// Version: 1.1.0
+
+// Copyright (c) 2023-present ElBe Development.
+
+// Permission is hereby granted, free of charge, to any person obtaining a
+// copy of this software and associated documentation files (the 'Software'),
+// to deal in the Software without restriction, including without limitation
+// the rights to use, copy, modify, merge, publish, distribute, sublicense,
+// and/or sell copies of the Software, and to permit persons to whom the
+// Software is furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED 'AS IS', WITHOUT WARRANTY OF ANY KIND, EXPRESS
+// OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+
+///////////
+// TESTS //
+///////////
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_escape() {
+        assert_eq!(logging_rs::xml::escape("plain"), "plain");
+        assert_eq!(logging_rs::xml::escape("a < b && c > 'd'"), "a &lt; b &amp;&amp; c &gt; &apos;d&apos;");
+        assert_eq!(logging_rs::xml::escape("line\r\n\tnext"), "line&#13;&#10;&#9;next");
+        assert_eq!(logging_rs::xml::escape("bell\u{7}"), "bell\u{fffd}");
+    }
+
+    #[test]
+    fn test_element() {
+        let record: logging_rs::Record = logging_rs::Record::new(
+            logging_rs::Level::WARN,
+            "Say \"{{word}}\"",
+            "src/main.rs",
+            vec![("word", "<hi>\n".to_owned())]
+        ).with_attachment("payload", b"ok");
+
+        let element: String = logging_rs::xml::element(&record);
+        assert_eq!(
+            element,
+            format!(
+                "<record id=\"{}\"><timestamp>{}</timestamp><level>WARNING</level><path>src/main.rs</path><message>Say &quot;&lt;hi&gt;&#10;&quot;</message><arguments><argument name=\"word\">&lt;hi&gt;&#10;</argument><argument name=\"payload\">{}</argument></arguments></record>",
+                logging_rs::ids::to_uuid(record.id),
+                record.timestamp.to_rfc3339(),
+                record.attachments[0].to_hex(logging_rs::attachments::PREVIEW_SIZE)
+            )
+        );
+
+        let mut formatter: logging_rs::Formatter = logging_rs::Formatter::xml();
+        formatter.timestamp_format = "%Y".into();
+
+        let line: String = formatter.format_record(&logging_rs::Output::STDOUT, &record);
+        assert!(!line.contains('\n'));
+        assert!(line.contains(&format!("<timestamp>{}</timestamp>", record.timestamp.format("%Y"))));
+        assert!(line.ends_with("</arguments></record>"));
+    }
+}